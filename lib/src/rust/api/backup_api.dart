@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_backup_connection`, `export_to`, `is_exported_property`, `parse_user_data`, `remove_sidecar_files`, `swap_database`, `validate_backup`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 导出数据库到指定路径
+///
+/// 使用 `VACUUM INTO` 生成一致的快照，WAL 中尚未合并的数据也会包含在内。
+/// 目标文件已存在时会被覆盖
+Future<void> exportDatabase({required String destPath}) =>
+    RustLib.instance.api.crateApiBackupApiExportDatabase(destPath: destPath);
+
+/// 从备份文件导入数据库，替换当前数据库
+///
+/// 导入前会检查文件完整性和迁移版本，来自更新版本应用的备份会被拒绝。
+/// 替换后自动执行迁移，失败时恢复原数据库
+Future<void> importDatabase({required String srcPath}) =>
+    RustLib.instance.api.crateApiBackupApiImportDatabase(srcPath: srcPath);
+
+/// 将收藏、阅读历史、搜索历史、模块列表和应用设置导出为带版本号的 JSON
+Future<String> exportUserData() =>
+    RustLib.instance.api.crateApiBackupApiExportUserData();
+
+/// 导入 export_user_data 导出的 JSON
+///
+/// merge 为 true 时与现有数据合并（导入的设置覆盖同名项，历史保留较新的记录），
+/// 为 false 时先清空现有的设置与历史。整个导入在一个事务中完成
+Future<UserDataImportSummary> importUserData({
+  required String json,
+  required bool merge,
+}) => RustLib.instance.api.crateApiBackupApiImportUserData(
+  json: json,
+  merge: merge,
+);
+
+/// 导入数据中引用但本地未安装的模块
+class MissingModule {
+  final String id;
+  final String name;
+  final String? sourceUrl;
+
+  const MissingModule({required this.id, required this.name, this.sourceUrl});
+
+  @override
+  int get hashCode => id.hashCode ^ name.hashCode ^ sourceUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MissingModule &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          sourceUrl == other.sourceUrl;
+}
+
+/// 用户数据导入结果
+class UserDataImportSummary {
+  final int properties;
+  final int readingHistory;
+  final int searchHistory;
+
+  /// 本地未安装的模块，可通过 source_url 重新安装
+  final List<MissingModule> missingModules;
+
+  const UserDataImportSummary({
+    required this.properties,
+    required this.readingHistory,
+    required this.searchHistory,
+    required this.missingModules,
+  });
+
+  @override
+  int get hashCode =>
+      properties.hashCode ^
+      readingHistory.hashCode ^
+      searchHistory.hashCode ^
+      missingModules.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UserDataImportSummary &&
+          runtimeType == other.runtimeType &&
+          properties == other.properties &&
+          readingHistory == other.readingHistory &&
+          searchHistory == other.searchHistory &&
+          missingModules == other.missingModules;
+}
@@ -0,0 +1,16 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 取消使用该令牌的进行中操作，令牌不存在（未开始或已结束）时返回 false
+///
+/// 令牌由 Flutter 端生成（如 UUID），传给以下操作：
+/// - `get_pictures_cancelable`
+/// - `download_to_file`
+/// - `start_chapter_download`（状态记为 cancelled，已下载的图片保留，可续传）
+bool cancel({required String token}) =>
+    RustLib.instance.api.crateApiCancelApiCancel(token: token);
@@ -41,3 +41,11 @@ String cryptoMd5Bytes({required List<int> data}) =>
 /// SHA256 哈希（字节数组）
 String cryptoSha256Bytes({required List<int> data}) =>
     RustLib.instance.api.crateApiCryptoApiCryptoSha256Bytes(data: data);
+
+/// CRC32 校验和（字节数组，返回 8 位十六进制）
+String cryptoCrc32Bytes({required List<int> data}) =>
+    RustLib.instance.api.crateApiCryptoApiCryptoCrc32Bytes(data: data);
+
+/// Adler32 校验和（字节数组，返回 8 位十六进制）
+String cryptoAdler32Bytes({required List<int> data}) =>
+    RustLib.instance.api.crateApiCryptoApiCryptoAdler32Bytes(data: data);
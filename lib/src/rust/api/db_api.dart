@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `save_encryption_state`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 执行数据库维护（VACUUM + ANALYZE），返回前后的文件大小
+///
+/// 维护期间所有数据库访问都会等待，建议在空闲时（如清理缓存后）调用
+Future<MaintenanceResult> runMaintenance() =>
+    RustLib.instance.api.crateApiDbApiRunMaintenance();
+
+/// 数据库是否已启用 SQLCipher 加密
+Future<bool> isDatabaseEncrypted() =>
+    RustLib.instance.api.crateApiDbApiIsDatabaseEncrypted();
+
+/// 当前构建是否支持数据库加密
+bool isDatabaseEncryptionSupported() =>
+    RustLib.instance.api.crateApiDbApiIsDatabaseEncryptionSupported();
+
+/// 数据库维护结果
+class MaintenanceResult {
+  /// 维护前数据库文件大小（字节）
+  final BigInt sizeBefore;
+
+  /// 维护后数据库文件大小（字节）
+  final BigInt sizeAfter;
+
+  const MaintenanceResult({required this.sizeBefore, required this.sizeAfter});
+
+  @override
+  int get hashCode => sizeBefore.hashCode ^ sizeAfter.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MaintenanceResult &&
+          runtimeType == other.runtimeType &&
+          sizeBefore == other.sizeBefore &&
+          sizeAfter == other.sizeAfter;
+}
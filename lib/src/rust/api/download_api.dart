@@ -0,0 +1,136 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chapter_dir`, `download_pictures`, `fetch_all_pictures`, `image_extension`, `is_cancelled`, `module_download_request`, `save_download`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+/// 流式下载文件到指定路径，并通过 StreamSink 报告进度
+///
+/// 下载失败或通过 `cancel_api::cancel(cancel_token)` 取消时会清理未完成的文件
+Stream<DownloadProgress> downloadToFile({
+  required String url,
+  required Map<String, String> headers,
+  required String destPath,
+  String? cancelToken,
+}) => RustLib.instance.api.crateApiDownloadApiDownloadToFile(
+  url: url,
+  headers: headers,
+  destPath: destPath,
+  cancelToken: cancelToken,
+);
+
+/// 下载整个章节到缓存目录，并通过 StreamSink 报告每页的进度
+///
+/// 已存在的图片文件会被跳过，因此失败或取消后重新调用即可续传
+Stream<ChapterDownload> startChapterDownload({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+  String? cancelToken,
+}) => RustLib.instance.api.crateApiDownloadApiStartChapterDownload(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+  cancelToken: cancelToken,
+);
+
+/// 获取章节下载状态
+Future<ChapterDownload?> getDownloadStatus({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+}) => RustLib.instance.api.crateApiDownloadApiGetDownloadStatus(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+);
+
+/// 列出下载记录，可按模块过滤，按更新时间倒序
+Future<List<ChapterDownload>> listDownloads({String? moduleId}) =>
+    RustLib.instance.api.crateApiDownloadApiListDownloads(moduleId: moduleId);
+
+/// 删除章节下载记录及已下载的文件
+Future<void> deleteDownload({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+}) => RustLib.instance.api.crateApiDownloadApiDeleteDownload(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+);
+
+/// 章节下载记录
+class ChapterDownload {
+  final String moduleId;
+  final String comicId;
+  final String epId;
+
+  /// downloading / completed / failed / cancelled
+  final String status;
+  final int totalPages;
+  final int downloadedPages;
+
+  /// 章节图片保存目录，图片按 0001.jpg、0002.jpg... 顺序命名
+  final String dirPath;
+
+  const ChapterDownload({
+    required this.moduleId,
+    required this.comicId,
+    required this.epId,
+    required this.status,
+    required this.totalPages,
+    required this.downloadedPages,
+    required this.dirPath,
+  });
+
+  @override
+  int get hashCode =>
+      moduleId.hashCode ^
+      comicId.hashCode ^
+      epId.hashCode ^
+      status.hashCode ^
+      totalPages.hashCode ^
+      downloadedPages.hashCode ^
+      dirPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterDownload &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          comicId == other.comicId &&
+          epId == other.epId &&
+          status == other.status &&
+          totalPages == other.totalPages &&
+          downloadedPages == other.downloadedPages &&
+          dirPath == other.dirPath;
+}
+
+/// 下载进度
+class DownloadProgress {
+  /// 已接收字节数
+  final BigInt received;
+
+  /// 总字节数（服务器未返回 Content-Length 时为 None）
+  final BigInt? total;
+
+  const DownloadProgress({required this.received, this.total});
+
+  @override
+  int get hashCode => received.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadProgress &&
+          runtimeType == other.runtimeType &&
+          received == other.received &&
+          total == other.total;
+}
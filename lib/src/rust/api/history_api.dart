@@ -0,0 +1,149 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../modules/types.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+/// 记录阅读进度（每部漫画只保留最近一次阅读的章节和页码）
+Future<void> recordRead({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+  required int page,
+}) => RustLib.instance.api.crateApiHistoryApiRecordRead(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+  page: page,
+);
+
+/// 更新历史记录中的漫画标题和封面（用于历史列表展示）
+Future<void> updateHistoryInfo({
+  required String moduleId,
+  required String comicId,
+  String? title,
+  String? thumbUrl,
+}) => RustLib.instance.api.crateApiHistoryApiUpdateHistoryInfo(
+  moduleId: moduleId,
+  comicId: comicId,
+  title: title,
+  thumbUrl: thumbUrl,
+);
+
+/// 分页获取阅读历史，按最近阅读时间倒序（page 从 1 开始）
+Future<List<ReadingHistory>> getHistory({
+  required int page,
+  required int limit,
+}) => RustLib.instance.api.crateApiHistoryApiGetHistory(
+  page: page,
+  limit: limit,
+);
+
+/// 分页获取阅读历史（附带总数），按最近阅读时间倒序，page 从 1 开始
+Future<HistoryPage> getHistoryPaged({required int page, required int limit}) =>
+    RustLib.instance.api.crateApiHistoryApiGetHistoryPaged(
+      page: page,
+      limit: limit,
+    );
+
+/// 获取某部漫画上次阅读的位置
+Future<ReadPosition?> getLastPosition({
+  required String moduleId,
+  required String comicId,
+}) => RustLib.instance.api.crateApiHistoryApiGetLastPosition(
+  moduleId: moduleId,
+  comicId: comicId,
+);
+
+/// 清除指定天数之前的阅读历史，返回删除的条数
+Future<BigInt> clearHistoryBefore({required PlatformInt64 days}) =>
+    RustLib.instance.api.crateApiHistoryApiClearHistoryBefore(days: days);
+
+/// 阅读历史分页结果
+class HistoryPage {
+  final PageInfo pageInfo;
+  final List<ReadingHistory> docs;
+
+  const HistoryPage({required this.pageInfo, required this.docs});
+
+  @override
+  int get hashCode => pageInfo.hashCode ^ docs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HistoryPage &&
+          runtimeType == other.runtimeType &&
+          pageInfo == other.pageInfo &&
+          docs == other.docs;
+}
+
+/// 上次阅读位置
+class ReadPosition {
+  final String epId;
+  final int pageIndex;
+
+  const ReadPosition({required this.epId, required this.pageIndex});
+
+  @override
+  int get hashCode => epId.hashCode ^ pageIndex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadPosition &&
+          runtimeType == other.runtimeType &&
+          epId == other.epId &&
+          pageIndex == other.pageIndex;
+}
+
+/// 阅读历史条目
+class ReadingHistory {
+  final String moduleId;
+  final String comicId;
+  final String epId;
+  final int pageIndex;
+  final String? title;
+  final String? thumbUrl;
+
+  /// 最近阅读时间（毫秒时间戳）
+  final PlatformInt64 updatedAt;
+
+  const ReadingHistory({
+    required this.moduleId,
+    required this.comicId,
+    required this.epId,
+    required this.pageIndex,
+    this.title,
+    this.thumbUrl,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      moduleId.hashCode ^
+      comicId.hashCode ^
+      epId.hashCode ^
+      pageIndex.hashCode ^
+      title.hashCode ^
+      thumbUrl.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadingHistory &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          comicId == other.comicId &&
+          epId == other.epId &&
+          pageIndex == other.pageIndex &&
+          title == other.title &&
+          thumbUrl == other.thumbUrl &&
+          updatedAt == other.updatedAt;
+}
@@ -4,8 +4,10 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../http/client.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `init_http_settings`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
 
 /// 发送 HTTP GET 请求
@@ -25,6 +27,17 @@ Future<HttpResponseDto> httpPost({
   body: body,
 );
 
+/// 发送 multipart/form-data POST 请求
+Future<HttpResponseDto> httpPostMultipart({
+  required String url,
+  required Map<String, String> headers,
+  required List<MultipartPart> parts,
+}) => RustLib.instance.api.crateApiHttpApiHttpPostMultipart(
+  url: url,
+  headers: headers,
+  parts: parts,
+);
+
 /// 发送自定义 HTTP 请求
 Future<HttpResponseDto> httpRequest({
   required String url,
@@ -49,23 +62,109 @@ Future<Uint8List> httpDownload({
   headers: headers,
 );
 
+/// 清除模块的所有 Cookie
+Future<void> clearModuleCookies({required String moduleId}) =>
+    RustLib.instance.api.crateApiHttpApiClearModuleCookies(moduleId: moduleId);
+
+/// 设置是否全局跳过 TLS 证书校验
+///
+/// 默认校验证书。通过分流 IP 访问时优先使用 `set_dns_override`，仅在证书本身无法匹配时开启。
+Future<void> setTlsInsecure({required bool insecure}) =>
+    RustLib.instance.api.crateApiHttpApiSetTlsInsecure(insecure: insecure);
+
+/// 获取是否全局跳过 TLS 证书校验
+bool getTlsInsecure() => RustLib.instance.api.crateApiHttpApiGetTlsInsecure();
+
+/// 设置是否输出完整的 HTTP 请求/响应日志（调试模块用，默认关闭，不持久化）
+///
+/// 开启后通过 tracing 记录请求的 URL、方法、请求头以及响应状态和耗时，
+/// Authorization、Cookie 和包含 token 的请求头会被隐藏
+void setHttpLogging({required bool enabled}) =>
+    RustLib.instance.api.crateApiHttpApiSetHttpLogging(enabled: enabled);
+
+/// 获取是否输出完整的 HTTP 请求/响应日志
+bool getHttpLogging() => RustLib.instance.api.crateApiHttpApiGetHttpLogging();
+
+/// 设置全局默认超时和 User-Agent
+///
+/// # 参数
+/// - `timeout_secs`: 未指定超时的请求使用的超时（秒），为空或 0 恢复默认 30 秒
+/// - `user_agent`: 请求未设置 User-Agent 时使用的值，为空恢复默认的浏览器 UA
+Future<void> setHttpDefaults({BigInt? timeoutSecs, String? userAgent}) =>
+    RustLib.instance.api.crateApiHttpApiSetHttpDefaults(
+      timeoutSecs: timeoutSecs,
+      userAgent: userAgent,
+    );
+
+/// 获取全局默认超时和 User-Agent
+HttpDefaults getHttpDefaults() =>
+    RustLib.instance.api.crateApiHttpApiGetHttpDefaults();
+
+/// 设置连接池和连接超时，保存后重建共享客户端
+///
+/// # 参数
+/// - `pool_max_idle_per_host`: 每个主机保留的最大空闲连接数（0–64），为空恢复默认 10
+/// - `pool_idle_timeout_secs`: 空闲连接的保持时间（5–300 秒），为空恢复默认 90 秒
+/// - `connect_timeout_secs`: 建立连接的超时（1–60 秒），为空恢复默认 10 秒
+///
+/// 超出范围的值会被截断
+Future<void> setHttpPoolSettings({
+  int? poolMaxIdlePerHost,
+  BigInt? poolIdleTimeoutSecs,
+  BigInt? connectTimeoutSecs,
+}) => RustLib.instance.api.crateApiHttpApiSetHttpPoolSettings(
+  poolMaxIdlePerHost: poolMaxIdlePerHost,
+  poolIdleTimeoutSecs: poolIdleTimeoutSecs,
+  connectTimeoutSecs: connectTimeoutSecs,
+);
+
+/// 获取连接池和连接超时设置
+HttpPoolSettings getHttpPoolSettings() =>
+    RustLib.instance.api.crateApiHttpApiGetHttpPoolSettings();
+
+/// 把域名固定解析到指定 IP（如分流 IP），请求仍使用原域名的 Host 和 SNI
+///
+/// 证书按原域名校验，无需关闭 TLS 证书校验。
+///
+/// # 参数
+/// - `host`: 域名，不区分大小写
+/// - `ip`: IPv4 或 IPv6 地址，为空时移除该域名的固定解析
+Future<void> setDnsOverride({required String host, String? ip}) =>
+    RustLib.instance.api.crateApiHttpApiSetDnsOverride(host: host, ip: ip);
+
+/// 获取所有固定解析（域名 -> IP）
+Map<String, String> getDnsOverrides() =>
+    RustLib.instance.api.crateApiHttpApiGetDnsOverrides();
+
 /// HTTP 响应 DTO（用于 Flutter）
 class HttpResponseDto {
   final int status;
   final Map<String, String> headers;
   final String body;
   final String contentType;
+  final String finalUrl;
+  final BigInt elapsedMs;
+  final BigInt bodySize;
 
   const HttpResponseDto({
     required this.status,
     required this.headers,
     required this.body,
     required this.contentType,
+    required this.finalUrl,
+    required this.elapsedMs,
+    required this.bodySize,
   });
 
   @override
   int get hashCode =>
-      status.hashCode ^ headers.hashCode ^ body.hashCode ^ contentType.hashCode;
+      status.hashCode ^
+      headers.hashCode ^
+      body.hashCode ^
+      contentType.hashCode ^
+      finalUrl.hashCode ^
+      elapsedMs.hashCode ^
+      bodySize.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -75,5 +174,8 @@ class HttpResponseDto {
           status == other.status &&
           headers == other.headers &&
           body == other.body &&
-          contentType == other.contentType;
+          contentType == other.contentType &&
+          finalUrl == other.finalUrl &&
+          elapsedMs == other.elapsedMs &&
+          bodySize == other.bodySize;
 }
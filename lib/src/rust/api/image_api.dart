@@ -6,6 +6,7 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `apply_orientation`, `encode_png_base64`, `exif_orientation`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ImageInfo`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
@@ -29,3 +30,84 @@ Future<String> rearrangeImageRows({
   imageDataBase64: imageDataBase64,
   rows: rows,
 );
+
+/// 解码图片并重新排列列（与 rearrange_image_rows 对称，用于按竖条打乱的图片）
+/// 参数：
+/// - image_data_base64: base64 编码的图片数据
+/// - cols: 要分割的列数
+/// 返回：重新排列后的图片数据（base64 编码的 PNG）
+Future<String> rearrangeImageCols({
+  required String imageDataBase64,
+  required int cols,
+}) => RustLib.instance.api.crateApiImageApiRearrangeImageCols(
+  imageDataBase64: imageDataBase64,
+  cols: cols,
+);
+
+/// 按网格重新排列图片
+/// 参数：
+/// - image_data_base64: base64 编码的图片数据
+/// - cols, rows: 网格的列数和行数
+/// - permutation: 长度为 cols*rows，目标第 i 个格子（按行优先）取源图第 permutation[i] 个格子
+/// 返回：重新排列后的图片数据（base64 编码的 PNG）
+///
+/// 宽高不能整除时，余数分配给最后一列和最后一行；
+/// 尺寸不同的格子互换时只复制两者重叠的部分
+Future<String> rearrangeImageGrid({
+  required String imageDataBase64,
+  required int cols,
+  required int rows,
+  required List<int> permutation,
+}) => RustLib.instance.api.crateApiImageApiRearrangeImageGrid(
+  imageDataBase64: imageDataBase64,
+  cols: cols,
+  rows: rows,
+  permutation: permutation,
+);
+
+/// 转换图片格式
+/// 参数：
+/// - image_data_base64: base64 编码的图片数据
+/// - format: 目标格式，"jpeg"（或 "jpg"）、"png"、"webp"
+/// - quality: JPEG 质量（1-100），默认 85；PNG 与 WebP（无损）忽略此参数
+/// 返回：转换后的图片数据（base64 编码）
+Future<String> convertImage({
+  required String imageDataBase64,
+  required String format,
+  int? quality,
+}) => RustLib.instance.api.crateApiImageApiConvertImage(
+  imageDataBase64: imageDataBase64,
+  format: format,
+  quality: quality,
+);
+
+/// 垂直拼接条漫图片并按最大高度重新切分
+/// 参数：
+/// - images_base64: base64 编码的图片列表（从上到下）
+/// - max_height: 每张输出图片的最大高度，避免生成过大的纹理
+/// 返回：切分后的图片列表（base64 编码的 PNG）
+///
+/// 输出宽度取所有图片的最大宽度，较窄的图片水平居中，两侧透明
+Future<List<String>> concatImagesVertical({
+  required List<String> imagesBase64,
+  required int maxHeight,
+}) => RustLib.instance.api.crateApiImageApiConcatImagesVertical(
+  imagesBase64: imagesBase64,
+  maxHeight: maxHeight,
+);
+
+/// 根据 EXIF 方向标记自动校正图片方向
+/// 参数：base64 编码的图片数据
+/// 返回：校正后的图片数据（base64，保持原格式）；无需校正时原样返回
+Future<String> autoOrientImage({required String imageDataBase64}) => RustLib
+    .instance
+    .api
+    .crateApiImageApiAutoOrientImage(imageDataBase64: imageDataBase64);
+
+/// 读取图片的 EXIF 信息
+/// 参数：base64 编码的图片数据
+/// 返回：标签名到显示值的 JSON 对象字符串；没有 EXIF 时返回 "{}"
+Future<String> readExif({required String imageDataBase64}) => RustLib
+    .instance
+    .api
+    .crateApiImageApiReadExif(imageDataBase64: imageDataBase64);
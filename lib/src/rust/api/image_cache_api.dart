@@ -4,9 +4,11 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../modules/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `content_type_for_extension`, `evict_image_cache`, `fetch_and_cache_image_to`, `image_cache_stats`, `parse_picture_list`, `prefetch_images_to`, `save_image_record`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `fmt`
 
 /// 获取缓存的图片文件路径
 Future<String?> getCachedImage({
@@ -34,6 +36,53 @@ Future<void> saveImageToCache({
   expireDays: expireDays,
 );
 
+/// 下载远程图片并写入缓存，返回本地文件路径
+///
+/// 使用 RemoteImageInfo 中携带的 headers（Referer、Cookie 等）请求图片，
+/// 已有未过期的缓存时直接返回缓存路径
+Future<String> fetchAndCacheImage({
+  required String moduleId,
+  required String remoteImageInfoJson,
+  PlatformInt64? expireDays,
+}) => RustLib.instance.api.crateApiImageCacheApiFetchAndCacheImage(
+  moduleId: moduleId,
+  remoteImageInfoJson: remoteImageInfoJson,
+  expireDays: expireDays,
+);
+
+/// 并发预取整个章节的图片到缓存，并通过 StreamSink 报告每张图片的完成情况
+///
+/// picture_urls_json 为 RemoteImageInfo 对象或 URL 字符串组成的 JSON 数组，
+/// 已缓存且未过期的图片会被跳过，concurrency 为同时下载的数量（至少为 1）
+Stream<ImagePrefetchItem> prefetchChapterImages({
+  required String moduleId,
+  required String pictureUrlsJson,
+  PlatformInt64? expireDays,
+  required int concurrency,
+}) => RustLib.instance.api.crateApiImageCacheApiPrefetchChapterImages(
+  moduleId: moduleId,
+  pictureUrlsJson: pictureUrlsJson,
+  expireDays: expireDays,
+  concurrency: concurrency,
+);
+
+/// 设置图片缓存容量上限（字节），None 恢复默认值
+Future<void> setImageCacheLimit({PlatformInt64? maxBytes}) => RustLib
+    .instance
+    .api
+    .crateApiImageCacheApiSetImageCacheLimit(maxBytes: maxBytes);
+
+/// 获取图片缓存容量上限（字节）
+Future<PlatformInt64> getImageCacheLimit() =>
+    RustLib.instance.api.crateApiImageCacheApiGetImageCacheLimit();
+
+/// 将图片缓存总大小限制在 max_bytes 以内
+/// 按 created_at 从旧到新删除缓存文件及记录，返回删除的条目数
+Future<BigInt> enforceImageCacheLimit({required PlatformInt64 maxBytes}) =>
+    RustLib.instance.api.crateApiImageCacheApiEnforceImageCacheLimit(
+      maxBytes: maxBytes,
+    );
+
 /// 清除指定模块的图片缓存
 Future<BigInt> clearImageCacheByModule({required String moduleId}) => RustLib
     .instance
@@ -52,6 +101,17 @@ Future<BigInt> clearExpiredImageCache() =>
 Future<ImageCacheStats> getImageCacheStats() =>
     RustLib.instance.api.crateApiImageCacheApiGetImageCacheStats();
 
+/// 分页列出图片缓存，可按模块过滤，按创建时间倒序，page 从 1 开始
+Future<ImageCachePage> listImageCache({
+  String? moduleId,
+  required int page,
+  required int limit,
+}) => RustLib.instance.api.crateApiImageCacheApiListImageCache(
+  moduleId: moduleId,
+  page: page,
+  limit: limit,
+);
+
 /// 使用模块处理图片
 /// 如果模块有 processImage 函数，则调用它处理图片
 /// 参数：
@@ -69,6 +129,63 @@ Future<String> processImageWithModule({
   paramsJson: paramsJson,
 );
 
+/// 图片缓存条目
+class ImageCacheEntry {
+  final String moduleId;
+  final String url;
+  final String filePath;
+  final PlatformInt64 fileSize;
+
+  /// 过期时间（毫秒时间戳）
+  final PlatformInt64 expireAt;
+
+  const ImageCacheEntry({
+    required this.moduleId,
+    required this.url,
+    required this.filePath,
+    required this.fileSize,
+    required this.expireAt,
+  });
+
+  @override
+  int get hashCode =>
+      moduleId.hashCode ^
+      url.hashCode ^
+      filePath.hashCode ^
+      fileSize.hashCode ^
+      expireAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageCacheEntry &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          url == other.url &&
+          filePath == other.filePath &&
+          fileSize == other.fileSize &&
+          expireAt == other.expireAt;
+}
+
+/// 图片缓存分页结果
+class ImageCachePage {
+  final PageInfo pageInfo;
+  final List<ImageCacheEntry> docs;
+
+  const ImageCachePage({required this.pageInfo, required this.docs});
+
+  @override
+  int get hashCode => pageInfo.hashCode ^ docs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageCachePage &&
+          runtimeType == other.runtimeType &&
+          pageInfo == other.pageInfo &&
+          docs == other.docs;
+}
+
 /// 缓存统计信息
 class ImageCacheStats {
   final BigInt totalCount;
@@ -100,3 +217,71 @@ class ImageCacheStats {
           expiredCount == other.expiredCount &&
           totalSize == other.totalSize;
 }
+
+/// 章节预取中单张图片的完成情况
+class ImagePrefetchItem {
+  /// 图片在章节中的下标
+  final int index;
+  final String url;
+
+  /// 本地缓存路径，失败时为 None
+  final String? path;
+  final String? error;
+
+  /// 是否命中已有缓存
+  final bool cached;
+
+  const ImagePrefetchItem({
+    required this.index,
+    required this.url,
+    this.path,
+    this.error,
+    required this.cached,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      url.hashCode ^
+      path.hashCode ^
+      error.hashCode ^
+      cached.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImagePrefetchItem &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          url == other.url &&
+          path == other.path &&
+          error == other.error &&
+          cached == other.cached;
+}
+
+/// 章节预取结果汇总
+class ImagePrefetchSummary {
+  final int succeeded;
+  final int failed;
+
+  /// 已有有效缓存而跳过下载的数量（计入 succeeded）
+  final int skipped;
+
+  const ImagePrefetchSummary({
+    required this.succeeded,
+    required this.failed,
+    required this.skipped,
+  });
+
+  @override
+  int get hashCode => succeeded.hashCode ^ failed.hashCode ^ skipped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImagePrefetchSummary &&
+          runtimeType == other.runtimeType &&
+          succeeded == other.succeeded &&
+          failed == other.failed &&
+          skipped == other.skipped;
+}
@@ -12,6 +12,17 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 Future<void> initApplication({required String rootPath}) =>
     RustLib.instance.api.crateApiInitInitApplication(rootPath: rootPath);
 
+/// 初始化应用并使用 SQLCipher 密钥打开加密数据库
+///
+/// 需要以 sqlcipher feature 编译，密钥错误时返回错误，可使用正确的密钥重试
+Future<void> initApplicationWithKey({
+  required String rootPath,
+  String? dbKey,
+}) => RustLib.instance.api.crateApiInitInitApplicationWithKey(
+  rootPath: rootPath,
+  dbKey: dbKey,
+);
+
 /// 获取应用是否已初始化
 bool isInitialized() => RustLib.instance.api.crateApiInitIsInitialized();
 
@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `init_logging`, `parse_level`
+
+/// 设置日志级别，立即生效（不持久化，重启后恢复为 info）
+///
+/// # 参数
+/// - `level`: "trace"、"debug"、"info"、"warn" 或 "error"
+void setLogLevel({required String level}) =>
+    RustLib.instance.api.crateApiLogApiSetLogLevel(level: level);
+
+/// 获取当前日志级别
+String getLogLevel() => RustLib.instance.api.crateApiLogApiGetLogLevel();
+
+/// 获取最近的日志（按时间顺序，最多 max_lines 行）
+///
+/// 配合 `set_log_level("debug")` 复现问题后导出，用于问题反馈
+List<String> getRecentLogs({required BigInt maxLines}) =>
+    RustLib.instance.api.crateApiLogApiGetRecentLogs(maxLines: maxLines);
+
+/// 设置内存中保留的日志行数，为 0 时不再保留
+void setLogBufferSize({required BigInt size}) =>
+    RustLib.instance.api.crateApiLogApiSetLogBufferSize(size: size);
+
+/// 清空内存中的日志
+void clearRecentLogs() => RustLib.instance.api.crateApiLogApiClearRecentLogs();
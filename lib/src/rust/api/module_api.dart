@@ -4,10 +4,12 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../js_engine/runtime.dart';
+import '../modules/error.dart';
 import '../modules/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_module_manager`, `init_module_manager`
+// These functions are ignored because they are not marked as `pub`: `get_module_manager`, `get_pictures_with_cancel`, `init_module_manager`, `init_module_pool`, `preload_modules_with`
 
 /// 获取所有已注册的模块列表
 Future<List<ModuleInfo>> getModules() =>
@@ -25,14 +27,43 @@ Future<ModuleInfo> registerModule({required String moduleId}) =>
 Future<ModuleInfo> importModuleFromUrl({required String url}) =>
     RustLib.instance.api.crateApiModuleApiImportModuleFromUrl(url: url);
 
-/// 更新插件（如果有URL来源）
-Future<ModuleInfo> updateModule({required String moduleId}) =>
+/// 从URL安装插件，`overwrite` 为 false 时拒绝覆盖已安装的同名模块
+Future<ModuleInfo> installModuleFromUrl({
+  required String url,
+  required bool overwrite,
+}) => RustLib.instance.api.crateApiModuleApiInstallModuleFromUrl(
+  url: url,
+  overwrite: overwrite,
+);
+
+/// 更新插件（如果有URL来源），只有远程版本更新时才替换
+Future<ModuleUpdateResult> updateModule({required String moduleId}) =>
     RustLib.instance.api.crateApiModuleApiUpdateModule(moduleId: moduleId);
 
-/// 删除插件
+/// 强制从来源URL重新安装插件，忽略版本比较
+Future<ModuleUpdateResult> forceUpdateModule({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiForceUpdateModule(moduleId: moduleId);
+
+/// 检查所有插件的可用更新（不应用）
+Future<List<ModuleUpdateInfo>> checkUpdates() =>
+    RustLib.instance.api.crateApiModuleApiCheckUpdates();
+
+/// 删除插件（保留模块的配置和缓存）
 Future<void> deleteModule({required String moduleId}) =>
     RustLib.instance.api.crateApiModuleApiDeleteModule(moduleId: moduleId);
 
+/// 完全卸载插件：删除脚本文件和模块记录
+///
+/// `purge_data` 为 true 时同时清除模块的配置项、图片缓存和网页缓存；
+/// 模块正在执行调用时返回错误
+Future<void> uninstallModule({
+  required String moduleId,
+  required bool purgeData,
+}) => RustLib.instance.api.crateApiModuleApiUninstallModule(
+  moduleId: moduleId,
+  purgeData: purgeData,
+);
+
 /// 设置/清除模块来源URL
 Future<void> setModuleSourceUrl({
   required String moduleId,
@@ -59,6 +90,12 @@ Future<void> setModuleEnabled({
   enabled: enabled,
 );
 
+/// 获取模块支持的可选功能（搜索、评论、登录、下载、图片处理）
+Future<ModuleCapabilities> getModuleCapabilities({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiGetModuleCapabilities(
+      moduleId: moduleId,
+    );
+
 /// 获取模块的分类列表
 Future<List<Category>> getCategories({required String moduleId}) =>
     RustLib.instance.api.crateApiModuleApiGetCategories(moduleId: moduleId);
@@ -80,6 +117,34 @@ Future<ComicsPage> getComics({
   page: page,
 );
 
+/// 获取标签列表，模块不支持时返回空列表
+Future<List<Tag>> getTags({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiGetTags(moduleId: moduleId);
+
+/// 按标签获取漫画列表，模块不支持时返回 Unsupported 错误
+Future<ComicsPage> getComicsByTag({
+  required String moduleId,
+  required String tag,
+  required String sortBy,
+  required int page,
+}) => RustLib.instance.api.crateApiModuleApiGetComicsByTag(
+  moduleId: moduleId,
+  tag: tag,
+  sortBy: sortBy,
+  page: page,
+);
+
+/// 获取作者的作品列表，模块不支持时以作者名搜索
+Future<ComicsPage> getComicsByAuthor({
+  required String moduleId,
+  required String author,
+  required int page,
+}) => RustLib.instance.api.crateApiModuleApiGetComicsByAuthor(
+  moduleId: moduleId,
+  author: author,
+  page: page,
+);
+
 /// 获取漫画详情 (参考 pikapika album/comicInfo)
 Future<ComicDetail> getComicDetail({
   required String moduleId,
@@ -113,6 +178,23 @@ Future<PicturePage> getPictures({
   page: page,
 );
 
+/// 获取章节图片，可通过 `cancel_api::cancel(cancel_token)` 取消
+///
+/// 取消后返回 `ModuleError::Cancelled`，正在执行的模块脚本会被中断
+Future<PicturePage> getPicturesCancelable({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+  required int page,
+  required String cancelToken,
+}) => RustLib.instance.api.crateApiModuleApiGetPicturesCancelable(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+  page: page,
+  cancelToken: cancelToken,
+);
+
 /// 搜索漫画 (参考 pikapika search)
 Future<ComicsPage> searchComics({
   required String moduleId,
@@ -126,6 +208,103 @@ Future<ComicsPage> searchComics({
   page: page,
 );
 
+/// 获取漫画评论，模块不支持评论时返回空页
+Future<CommentPage> getComments({
+  required String moduleId,
+  required String comicId,
+  required int page,
+}) => RustLib.instance.api.crateApiModuleApiGetComments(
+  moduleId: moduleId,
+  comicId: comicId,
+  page: page,
+);
+
+/// 获取相关推荐漫画（"猜你喜欢"），模块不支持时返回空列表
+Future<ComicsPage> getRecommend({
+  required String moduleId,
+  required String comicId,
+  required int page,
+}) => RustLib.instance.api.crateApiModuleApiGetRecommend(
+  moduleId: moduleId,
+  comicId: comicId,
+  page: page,
+);
+
+/// 随机获取一部漫画，模块没有漫画时返回 None
+Future<ComicSimple?> getRandomComic({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiGetRandomComic(moduleId: moduleId);
+
+/// 发表评论，模块不支持时返回 Unsupported 错误
+Future<void> postComment({
+  required String moduleId,
+  required String comicId,
+  required String content,
+}) => RustLib.instance.api.crateApiModuleApiPostComment(
+  moduleId: moduleId,
+  comicId: comicId,
+  content: content,
+);
+
+/// 登录模块，credentials_json 原样传给模块的 login 函数（如 {"username": "...", "password": "..."}）
+Future<LoginResult> login({
+  required String moduleId,
+  required String credentialsJson,
+}) => RustLib.instance.api.crateApiModuleApiLogin(
+  moduleId: moduleId,
+  credentialsJson: credentialsJson,
+);
+
+/// 登出模块并清除保存的会话
+Future<void> logout({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiLogout(moduleId: moduleId);
+
+/// 获取模块的登录状态
+Future<bool> getLoginStatus({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiGetLoginStatus(moduleId: moduleId);
+
+/// 切换服务端收藏状态，返回新状态；模块不支持时返回 Unsupported 错误，可改用本地收藏
+Future<bool> toggleFavourite({
+  required String moduleId,
+  required String comicId,
+}) => RustLib.instance.api.crateApiModuleApiToggleFavourite(
+  moduleId: moduleId,
+  comicId: comicId,
+);
+
+/// 切换服务端点赞状态，返回新状态；模块不支持时返回 Unsupported 错误
+Future<bool> toggleLike({required String moduleId, required String comicId}) =>
+    RustLib.instance.api.crateApiModuleApiToggleLike(
+      moduleId: moduleId,
+      comicId: comicId,
+    );
+
+/// 设置聚合搜索的并发模块数，None 恢复默认值
+Future<void> setSearchAllConcurrency({int? limit}) =>
+    RustLib.instance.api.crateApiModuleApiSetSearchAllConcurrency(limit: limit);
+
+/// 获取聚合搜索的并发模块数
+Future<int> getSearchAllConcurrency() =>
+    RustLib.instance.api.crateApiModuleApiGetSearchAllConcurrency();
+
+/// 在所有已启用的模块中搜索
+///
+/// 各模块并发搜索（受并发数设置限制），每个模块单独超时；
+/// 单个模块失败只记录在 errors 中，不影响整体结果
+Future<AggregatedSearchResult> searchAll({
+  required String keyword,
+  required int page,
+}) => RustLib.instance.api.crateApiModuleApiSearchAll(
+  keyword: keyword,
+  page: page,
+);
+
+/// 预加载模块运行时，避免首次使用时解析脚本造成的卡顿
+///
+/// ids 为空时预加载所有已启用的模块；已加载的模块会跳过，已禁用的模块记为失败。
+/// 各模块并发加载，加载数量超过运行时上限时较早加载的会被卸载
+Future<ModulePreloadResult> preloadModules({required List<String> ids}) =>
+    RustLib.instance.api.crateApiModuleApiPreloadModules(ids: ids);
+
 /// 调用模块的任意函数（高级 API）
 Future<String> callModuleFunction({
   required String moduleId,
@@ -137,6 +316,99 @@ Future<String> callModuleFunction({
   argsJson: argsJson,
 );
 
+/// 在同一模块上依次调用多个函数（如打开漫画时的详情、章节和首页图片），减少跨边界调用次数
+///
+/// 单个调用失败不影响其他调用，结果与 calls 一一对应
+Future<List<BatchCallResult>> batchCall({
+  required String moduleId,
+  required List<BatchCall> calls,
+}) => RustLib.instance.api.crateApiModuleApiBatchCall(
+  moduleId: moduleId,
+  calls: calls,
+);
+
+/// 调用模块的任意函数，并指定执行超时（毫秒）
+Future<String> callModuleFunctionWithTimeout({
+  required String moduleId,
+  required String funcName,
+  required String argsJson,
+  required BigInt timeoutMs,
+}) => RustLib.instance.api.crateApiModuleApiCallModuleFunctionWithTimeout(
+  moduleId: moduleId,
+  funcName: funcName,
+  argsJson: argsJson,
+  timeoutMs: timeoutMs,
+);
+
+/// 调用模块的任意函数，并返回调用期间的 console 日志（模块调试用）
+Future<ModuleCallOutput> callModuleFunctionWithLogs({
+  required String moduleId,
+  required String funcName,
+  required String argsJson,
+}) => RustLib.instance.api.crateApiModuleApiCallModuleFunctionWithLogs(
+  moduleId: moduleId,
+  funcName: funcName,
+  argsJson: argsJson,
+);
+
+/// 设置最多同时保留的模块运行时数量（至少为 1）
+///
+/// 超出上限时卸载最久未使用的模块，被卸载的模块下次调用时会重新加载
+Future<void> setMaxLoadedModules({required int max}) =>
+    RustLib.instance.api.crateApiModuleApiSetMaxLoadedModules(max: max);
+
+/// 获取最多同时保留的模块运行时数量
+Future<int> getMaxLoadedModules() =>
+    RustLib.instance.api.crateApiModuleApiGetMaxLoadedModules();
+
+/// 开启或关闭模块脚本热重载（开发用，默认关闭，不持久化）
+///
+/// 开启后监听模块目录，`.js` 文件变化时卸载对应模块的运行时，下次调用时重新加载脚本
+Future<void> setDevHotReload({required bool enabled}) =>
+    RustLib.instance.api.crateApiModuleApiSetDevHotReload(enabled: enabled);
+
+/// 模块脚本热重载是否已开启
+bool isDevHotReloadEnabled() =>
+    RustLib.instance.api.crateApiModuleApiIsDevHotReloadEnabled();
+
+/// 设置模块运行时的内存上限（MB）和最大栈大小（KB），都为空时恢复默认值（64MB / 1024KB）
+///
+/// 只设置其中一项时另一项使用默认值；修改后模块会在下次调用时按新限制重新加载
+Future<void> setModuleRuntimeLimits({
+  required String moduleId,
+  int? memoryLimitMb,
+  int? stackSizeKb,
+}) => RustLib.instance.api.crateApiModuleApiSetModuleRuntimeLimits(
+  moduleId: moduleId,
+  memoryLimitMb: memoryLimitMb,
+  stackSizeKb: stackSizeKb,
+);
+
+/// 获取模块运行时的内存和栈限制
+Future<RuntimeLimits> getModuleRuntimeLimits({required String moduleId}) =>
+    RustLib.instance.api.crateApiModuleApiGetModuleRuntimeLimits(
+      moduleId: moduleId,
+    );
+
+/// 获取模块函数的调用统计（次数、耗时、最近错误），重启后清零
+Future<List<FunctionMetrics>> getModuleMetrics() =>
+    RustLib.instance.api.crateApiModuleApiGetModuleMetrics();
+
+/// 试运行模块脚本：在全新的 JS 运行时中加载脚本并调用指定函数
+///
+/// 不注册模块、不写入模块目录，使用与正式调用相同的内存限制和默认超时。
+/// 返回函数结果、console 日志以及异常调用栈。
+/// 脚本中的 storage 使用内存存储，试运行结束后丢弃
+Future<ModuleCallOutput> testModuleScript({
+  required String script,
+  required String funcName,
+  required String argsJson,
+}) => RustLib.instance.api.crateApiModuleApiTestModuleScript(
+  script: script,
+  funcName: funcName,
+  argsJson: argsJson,
+);
+
 /// 获取模块存储的值
 Future<String?> getModuleStorage({
   required String moduleId,
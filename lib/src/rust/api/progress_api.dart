@@ -0,0 +1,88 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+/// 保存章节阅读进度（存在则更新）
+Future<void> setProgress({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+  required int lastPage,
+  required int totalPages,
+}) => RustLib.instance.api.crateApiProgressApiSetProgress(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+  lastPage: lastPage,
+  totalPages: totalPages,
+);
+
+/// 获取单个章节的阅读进度
+Future<ChapterProgress?> getProgress({
+  required String moduleId,
+  required String comicId,
+  required String epId,
+}) => RustLib.instance.api.crateApiProgressApiGetProgress(
+  moduleId: moduleId,
+  comicId: comicId,
+  epId: epId,
+);
+
+/// 获取漫画所有章节的阅读进度，返回 ep_id -> last_page
+Future<Map<String, int>> getComicProgress({
+  required String moduleId,
+  required String comicId,
+}) => RustLib.instance.api.crateApiProgressApiGetComicProgress(
+  moduleId: moduleId,
+  comicId: comicId,
+);
+
+/// 章节阅读进度
+class ChapterProgress {
+  final String moduleId;
+  final String comicId;
+  final String epId;
+  final int lastPage;
+
+  /// 章节总页数（未知时为 0）
+  final int totalPages;
+
+  /// 更新时间（毫秒时间戳）
+  final PlatformInt64 updatedAt;
+
+  const ChapterProgress({
+    required this.moduleId,
+    required this.comicId,
+    required this.epId,
+    required this.lastPage,
+    required this.totalPages,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      moduleId.hashCode ^
+      comicId.hashCode ^
+      epId.hashCode ^
+      lastPage.hashCode ^
+      totalPages.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterProgress &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          comicId == other.comicId &&
+          epId == other.epId &&
+          lastPage == other.lastPage &&
+          totalPages == other.totalPages &&
+          updatedAt == other.updatedAt;
+}
@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../modules/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
@@ -37,6 +38,17 @@ Future<void> deleteProperty({required String moduleId, required String key}) =>
 Future<List<PropertyItem>> listProperties({required String moduleId}) =>
     RustLib.instance.api.crateApiPropertyApiListProperties(moduleId: moduleId);
 
+/// 分页列出模块的属性（按 key 排序，page 从 1 开始）
+Future<PropertyPage> listPropertiesPaged({
+  required String moduleId,
+  required int page,
+  required int limit,
+}) => RustLib.instance.api.crateApiPropertyApiListPropertiesPaged(
+  moduleId: moduleId,
+  page: page,
+  limit: limit,
+);
+
 /// 按前缀列出属性
 Future<List<PropertyItem>> listPropertiesByPrefix({
   required String moduleId,
@@ -46,6 +58,15 @@ Future<List<PropertyItem>> listPropertiesByPrefix({
   prefix: prefix,
 );
 
+/// 按前缀删除属性，返回删除的条数
+Future<BigInt> deletePropertiesByPrefix({
+  required String moduleId,
+  required String prefix,
+}) => RustLib.instance.api.crateApiPropertyApiDeletePropertiesByPrefix(
+  moduleId: moduleId,
+  prefix: prefix,
+);
+
 /// 清除模块的所有属性
 Future<BigInt> clearModuleProperties({required String moduleId}) => RustLib
     .instance
@@ -89,3 +110,22 @@ class PropertyItem {
           key == other.key &&
           value == other.value;
 }
+
+/// 属性分页结果
+class PropertyPage {
+  final PageInfo pageInfo;
+  final List<PropertyItem> docs;
+
+  const PropertyPage({required this.pageInfo, required this.docs});
+
+  @override
+  int get hashCode => pageInfo.hashCode ^ docs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PropertyPage &&
+          runtimeType == other.runtimeType &&
+          pageInfo == other.pageInfo &&
+          docs == other.docs;
+}
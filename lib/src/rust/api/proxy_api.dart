@@ -6,7 +6,7 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `init_proxy`
+// These functions are ignored because they are not marked as `pub`: `init_module_proxies`, `init_proxy`
 
 /// 设置代理
 ///
@@ -20,3 +20,28 @@ Future<String?> getProxy() => RustLib.instance.api.crateApiProxyApiGetProxy();
 
 /// 清除代理设置
 Future<void> clearProxy() => RustLib.instance.api.crateApiProxyApiClearProxy();
+
+/// 设置代理绕过列表
+///
+/// # 参数
+/// - `bypass`: 逗号分隔的主机名、IP 或 CIDR（如 "localhost,.lan,192.168.0.0/16"），这些主机不走代理。为空则清除。
+Future<void> setProxyBypass({String? bypass}) =>
+    RustLib.instance.api.crateApiProxyApiSetProxyBypass(bypass: bypass);
+
+/// 获取代理绕过列表
+String getProxyBypass() =>
+    RustLib.instance.api.crateApiProxyApiGetProxyBypass();
+
+/// 设置模块默认代理
+///
+/// # 参数
+/// - `module_id`: 模块 ID
+/// - `url`: 代理 URL（http:// 或 socks5://），"direct" 表示该模块不走全局代理；为空则跟随全局设置
+Future<void> setModuleProxy({required String moduleId, String? url}) => RustLib
+    .instance
+    .api
+    .crateApiProxyApiSetModuleProxy(moduleId: moduleId, url: url);
+
+/// 获取模块默认代理设置
+String? getModuleProxy({required String moduleId}) =>
+    RustLib.instance.api.crateApiProxyApiGetModuleProxy(moduleId: moduleId);
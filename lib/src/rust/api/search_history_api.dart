@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 记录一次搜索（重复的关键词会累加次数并更新时间）
+Future<void> recordSearch({
+  required String moduleId,
+  required String keyword,
+}) => RustLib.instance.api.crateApiSearchHistoryApiRecordSearch(
+  moduleId: moduleId,
+  keyword: keyword,
+);
+
+/// 获取最近的搜索记录，按搜索次数、最近搜索时间倒序
+Future<List<SearchRecord>> recentSearches({
+  required String moduleId,
+  required int limit,
+}) => RustLib.instance.api.crateApiSearchHistoryApiRecentSearches(
+  moduleId: moduleId,
+  limit: limit,
+);
+
+/// 清除指定模块的搜索记录
+Future<BigInt> clearSearchHistory({required String moduleId}) => RustLib
+    .instance
+    .api
+    .crateApiSearchHistoryApiClearSearchHistory(moduleId: moduleId);
+
+/// 搜索记录
+class SearchRecord {
+  final String keyword;
+  final int count;
+
+  /// 最近搜索时间（毫秒时间戳）
+  final PlatformInt64 searchedAt;
+
+  const SearchRecord({
+    required this.keyword,
+    required this.count,
+    required this.searchedAt,
+  });
+
+  @override
+  int get hashCode => keyword.hashCode ^ count.hashCode ^ searchedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchRecord &&
+          runtimeType == other.runtimeType &&
+          keyword == other.keyword &&
+          count == other.count &&
+          searchedAt == other.searchedAt;
+}
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 获取使用统计
+///
+/// 数量和大小都通过聚合查询计算，不会加载缓存记录。
+/// 收藏保存在各模块对应的站点上，本地无法统计
+Future<UsageStats> getUsageStats() =>
+    RustLib.instance.api.crateApiStatsApiGetUsageStats();
+
+/// 应用使用统计
+class UsageStats {
+  /// 阅读历史中的漫画数量
+  final BigInt historyComics;
+
+  /// 已安装模块数量
+  final BigInt modules;
+  final BigInt enabledModules;
+
+  /// 已下载完成的章节数量
+  final BigInt downloadedChapters;
+  final BigInt imageCacheCount;
+
+  /// 图片缓存总大小（字节）
+  final BigInt imageCacheSize;
+  final BigInt webCacheCount;
+
+  /// 网页缓存总大小（字节）
+  final BigInt webCacheSize;
+
+  /// 数据库文件大小（字节，含 WAL）
+  final BigInt databaseSize;
+
+  const UsageStats({
+    required this.historyComics,
+    required this.modules,
+    required this.enabledModules,
+    required this.downloadedChapters,
+    required this.imageCacheCount,
+    required this.imageCacheSize,
+    required this.webCacheCount,
+    required this.webCacheSize,
+    required this.databaseSize,
+  });
+
+  @override
+  int get hashCode =>
+      historyComics.hashCode ^
+      modules.hashCode ^
+      enabledModules.hashCode ^
+      downloadedChapters.hashCode ^
+      imageCacheCount.hashCode ^
+      imageCacheSize.hashCode ^
+      webCacheCount.hashCode ^
+      webCacheSize.hashCode ^
+      databaseSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UsageStats &&
+          runtimeType == other.runtimeType &&
+          historyComics == other.historyComics &&
+          modules == other.modules &&
+          enabledModules == other.enabledModules &&
+          downloadedChapters == other.downloadedChapters &&
+          imageCacheCount == other.imageCacheCount &&
+          imageCacheSize == other.imageCacheSize &&
+          webCacheCount == other.webCacheCount &&
+          webCacheSize == other.webCacheSize &&
+          databaseSize == other.databaseSize;
+}
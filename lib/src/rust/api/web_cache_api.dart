@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 获取缓存的网页内容（已过期的条目会被删除并返回 None）
+Future<WebCacheItem?> getWebCache({
+  required String moduleId,
+  required String url,
+}) => RustLib.instance.api.crateApiWebCacheApiGetWebCache(
+  moduleId: moduleId,
+  url: url,
+);
+
+/// 保存网页内容到缓存
+Future<void> saveWebCache({
+  required String moduleId,
+  required String url,
+  required String body,
+  required String contentType,
+  required PlatformInt64 expireSecs,
+}) => RustLib.instance.api.crateApiWebCacheApiSaveWebCache(
+  moduleId: moduleId,
+  url: url,
+  body: body,
+  contentType: contentType,
+  expireSecs: expireSecs,
+);
+
+/// 删除指定网页缓存
+Future<void> removeWebCache({required String moduleId, required String url}) =>
+    RustLib.instance.api.crateApiWebCacheApiRemoveWebCache(
+      moduleId: moduleId,
+      url: url,
+    );
+
+/// 清除指定模块的网页缓存
+Future<BigInt> clearWebCacheByModule({required String moduleId}) => RustLib
+    .instance
+    .api
+    .crateApiWebCacheApiClearWebCacheByModule(moduleId: moduleId);
+
+/// 清除过期的网页缓存
+Future<BigInt> clearExpiredWebCache() =>
+    RustLib.instance.api.crateApiWebCacheApiClearExpiredWebCache();
+
+/// 网页缓存条目
+class WebCacheItem {
+  final String url;
+  final String body;
+  final String contentType;
+
+  const WebCacheItem({
+    required this.url,
+    required this.body,
+    required this.contentType,
+  });
+
+  @override
+  int get hashCode => url.hashCode ^ body.hashCode ^ contentType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebCacheItem &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          body == other.body &&
+          contentType == other.contentType;
+}
@@ -3,20 +3,33 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/backup_api.dart';
+import 'api/cancel_api.dart';
 import 'api/crypto_api.dart';
+import 'api/db_api.dart';
+import 'api/download_api.dart';
+import 'api/history_api.dart';
 import 'api/http_api.dart';
 import 'api/image_api.dart';
 import 'api/image_cache_api.dart';
 import 'api/init.dart';
+import 'api/log_api.dart';
 import 'api/module_api.dart';
+import 'api/progress_api.dart';
 import 'api/property_api.dart';
 import 'api/proxy_api.dart';
+import 'api/search_history_api.dart';
 import 'api/simple.dart';
+import 'api/stats_api.dart';
+import 'api/web_cache_api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'http/client.dart';
+import 'js_engine/runtime.dart';
+import 'modules/error.dart';
 import 'modules/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
@@ -76,7 +89,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -320161379;
+  int get rustContentHash => 178072191;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -87,30 +100,89 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<String> crateApiImageApiAutoOrientImage({
+    required String imageDataBase64,
+  });
+
+  Future<List<BatchCallResult>> crateApiModuleApiBatchCall({
+    required String moduleId,
+    required List<BatchCall> calls,
+  });
+
   Future<String> crateApiModuleApiCallModuleFunction({
     required String moduleId,
     required String funcName,
     required String argsJson,
   });
 
+  Future<ModuleCallOutput> crateApiModuleApiCallModuleFunctionWithLogs({
+    required String moduleId,
+    required String funcName,
+    required String argsJson,
+  });
+
+  Future<String> crateApiModuleApiCallModuleFunctionWithTimeout({
+    required String moduleId,
+    required String funcName,
+    required String argsJson,
+    required BigInt timeoutMs,
+  });
+
+  bool crateApiCancelApiCancel({required String token});
+
+  Future<List<ModuleUpdateInfo>> crateApiModuleApiCheckUpdates();
+
   Future<BigInt> crateApiImageCacheApiClearAllImageCache();
 
   Future<BigInt> crateApiImageCacheApiClearExpiredImageCache();
 
+  Future<BigInt> crateApiWebCacheApiClearExpiredWebCache();
+
+  Future<BigInt> crateApiHistoryApiClearHistoryBefore({
+    required PlatformInt64 days,
+  });
+
   Future<BigInt> crateApiImageCacheApiClearImageCacheByModule({
     required String moduleId,
   });
 
+  Future<void> crateApiHttpApiClearModuleCookies({required String moduleId});
+
   Future<BigInt> crateApiPropertyApiClearModuleProperties({
     required String moduleId,
   });
 
   Future<void> crateApiProxyApiClearProxy();
 
+  void crateApiLogApiClearRecentLogs();
+
+  Future<BigInt> crateApiSearchHistoryApiClearSearchHistory({
+    required String moduleId,
+  });
+
+  Future<BigInt> crateApiWebCacheApiClearWebCacheByModule({
+    required String moduleId,
+  });
+
+  Future<List<String>> crateApiImageApiConcatImagesVertical({
+    required List<String> imagesBase64,
+    required int maxHeight,
+  });
+
+  Future<String> crateApiImageApiConvertImage({
+    required String imageDataBase64,
+    required String format,
+    int? quality,
+  });
+
+  String crateApiCryptoApiCryptoAdler32Bytes({required List<int> data});
+
   String crateApiCryptoApiCryptoBase64Decode({required String data});
 
   String crateApiCryptoApiCryptoBase64Encode({required String data});
 
+  String crateApiCryptoApiCryptoCrc32Bytes({required List<int> data});
+
   String crateApiCryptoApiCryptoHexDecode({required String data});
 
   String crateApiCryptoApiCryptoHexEncode({required String data});
@@ -127,13 +199,49 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiPropertyApiDeleteAppSetting({required String key});
 
+  Future<void> crateApiDownloadApiDeleteDownload({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+  });
+
   Future<void> crateApiModuleApiDeleteModule({required String moduleId});
 
+  Future<BigInt> crateApiPropertyApiDeletePropertiesByPrefix({
+    required String moduleId,
+    required String prefix,
+  });
+
   Future<void> crateApiPropertyApiDeleteProperty({
     required String moduleId,
     required String key,
   });
 
+  Stream<DownloadProgress> crateApiDownloadApiDownloadToFile({
+    required String url,
+    required Map<String, String> headers,
+    required String destPath,
+    String? cancelToken,
+  });
+
+  Future<BigInt> crateApiImageCacheApiEnforceImageCacheLimit({
+    required PlatformInt64 maxBytes,
+  });
+
+  Future<void> crateApiBackupApiExportDatabase({required String destPath});
+
+  Future<String> crateApiBackupApiExportUserData();
+
+  Future<String> crateApiImageCacheApiFetchAndCacheImage({
+    required String moduleId,
+    required String remoteImageInfoJson,
+    PlatformInt64? expireDays,
+  });
+
+  Future<ModuleUpdateResult> crateApiModuleApiForceUpdateModule({
+    required String moduleId,
+  });
+
   String? crateApiInitGetCacheDir();
 
   Future<String?> crateApiImageCacheApiGetCachedImage({
@@ -150,6 +258,11 @@ abstract class RustLibApi extends BaseApi {
     required String comicId,
   });
 
+  Future<Map<String, int>> crateApiProgressApiGetComicProgress({
+    required String moduleId,
+    required String comicId,
+  });
+
   Future<ComicsPage> crateApiModuleApiGetComics({
     required String moduleId,
     required String categorySlug,
@@ -157,18 +270,86 @@ abstract class RustLibApi extends BaseApi {
     required int page,
   });
 
+  Future<ComicsPage> crateApiModuleApiGetComicsByAuthor({
+    required String moduleId,
+    required String author,
+    required int page,
+  });
+
+  Future<ComicsPage> crateApiModuleApiGetComicsByTag({
+    required String moduleId,
+    required String tag,
+    required String sortBy,
+    required int page,
+  });
+
+  Future<CommentPage> crateApiModuleApiGetComments({
+    required String moduleId,
+    required String comicId,
+    required int page,
+  });
+
+  Map<String, String> crateApiHttpApiGetDnsOverrides();
+
+  Future<ChapterDownload?> crateApiDownloadApiGetDownloadStatus({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+  });
+
   Future<EpPage> crateApiModuleApiGetEps({
     required String moduleId,
     required String comicId,
     required int page,
   });
 
+  Future<List<ReadingHistory>> crateApiHistoryApiGetHistory({
+    required int page,
+    required int limit,
+  });
+
+  Future<HistoryPage> crateApiHistoryApiGetHistoryPaged({
+    required int page,
+    required int limit,
+  });
+
+  HttpDefaults crateApiHttpApiGetHttpDefaults();
+
+  bool crateApiHttpApiGetHttpLogging();
+
+  HttpPoolSettings crateApiHttpApiGetHttpPoolSettings();
+
+  Future<PlatformInt64> crateApiImageCacheApiGetImageCacheLimit();
+
   Future<ImageCacheStats> crateApiImageCacheApiGetImageCacheStats();
 
   Future<String> crateApiImageApiGetImageInfo({
     required String imageDataBase64,
   });
 
+  Future<ReadPosition?> crateApiHistoryApiGetLastPosition({
+    required String moduleId,
+    required String comicId,
+  });
+
+  String crateApiLogApiGetLogLevel();
+
+  Future<bool> crateApiModuleApiGetLoginStatus({required String moduleId});
+
+  Future<int> crateApiModuleApiGetMaxLoadedModules();
+
+  Future<ModuleCapabilities> crateApiModuleApiGetModuleCapabilities({
+    required String moduleId,
+  });
+
+  Future<List<FunctionMetrics>> crateApiModuleApiGetModuleMetrics();
+
+  String? crateApiProxyApiGetModuleProxy({required String moduleId});
+
+  Future<RuntimeLimits> crateApiModuleApiGetModuleRuntimeLimits({
+    required String moduleId,
+  });
+
   Future<String?> crateApiModuleApiGetModuleStorage({
     required String moduleId,
     required String key,
@@ -185,14 +366,55 @@ abstract class RustLibApi extends BaseApi {
     required int page,
   });
 
+  Future<PicturePage> crateApiModuleApiGetPicturesCancelable({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int page,
+    required String cancelToken,
+  });
+
+  Future<ChapterProgress?> crateApiProgressApiGetProgress({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+  });
+
   Future<String?> crateApiProxyApiGetProxy();
 
+  String crateApiProxyApiGetProxyBypass();
+
+  Future<ComicSimple?> crateApiModuleApiGetRandomComic({
+    required String moduleId,
+  });
+
+  List<String> crateApiLogApiGetRecentLogs({required BigInt maxLines});
+
+  Future<ComicsPage> crateApiModuleApiGetRecommend({
+    required String moduleId,
+    required String comicId,
+    required int page,
+  });
+
   String? crateApiInitGetRootPath();
 
+  Future<int> crateApiModuleApiGetSearchAllConcurrency();
+
   Future<List<SortOption>> crateApiModuleApiGetSortOptions({
     required String moduleId,
   });
 
+  Future<List<Tag>> crateApiModuleApiGetTags({required String moduleId});
+
+  bool crateApiHttpApiGetTlsInsecure();
+
+  Future<UsageStats> crateApiStatsApiGetUsageStats();
+
+  Future<WebCacheItem?> crateApiWebCacheApiGetWebCache({
+    required String moduleId,
+    required String url,
+  });
+
   String crateApiSimpleGreet({required String name});
 
   Future<Uint8List> crateApiHttpApiHttpDownload({
@@ -211,6 +433,12 @@ abstract class RustLibApi extends BaseApi {
     String? body,
   });
 
+  Future<HttpResponseDto> crateApiHttpApiHttpPostMultipart({
+    required String url,
+    required Map<String, String> headers,
+    required List<MultipartPart> parts,
+  });
+
   Future<HttpResponseDto> crateApiHttpApiHttpRequest({
     required String url,
     required String method,
@@ -219,20 +447,53 @@ abstract class RustLibApi extends BaseApi {
     required BigInt timeoutSecs,
   });
 
+  Future<void> crateApiBackupApiImportDatabase({required String srcPath});
+
   Future<ModuleInfo> crateApiModuleApiImportModuleFromUrl({
     required String url,
   });
 
+  Future<UserDataImportSummary> crateApiBackupApiImportUserData({
+    required String json,
+    required bool merge,
+  });
+
   Future<void> crateApiSimpleInitApp();
 
   Future<void> crateApiInitInitApplication({required String rootPath});
 
+  Future<void> crateApiInitInitApplicationWithKey({
+    required String rootPath,
+    String? dbKey,
+  });
+
   Future<void> crateApiInitInitFrb();
 
+  Future<ModuleInfo> crateApiModuleApiInstallModuleFromUrl({
+    required String url,
+    required bool overwrite,
+  });
+
+  Future<bool> crateApiDbApiIsDatabaseEncrypted();
+
+  bool crateApiDbApiIsDatabaseEncryptionSupported();
+
+  bool crateApiModuleApiIsDevHotReloadEnabled();
+
   bool crateApiInitIsInitialized();
 
   Future<List<PropertyItem>> crateApiPropertyApiListAppSettings();
 
+  Future<List<ChapterDownload>> crateApiDownloadApiListDownloads({
+    String? moduleId,
+  });
+
+  Future<ImageCachePage> crateApiImageCacheApiListImageCache({
+    String? moduleId,
+    required int page,
+    required int limit,
+  });
+
   Future<List<PropertyItem>> crateApiPropertyApiListProperties({
     required String moduleId,
   });
@@ -242,6 +503,12 @@ abstract class RustLibApi extends BaseApi {
     required String prefix,
   });
 
+  Future<PropertyPage> crateApiPropertyApiListPropertiesPaged({
+    required String moduleId,
+    required int page,
+    required int limit,
+  });
+
   Future<String?> crateApiPropertyApiLoadAppSetting({required String key});
 
   Future<void> crateApiModuleApiLoadModule({required String moduleId});
@@ -251,17 +518,72 @@ abstract class RustLibApi extends BaseApi {
     required String key,
   });
 
+  Future<LoginResult> crateApiModuleApiLogin({
+    required String moduleId,
+    required String credentialsJson,
+  });
+
+  Future<void> crateApiModuleApiLogout({required String moduleId});
+
+  Future<void> crateApiModuleApiPostComment({
+    required String moduleId,
+    required String comicId,
+    required String content,
+  });
+
+  Stream<ImagePrefetchItem> crateApiImageCacheApiPrefetchChapterImages({
+    required String moduleId,
+    required String pictureUrlsJson,
+    PlatformInt64? expireDays,
+    required int concurrency,
+  });
+
+  Future<ModulePreloadResult> crateApiModuleApiPreloadModules({
+    required List<String> ids,
+  });
+
   Future<String> crateApiImageCacheApiProcessImageWithModule({
     required String moduleId,
     required String imageDataBase64,
     required String paramsJson,
   });
 
+  Future<String> crateApiImageApiReadExif({required String imageDataBase64});
+
+  Future<String> crateApiImageApiRearrangeImageCols({
+    required String imageDataBase64,
+    required int cols,
+  });
+
+  Future<String> crateApiImageApiRearrangeImageGrid({
+    required String imageDataBase64,
+    required int cols,
+    required int rows,
+    required List<int> permutation,
+  });
+
   Future<String> crateApiImageApiRearrangeImageRows({
     required String imageDataBase64,
     required int rows,
   });
 
+  Future<List<SearchRecord>> crateApiSearchHistoryApiRecentSearches({
+    required String moduleId,
+    required int limit,
+  });
+
+  Future<void> crateApiHistoryApiRecordRead({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int page,
+  });
+
+  Future<void> crateApiSearchHistoryApiRecordSearch({
+    required String moduleId,
+    required String keyword,
+  });
+
   Future<ModuleInfo> crateApiModuleApiRegisterModule({
     required String moduleId,
   });
@@ -271,6 +593,13 @@ abstract class RustLibApi extends BaseApi {
     required String key,
   });
 
+  Future<void> crateApiWebCacheApiRemoveWebCache({
+    required String moduleId,
+    required String url,
+  });
+
+  Future<MaintenanceResult> crateApiDbApiRunMaintenance();
+
   Future<void> crateApiPropertyApiSaveAppSetting({
     required String key,
     required String value,
@@ -291,8 +620,21 @@ abstract class RustLibApi extends BaseApi {
     required String value,
   });
 
+  Future<void> crateApiWebCacheApiSaveWebCache({
+    required String moduleId,
+    required String url,
+    required String body,
+    required String contentType,
+    required PlatformInt64 expireSecs,
+  });
+
   Future<List<ModuleInfo>> crateApiModuleApiScanAndRegisterModules();
 
+  Future<AggregatedSearchResult> crateApiModuleApiSearchAll({
+    required String keyword,
+    required int page,
+  });
+
   Future<ComicsPage> crateApiModuleApiSearchComics({
     required String moduleId,
     required String keyword,
@@ -300,11 +642,52 @@ abstract class RustLibApi extends BaseApi {
     required int page,
   });
 
+  Future<void> crateApiModuleApiSetDevHotReload({required bool enabled});
+
+  Future<void> crateApiHttpApiSetDnsOverride({
+    required String host,
+    String? ip,
+  });
+
+  Future<void> crateApiHttpApiSetHttpDefaults({
+    BigInt? timeoutSecs,
+    String? userAgent,
+  });
+
+  void crateApiHttpApiSetHttpLogging({required bool enabled});
+
+  Future<void> crateApiHttpApiSetHttpPoolSettings({
+    int? poolMaxIdlePerHost,
+    BigInt? poolIdleTimeoutSecs,
+    BigInt? connectTimeoutSecs,
+  });
+
+  Future<void> crateApiImageCacheApiSetImageCacheLimit({
+    PlatformInt64? maxBytes,
+  });
+
+  void crateApiLogApiSetLogBufferSize({required BigInt size});
+
+  void crateApiLogApiSetLogLevel({required String level});
+
+  Future<void> crateApiModuleApiSetMaxLoadedModules({required int max});
+
   Future<void> crateApiModuleApiSetModuleEnabled({
     required String moduleId,
     required bool enabled,
   });
 
+  Future<void> crateApiProxyApiSetModuleProxy({
+    required String moduleId,
+    String? url,
+  });
+
+  Future<void> crateApiModuleApiSetModuleRuntimeLimits({
+    required String moduleId,
+    int? memoryLimitMb,
+    int? stackSizeKb,
+  });
+
   Future<void> crateApiModuleApiSetModuleSourceUrl({
     required String moduleId,
     String? sourceUrl,
@@ -316,34 +699,81 @@ abstract class RustLibApi extends BaseApi {
     required String value,
   });
 
+  Future<void> crateApiProgressApiSetProgress({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int lastPage,
+    required int totalPages,
+  });
+
   Future<void> crateApiProxyApiSetProxy({String? url});
 
-  Future<void> crateApiModuleApiUnloadModule({required String moduleId});
+  Future<void> crateApiProxyApiSetProxyBypass({String? bypass});
 
-  Future<ModuleInfo> crateApiModuleApiUpdateModule({required String moduleId});
-}
+  Future<void> crateApiModuleApiSetSearchAllConcurrency({int? limit});
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+  Future<void> crateApiHttpApiSetTlsInsecure({required bool insecure});
 
-  @override
-  Future<String> crateApiModuleApiCallModuleFunction({
+  Stream<ChapterDownload> crateApiDownloadApiStartChapterDownload({
     required String moduleId,
+    required String comicId,
+    required String epId,
+    String? cancelToken,
+  });
+
+  Future<ModuleCallOutput> crateApiModuleApiTestModuleScript({
+    required String script,
     required String funcName,
     required String argsJson,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(funcName, serializer);
-          sse_encode_String(argsJson, serializer);
+  });
+
+  Future<bool> crateApiModuleApiToggleFavourite({
+    required String moduleId,
+    required String comicId,
+  });
+
+  Future<bool> crateApiModuleApiToggleLike({
+    required String moduleId,
+    required String comicId,
+  });
+
+  Future<void> crateApiModuleApiUninstallModule({
+    required String moduleId,
+    required bool purgeData,
+  });
+
+  Future<void> crateApiModuleApiUnloadModule({required String moduleId});
+
+  Future<void> crateApiHistoryApiUpdateHistoryInfo({
+    required String moduleId,
+    required String comicId,
+    String? title,
+    String? thumbUrl,
+  });
+
+  Future<ModuleUpdateResult> crateApiModuleApiUpdateModule({
+    required String moduleId,
+  });
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  Future<String> crateApiImageApiAutoOrientImage({
+    required String imageDataBase64,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(imageDataBase64, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -355,25 +785,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiCallModuleFunctionConstMeta,
-        argValues: [moduleId, funcName, argsJson],
+        constMeta: kCrateApiImageApiAutoOrientImageConstMeta,
+        argValues: [imageDataBase64],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiCallModuleFunctionConstMeta =>
+  TaskConstMeta get kCrateApiImageApiAutoOrientImageConstMeta =>
       const TaskConstMeta(
-        debugName: "call_module_function",
-        argNames: ["moduleId", "funcName", "argsJson"],
+        debugName: "auto_orient_image",
+        argNames: ["imageDataBase64"],
       );
 
   @override
-  Future<BigInt> crateApiImageCacheApiClearAllImageCache() {
+  Future<List<BatchCallResult>> crateApiModuleApiBatchCall({
+    required String moduleId,
+    required List<BatchCall> calls,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_list_batch_call(calls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -382,25 +817,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_list_batch_call_result,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiImageCacheApiClearAllImageCacheConstMeta,
-        argValues: [],
+        constMeta: kCrateApiModuleApiBatchCallConstMeta,
+        argValues: [moduleId, calls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiClearAllImageCacheConstMeta =>
-      const TaskConstMeta(debugName: "clear_all_image_cache", argNames: []);
+  TaskConstMeta get kCrateApiModuleApiBatchCallConstMeta => const TaskConstMeta(
+    debugName: "batch_call",
+    argNames: ["moduleId", "calls"],
+  );
 
   @override
-  Future<BigInt> crateApiImageCacheApiClearExpiredImageCache() {
+  Future<String> crateApiModuleApiCallModuleFunction({
+    required String moduleId,
+    required String funcName,
+    required String argsJson,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(funcName, serializer);
+          sse_encode_String(argsJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -409,28 +853,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiImageCacheApiClearExpiredImageCacheConstMeta,
-        argValues: [],
+        constMeta: kCrateApiModuleApiCallModuleFunctionConstMeta,
+        argValues: [moduleId, funcName, argsJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiClearExpiredImageCacheConstMeta =>
-      const TaskConstMeta(debugName: "clear_expired_image_cache", argNames: []);
+  TaskConstMeta get kCrateApiModuleApiCallModuleFunctionConstMeta =>
+      const TaskConstMeta(
+        debugName: "call_module_function",
+        argNames: ["moduleId", "funcName", "argsJson"],
+      );
 
   @override
-  Future<BigInt> crateApiImageCacheApiClearImageCacheByModule({
+  Future<ModuleCallOutput> crateApiModuleApiCallModuleFunctionWithLogs({
     required String moduleId,
+    required String funcName,
+    required String argsJson,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
+          sse_encode_String(funcName, serializer);
+          sse_encode_String(argsJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -439,31 +890,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_module_call_output,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiImageCacheApiClearImageCacheByModuleConstMeta,
-        argValues: [moduleId],
+        constMeta: kCrateApiModuleApiCallModuleFunctionWithLogsConstMeta,
+        argValues: [moduleId, funcName, argsJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiClearImageCacheByModuleConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiCallModuleFunctionWithLogsConstMeta =>
       const TaskConstMeta(
-        debugName: "clear_image_cache_by_module",
-        argNames: ["moduleId"],
+        debugName: "call_module_function_with_logs",
+        argNames: ["moduleId", "funcName", "argsJson"],
       );
 
   @override
-  Future<BigInt> crateApiPropertyApiClearModuleProperties({
+  Future<String> crateApiModuleApiCallModuleFunctionWithTimeout({
     required String moduleId,
+    required String funcName,
+    required String argsJson,
+    required BigInt timeoutMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
+          sse_encode_String(funcName, serializer);
+          sse_encode_String(argsJson, serializer);
+          sse_encode_u_64(timeoutMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -472,24 +929,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiPropertyApiClearModulePropertiesConstMeta,
-        argValues: [moduleId],
+        constMeta: kCrateApiModuleApiCallModuleFunctionWithTimeoutConstMeta,
+        argValues: [moduleId, funcName, argsJson, timeoutMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiClearModulePropertiesConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiCallModuleFunctionWithTimeoutConstMeta =>
       const TaskConstMeta(
-        debugName: "clear_module_properties",
-        argNames: ["moduleId"],
+        debugName: "call_module_function_with_timeout",
+        argNames: ["moduleId", "funcName", "argsJson", "timeoutMs"],
       );
 
   @override
-  Future<void> crateApiProxyApiClearProxy() {
+  bool crateApiCancelApiCancel({required String token}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(token, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCancelApiCancelConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCancelApiCancelConstMeta =>
+      const TaskConstMeta(debugName: "cancel", argNames: ["token"]);
+
+  @override
+  Future<List<ModuleUpdateInfo>> crateApiModuleApiCheckUpdates() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -497,248 +977,182 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 7,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_module_update_info,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiProxyApiClearProxyConstMeta,
+        constMeta: kCrateApiModuleApiCheckUpdatesConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProxyApiClearProxyConstMeta =>
-      const TaskConstMeta(debugName: "clear_proxy", argNames: []);
+  TaskConstMeta get kCrateApiModuleApiCheckUpdatesConstMeta =>
+      const TaskConstMeta(debugName: "check_updates", argNames: []);
 
   @override
-  String crateApiCryptoApiCryptoBase64Decode({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<BigInt> crateApiImageCacheApiClearAllImageCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCryptoApiCryptoBase64DecodeConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiImageCacheApiClearAllImageCacheConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoApiCryptoBase64DecodeConstMeta =>
-      const TaskConstMeta(
-        debugName: "crypto_base64_decode",
-        argNames: ["data"],
-      );
+  TaskConstMeta get kCrateApiImageCacheApiClearAllImageCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_all_image_cache", argNames: []);
 
   @override
-  String crateApiCryptoApiCryptoBase64Encode({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<BigInt> crateApiImageCacheApiClearExpiredImageCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCryptoApiCryptoBase64EncodeConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiImageCacheApiClearExpiredImageCacheConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoApiCryptoBase64EncodeConstMeta =>
-      const TaskConstMeta(
-        debugName: "crypto_base64_encode",
-        argNames: ["data"],
-      );
+  TaskConstMeta get kCrateApiImageCacheApiClearExpiredImageCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_expired_image_cache", argNames: []);
 
   @override
-  String crateApiCryptoApiCryptoHexDecode({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<BigInt> crateApiWebCacheApiClearExpiredWebCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCryptoApiCryptoHexDecodeConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiWebCacheApiClearExpiredWebCacheConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoApiCryptoHexDecodeConstMeta =>
-      const TaskConstMeta(debugName: "crypto_hex_decode", argNames: ["data"]);
+  TaskConstMeta get kCrateApiWebCacheApiClearExpiredWebCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_expired_web_cache", argNames: []);
 
   @override
-  String crateApiCryptoApiCryptoHexEncode({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<BigInt> crateApiHistoryApiClearHistoryBefore({
+    required PlatformInt64 days,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+          sse_encode_i_64(days, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCryptoApiCryptoHexEncodeConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiHistoryApiClearHistoryBeforeConstMeta,
+        argValues: [days],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoApiCryptoHexEncodeConstMeta =>
-      const TaskConstMeta(debugName: "crypto_hex_encode", argNames: ["data"]);
+  TaskConstMeta get kCrateApiHistoryApiClearHistoryBeforeConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_history_before",
+        argNames: ["days"],
+      );
 
   @override
-  String crateApiCryptoApiCryptoMd5({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<BigInt> crateApiImageCacheApiClearImageCacheByModule({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCryptoApiCryptoMd5ConstMeta,
-        argValues: [data],
+        constMeta: kCrateApiImageCacheApiClearImageCacheByModuleConstMeta,
+        argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoApiCryptoMd5ConstMeta =>
-      const TaskConstMeta(debugName: "crypto_md5", argNames: ["data"]);
-
-  @override
-  String crateApiCryptoApiCryptoMd5Bytes({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCryptoApiCryptoMd5BytesConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCryptoApiCryptoMd5BytesConstMeta =>
-      const TaskConstMeta(debugName: "crypto_md5_bytes", argNames: ["data"]);
-
-  @override
-  String crateApiCryptoApiCryptoSha256({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCryptoApiCryptoSha256ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCryptoApiCryptoSha256ConstMeta =>
-      const TaskConstMeta(debugName: "crypto_sha256", argNames: ["data"]);
-
-  @override
-  String crateApiCryptoApiCryptoSha256Bytes({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCryptoApiCryptoSha256BytesConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCryptoApiCryptoSha256BytesConstMeta =>
-      const TaskConstMeta(debugName: "crypto_sha256_bytes", argNames: ["data"]);
-
-  @override
-  String crateApiCryptoApiCryptoSha512({required String data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCryptoApiCryptoSha512ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCryptoApiCryptoSha512ConstMeta =>
-      const TaskConstMeta(debugName: "crypto_sha512", argNames: ["data"]);
+  TaskConstMeta get kCrateApiImageCacheApiClearImageCacheByModuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_image_cache_by_module",
+        argNames: ["moduleId"],
+      );
 
   @override
-  Future<void> crateApiPropertyApiDeleteAppSetting({required String key}) {
+  Future<void> crateApiHttpApiClearModuleCookies({required String moduleId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
+          sse_encode_String(moduleId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 13,
             port: port_,
           );
         },
@@ -746,18 +1160,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiPropertyApiDeleteAppSettingConstMeta,
-        argValues: [key],
+        constMeta: kCrateApiHttpApiClearModuleCookiesConstMeta,
+        argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiDeleteAppSettingConstMeta =>
-      const TaskConstMeta(debugName: "delete_app_setting", argNames: ["key"]);
+  TaskConstMeta get kCrateApiHttpApiClearModuleCookiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_module_cookies",
+        argNames: ["moduleId"],
+      );
 
   @override
-  Future<void> crateApiModuleApiDeleteModule({required String moduleId}) {
+  Future<BigInt> crateApiPropertyApiClearModuleProperties({
+    required String moduleId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -766,39 +1185,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 14,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiDeleteModuleConstMeta,
+        constMeta: kCrateApiPropertyApiClearModulePropertiesConstMeta,
         argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiDeleteModuleConstMeta =>
-      const TaskConstMeta(debugName: "delete_module", argNames: ["moduleId"]);
+  TaskConstMeta get kCrateApiPropertyApiClearModulePropertiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_module_properties",
+        argNames: ["moduleId"],
+      );
 
   @override
-  Future<void> crateApiPropertyApiDeleteProperty({
-    required String moduleId,
-    required String key,
-  }) {
+  Future<void> crateApiProxyApiClearProxy() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 15,
             port: port_,
           );
         },
@@ -806,78 +1223,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiPropertyApiDeletePropertyConstMeta,
-        argValues: [moduleId, key],
+        constMeta: kCrateApiProxyApiClearProxyConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiDeletePropertyConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_property",
-        argNames: ["moduleId", "key"],
-      );
+  TaskConstMeta get kCrateApiProxyApiClearProxyConstMeta =>
+      const TaskConstMeta(debugName: "clear_proxy", argNames: []);
 
   @override
-  String? crateApiInitGetCacheDir() {
+  void crateApiLogApiClearRecentLogs() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitGetCacheDirConstMeta,
+        constMeta: kCrateApiLogApiClearRecentLogsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGetCacheDirConstMeta =>
-      const TaskConstMeta(debugName: "get_cache_dir", argNames: []);
+  TaskConstMeta get kCrateApiLogApiClearRecentLogsConstMeta =>
+      const TaskConstMeta(debugName: "clear_recent_logs", argNames: []);
 
   @override
-  Future<String?> crateApiImageCacheApiGetCachedImage({
+  Future<BigInt> crateApiSearchHistoryApiClearSearchHistory({
     required String moduleId,
-    required String url,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 17,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiImageCacheApiGetCachedImageConstMeta,
-        argValues: [moduleId, url],
+        constMeta: kCrateApiSearchHistoryApiClearSearchHistoryConstMeta,
+        argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiGetCachedImageConstMeta =>
+  TaskConstMeta get kCrateApiSearchHistoryApiClearSearchHistoryConstMeta =>
       const TaskConstMeta(
-        debugName: "get_cached_image",
-        argNames: ["moduleId", "url"],
+        debugName: "clear_search_history",
+        argNames: ["moduleId"],
       );
 
   @override
-  Future<List<Category>> crateApiModuleApiGetCategories({
+  Future<BigInt> crateApiWebCacheApiClearWebCacheByModule({
     required String moduleId,
   }) {
     return handler.executeNormal(
@@ -888,631 +1300,669 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_category,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiGetCategoriesConstMeta,
+        constMeta: kCrateApiWebCacheApiClearWebCacheByModuleConstMeta,
         argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetCategoriesConstMeta =>
-      const TaskConstMeta(debugName: "get_categories", argNames: ["moduleId"]);
+  TaskConstMeta get kCrateApiWebCacheApiClearWebCacheByModuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_web_cache_by_module",
+        argNames: ["moduleId"],
+      );
 
   @override
-  Future<ComicDetail> crateApiModuleApiGetComicDetail({
-    required String moduleId,
-    required String comicId,
+  Future<List<String>> crateApiImageApiConcatImagesVertical({
+    required List<String> imagesBase64,
+    required int maxHeight,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(comicId, serializer);
+          sse_encode_list_String(imagesBase64, serializer);
+          sse_encode_u_32(maxHeight, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_comic_detail,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiGetComicDetailConstMeta,
-        argValues: [moduleId, comicId],
+        constMeta: kCrateApiImageApiConcatImagesVerticalConstMeta,
+        argValues: [imagesBase64, maxHeight],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetComicDetailConstMeta =>
+  TaskConstMeta get kCrateApiImageApiConcatImagesVerticalConstMeta =>
       const TaskConstMeta(
-        debugName: "get_comic_detail",
-        argNames: ["moduleId", "comicId"],
+        debugName: "concat_images_vertical",
+        argNames: ["imagesBase64", "maxHeight"],
       );
 
   @override
-  Future<ComicsPage> crateApiModuleApiGetComics({
-    required String moduleId,
-    required String categorySlug,
-    required String sortBy,
-    required int page,
+  Future<String> crateApiImageApiConvertImage({
+    required String imageDataBase64,
+    required String format,
+    int? quality,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(categorySlug, serializer);
-          sse_encode_String(sortBy, serializer);
-          sse_encode_i_32(page, serializer);
+          sse_encode_String(imageDataBase64, serializer);
+          sse_encode_String(format, serializer);
+          sse_encode_opt_box_autoadd_u_8(quality, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 20,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_comics_page,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiGetComicsConstMeta,
-        argValues: [moduleId, categorySlug, sortBy, page],
+        constMeta: kCrateApiImageApiConvertImageConstMeta,
+        argValues: [imageDataBase64, format, quality],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetComicsConstMeta => const TaskConstMeta(
-    debugName: "get_comics",
-    argNames: ["moduleId", "categorySlug", "sortBy", "page"],
-  );
+  TaskConstMeta get kCrateApiImageApiConvertImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_image",
+        argNames: ["imageDataBase64", "format", "quality"],
+      );
 
   @override
-  Future<EpPage> crateApiModuleApiGetEps({
-    required String moduleId,
-    required String comicId,
-    required int page,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoAdler32Bytes({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(comicId, serializer);
-          sse_encode_i_32(page, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_ep_page,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiModuleApiGetEpsConstMeta,
-        argValues: [moduleId, comicId, page],
+        constMeta: kCrateApiCryptoApiCryptoAdler32BytesConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetEpsConstMeta => const TaskConstMeta(
-    debugName: "get_eps",
-    argNames: ["moduleId", "comicId", "page"],
-  );
+  TaskConstMeta get kCrateApiCryptoApiCryptoAdler32BytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "crypto_adler32_bytes",
+        argNames: ["data"],
+      );
 
   @override
-  Future<ImageCacheStats> crateApiImageCacheApiGetImageCacheStats() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoBase64Decode({required String data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_image_cache_stats,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiImageCacheApiGetImageCacheStatsConstMeta,
-        argValues: [],
-        apiImpl: this,
+        constMeta: kCrateApiCryptoApiCryptoBase64DecodeConstMeta,
+        argValues: [data],
+        apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiGetImageCacheStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_image_cache_stats", argNames: []);
+  TaskConstMeta get kCrateApiCryptoApiCryptoBase64DecodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "crypto_base64_decode",
+        argNames: ["data"],
+      );
 
   @override
-  Future<String> crateApiImageApiGetImageInfo({
-    required String imageDataBase64,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoBase64Encode({required String data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(imageDataBase64, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiImageApiGetImageInfoConstMeta,
-        argValues: [imageDataBase64],
+        constMeta: kCrateApiCryptoApiCryptoBase64EncodeConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageApiGetImageInfoConstMeta =>
+  TaskConstMeta get kCrateApiCryptoApiCryptoBase64EncodeConstMeta =>
       const TaskConstMeta(
-        debugName: "get_image_info",
-        argNames: ["imageDataBase64"],
+        debugName: "crypto_base64_encode",
+        argNames: ["data"],
       );
 
   @override
-  Future<String?> crateApiModuleApiGetModuleStorage({
-    required String moduleId,
-    required String key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoCrc32Bytes({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(key, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiModuleApiGetModuleStorageConstMeta,
-        argValues: [moduleId, key],
+        constMeta: kCrateApiCryptoApiCryptoCrc32BytesConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetModuleStorageConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_module_storage",
-        argNames: ["moduleId", "key"],
-      );
+  TaskConstMeta get kCrateApiCryptoApiCryptoCrc32BytesConstMeta =>
+      const TaskConstMeta(debugName: "crypto_crc32_bytes", argNames: ["data"]);
 
   @override
-  Future<List<ModuleInfo>> crateApiModuleApiGetModules() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoHexDecode({required String data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_module_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiGetModulesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCryptoApiCryptoHexDecodeConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetModulesConstMeta =>
-      const TaskConstMeta(debugName: "get_modules", argNames: []);
+  TaskConstMeta get kCrateApiCryptoApiCryptoHexDecodeConstMeta =>
+      const TaskConstMeta(debugName: "crypto_hex_decode", argNames: ["data"]);
 
   @override
-  String? crateApiInitGetModulesDir() {
+  String crateApiCryptoApiCryptoHexEncode({required String data}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitGetModulesDirConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCryptoApiCryptoHexEncodeConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGetModulesDirConstMeta =>
-      const TaskConstMeta(debugName: "get_modules_dir", argNames: []);
+  TaskConstMeta get kCrateApiCryptoApiCryptoHexEncodeConstMeta =>
+      const TaskConstMeta(debugName: "crypto_hex_encode", argNames: ["data"]);
 
   @override
-  Future<PicturePage> crateApiModuleApiGetPictures({
-    required String moduleId,
-    required String comicId,
-    required String epId,
-    required int page,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoMd5({required String data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(comicId, serializer);
-          sse_encode_String(epId, serializer);
-          sse_encode_i_32(page, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_picture_page,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiModuleApiGetPicturesConstMeta,
-        argValues: [moduleId, comicId, epId, page],
+        constMeta: kCrateApiCryptoApiCryptoMd5ConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetPicturesConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_pictures",
-        argNames: ["moduleId", "comicId", "epId", "page"],
-      );
+  TaskConstMeta get kCrateApiCryptoApiCryptoMd5ConstMeta =>
+      const TaskConstMeta(debugName: "crypto_md5", argNames: ["data"]);
 
   @override
-  Future<String?> crateApiProxyApiGetProxy() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoMd5Bytes({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiProxyApiGetProxyConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCryptoApiCryptoMd5BytesConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProxyApiGetProxyConstMeta =>
-      const TaskConstMeta(debugName: "get_proxy", argNames: []);
+  TaskConstMeta get kCrateApiCryptoApiCryptoMd5BytesConstMeta =>
+      const TaskConstMeta(debugName: "crypto_md5_bytes", argNames: ["data"]);
 
   @override
-  String? crateApiInitGetRootPath() {
+  String crateApiCryptoApiCryptoSha256({required String data}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitGetRootPathConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCryptoApiCryptoSha256ConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGetRootPathConstMeta =>
-      const TaskConstMeta(debugName: "get_root_path", argNames: []);
+  TaskConstMeta get kCrateApiCryptoApiCryptoSha256ConstMeta =>
+      const TaskConstMeta(debugName: "crypto_sha256", argNames: ["data"]);
 
   @override
-  Future<List<SortOption>> crateApiModuleApiGetSortOptions({
-    required String moduleId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiCryptoApiCryptoSha256Bytes({required List<int> data}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_sort_option,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiModuleApiGetSortOptionsConstMeta,
-        argValues: [moduleId],
+        constMeta: kCrateApiCryptoApiCryptoSha256BytesConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiGetSortOptionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_sort_options",
-        argNames: ["moduleId"],
-      );
+  TaskConstMeta get kCrateApiCryptoApiCryptoSha256BytesConstMeta =>
+      const TaskConstMeta(debugName: "crypto_sha256_bytes", argNames: ["data"]);
 
   @override
-  String crateApiSimpleGreet({required String name}) {
+  String crateApiCryptoApiCryptoSha512({required String data}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+          sse_encode_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiCryptoApiCryptoSha512ConstMeta,
+        argValues: [data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiCryptoApiCryptoSha512ConstMeta =>
+      const TaskConstMeta(debugName: "crypto_sha512", argNames: ["data"]);
 
   @override
-  Future<Uint8List> crateApiHttpApiHttpDownload({
-    required String url,
-    required Map<String, String> headers,
-  }) {
+  Future<void> crateApiPropertyApiDeleteAppSetting({required String key}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          sse_encode_Map_String_String_None(headers, serializer);
+          sse_encode_String(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 32,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiHttpApiHttpDownloadConstMeta,
-        argValues: [url, headers],
+        constMeta: kCrateApiPropertyApiDeleteAppSettingConstMeta,
+        argValues: [key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHttpApiHttpDownloadConstMeta =>
-      const TaskConstMeta(
-        debugName: "http_download",
-        argNames: ["url", "headers"],
-      );
+  TaskConstMeta get kCrateApiPropertyApiDeleteAppSettingConstMeta =>
+      const TaskConstMeta(debugName: "delete_app_setting", argNames: ["key"]);
 
   @override
-  Future<HttpResponseDto> crateApiHttpApiHttpGet({
-    required String url,
-    required Map<String, String> headers,
-  }) {
+  Future<void> crateApiDownloadApiDeleteDownload({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          sse_encode_Map_String_String_None(headers, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 33,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_http_response_dto,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiHttpApiHttpGetConstMeta,
-        argValues: [url, headers],
+        constMeta: kCrateApiDownloadApiDeleteDownloadConstMeta,
+        argValues: [moduleId, comicId, epId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHttpApiHttpGetConstMeta =>
-      const TaskConstMeta(debugName: "http_get", argNames: ["url", "headers"]);
+  TaskConstMeta get kCrateApiDownloadApiDeleteDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_download",
+        argNames: ["moduleId", "comicId", "epId"],
+      );
 
   @override
-  Future<HttpResponseDto> crateApiHttpApiHttpPost({
-    required String url,
-    required Map<String, String> headers,
-    String? body,
+  Future<void> crateApiModuleApiDeleteModule({required String moduleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiDeleteModuleConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiDeleteModuleConstMeta =>
+      const TaskConstMeta(debugName: "delete_module", argNames: ["moduleId"]);
+
+  @override
+  Future<BigInt> crateApiPropertyApiDeletePropertiesByPrefix({
+    required String moduleId,
+    required String prefix,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          sse_encode_Map_String_String_None(headers, serializer);
-          sse_encode_opt_String(body, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(prefix, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_http_response_dto,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiHttpApiHttpPostConstMeta,
-        argValues: [url, headers, body],
+        constMeta: kCrateApiPropertyApiDeletePropertiesByPrefixConstMeta,
+        argValues: [moduleId, prefix],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHttpApiHttpPostConstMeta => const TaskConstMeta(
-    debugName: "http_post",
-    argNames: ["url", "headers", "body"],
-  );
+  TaskConstMeta get kCrateApiPropertyApiDeletePropertiesByPrefixConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_properties_by_prefix",
+        argNames: ["moduleId", "prefix"],
+      );
 
   @override
-  Future<HttpResponseDto> crateApiHttpApiHttpRequest({
-    required String url,
-    required String method,
-    required Map<String, String> headers,
-    String? body,
-    required BigInt timeoutSecs,
+  Future<void> crateApiPropertyApiDeleteProperty({
+    required String moduleId,
+    required String key,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          sse_encode_String(method, serializer);
-          sse_encode_Map_String_String_None(headers, serializer);
-          sse_encode_opt_String(body, serializer);
-          sse_encode_u_64(timeoutSecs, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_http_response_dto,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiHttpApiHttpRequestConstMeta,
-        argValues: [url, method, headers, body, timeoutSecs],
+        constMeta: kCrateApiPropertyApiDeletePropertyConstMeta,
+        argValues: [moduleId, key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHttpApiHttpRequestConstMeta => const TaskConstMeta(
-    debugName: "http_request",
-    argNames: ["url", "method", "headers", "body", "timeoutSecs"],
-  );
+  TaskConstMeta get kCrateApiPropertyApiDeletePropertyConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_property",
+        argNames: ["moduleId", "key"],
+      );
 
   @override
-  Future<ModuleInfo> crateApiModuleApiImportModuleFromUrl({
+  Stream<DownloadProgress> crateApiDownloadApiDownloadToFile({
     required String url,
+    required Map<String, String> headers,
+    required String destPath,
+    String? cancelToken,
+  }) {
+    final sink = RustStreamSink<DownloadProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(url, serializer);
+            sse_encode_Map_String_String_None(headers, serializer);
+            sse_encode_String(destPath, serializer);
+            sse_encode_opt_String(cancelToken, serializer);
+            sse_encode_StreamSink_download_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 37,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiDownloadApiDownloadToFileConstMeta,
+          argValues: [url, headers, destPath, cancelToken, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadApiDownloadToFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "download_to_file",
+        argNames: ["url", "headers", "destPath", "cancelToken", "sink"],
+      );
+
+  @override
+  Future<BigInt> crateApiImageCacheApiEnforceImageCacheLimit({
+    required PlatformInt64 maxBytes,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_i_64(maxBytes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_module_info,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiImportModuleFromUrlConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiImageCacheApiEnforceImageCacheLimitConstMeta,
+        argValues: [maxBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiImportModuleFromUrlConstMeta =>
+  TaskConstMeta get kCrateApiImageCacheApiEnforceImageCacheLimitConstMeta =>
       const TaskConstMeta(
-        debugName: "import_module_from_url",
-        argNames: ["url"],
+        debugName: "enforce_image_cache_limit",
+        argNames: ["maxBytes"],
       );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiBackupApiExportDatabase({required String destPath}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(destPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
+        constMeta: kCrateApiBackupApiExportDatabaseConstMeta,
+        argValues: [destPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupApiExportDatabaseConstMeta =>
+      const TaskConstMeta(debugName: "export_database", argNames: ["destPath"]);
+
+  @override
+  Future<String> crateApiBackupApiExportUserData() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiBackupApiExportUserDataConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiBackupApiExportUserDataConstMeta =>
+      const TaskConstMeta(debugName: "export_user_data", argNames: []);
 
   @override
-  Future<void> crateApiInitInitApplication({required String rootPath}) {
+  Future<String> crateApiImageCacheApiFetchAndCacheImage({
+    required String moduleId,
+    required String remoteImageInfoJson,
+    PlatformInt64? expireDays,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(rootPath, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(remoteImageInfoJson, serializer);
+          sse_encode_opt_box_autoadd_i_64(expireDays, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1521,28 +1971,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitInitApplicationConstMeta,
-        argValues: [rootPath],
+        constMeta: kCrateApiImageCacheApiFetchAndCacheImageConstMeta,
+        argValues: [moduleId, remoteImageInfoJson, expireDays],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitInitApplicationConstMeta =>
+  TaskConstMeta get kCrateApiImageCacheApiFetchAndCacheImageConstMeta =>
       const TaskConstMeta(
-        debugName: "init_application",
-        argNames: ["rootPath"],
+        debugName: "fetch_and_cache_image",
+        argNames: ["moduleId", "remoteImageInfoJson", "expireDays"],
       );
 
   @override
-  Future<void> crateApiInitInitFrb() {
+  Future<ModuleUpdateResult> crateApiModuleApiForceUpdateModule({
+    required String moduleId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1551,21 +2004,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_module_update_result,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitInitFrbConstMeta,
-        argValues: [],
+        constMeta: kCrateApiModuleApiForceUpdateModuleConstMeta,
+        argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitInitFrbConstMeta =>
-      const TaskConstMeta(debugName: "init_frb", argNames: []);
+  TaskConstMeta get kCrateApiModuleApiForceUpdateModuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "force_update_module",
+        argNames: ["moduleId"],
+      );
 
   @override
-  bool crateApiInitIsInitialized() {
+  String? crateApiInitGetCacheDir() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
@@ -1573,25 +2029,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitIsInitializedConstMeta,
+        constMeta: kCrateApiInitGetCacheDirConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitIsInitializedConstMeta =>
-      const TaskConstMeta(debugName: "is_initialized", argNames: []);
+  TaskConstMeta get kCrateApiInitGetCacheDirConstMeta =>
+      const TaskConstMeta(debugName: "get_cache_dir", argNames: []);
 
   @override
-  Future<List<PropertyItem>> crateApiPropertyApiListAppSettings() {
+  Future<String?> crateApiImageCacheApiGetCachedImage({
+    required String moduleId,
+    required String url,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1600,21 +2061,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_property_item,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiPropertyApiListAppSettingsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiImageCacheApiGetCachedImageConstMeta,
+        argValues: [moduleId, url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiListAppSettingsConstMeta =>
-      const TaskConstMeta(debugName: "list_app_settings", argNames: []);
+  TaskConstMeta get kCrateApiImageCacheApiGetCachedImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cached_image",
+        argNames: ["moduleId", "url"],
+      );
 
   @override
-  Future<List<PropertyItem>> crateApiPropertyApiListProperties({
+  Future<List<Category>> crateApiModuleApiGetCategories({
     required String moduleId,
   }) {
     return handler.executeNormal(
@@ -1630,30 +2094,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_property_item,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_list_category,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiPropertyApiListPropertiesConstMeta,
+        constMeta: kCrateApiModuleApiGetCategoriesConstMeta,
         argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiListPropertiesConstMeta =>
-      const TaskConstMeta(debugName: "list_properties", argNames: ["moduleId"]);
+  TaskConstMeta get kCrateApiModuleApiGetCategoriesConstMeta =>
+      const TaskConstMeta(debugName: "get_categories", argNames: ["moduleId"]);
 
   @override
-  Future<List<PropertyItem>> crateApiPropertyApiListPropertiesByPrefix({
+  Future<ComicDetail> crateApiModuleApiGetComicDetail({
     required String moduleId,
-    required String prefix,
+    required String comicId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_String(prefix, serializer);
+          sse_encode_String(comicId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1662,29 +2126,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_property_item,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_comic_detail,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiPropertyApiListPropertiesByPrefixConstMeta,
-        argValues: [moduleId, prefix],
+        constMeta: kCrateApiModuleApiGetComicDetailConstMeta,
+        argValues: [moduleId, comicId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiListPropertiesByPrefixConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiGetComicDetailConstMeta =>
       const TaskConstMeta(
-        debugName: "list_properties_by_prefix",
-        argNames: ["moduleId", "prefix"],
+        debugName: "get_comic_detail",
+        argNames: ["moduleId", "comicId"],
       );
 
   @override
-  Future<String?> crateApiPropertyApiLoadAppSetting({required String key}) {
+  Future<Map<String, int>> crateApiProgressApiGetComicProgress({
+    required String moduleId,
+    required String comicId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1693,26 +2161,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_Map_String_i_32_None,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiPropertyApiLoadAppSettingConstMeta,
-        argValues: [key],
+        constMeta: kCrateApiProgressApiGetComicProgressConstMeta,
+        argValues: [moduleId, comicId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiLoadAppSettingConstMeta =>
-      const TaskConstMeta(debugName: "load_app_setting", argNames: ["key"]);
+  TaskConstMeta get kCrateApiProgressApiGetComicProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_comic_progress",
+        argNames: ["moduleId", "comicId"],
+      );
 
   @override
-  Future<void> crateApiModuleApiLoadModule({required String moduleId}) {
+  Future<ComicsPage> crateApiModuleApiGetComics({
+    required String moduleId,
+    required String categorySlug,
+    required String sortBy,
+    required int page,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
+          sse_encode_String(categorySlug, serializer);
+          sse_encode_String(sortBy, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1721,30 +2200,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_comics_page,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiModuleApiLoadModuleConstMeta,
-        argValues: [moduleId],
+        constMeta: kCrateApiModuleApiGetComicsConstMeta,
+        argValues: [moduleId, categorySlug, sortBy, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiLoadModuleConstMeta =>
-      const TaskConstMeta(debugName: "load_module", argNames: ["moduleId"]);
+  TaskConstMeta get kCrateApiModuleApiGetComicsConstMeta => const TaskConstMeta(
+    debugName: "get_comics",
+    argNames: ["moduleId", "categorySlug", "sortBy", "page"],
+  );
 
   @override
-  Future<String?> crateApiPropertyApiLoadProperty({
+  Future<ComicsPage> crateApiModuleApiGetComicsByAuthor({
     required String moduleId,
-    required String key,
+    required String author,
+    required int page,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_String(key, serializer);
+          sse_encode_String(author, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1753,35 +2236,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_comics_page,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiPropertyApiLoadPropertyConstMeta,
-        argValues: [moduleId, key],
+        constMeta: kCrateApiModuleApiGetComicsByAuthorConstMeta,
+        argValues: [moduleId, author, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiLoadPropertyConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiGetComicsByAuthorConstMeta =>
       const TaskConstMeta(
-        debugName: "load_property",
-        argNames: ["moduleId", "key"],
+        debugName: "get_comics_by_author",
+        argNames: ["moduleId", "author", "page"],
       );
 
   @override
-  Future<String> crateApiImageCacheApiProcessImageWithModule({
+  Future<ComicsPage> crateApiModuleApiGetComicsByTag({
     required String moduleId,
-    required String imageDataBase64,
-    required String paramsJson,
+    required String tag,
+    required String sortBy,
+    required int page,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_String(imageDataBase64, serializer);
-          sse_encode_String(paramsJson, serializer);
+          sse_encode_String(tag, serializer);
+          sse_encode_String(sortBy, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1790,33 +2275,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_comics_page,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiImageCacheApiProcessImageWithModuleConstMeta,
-        argValues: [moduleId, imageDataBase64, paramsJson],
+        constMeta: kCrateApiModuleApiGetComicsByTagConstMeta,
+        argValues: [moduleId, tag, sortBy, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiProcessImageWithModuleConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiGetComicsByTagConstMeta =>
       const TaskConstMeta(
-        debugName: "process_image_with_module",
-        argNames: ["moduleId", "imageDataBase64", "paramsJson"],
+        debugName: "get_comics_by_tag",
+        argNames: ["moduleId", "tag", "sortBy", "page"],
       );
 
   @override
-  Future<String> crateApiImageApiRearrangeImageRows({
-    required String imageDataBase64,
-    required int rows,
+  Future<CommentPage> crateApiModuleApiGetComments({
+    required String moduleId,
+    required String comicId,
+    required int page,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(imageDataBase64, serializer);
-          sse_encode_u_32(rows, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1825,63 +2312,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_comment_page,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiImageApiRearrangeImageRowsConstMeta,
-        argValues: [imageDataBase64, rows],
+        constMeta: kCrateApiModuleApiGetCommentsConstMeta,
+        argValues: [moduleId, comicId, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageApiRearrangeImageRowsConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiGetCommentsConstMeta =>
       const TaskConstMeta(
-        debugName: "rearrange_image_rows",
-        argNames: ["imageDataBase64", "rows"],
+        debugName: "get_comments",
+        argNames: ["moduleId", "comicId", "page"],
       );
 
   @override
-  Future<ModuleInfo> crateApiModuleApiRegisterModule({
-    required String moduleId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  Map<String, String> crateApiHttpApiGetDnsOverrides() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_module_info,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_Map_String_String_None,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiModuleApiRegisterModuleConstMeta,
-        argValues: [moduleId],
+        constMeta: kCrateApiHttpApiGetDnsOverridesConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiRegisterModuleConstMeta =>
-      const TaskConstMeta(debugName: "register_module", argNames: ["moduleId"]);
+  TaskConstMeta get kCrateApiHttpApiGetDnsOverridesConstMeta =>
+      const TaskConstMeta(debugName: "get_dns_overrides", argNames: []);
 
   @override
-  Future<void> crateApiModuleApiRemoveModuleStorage({
+  Future<ChapterDownload?> crateApiDownloadApiGetDownloadStatus({
     required String moduleId,
-    required String key,
+    required String comicId,
+    required String epId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_String(key, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1890,33 +2371,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_chapter_download,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiRemoveModuleStorageConstMeta,
-        argValues: [moduleId, key],
+        constMeta: kCrateApiDownloadApiGetDownloadStatusConstMeta,
+        argValues: [moduleId, comicId, epId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiRemoveModuleStorageConstMeta =>
+  TaskConstMeta get kCrateApiDownloadApiGetDownloadStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_module_storage",
-        argNames: ["moduleId", "key"],
+        debugName: "get_download_status",
+        argNames: ["moduleId", "comicId", "epId"],
       );
 
   @override
-  Future<void> crateApiPropertyApiSaveAppSetting({
-    required String key,
-    required String value,
+  Future<EpPage> crateApiModuleApiGetEps({
+    required String moduleId,
+    required String comicId,
+    required int page,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1925,41 +2408,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_ep_page,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiPropertyApiSaveAppSettingConstMeta,
-        argValues: [key, value],
+        constMeta: kCrateApiModuleApiGetEpsConstMeta,
+        argValues: [moduleId, comicId, page],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiSaveAppSettingConstMeta =>
-      const TaskConstMeta(
-        debugName: "save_app_setting",
-        argNames: ["key", "value"],
-      );
+  TaskConstMeta get kCrateApiModuleApiGetEpsConstMeta => const TaskConstMeta(
+    debugName: "get_eps",
+    argNames: ["moduleId", "comicId", "page"],
+  );
 
   @override
-  Future<void> crateApiImageCacheApiSaveImageToCache({
-    required String moduleId,
-    required String url,
-    required String filePath,
-    required String contentType,
-    required PlatformInt64 fileSize,
-    PlatformInt64? expireDays,
+  Future<List<ReadingHistory>> crateApiHistoryApiGetHistory({
+    required int page,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(url, serializer);
-          sse_encode_String(filePath, serializer);
-          sse_encode_String(contentType, serializer);
-          sse_encode_i_64(fileSize, serializer);
-          sse_encode_opt_box_autoadd_i_64(expireDays, serializer);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1968,42 +2442,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_reading_history,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiImageCacheApiSaveImageToCacheConstMeta,
-        argValues: [moduleId, url, filePath, contentType, fileSize, expireDays],
+        constMeta: kCrateApiHistoryApiGetHistoryConstMeta,
+        argValues: [page, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiImageCacheApiSaveImageToCacheConstMeta =>
+  TaskConstMeta get kCrateApiHistoryApiGetHistoryConstMeta =>
       const TaskConstMeta(
-        debugName: "save_image_to_cache",
-        argNames: [
-          "moduleId",
-          "url",
-          "filePath",
-          "contentType",
-          "fileSize",
-          "expireDays",
-        ],
+        debugName: "get_history",
+        argNames: ["page", "limit"],
       );
 
   @override
-  Future<void> crateApiPropertyApiSaveProperty({
-    required String moduleId,
-    required String key,
-    required String value,
+  Future<HistoryPage> crateApiHistoryApiGetHistoryPaged({
+    required int page,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
+          sse_encode_i_32(page, serializer);
+          sse_encode_i_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -2012,24 +2477,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_history_page,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiPropertyApiSavePropertyConstMeta,
-        argValues: [moduleId, key, value],
+        constMeta: kCrateApiHistoryApiGetHistoryPagedConstMeta,
+        argValues: [page, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPropertyApiSavePropertyConstMeta =>
+  TaskConstMeta get kCrateApiHistoryApiGetHistoryPagedConstMeta =>
       const TaskConstMeta(
-        debugName: "save_property",
-        argNames: ["moduleId", "key", "value"],
+        debugName: "get_history_paged",
+        argNames: ["page", "limit"],
       );
 
   @override
-  Future<List<ModuleInfo>> crateApiModuleApiScanAndRegisterModules() {
+  HttpDefaults crateApiHttpApiGetHttpDefaults() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_defaults,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHttpApiGetHttpDefaultsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiGetHttpDefaultsConstMeta =>
+      const TaskConstMeta(debugName: "get_http_defaults", argNames: []);
+
+  @override
+  bool crateApiHttpApiGetHttpLogging() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHttpApiGetHttpLoggingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiGetHttpLoggingConstMeta =>
+      const TaskConstMeta(debugName: "get_http_logging", argNames: []);
+
+  @override
+  HttpPoolSettings crateApiHttpApiGetHttpPoolSettings() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_pool_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHttpApiGetHttpPoolSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiGetHttpPoolSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_http_pool_settings", argNames: []);
+
+  @override
+  Future<PlatformInt64> crateApiImageCacheApiGetImageCacheLimit() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2037,200 +2568,203 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 60,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_module_info,
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiScanAndRegisterModulesConstMeta,
+        constMeta: kCrateApiImageCacheApiGetImageCacheLimitConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiScanAndRegisterModulesConstMeta =>
-      const TaskConstMeta(debugName: "scan_and_register_modules", argNames: []);
+  TaskConstMeta get kCrateApiImageCacheApiGetImageCacheLimitConstMeta =>
+      const TaskConstMeta(debugName: "get_image_cache_limit", argNames: []);
 
   @override
-  Future<ComicsPage> crateApiModuleApiSearchComics({
-    required String moduleId,
-    required String keyword,
-    required String sortBy,
-    required int page,
-  }) {
+  Future<ImageCacheStats> crateApiImageCacheApiGetImageCacheStats() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_String(keyword, serializer);
-          sse_encode_String(sortBy, serializer);
-          sse_encode_i_32(page, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 61,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_comics_page,
+          decodeSuccessData: sse_decode_image_cache_stats,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiSearchComicsConstMeta,
-        argValues: [moduleId, keyword, sortBy, page],
+        constMeta: kCrateApiImageCacheApiGetImageCacheStatsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiSearchComicsConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_comics",
-        argNames: ["moduleId", "keyword", "sortBy", "page"],
-      );
+  TaskConstMeta get kCrateApiImageCacheApiGetImageCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: "get_image_cache_stats", argNames: []);
 
   @override
-  Future<void> crateApiModuleApiSetModuleEnabled({
-    required String moduleId,
-    required bool enabled,
+  Future<String> crateApiImageApiGetImageInfo({
+    required String imageDataBase64,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
-          sse_encode_bool(enabled, serializer);
+          sse_encode_String(imageDataBase64, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 62,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiSetModuleEnabledConstMeta,
-        argValues: [moduleId, enabled],
+        constMeta: kCrateApiImageApiGetImageInfoConstMeta,
+        argValues: [imageDataBase64],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiSetModuleEnabledConstMeta =>
+  TaskConstMeta get kCrateApiImageApiGetImageInfoConstMeta =>
       const TaskConstMeta(
-        debugName: "set_module_enabled",
-        argNames: ["moduleId", "enabled"],
+        debugName: "get_image_info",
+        argNames: ["imageDataBase64"],
       );
 
   @override
-  Future<void> crateApiModuleApiSetModuleSourceUrl({
+  Future<ReadPosition?> crateApiHistoryApiGetLastPosition({
     required String moduleId,
-    String? sourceUrl,
+    required String comicId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_opt_String(sourceUrl, serializer);
+          sse_encode_String(comicId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 63,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_read_position,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiSetModuleSourceUrlConstMeta,
-        argValues: [moduleId, sourceUrl],
+        constMeta: kCrateApiHistoryApiGetLastPositionConstMeta,
+        argValues: [moduleId, comicId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiSetModuleSourceUrlConstMeta =>
+  TaskConstMeta get kCrateApiHistoryApiGetLastPositionConstMeta =>
       const TaskConstMeta(
-        debugName: "set_module_source_url",
-        argNames: ["moduleId", "sourceUrl"],
+        debugName: "get_last_position",
+        argNames: ["moduleId", "comicId"],
       );
 
   @override
-  Future<void> crateApiModuleApiSetModuleStorage({
-    required String moduleId,
-    required String key,
-    required String value,
-  }) {
+  String crateApiLogApiGetLogLevel() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLogApiGetLogLevelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLogApiGetLogLevelConstMeta =>
+      const TaskConstMeta(debugName: "get_log_level", argNames: []);
+
+  @override
+  Future<bool> crateApiModuleApiGetLoginStatus({required String moduleId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(moduleId, serializer);
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 65,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiModuleApiSetModuleStorageConstMeta,
-        argValues: [moduleId, key, value],
+        constMeta: kCrateApiModuleApiGetLoginStatusConstMeta,
+        argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiSetModuleStorageConstMeta =>
+  TaskConstMeta get kCrateApiModuleApiGetLoginStatusConstMeta =>
       const TaskConstMeta(
-        debugName: "set_module_storage",
-        argNames: ["moduleId", "key", "value"],
+        debugName: "get_login_status",
+        argNames: ["moduleId"],
       );
 
   @override
-  Future<void> crateApiProxyApiSetProxy({String? url}) {
+  Future<int> crateApiModuleApiGetMaxLoadedModules() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 66,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiProxyApiSetProxyConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiModuleApiGetMaxLoadedModulesConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiProxyApiSetProxyConstMeta =>
-      const TaskConstMeta(debugName: "set_proxy", argNames: ["url"]);
+  TaskConstMeta get kCrateApiModuleApiGetMaxLoadedModulesConstMeta =>
+      const TaskConstMeta(debugName: "get_max_loaded_modules", argNames: []);
 
   @override
-  Future<void> crateApiModuleApiUnloadModule({required String moduleId}) {
+  Future<ModuleCapabilities> crateApiModuleApiGetModuleCapabilities({
+    required String moduleId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2239,122 +2773,3493 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 67,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_module_capabilities,
+          decodeErrorData: sse_decode_module_error,
         ),
-        constMeta: kCrateApiModuleApiUnloadModuleConstMeta,
+        constMeta: kCrateApiModuleApiGetModuleCapabilitiesConstMeta,
         argValues: [moduleId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiUnloadModuleConstMeta =>
-      const TaskConstMeta(debugName: "unload_module", argNames: ["moduleId"]);
+  TaskConstMeta get kCrateApiModuleApiGetModuleCapabilitiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_module_capabilities",
+        argNames: ["moduleId"],
+      );
 
   @override
-  Future<ModuleInfo> crateApiModuleApiUpdateModule({required String moduleId}) {
+  Future<List<FunctionMetrics>> crateApiModuleApiGetModuleMetrics() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(moduleId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 68,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_module_info,
+          decodeSuccessData: sse_decode_list_function_metrics,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiModuleApiUpdateModuleConstMeta,
-        argValues: [moduleId],
+        constMeta: kCrateApiModuleApiGetModuleMetricsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModuleApiUpdateModuleConstMeta =>
-      const TaskConstMeta(debugName: "update_module", argNames: ["moduleId"]);
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
+  TaskConstMeta get kCrateApiModuleApiGetModuleMetricsConstMeta =>
+      const TaskConstMeta(debugName: "get_module_metrics", argNames: []);
 
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
+  @override
+  String? crateApiProxyApiGetModuleProxy({required String moduleId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProxyApiGetModuleProxyConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiProxyApiGetModuleProxyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_module_proxy",
+        argNames: ["moduleId"],
+      );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  Future<RuntimeLimits> crateApiModuleApiGetModuleRuntimeLimits({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_runtime_limits,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiGetModuleRuntimeLimitsConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
-  }
+  TaskConstMeta get kCrateApiModuleApiGetModuleRuntimeLimitsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_module_runtime_limits",
+        argNames: ["moduleId"],
+      );
 
-  @protected
-  RemoteImageInfo dco_decode_box_autoadd_remote_image_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_remote_image_info(raw);
+  @override
+  Future<String?> crateApiModuleApiGetModuleStorage({
+    required String moduleId,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiGetModuleStorageConstMeta,
+        argValues: [moduleId, key],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  Category dco_decode_category(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return Category(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      description: dco_decode_String(arr[2]),
-      thumb: dco_decode_opt_box_autoadd_remote_image_info(arr[3]),
-      isWeb: dco_decode_bool(arr[4]),
-      active: dco_decode_bool(arr[5]),
-      link: dco_decode_opt_String(arr[6]),
+  TaskConstMeta get kCrateApiModuleApiGetModuleStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_module_storage",
+        argNames: ["moduleId", "key"],
+      );
+
+  @override
+  Future<List<ModuleInfo>> crateApiModuleApiGetModules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_module_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiGetModulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  ComicDetail dco_decode_comic_detail(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 19)
-      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
-    return ComicDetail(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      author: dco_decode_String(arr[2]),
-      pagesCount: dco_decode_i_32(arr[3]),
-      epsCount: dco_decode_i_32(arr[4]),
-      finished: dco_decode_bool(arr[5]),
+  TaskConstMeta get kCrateApiModuleApiGetModulesConstMeta =>
+      const TaskConstMeta(debugName: "get_modules", argNames: []);
+
+  @override
+  String? crateApiInitGetModulesDir() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitGetModulesDirConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitGetModulesDirConstMeta =>
+      const TaskConstMeta(debugName: "get_modules_dir", argNames: []);
+
+  @override
+  Future<PicturePage> crateApiModuleApiGetPictures({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_picture_page,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiGetPicturesConstMeta,
+        argValues: [moduleId, comicId, epId, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetPicturesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pictures",
+        argNames: ["moduleId", "comicId", "epId", "page"],
+      );
+
+  @override
+  Future<PicturePage> crateApiModuleApiGetPicturesCancelable({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int page,
+    required String cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
+          sse_encode_i_32(page, serializer);
+          sse_encode_String(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_picture_page,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiGetPicturesCancelableConstMeta,
+        argValues: [moduleId, comicId, epId, page, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetPicturesCancelableConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pictures_cancelable",
+        argNames: ["moduleId", "comicId", "epId", "page", "cancelToken"],
+      );
+
+  @override
+  Future<ChapterProgress?> crateApiProgressApiGetProgress({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_chapter_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiProgressApiGetProgressConstMeta,
+        argValues: [moduleId, comicId, epId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProgressApiGetProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_progress",
+        argNames: ["moduleId", "comicId", "epId"],
+      );
+
+  @override
+  Future<String?> crateApiProxyApiGetProxy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiProxyApiGetProxyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyApiGetProxyConstMeta =>
+      const TaskConstMeta(debugName: "get_proxy", argNames: []);
+
+  @override
+  String crateApiProxyApiGetProxyBypass() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProxyApiGetProxyBypassConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyApiGetProxyBypassConstMeta =>
+      const TaskConstMeta(debugName: "get_proxy_bypass", argNames: []);
+
+  @override
+  Future<ComicSimple?> crateApiModuleApiGetRandomComic({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_comic_simple,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiGetRandomComicConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetRandomComicConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_random_comic",
+        argNames: ["moduleId"],
+      );
+
+  @override
+  List<String> crateApiLogApiGetRecentLogs({required BigInt maxLines}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(maxLines, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLogApiGetRecentLogsConstMeta,
+        argValues: [maxLines],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLogApiGetRecentLogsConstMeta =>
+      const TaskConstMeta(debugName: "get_recent_logs", argNames: ["maxLines"]);
+
+  @override
+  Future<ComicsPage> crateApiModuleApiGetRecommend({
+    required String moduleId,
+    required String comicId,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_comics_page,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiGetRecommendConstMeta,
+        argValues: [moduleId, comicId, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetRecommendConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_recommend",
+        argNames: ["moduleId", "comicId", "page"],
+      );
+
+  @override
+  String? crateApiInitGetRootPath() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitGetRootPathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitGetRootPathConstMeta =>
+      const TaskConstMeta(debugName: "get_root_path", argNames: []);
+
+  @override
+  Future<int> crateApiModuleApiGetSearchAllConcurrency() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiGetSearchAllConcurrencyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetSearchAllConcurrencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_search_all_concurrency",
+        argNames: [],
+      );
+
+  @override
+  Future<List<SortOption>> crateApiModuleApiGetSortOptions({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_sort_option,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiGetSortOptionsConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetSortOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_sort_options",
+        argNames: ["moduleId"],
+      );
+
+  @override
+  Future<List<Tag>> crateApiModuleApiGetTags({required String moduleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_tag,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiGetTagsConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiGetTagsConstMeta =>
+      const TaskConstMeta(debugName: "get_tags", argNames: ["moduleId"]);
+
+  @override
+  bool crateApiHttpApiGetTlsInsecure() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHttpApiGetTlsInsecureConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiGetTlsInsecureConstMeta =>
+      const TaskConstMeta(debugName: "get_tls_insecure", argNames: []);
+
+  @override
+  Future<UsageStats> crateApiStatsApiGetUsageStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usage_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStatsApiGetUsageStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStatsApiGetUsageStatsConstMeta =>
+      const TaskConstMeta(debugName: "get_usage_stats", argNames: []);
+
+  @override
+  Future<WebCacheItem?> crateApiWebCacheApiGetWebCache({
+    required String moduleId,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_web_cache_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebCacheApiGetWebCacheConstMeta,
+        argValues: [moduleId, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebCacheApiGetWebCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_web_cache",
+        argNames: ["moduleId", "url"],
+      );
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<Uint8List> crateApiHttpApiHttpDownload({
+    required String url,
+    required Map<String, String> headers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_Map_String_String_None(headers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiHttpDownloadConstMeta,
+        argValues: [url, headers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiHttpDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "http_download",
+        argNames: ["url", "headers"],
+      );
+
+  @override
+  Future<HttpResponseDto> crateApiHttpApiHttpGet({
+    required String url,
+    required Map<String, String> headers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_Map_String_String_None(headers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_response_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiHttpGetConstMeta,
+        argValues: [url, headers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiHttpGetConstMeta =>
+      const TaskConstMeta(debugName: "http_get", argNames: ["url", "headers"]);
+
+  @override
+  Future<HttpResponseDto> crateApiHttpApiHttpPost({
+    required String url,
+    required Map<String, String> headers,
+    String? body,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_Map_String_String_None(headers, serializer);
+          sse_encode_opt_String(body, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_response_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiHttpPostConstMeta,
+        argValues: [url, headers, body],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiHttpPostConstMeta => const TaskConstMeta(
+    debugName: "http_post",
+    argNames: ["url", "headers", "body"],
+  );
+
+  @override
+  Future<HttpResponseDto> crateApiHttpApiHttpPostMultipart({
+    required String url,
+    required Map<String, String> headers,
+    required List<MultipartPart> parts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_Map_String_String_None(headers, serializer);
+          sse_encode_list_multipart_part(parts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_response_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiHttpPostMultipartConstMeta,
+        argValues: [url, headers, parts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiHttpPostMultipartConstMeta =>
+      const TaskConstMeta(
+        debugName: "http_post_multipart",
+        argNames: ["url", "headers", "parts"],
+      );
+
+  @override
+  Future<HttpResponseDto> crateApiHttpApiHttpRequest({
+    required String url,
+    required String method,
+    required Map<String, String> headers,
+    String? body,
+    required BigInt timeoutSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_String(method, serializer);
+          sse_encode_Map_String_String_None(headers, serializer);
+          sse_encode_opt_String(body, serializer);
+          sse_encode_u_64(timeoutSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_response_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiHttpRequestConstMeta,
+        argValues: [url, method, headers, body, timeoutSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiHttpRequestConstMeta => const TaskConstMeta(
+    debugName: "http_request",
+    argNames: ["url", "method", "headers", "body", "timeoutSecs"],
+  );
+
+  @override
+  Future<void> crateApiBackupApiImportDatabase({required String srcPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(srcPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiBackupApiImportDatabaseConstMeta,
+        argValues: [srcPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupApiImportDatabaseConstMeta =>
+      const TaskConstMeta(debugName: "import_database", argNames: ["srcPath"]);
+
+  @override
+  Future<ModuleInfo> crateApiModuleApiImportModuleFromUrl({
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_module_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiImportModuleFromUrlConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiImportModuleFromUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_module_from_url",
+        argNames: ["url"],
+      );
+
+  @override
+  Future<UserDataImportSummary> crateApiBackupApiImportUserData({
+    required String json,
+    required bool merge,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          sse_encode_bool(merge, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user_data_import_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiBackupApiImportUserDataConstMeta,
+        argValues: [json, merge],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupApiImportUserDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_user_data",
+        argNames: ["json", "merge"],
+      );
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiInitInitApplication({required String rootPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rootPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitInitApplicationConstMeta,
+        argValues: [rootPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitInitApplicationConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_application",
+        argNames: ["rootPath"],
+      );
+
+  @override
+  Future<void> crateApiInitInitApplicationWithKey({
+    required String rootPath,
+    String? dbKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rootPath, serializer);
+          sse_encode_opt_String(dbKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitInitApplicationWithKeyConstMeta,
+        argValues: [rootPath, dbKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitInitApplicationWithKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_application_with_key",
+        argNames: ["rootPath", "dbKey"],
+      );
+
+  @override
+  Future<void> crateApiInitInitFrb() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitInitFrbConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitInitFrbConstMeta =>
+      const TaskConstMeta(debugName: "init_frb", argNames: []);
+
+  @override
+  Future<ModuleInfo> crateApiModuleApiInstallModuleFromUrl({
+    required String url,
+    required bool overwrite,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_bool(overwrite, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_module_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiInstallModuleFromUrlConstMeta,
+        argValues: [url, overwrite],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiInstallModuleFromUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "install_module_from_url",
+        argNames: ["url", "overwrite"],
+      );
+
+  @override
+  Future<bool> crateApiDbApiIsDatabaseEncrypted() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDbApiIsDatabaseEncryptedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbApiIsDatabaseEncryptedConstMeta =>
+      const TaskConstMeta(debugName: "is_database_encrypted", argNames: []);
+
+  @override
+  bool crateApiDbApiIsDatabaseEncryptionSupported() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDbApiIsDatabaseEncryptionSupportedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbApiIsDatabaseEncryptionSupportedConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_database_encryption_supported",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiModuleApiIsDevHotReloadEnabled() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModuleApiIsDevHotReloadEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiIsDevHotReloadEnabledConstMeta =>
+      const TaskConstMeta(debugName: "is_dev_hot_reload_enabled", argNames: []);
+
+  @override
+  bool crateApiInitIsInitialized() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitIsInitializedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitIsInitializedConstMeta =>
+      const TaskConstMeta(debugName: "is_initialized", argNames: []);
+
+  @override
+  Future<List<PropertyItem>> crateApiPropertyApiListAppSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_property_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiListAppSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiListAppSettingsConstMeta =>
+      const TaskConstMeta(debugName: "list_app_settings", argNames: []);
+
+  @override
+  Future<List<ChapterDownload>> crateApiDownloadApiListDownloads({
+    String? moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_chapter_download,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDownloadApiListDownloadsConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloadApiListDownloadsConstMeta =>
+      const TaskConstMeta(debugName: "list_downloads", argNames: ["moduleId"]);
+
+  @override
+  Future<ImageCachePage> crateApiImageCacheApiListImageCache({
+    String? moduleId,
+    required int page,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(moduleId, serializer);
+          sse_encode_i_32(page, serializer);
+          sse_encode_i_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_image_cache_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageCacheApiListImageCacheConstMeta,
+        argValues: [moduleId, page, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageCacheApiListImageCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_image_cache",
+        argNames: ["moduleId", "page", "limit"],
+      );
+
+  @override
+  Future<List<PropertyItem>> crateApiPropertyApiListProperties({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_property_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiListPropertiesConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiListPropertiesConstMeta =>
+      const TaskConstMeta(debugName: "list_properties", argNames: ["moduleId"]);
+
+  @override
+  Future<List<PropertyItem>> crateApiPropertyApiListPropertiesByPrefix({
+    required String moduleId,
+    required String prefix,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(prefix, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_property_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiListPropertiesByPrefixConstMeta,
+        argValues: [moduleId, prefix],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiListPropertiesByPrefixConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_properties_by_prefix",
+        argNames: ["moduleId", "prefix"],
+      );
+
+  @override
+  Future<PropertyPage> crateApiPropertyApiListPropertiesPaged({
+    required String moduleId,
+    required int page,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_i_32(page, serializer);
+          sse_encode_i_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_property_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiListPropertiesPagedConstMeta,
+        argValues: [moduleId, page, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiListPropertiesPagedConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_properties_paged",
+        argNames: ["moduleId", "page", "limit"],
+      );
+
+  @override
+  Future<String?> crateApiPropertyApiLoadAppSetting({required String key}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiLoadAppSettingConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiLoadAppSettingConstMeta =>
+      const TaskConstMeta(debugName: "load_app_setting", argNames: ["key"]);
+
+  @override
+  Future<void> crateApiModuleApiLoadModule({required String moduleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiLoadModuleConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiLoadModuleConstMeta =>
+      const TaskConstMeta(debugName: "load_module", argNames: ["moduleId"]);
+
+  @override
+  Future<String?> crateApiPropertyApiLoadProperty({
+    required String moduleId,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiLoadPropertyConstMeta,
+        argValues: [moduleId, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiLoadPropertyConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_property",
+        argNames: ["moduleId", "key"],
+      );
+
+  @override
+  Future<LoginResult> crateApiModuleApiLogin({
+    required String moduleId,
+    required String credentialsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(credentialsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_login_result,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiLoginConstMeta,
+        argValues: [moduleId, credentialsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiLoginConstMeta => const TaskConstMeta(
+    debugName: "login",
+    argNames: ["moduleId", "credentialsJson"],
+  );
+
+  @override
+  Future<void> crateApiModuleApiLogout({required String moduleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiLogoutConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiLogoutConstMeta =>
+      const TaskConstMeta(debugName: "logout", argNames: ["moduleId"]);
+
+  @override
+  Future<void> crateApiModuleApiPostComment({
+    required String moduleId,
+    required String comicId,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiPostCommentConstMeta,
+        argValues: [moduleId, comicId, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiPostCommentConstMeta =>
+      const TaskConstMeta(
+        debugName: "post_comment",
+        argNames: ["moduleId", "comicId", "content"],
+      );
+
+  @override
+  Stream<ImagePrefetchItem> crateApiImageCacheApiPrefetchChapterImages({
+    required String moduleId,
+    required String pictureUrlsJson,
+    PlatformInt64? expireDays,
+    required int concurrency,
+  }) {
+    final sink = RustStreamSink<ImagePrefetchItem>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(moduleId, serializer);
+            sse_encode_String(pictureUrlsJson, serializer);
+            sse_encode_opt_box_autoadd_i_64(expireDays, serializer);
+            sse_encode_u_32(concurrency, serializer);
+            sse_encode_StreamSink_image_prefetch_item_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 119,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_image_prefetch_summary,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiImageCacheApiPrefetchChapterImagesConstMeta,
+          argValues: [moduleId, pictureUrlsJson, expireDays, concurrency, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiImageCacheApiPrefetchChapterImagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "prefetch_chapter_images",
+        argNames: [
+          "moduleId",
+          "pictureUrlsJson",
+          "expireDays",
+          "concurrency",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<ModulePreloadResult> crateApiModuleApiPreloadModules({
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(ids, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_module_preload_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiPreloadModulesConstMeta,
+        argValues: [ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiPreloadModulesConstMeta =>
+      const TaskConstMeta(debugName: "preload_modules", argNames: ["ids"]);
+
+  @override
+  Future<String> crateApiImageCacheApiProcessImageWithModule({
+    required String moduleId,
+    required String imageDataBase64,
+    required String paramsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(imageDataBase64, serializer);
+          sse_encode_String(paramsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageCacheApiProcessImageWithModuleConstMeta,
+        argValues: [moduleId, imageDataBase64, paramsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageCacheApiProcessImageWithModuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_image_with_module",
+        argNames: ["moduleId", "imageDataBase64", "paramsJson"],
+      );
+
+  @override
+  Future<String> crateApiImageApiReadExif({required String imageDataBase64}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(imageDataBase64, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageApiReadExifConstMeta,
+        argValues: [imageDataBase64],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageApiReadExifConstMeta => const TaskConstMeta(
+    debugName: "read_exif",
+    argNames: ["imageDataBase64"],
+  );
+
+  @override
+  Future<String> crateApiImageApiRearrangeImageCols({
+    required String imageDataBase64,
+    required int cols,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(imageDataBase64, serializer);
+          sse_encode_u_32(cols, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageApiRearrangeImageColsConstMeta,
+        argValues: [imageDataBase64, cols],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageApiRearrangeImageColsConstMeta =>
+      const TaskConstMeta(
+        debugName: "rearrange_image_cols",
+        argNames: ["imageDataBase64", "cols"],
+      );
+
+  @override
+  Future<String> crateApiImageApiRearrangeImageGrid({
+    required String imageDataBase64,
+    required int cols,
+    required int rows,
+    required List<int> permutation,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(imageDataBase64, serializer);
+          sse_encode_u_32(cols, serializer);
+          sse_encode_u_32(rows, serializer);
+          sse_encode_list_prim_u_32_loose(permutation, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageApiRearrangeImageGridConstMeta,
+        argValues: [imageDataBase64, cols, rows, permutation],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageApiRearrangeImageGridConstMeta =>
+      const TaskConstMeta(
+        debugName: "rearrange_image_grid",
+        argNames: ["imageDataBase64", "cols", "rows", "permutation"],
+      );
+
+  @override
+  Future<String> crateApiImageApiRearrangeImageRows({
+    required String imageDataBase64,
+    required int rows,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(imageDataBase64, serializer);
+          sse_encode_u_32(rows, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageApiRearrangeImageRowsConstMeta,
+        argValues: [imageDataBase64, rows],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageApiRearrangeImageRowsConstMeta =>
+      const TaskConstMeta(
+        debugName: "rearrange_image_rows",
+        argNames: ["imageDataBase64", "rows"],
+      );
+
+  @override
+  Future<List<SearchRecord>> crateApiSearchHistoryApiRecentSearches({
+    required String moduleId,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSearchHistoryApiRecentSearchesConstMeta,
+        argValues: [moduleId, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchHistoryApiRecentSearchesConstMeta =>
+      const TaskConstMeta(
+        debugName: "recent_searches",
+        argNames: ["moduleId", "limit"],
+      );
+
+  @override
+  Future<void> crateApiHistoryApiRecordRead({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHistoryApiRecordReadConstMeta,
+        argValues: [moduleId, comicId, epId, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHistoryApiRecordReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "record_read",
+        argNames: ["moduleId", "comicId", "epId", "page"],
+      );
+
+  @override
+  Future<void> crateApiSearchHistoryApiRecordSearch({
+    required String moduleId,
+    required String keyword,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(keyword, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSearchHistoryApiRecordSearchConstMeta,
+        argValues: [moduleId, keyword],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchHistoryApiRecordSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "record_search",
+        argNames: ["moduleId", "keyword"],
+      );
+
+  @override
+  Future<ModuleInfo> crateApiModuleApiRegisterModule({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_module_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiRegisterModuleConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiRegisterModuleConstMeta =>
+      const TaskConstMeta(debugName: "register_module", argNames: ["moduleId"]);
+
+  @override
+  Future<void> crateApiModuleApiRemoveModuleStorage({
+    required String moduleId,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiRemoveModuleStorageConstMeta,
+        argValues: [moduleId, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiRemoveModuleStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_module_storage",
+        argNames: ["moduleId", "key"],
+      );
+
+  @override
+  Future<void> crateApiWebCacheApiRemoveWebCache({
+    required String moduleId,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebCacheApiRemoveWebCacheConstMeta,
+        argValues: [moduleId, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebCacheApiRemoveWebCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_web_cache",
+        argNames: ["moduleId", "url"],
+      );
+
+  @override
+  Future<MaintenanceResult> crateApiDbApiRunMaintenance() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_maintenance_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiDbApiRunMaintenanceConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbApiRunMaintenanceConstMeta =>
+      const TaskConstMeta(debugName: "run_maintenance", argNames: []);
+
+  @override
+  Future<void> crateApiPropertyApiSaveAppSetting({
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiSaveAppSettingConstMeta,
+        argValues: [key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiSaveAppSettingConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_app_setting",
+        argNames: ["key", "value"],
+      );
+
+  @override
+  Future<void> crateApiImageCacheApiSaveImageToCache({
+    required String moduleId,
+    required String url,
+    required String filePath,
+    required String contentType,
+    required PlatformInt64 fileSize,
+    PlatformInt64? expireDays,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_String(filePath, serializer);
+          sse_encode_String(contentType, serializer);
+          sse_encode_i_64(fileSize, serializer);
+          sse_encode_opt_box_autoadd_i_64(expireDays, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageCacheApiSaveImageToCacheConstMeta,
+        argValues: [moduleId, url, filePath, contentType, fileSize, expireDays],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageCacheApiSaveImageToCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_image_to_cache",
+        argNames: [
+          "moduleId",
+          "url",
+          "filePath",
+          "contentType",
+          "fileSize",
+          "expireDays",
+        ],
+      );
+
+  @override
+  Future<void> crateApiPropertyApiSaveProperty({
+    required String moduleId,
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPropertyApiSavePropertyConstMeta,
+        argValues: [moduleId, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPropertyApiSavePropertyConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_property",
+        argNames: ["moduleId", "key", "value"],
+      );
+
+  @override
+  Future<void> crateApiWebCacheApiSaveWebCache({
+    required String moduleId,
+    required String url,
+    required String body,
+    required String contentType,
+    required PlatformInt64 expireSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_String(body, serializer);
+          sse_encode_String(contentType, serializer);
+          sse_encode_i_64(expireSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebCacheApiSaveWebCacheConstMeta,
+        argValues: [moduleId, url, body, contentType, expireSecs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebCacheApiSaveWebCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_web_cache",
+        argNames: ["moduleId", "url", "body", "contentType", "expireSecs"],
+      );
+
+  @override
+  Future<List<ModuleInfo>> crateApiModuleApiScanAndRegisterModules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_module_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiScanAndRegisterModulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiScanAndRegisterModulesConstMeta =>
+      const TaskConstMeta(debugName: "scan_and_register_modules", argNames: []);
+
+  @override
+  Future<AggregatedSearchResult> crateApiModuleApiSearchAll({
+    required String keyword,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(keyword, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_aggregated_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSearchAllConstMeta,
+        argValues: [keyword, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSearchAllConstMeta => const TaskConstMeta(
+    debugName: "search_all",
+    argNames: ["keyword", "page"],
+  );
+
+  @override
+  Future<ComicsPage> crateApiModuleApiSearchComics({
+    required String moduleId,
+    required String keyword,
+    required String sortBy,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(keyword, serializer);
+          sse_encode_String(sortBy, serializer);
+          sse_encode_i_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_comics_page,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiSearchComicsConstMeta,
+        argValues: [moduleId, keyword, sortBy, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSearchComicsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_comics",
+        argNames: ["moduleId", "keyword", "sortBy", "page"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiSetDevHotReload({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetDevHotReloadConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetDevHotReloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_dev_hot_reload",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiHttpApiSetDnsOverride({
+    required String host,
+    String? ip,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          sse_encode_opt_String(ip, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiSetDnsOverrideConstMeta,
+        argValues: [host, ip],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiSetDnsOverrideConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_dns_override",
+        argNames: ["host", "ip"],
+      );
+
+  @override
+  Future<void> crateApiHttpApiSetHttpDefaults({
+    BigInt? timeoutSecs,
+    String? userAgent,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_64(timeoutSecs, serializer);
+          sse_encode_opt_String(userAgent, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiSetHttpDefaultsConstMeta,
+        argValues: [timeoutSecs, userAgent],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiSetHttpDefaultsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_http_defaults",
+        argNames: ["timeoutSecs", "userAgent"],
+      );
+
+  @override
+  void crateApiHttpApiSetHttpLogging({required bool enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHttpApiSetHttpLoggingConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiSetHttpLoggingConstMeta =>
+      const TaskConstMeta(debugName: "set_http_logging", argNames: ["enabled"]);
+
+  @override
+  Future<void> crateApiHttpApiSetHttpPoolSettings({
+    int? poolMaxIdlePerHost,
+    BigInt? poolIdleTimeoutSecs,
+    BigInt? connectTimeoutSecs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(poolMaxIdlePerHost, serializer);
+          sse_encode_opt_box_autoadd_u_64(poolIdleTimeoutSecs, serializer);
+          sse_encode_opt_box_autoadd_u_64(connectTimeoutSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiSetHttpPoolSettingsConstMeta,
+        argValues: [
+          poolMaxIdlePerHost,
+          poolIdleTimeoutSecs,
+          connectTimeoutSecs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiSetHttpPoolSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_http_pool_settings",
+        argNames: [
+          "poolMaxIdlePerHost",
+          "poolIdleTimeoutSecs",
+          "connectTimeoutSecs",
+        ],
+      );
+
+  @override
+  Future<void> crateApiImageCacheApiSetImageCacheLimit({
+    PlatformInt64? maxBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_i_64(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiImageCacheApiSetImageCacheLimitConstMeta,
+        argValues: [maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageCacheApiSetImageCacheLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_image_cache_limit",
+        argNames: ["maxBytes"],
+      );
+
+  @override
+  void crateApiLogApiSetLogBufferSize({required BigInt size}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(size, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLogApiSetLogBufferSizeConstMeta,
+        argValues: [size],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLogApiSetLogBufferSizeConstMeta =>
+      const TaskConstMeta(debugName: "set_log_buffer_size", argNames: ["size"]);
+
+  @override
+  void crateApiLogApiSetLogLevel({required String level}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLogApiSetLogLevelConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLogApiSetLogLevelConstMeta =>
+      const TaskConstMeta(debugName: "set_log_level", argNames: ["level"]);
+
+  @override
+  Future<void> crateApiModuleApiSetMaxLoadedModules({required int max}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(max, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetMaxLoadedModulesConstMeta,
+        argValues: [max],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetMaxLoadedModulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_max_loaded_modules",
+        argNames: ["max"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiSetModuleEnabled({
+    required String moduleId,
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetModuleEnabledConstMeta,
+        argValues: [moduleId, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetModuleEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_module_enabled",
+        argNames: ["moduleId", "enabled"],
+      );
+
+  @override
+  Future<void> crateApiProxyApiSetModuleProxy({
+    required String moduleId,
+    String? url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_opt_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiProxyApiSetModuleProxyConstMeta,
+        argValues: [moduleId, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyApiSetModuleProxyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_module_proxy",
+        argNames: ["moduleId", "url"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiSetModuleRuntimeLimits({
+    required String moduleId,
+    int? memoryLimitMb,
+    int? stackSizeKb,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_opt_box_autoadd_u_32(memoryLimitMb, serializer);
+          sse_encode_opt_box_autoadd_u_32(stackSizeKb, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetModuleRuntimeLimitsConstMeta,
+        argValues: [moduleId, memoryLimitMb, stackSizeKb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetModuleRuntimeLimitsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_module_runtime_limits",
+        argNames: ["moduleId", "memoryLimitMb", "stackSizeKb"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiSetModuleSourceUrl({
+    required String moduleId,
+    String? sourceUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_opt_String(sourceUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetModuleSourceUrlConstMeta,
+        argValues: [moduleId, sourceUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetModuleSourceUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_module_source_url",
+        argNames: ["moduleId", "sourceUrl"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiSetModuleStorage({
+    required String moduleId,
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetModuleStorageConstMeta,
+        argValues: [moduleId, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetModuleStorageConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_module_storage",
+        argNames: ["moduleId", "key", "value"],
+      );
+
+  @override
+  Future<void> crateApiProgressApiSetProgress({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    required int lastPage,
+    required int totalPages,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_String(epId, serializer);
+          sse_encode_i_32(lastPage, serializer);
+          sse_encode_i_32(totalPages, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiProgressApiSetProgressConstMeta,
+        argValues: [moduleId, comicId, epId, lastPage, totalPages],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProgressApiSetProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_progress",
+        argNames: ["moduleId", "comicId", "epId", "lastPage", "totalPages"],
+      );
+
+  @override
+  Future<void> crateApiProxyApiSetProxy({String? url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiProxyApiSetProxyConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyApiSetProxyConstMeta =>
+      const TaskConstMeta(debugName: "set_proxy", argNames: ["url"]);
+
+  @override
+  Future<void> crateApiProxyApiSetProxyBypass({String? bypass}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(bypass, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiProxyApiSetProxyBypassConstMeta,
+        argValues: [bypass],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProxyApiSetProxyBypassConstMeta =>
+      const TaskConstMeta(debugName: "set_proxy_bypass", argNames: ["bypass"]);
+
+  @override
+  Future<void> crateApiModuleApiSetSearchAllConcurrency({int? limit}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 157,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiSetSearchAllConcurrencyConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiSetSearchAllConcurrencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_search_all_concurrency",
+        argNames: ["limit"],
+      );
+
+  @override
+  Future<void> crateApiHttpApiSetTlsInsecure({required bool insecure}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(insecure, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 158,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHttpApiSetTlsInsecureConstMeta,
+        argValues: [insecure],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpApiSetTlsInsecureConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_tls_insecure",
+        argNames: ["insecure"],
+      );
+
+  @override
+  Stream<ChapterDownload> crateApiDownloadApiStartChapterDownload({
+    required String moduleId,
+    required String comicId,
+    required String epId,
+    String? cancelToken,
+  }) {
+    final sink = RustStreamSink<ChapterDownload>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(moduleId, serializer);
+            sse_encode_String(comicId, serializer);
+            sse_encode_String(epId, serializer);
+            sse_encode_opt_String(cancelToken, serializer);
+            sse_encode_StreamSink_chapter_download_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 159,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiDownloadApiStartChapterDownloadConstMeta,
+          argValues: [moduleId, comicId, epId, cancelToken, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadApiStartChapterDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_chapter_download",
+        argNames: ["moduleId", "comicId", "epId", "cancelToken", "sink"],
+      );
+
+  @override
+  Future<ModuleCallOutput> crateApiModuleApiTestModuleScript({
+    required String script,
+    required String funcName,
+    required String argsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(script, serializer);
+          sse_encode_String(funcName, serializer);
+          sse_encode_String(argsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_module_call_output,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiTestModuleScriptConstMeta,
+        argValues: [script, funcName, argsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiTestModuleScriptConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_module_script",
+        argNames: ["script", "funcName", "argsJson"],
+      );
+
+  @override
+  Future<bool> crateApiModuleApiToggleFavourite({
+    required String moduleId,
+    required String comicId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiToggleFavouriteConstMeta,
+        argValues: [moduleId, comicId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiToggleFavouriteConstMeta =>
+      const TaskConstMeta(
+        debugName: "toggle_favourite",
+        argNames: ["moduleId", "comicId"],
+      );
+
+  @override
+  Future<bool> crateApiModuleApiToggleLike({
+    required String moduleId,
+    required String comicId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_module_error,
+        ),
+        constMeta: kCrateApiModuleApiToggleLikeConstMeta,
+        argValues: [moduleId, comicId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiToggleLikeConstMeta =>
+      const TaskConstMeta(
+        debugName: "toggle_like",
+        argNames: ["moduleId", "comicId"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiUninstallModule({
+    required String moduleId,
+    required bool purgeData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_bool(purgeData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiUninstallModuleConstMeta,
+        argValues: [moduleId, purgeData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiUninstallModuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "uninstall_module",
+        argNames: ["moduleId", "purgeData"],
+      );
+
+  @override
+  Future<void> crateApiModuleApiUnloadModule({required String moduleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiUnloadModuleConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiUnloadModuleConstMeta =>
+      const TaskConstMeta(debugName: "unload_module", argNames: ["moduleId"]);
+
+  @override
+  Future<void> crateApiHistoryApiUpdateHistoryInfo({
+    required String moduleId,
+    required String comicId,
+    String? title,
+    String? thumbUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          sse_encode_String(comicId, serializer);
+          sse_encode_opt_String(title, serializer);
+          sse_encode_opt_String(thumbUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHistoryApiUpdateHistoryInfoConstMeta,
+        argValues: [moduleId, comicId, title, thumbUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHistoryApiUpdateHistoryInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_history_info",
+        argNames: ["moduleId", "comicId", "title", "thumbUrl"],
+      );
+
+  @override
+  Future<ModuleUpdateResult> crateApiModuleApiUpdateModule({
+    required String moduleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(moduleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_module_update_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModuleApiUpdateModuleConstMeta,
+        argValues: [moduleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModuleApiUpdateModuleConstMeta =>
+      const TaskConstMeta(debugName: "update_module", argNames: ["moduleId"]);
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, int> dco_decode_Map_String_i_32_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_i_32(raw).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  RustStreamSink<ChapterDownload> dco_decode_StreamSink_chapter_download_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DownloadProgress> dco_decode_StreamSink_download_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ImagePrefetchItem> dco_decode_StreamSink_image_prefetch_item_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AggregatedSearchResult dco_decode_aggregated_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AggregatedSearchResult(
+      results: dco_decode_list_module_search_page(arr[0]),
+      errors: dco_decode_list_module_search_error(arr[1]),
+    );
+  }
+
+  @protected
+  BatchCall dco_decode_batch_call(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BatchCall(
+      funcName: dco_decode_String(arr[0]),
+      argsJson: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  BatchCallResult dco_decode_batch_call_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BatchCallResult(
+      result: dco_decode_opt_String(arr[0]),
+      error: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ChapterDownload dco_decode_box_autoadd_chapter_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_chapter_download(raw);
+  }
+
+  @protected
+  ChapterProgress dco_decode_box_autoadd_chapter_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_chapter_progress(raw);
+  }
+
+  @protected
+  ComicSimple dco_decode_box_autoadd_comic_simple(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_comic_simple(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  ReadPosition dco_decode_box_autoadd_read_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_read_position(raw);
+  }
+
+  @protected
+  RemoteImageInfo dco_decode_box_autoadd_remote_image_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_remote_image_info(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  WebCacheItem dco_decode_box_autoadd_web_cache_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_web_cache_item(raw);
+  }
+
+  @protected
+  Category dco_decode_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Category(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+      thumb: dco_decode_opt_box_autoadd_remote_image_info(arr[3]),
+      isWeb: dco_decode_bool(arr[4]),
+      active: dco_decode_bool(arr[5]),
+      link: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  ChapterDownload dco_decode_chapter_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ChapterDownload(
+      moduleId: dco_decode_String(arr[0]),
+      comicId: dco_decode_String(arr[1]),
+      epId: dco_decode_String(arr[2]),
+      status: dco_decode_String(arr[3]),
+      totalPages: dco_decode_i_32(arr[4]),
+      downloadedPages: dco_decode_i_32(arr[5]),
+      dirPath: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  ChapterProgress dco_decode_chapter_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ChapterProgress(
+      moduleId: dco_decode_String(arr[0]),
+      comicId: dco_decode_String(arr[1]),
+      epId: dco_decode_String(arr[2]),
+      lastPage: dco_decode_i_32(arr[3]),
+      totalPages: dco_decode_i_32(arr[4]),
+      updatedAt: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  ComicDetail dco_decode_comic_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 19)
+      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
+    return ComicDetail(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      author: dco_decode_String(arr[2]),
+      pagesCount: dco_decode_i_32(arr[3]),
+      epsCount: dco_decode_i_32(arr[4]),
+      finished: dco_decode_bool(arr[5]),
       categories: dco_decode_list_String(arr[6]),
       thumb: dco_decode_remote_image_info(arr[7]),
       likesCount: dco_decode_i_32(arr[8]),
@@ -2372,1138 +6277,3663 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  ComicSimple dco_decode_comic_simple(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return ComicSimple(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      author: dco_decode_String(arr[2]),
-      pagesCount: dco_decode_i_32(arr[3]),
-      epsCount: dco_decode_i_32(arr[4]),
-      finished: dco_decode_bool(arr[5]),
-      categories: dco_decode_list_String(arr[6]),
-      thumb: dco_decode_remote_image_info(arr[7]),
-      likesCount: dco_decode_i_32(arr[8]),
+  ComicSimple dco_decode_comic_simple(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ComicSimple(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      author: dco_decode_String(arr[2]),
+      pagesCount: dco_decode_i_32(arr[3]),
+      epsCount: dco_decode_i_32(arr[4]),
+      finished: dco_decode_bool(arr[5]),
+      categories: dco_decode_list_String(arr[6]),
+      thumb: dco_decode_remote_image_info(arr[7]),
+      likesCount: dco_decode_i_32(arr[8]),
+      moduleId: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  ComicsPage dco_decode_comics_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ComicsPage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_comic_simple(arr[1]),
+    );
+  }
+
+  @protected
+  Comment dco_decode_comment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Comment(
+      id: dco_decode_String(arr[0]),
+      author: dco_decode_String(arr[1]),
+      avatar: dco_decode_opt_box_autoadd_remote_image_info(arr[2]),
+      content: dco_decode_String(arr[3]),
+      createdAt: dco_decode_String(arr[4]),
+      likes: dco_decode_i_32(arr[5]),
+      replyCount: dco_decode_i_32(arr[6]),
+    );
+  }
+
+  @protected
+  CommentPage dco_decode_comment_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CommentPage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_comment(arr[1]),
+    );
+  }
+
+  @protected
+  ConsoleLog dco_decode_console_log(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConsoleLog(
+      level: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+      timestamp: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  DownloadProgress dco_decode_download_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DownloadProgress(
+      received: dco_decode_u_64(arr[0]),
+      total: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  Ep dco_decode_ep(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Ep(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      order: dco_decode_i_32(arr[2]),
+      updatedAt: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  EpPage dco_decode_ep_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpPage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_ep(arr[1]),
+    );
+  }
+
+  @protected
+  FunctionMetrics dco_decode_function_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return FunctionMetrics(
+      moduleId: dco_decode_String(arr[0]),
+      function: dco_decode_String(arr[1]),
+      calls: dco_decode_u_64(arr[2]),
+      errors: dco_decode_u_64(arr[3]),
+      totalMs: dco_decode_u_64(arr[4]),
+      avgMs: dco_decode_u_64(arr[5]),
+      maxMs: dco_decode_u_64(arr[6]),
+      lastError: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  HistoryPage dco_decode_history_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HistoryPage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_reading_history(arr[1]),
+    );
+  }
+
+  @protected
+  HttpDefaults dco_decode_http_defaults(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HttpDefaults(
+      timeoutSecs: dco_decode_u_64(arr[0]),
+      userAgent: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  HttpPoolSettings dco_decode_http_pool_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HttpPoolSettings(
+      poolMaxIdlePerHost: dco_decode_u_32(arr[0]),
+      poolIdleTimeoutSecs: dco_decode_u_64(arr[1]),
+      connectTimeoutSecs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  HttpResponseDto dco_decode_http_response_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return HttpResponseDto(
+      status: dco_decode_u_16(arr[0]),
+      headers: dco_decode_Map_String_String_None(arr[1]),
+      body: dco_decode_String(arr[2]),
+      contentType: dco_decode_String(arr[3]),
+      finalUrl: dco_decode_String(arr[4]),
+      elapsedMs: dco_decode_u_64(arr[5]),
+      bodySize: dco_decode_usize(arr[6]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImageCacheEntry dco_decode_image_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ImageCacheEntry(
+      moduleId: dco_decode_String(arr[0]),
+      url: dco_decode_String(arr[1]),
+      filePath: dco_decode_String(arr[2]),
+      fileSize: dco_decode_i_64(arr[3]),
+      expireAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  ImageCachePage dco_decode_image_cache_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImageCachePage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_image_cache_entry(arr[1]),
+    );
+  }
+
+  @protected
+  ImageCacheStats dco_decode_image_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImageCacheStats(
+      totalCount: dco_decode_u_64(arr[0]),
+      validCount: dco_decode_u_64(arr[1]),
+      expiredCount: dco_decode_u_64(arr[2]),
+      totalSize: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  ImagePrefetchItem dco_decode_image_prefetch_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ImagePrefetchItem(
+      index: dco_decode_u_32(arr[0]),
+      url: dco_decode_String(arr[1]),
+      path: dco_decode_opt_String(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+      cached: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ImagePrefetchSummary dco_decode_image_prefetch_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ImagePrefetchSummary(
+      succeeded: dco_decode_u_32(arr[0]),
+      failed: dco_decode_u_32(arr[1]),
+      skipped: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BatchCall> dco_decode_list_batch_call(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_call).toList();
+  }
+
+  @protected
+  List<BatchCallResult> dco_decode_list_batch_call_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_call_result).toList();
+  }
+
+  @protected
+  List<Category> dco_decode_list_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_category).toList();
+  }
+
+  @protected
+  List<ChapterDownload> dco_decode_list_chapter_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter_download).toList();
+  }
+
+  @protected
+  List<ComicSimple> dco_decode_list_comic_simple(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_comic_simple).toList();
+  }
+
+  @protected
+  List<Comment> dco_decode_list_comment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_comment).toList();
+  }
+
+  @protected
+  List<ConsoleLog> dco_decode_list_console_log(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_console_log).toList();
+  }
+
+  @protected
+  List<Ep> dco_decode_list_ep(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ep).toList();
+  }
+
+  @protected
+  List<FunctionMetrics> dco_decode_list_function_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_function_metrics).toList();
+  }
+
+  @protected
+  List<ImageCacheEntry> dco_decode_list_image_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_image_cache_entry).toList();
+  }
+
+  @protected
+  List<MissingModule> dco_decode_list_missing_module(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_missing_module).toList();
+  }
+
+  @protected
+  List<ModuleInfo> dco_decode_list_module_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_module_info).toList();
+  }
+
+  @protected
+  List<ModuleLoadError> dco_decode_list_module_load_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_module_load_error).toList();
+  }
+
+  @protected
+  List<ModuleSearchError> dco_decode_list_module_search_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_module_search_error).toList();
+  }
+
+  @protected
+  List<ModuleSearchPage> dco_decode_list_module_search_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_module_search_page).toList();
+  }
+
+  @protected
+  List<ModuleUpdateInfo> dco_decode_list_module_update_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_module_update_info).toList();
+  }
+
+  @protected
+  List<MultipartPart> dco_decode_list_multipart_part(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_multipart_part).toList();
+  }
+
+  @protected
+  List<Picture> dco_decode_list_picture(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_picture).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<PropertyItem> dco_decode_list_property_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_property_item).toList();
+  }
+
+  @protected
+  List<ReadingHistory> dco_decode_list_reading_history(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reading_history).toList();
+  }
+
+  @protected
+  List<(String, int)> dco_decode_list_record_string_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_i_32).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<SearchRecord> dco_decode_list_search_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_record).toList();
+  }
+
+  @protected
+  List<SortOption> dco_decode_list_sort_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sort_option).toList();
+  }
+
+  @protected
+  List<Tag> dco_decode_list_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag).toList();
+  }
+
+  @protected
+  LoginResult dco_decode_login_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LoginResult(
+      success: dco_decode_bool(arr[0]),
+      message: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  MaintenanceResult dco_decode_maintenance_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MaintenanceResult(
+      sizeBefore: dco_decode_u_64(arr[0]),
+      sizeAfter: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  MissingModule dco_decode_missing_module(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MissingModule(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      sourceUrl: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ModuleCallOutput dco_decode_module_call_output(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ModuleCallOutput(
+      result: dco_decode_opt_String(arr[0]),
+      error: dco_decode_opt_String(arr[1]),
+      stack: dco_decode_opt_String(arr[2]),
+      logs: dco_decode_list_console_log(arr[3]),
+    );
+  }
+
+  @protected
+  ModuleCapabilities dco_decode_module_capabilities(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ModuleCapabilities(
+      search: dco_decode_bool(arr[0]),
+      comments: dco_decode_bool(arr[1]),
+      login: dco_decode_bool(arr[2]),
+      download: dco_decode_bool(arr[3]),
+      processImage: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ModuleError dco_decode_module_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ModuleError_NotFound(moduleId: dco_decode_String(raw[1]));
+      case 1:
+        return ModuleError_Disabled(moduleId: dco_decode_String(raw[1]));
+      case 2:
+        return ModuleError_JsException(
+          message: dco_decode_String(raw[1]),
+          stack: dco_decode_String(raw[2]),
+        );
+      case 3:
+        return ModuleError_MissingFunctions(
+          moduleId: dco_decode_String(raw[1]),
+          functions: dco_decode_list_String(raw[2]),
+        );
+      case 4:
+        return ModuleError_Unsupported(
+          moduleId: dco_decode_String(raw[1]),
+          funcName: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return ModuleError_Timeout(
+          funcName: dco_decode_String(raw[1]),
+          timeoutMs: dco_decode_u_64(raw[2]),
+        );
+      case 6:
+        return ModuleError_HttpError(message: dco_decode_String(raw[1]));
+      case 7:
+        return ModuleError_ParseError(
+          target: dco_decode_String(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 8:
+        return ModuleError_Cancelled();
+      case 9:
+        return ModuleError_Internal(message: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ModuleInfo dco_decode_module_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ModuleInfo(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      version: dco_decode_String(arr[2]),
+      author: dco_decode_String(arr[3]),
+      description: dco_decode_String(arr[4]),
+      icon: dco_decode_opt_String(arr[5]),
+      enabled: dco_decode_bool(arr[6]),
+      sourceUrl: dco_decode_opt_String(arr[7]),
+      sandbox: dco_decode_bool(arr[8]),
+      permissions: dco_decode_list_String(arr[9]),
+    );
+  }
+
+  @protected
+  ModuleLoadError dco_decode_module_load_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ModuleLoadError(
+      moduleId: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ModulePreloadResult dco_decode_module_preload_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ModulePreloadResult(
+      loaded: dco_decode_list_String(arr[0]),
+      skipped: dco_decode_list_String(arr[1]),
+      failed: dco_decode_list_module_load_error(arr[2]),
+    );
+  }
+
+  @protected
+  ModuleSearchError dco_decode_module_search_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ModuleSearchError(
+      moduleId: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ModuleSearchPage dco_decode_module_search_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ModuleSearchPage(
+      moduleId: dco_decode_String(arr[0]),
+      page: dco_decode_comics_page(arr[1]),
+    );
+  }
+
+  @protected
+  ModuleUpdateInfo dco_decode_module_update_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ModuleUpdateInfo(
+      moduleId: dco_decode_String(arr[0]),
+      currentVersion: dco_decode_String(arr[1]),
+      latestVersion: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ModuleUpdateResult dco_decode_module_update_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ModuleUpdateResult_UpToDate();
+      case 1:
+        return ModuleUpdateResult_Updated(
+          from: dco_decode_String(raw[1]),
+          to: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return ModuleUpdateResult_Failed(message: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  MultipartPart dco_decode_multipart_part(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MultipartPart(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_opt_String(arr[1]),
+      data: dco_decode_opt_String(arr[2]),
+      filename: dco_decode_opt_String(arr[3]),
+      contentType: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ChapterDownload? dco_decode_opt_box_autoadd_chapter_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_chapter_download(raw);
+  }
+
+  @protected
+  ChapterProgress? dco_decode_opt_box_autoadd_chapter_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_chapter_progress(raw);
+  }
+
+  @protected
+  ComicSimple? dco_decode_opt_box_autoadd_comic_simple(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_comic_simple(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ReadPosition? dco_decode_opt_box_autoadd_read_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_read_position(raw);
+  }
+
+  @protected
+  RemoteImageInfo? dco_decode_opt_box_autoadd_remote_image_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_remote_image_info(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  WebCacheItem? dco_decode_opt_box_autoadd_web_cache_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_web_cache_item(raw);
+  }
+
+  @protected
+  PageInfo dco_decode_page_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PageInfo(
+      total: dco_decode_i_32(arr[0]),
+      limit: dco_decode_i_32(arr[1]),
+      page: dco_decode_i_32(arr[2]),
+      pages: dco_decode_i_32(arr[3]),
+    );
+  }
+
+  @protected
+  Picture dco_decode_picture(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Picture(
+      id: dco_decode_String(arr[0]),
+      media: dco_decode_remote_image_info(arr[1]),
+      metadata: dco_decode_Map_String_String_None(arr[2]),
+    );
+  }
+
+  @protected
+  PicturePage dco_decode_picture_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PicturePage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_picture(arr[1]),
+    );
+  }
+
+  @protected
+  PropertyItem dco_decode_property_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PropertyItem(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  PropertyPage dco_decode_property_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PropertyPage(
+      pageInfo: dco_decode_page_info(arr[0]),
+      docs: dco_decode_list_property_item(arr[1]),
+    );
+  }
+
+  @protected
+  ReadPosition dco_decode_read_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReadPosition(
+      epId: dco_decode_String(arr[0]),
+      pageIndex: dco_decode_i_32(arr[1]),
+    );
+  }
+
+  @protected
+  ReadingHistory dco_decode_reading_history(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ReadingHistory(
+      moduleId: dco_decode_String(arr[0]),
+      comicId: dco_decode_String(arr[1]),
+      epId: dco_decode_String(arr[2]),
+      pageIndex: dco_decode_i_32(arr[3]),
+      title: dco_decode_opt_String(arr[4]),
+      thumbUrl: dco_decode_opt_String(arr[5]),
+      updatedAt: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  (String, int) dco_decode_record_string_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_i_32(arr[1]));
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  RemoteImageInfo dco_decode_remote_image_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RemoteImageInfo(
+      originalName: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      fileServer: dco_decode_String(arr[2]),
+      headers: dco_decode_Map_String_String_None(arr[3]),
+    );
+  }
+
+  @protected
+  RuntimeLimits dco_decode_runtime_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RuntimeLimits(
+      memoryLimitMb: dco_decode_u_32(arr[0]),
+      stackSizeKb: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SearchRecord dco_decode_search_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SearchRecord(
+      keyword: dco_decode_String(arr[0]),
+      count: dco_decode_i_32(arr[1]),
+      searchedAt: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  SortOption dco_decode_sort_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SortOption(
+      value: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  Tag dco_decode_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Tag(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      count: dco_decode_opt_box_autoadd_i_32(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UsageStats dco_decode_usage_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return UsageStats(
+      historyComics: dco_decode_u_64(arr[0]),
+      modules: dco_decode_u_64(arr[1]),
+      enabledModules: dco_decode_u_64(arr[2]),
+      downloadedChapters: dco_decode_u_64(arr[3]),
+      imageCacheCount: dco_decode_u_64(arr[4]),
+      imageCacheSize: dco_decode_u_64(arr[5]),
+      webCacheCount: dco_decode_u_64(arr[6]),
+      webCacheSize: dco_decode_u_64(arr[7]),
+      databaseSize: dco_decode_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  UserDataImportSummary dco_decode_user_data_import_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return UserDataImportSummary(
+      properties: dco_decode_u_32(arr[0]),
+      readingHistory: dco_decode_u_32(arr[1]),
+      searchHistory: dco_decode_u_32(arr[2]),
+      missingModules: dco_decode_list_missing_module(arr[3]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WebCacheItem dco_decode_web_cache_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WebCacheItem(
+      url: dco_decode_String(arr[0]),
+      body: dco_decode_String(arr[1]),
+      contentType: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, int> sse_decode_Map_String_i_32_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_i_32(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<ChapterDownload> sse_decode_StreamSink_chapter_download_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DownloadProgress> sse_decode_StreamSink_download_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ImagePrefetchItem> sse_decode_StreamSink_image_prefetch_item_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AggregatedSearchResult sse_decode_aggregated_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_results = sse_decode_list_module_search_page(deserializer);
+    var var_errors = sse_decode_list_module_search_error(deserializer);
+    return AggregatedSearchResult(results: var_results, errors: var_errors);
+  }
+
+  @protected
+  BatchCall sse_decode_batch_call(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_funcName = sse_decode_String(deserializer);
+    var var_argsJson = sse_decode_String(deserializer);
+    return BatchCall(funcName: var_funcName, argsJson: var_argsJson);
+  }
+
+  @protected
+  BatchCallResult sse_decode_batch_call_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_result = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return BatchCallResult(result: var_result, error: var_error);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  ChapterDownload sse_decode_box_autoadd_chapter_download(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_chapter_download(deserializer));
+  }
+
+  @protected
+  ChapterProgress sse_decode_box_autoadd_chapter_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_chapter_progress(deserializer));
+  }
+
+  @protected
+  ComicSimple sse_decode_box_autoadd_comic_simple(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_comic_simple(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  ReadPosition sse_decode_box_autoadd_read_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_read_position(deserializer));
+  }
+
+  @protected
+  RemoteImageInfo sse_decode_box_autoadd_remote_image_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_remote_image_info(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
+  @protected
+  WebCacheItem sse_decode_box_autoadd_web_cache_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_web_cache_item(deserializer));
+  }
+
+  @protected
+  Category sse_decode_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_thumb = sse_decode_opt_box_autoadd_remote_image_info(deserializer);
+    var var_isWeb = sse_decode_bool(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    var var_link = sse_decode_opt_String(deserializer);
+    return Category(
+      id: var_id,
+      title: var_title,
+      description: var_description,
+      thumb: var_thumb,
+      isWeb: var_isWeb,
+      active: var_active,
+      link: var_link,
+    );
+  }
+
+  @protected
+  ChapterDownload sse_decode_chapter_download(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_comicId = sse_decode_String(deserializer);
+    var var_epId = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_totalPages = sse_decode_i_32(deserializer);
+    var var_downloadedPages = sse_decode_i_32(deserializer);
+    var var_dirPath = sse_decode_String(deserializer);
+    return ChapterDownload(
+      moduleId: var_moduleId,
+      comicId: var_comicId,
+      epId: var_epId,
+      status: var_status,
+      totalPages: var_totalPages,
+      downloadedPages: var_downloadedPages,
+      dirPath: var_dirPath,
+    );
+  }
+
+  @protected
+  ChapterProgress sse_decode_chapter_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_comicId = sse_decode_String(deserializer);
+    var var_epId = sse_decode_String(deserializer);
+    var var_lastPage = sse_decode_i_32(deserializer);
+    var var_totalPages = sse_decode_i_32(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return ChapterProgress(
+      moduleId: var_moduleId,
+      comicId: var_comicId,
+      epId: var_epId,
+      lastPage: var_lastPage,
+      totalPages: var_totalPages,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  ComicDetail sse_decode_comic_detail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_pagesCount = sse_decode_i_32(deserializer);
+    var var_epsCount = sse_decode_i_32(deserializer);
+    var var_finished = sse_decode_bool(deserializer);
+    var var_categories = sse_decode_list_String(deserializer);
+    var var_thumb = sse_decode_remote_image_info(deserializer);
+    var var_likesCount = sse_decode_i_32(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_chineseTeam = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_updatedAt = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_String(deserializer);
+    var var_allowDownload = sse_decode_bool(deserializer);
+    var var_viewsCount = sse_decode_i_32(deserializer);
+    var var_isFavourite = sse_decode_bool(deserializer);
+    var var_isLiked = sse_decode_bool(deserializer);
+    var var_commentsCount = sse_decode_i_32(deserializer);
+    return ComicDetail(
+      id: var_id,
+      title: var_title,
+      author: var_author,
+      pagesCount: var_pagesCount,
+      epsCount: var_epsCount,
+      finished: var_finished,
+      categories: var_categories,
+      thumb: var_thumb,
+      likesCount: var_likesCount,
+      description: var_description,
+      chineseTeam: var_chineseTeam,
+      tags: var_tags,
+      updatedAt: var_updatedAt,
+      createdAt: var_createdAt,
+      allowDownload: var_allowDownload,
+      viewsCount: var_viewsCount,
+      isFavourite: var_isFavourite,
+      isLiked: var_isLiked,
+      commentsCount: var_commentsCount,
+    );
+  }
+
+  @protected
+  ComicSimple sse_decode_comic_simple(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_pagesCount = sse_decode_i_32(deserializer);
+    var var_epsCount = sse_decode_i_32(deserializer);
+    var var_finished = sse_decode_bool(deserializer);
+    var var_categories = sse_decode_list_String(deserializer);
+    var var_thumb = sse_decode_remote_image_info(deserializer);
+    var var_likesCount = sse_decode_i_32(deserializer);
+    var var_moduleId = sse_decode_opt_String(deserializer);
+    return ComicSimple(
+      id: var_id,
+      title: var_title,
+      author: var_author,
+      pagesCount: var_pagesCount,
+      epsCount: var_epsCount,
+      finished: var_finished,
+      categories: var_categories,
+      thumb: var_thumb,
+      likesCount: var_likesCount,
+      moduleId: var_moduleId,
+    );
+  }
+
+  @protected
+  ComicsPage sse_decode_comics_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_comic_simple(deserializer);
+    return ComicsPage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  Comment sse_decode_comment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_avatar = sse_decode_opt_box_autoadd_remote_image_info(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_String(deserializer);
+    var var_likes = sse_decode_i_32(deserializer);
+    var var_replyCount = sse_decode_i_32(deserializer);
+    return Comment(
+      id: var_id,
+      author: var_author,
+      avatar: var_avatar,
+      content: var_content,
+      createdAt: var_createdAt,
+      likes: var_likes,
+      replyCount: var_replyCount,
+    );
+  }
+
+  @protected
+  CommentPage sse_decode_comment_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_comment(deserializer);
+    return CommentPage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  ConsoleLog sse_decode_console_log(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    return ConsoleLog(
+      level: var_level,
+      message: var_message,
+      timestamp: var_timestamp,
+    );
+  }
+
+  @protected
+  DownloadProgress sse_decode_download_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_received = sse_decode_u_64(deserializer);
+    var var_total = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return DownloadProgress(received: var_received, total: var_total);
+  }
+
+  @protected
+  Ep sse_decode_ep(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_order = sse_decode_i_32(deserializer);
+    var var_updatedAt = sse_decode_String(deserializer);
+    return Ep(
+      id: var_id,
+      title: var_title,
+      order: var_order,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  EpPage sse_decode_ep_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_ep(deserializer);
+    return EpPage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  FunctionMetrics sse_decode_function_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_function = sse_decode_String(deserializer);
+    var var_calls = sse_decode_u_64(deserializer);
+    var var_errors = sse_decode_u_64(deserializer);
+    var var_totalMs = sse_decode_u_64(deserializer);
+    var var_avgMs = sse_decode_u_64(deserializer);
+    var var_maxMs = sse_decode_u_64(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return FunctionMetrics(
+      moduleId: var_moduleId,
+      function: var_function,
+      calls: var_calls,
+      errors: var_errors,
+      totalMs: var_totalMs,
+      avgMs: var_avgMs,
+      maxMs: var_maxMs,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  HistoryPage sse_decode_history_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_reading_history(deserializer);
+    return HistoryPage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  HttpDefaults sse_decode_http_defaults(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeoutSecs = sse_decode_u_64(deserializer);
+    var var_userAgent = sse_decode_String(deserializer);
+    return HttpDefaults(timeoutSecs: var_timeoutSecs, userAgent: var_userAgent);
+  }
+
+  @protected
+  HttpPoolSettings sse_decode_http_pool_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_poolMaxIdlePerHost = sse_decode_u_32(deserializer);
+    var var_poolIdleTimeoutSecs = sse_decode_u_64(deserializer);
+    var var_connectTimeoutSecs = sse_decode_u_64(deserializer);
+    return HttpPoolSettings(
+      poolMaxIdlePerHost: var_poolMaxIdlePerHost,
+      poolIdleTimeoutSecs: var_poolIdleTimeoutSecs,
+      connectTimeoutSecs: var_connectTimeoutSecs,
+    );
+  }
+
+  @protected
+  HttpResponseDto sse_decode_http_response_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_u_16(deserializer);
+    var var_headers = sse_decode_Map_String_String_None(deserializer);
+    var var_body = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    var var_finalUrl = sse_decode_String(deserializer);
+    var var_elapsedMs = sse_decode_u_64(deserializer);
+    var var_bodySize = sse_decode_usize(deserializer);
+    return HttpResponseDto(
+      status: var_status,
+      headers: var_headers,
+      body: var_body,
+      contentType: var_contentType,
+      finalUrl: var_finalUrl,
+      elapsedMs: var_elapsedMs,
+      bodySize: var_bodySize,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImageCacheEntry sse_decode_image_cache_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_filePath = sse_decode_String(deserializer);
+    var var_fileSize = sse_decode_i_64(deserializer);
+    var var_expireAt = sse_decode_i_64(deserializer);
+    return ImageCacheEntry(
+      moduleId: var_moduleId,
+      url: var_url,
+      filePath: var_filePath,
+      fileSize: var_fileSize,
+      expireAt: var_expireAt,
+    );
+  }
+
+  @protected
+  ImageCachePage sse_decode_image_cache_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_image_cache_entry(deserializer);
+    return ImageCachePage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  ImageCacheStats sse_decode_image_cache_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalCount = sse_decode_u_64(deserializer);
+    var var_validCount = sse_decode_u_64(deserializer);
+    var var_expiredCount = sse_decode_u_64(deserializer);
+    var var_totalSize = sse_decode_u_64(deserializer);
+    return ImageCacheStats(
+      totalCount: var_totalCount,
+      validCount: var_validCount,
+      expiredCount: var_expiredCount,
+      totalSize: var_totalSize,
+    );
+  }
+
+  @protected
+  ImagePrefetchItem sse_decode_image_prefetch_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_cached = sse_decode_bool(deserializer);
+    return ImagePrefetchItem(
+      index: var_index,
+      url: var_url,
+      path: var_path,
+      error: var_error,
+      cached: var_cached,
+    );
+  }
+
+  @protected
+  ImagePrefetchSummary sse_decode_image_prefetch_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_succeeded = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_u_32(deserializer);
+    return ImagePrefetchSummary(
+      succeeded: var_succeeded,
+      failed: var_failed,
+      skipped: var_skipped,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchCall> sse_decode_list_batch_call(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchCall>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_call(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchCallResult> sse_decode_list_batch_call_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchCallResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_call_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Category> sse_decode_list_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Category>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_category(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChapterDownload> sse_decode_list_chapter_download(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChapterDownload>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter_download(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ComicSimple> sse_decode_list_comic_simple(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ComicSimple>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_comic_simple(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Comment> sse_decode_list_comment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Comment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_comment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConsoleLog> sse_decode_list_console_log(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConsoleLog>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_console_log(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Ep> sse_decode_list_ep(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Ep>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ep(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FunctionMetrics> sse_decode_list_function_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FunctionMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_function_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImageCacheEntry> sse_decode_list_image_cache_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImageCacheEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_image_cache_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MissingModule> sse_decode_list_missing_module(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MissingModule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_missing_module(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModuleInfo> sse_decode_list_module_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModuleInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_module_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModuleLoadError> sse_decode_list_module_load_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModuleLoadError>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_module_load_error(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModuleSearchError> sse_decode_list_module_search_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModuleSearchError>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_module_search_error(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModuleSearchPage> sse_decode_list_module_search_page(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModuleSearchPage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_module_search_page(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModuleUpdateInfo> sse_decode_list_module_update_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModuleUpdateInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_module_update_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MultipartPart> sse_decode_list_multipart_part(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MultipartPart>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_multipart_part(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Picture> sse_decode_list_picture(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Picture>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_picture(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<PropertyItem> sse_decode_list_property_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PropertyItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_property_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReadingHistory> sse_decode_list_reading_history(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReadingHistory>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reading_history(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, int)> sse_decode_list_record_string_i_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, int)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_i_32(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchRecord> sse_decode_list_search_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SortOption> sse_decode_list_sort_option(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SortOption>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sort_option(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Tag> sse_decode_list_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Tag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoginResult sse_decode_login_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_success = sse_decode_bool(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return LoginResult(success: var_success, message: var_message);
+  }
+
+  @protected
+  MaintenanceResult sse_decode_maintenance_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sizeBefore = sse_decode_u_64(deserializer);
+    var var_sizeAfter = sse_decode_u_64(deserializer);
+    return MaintenanceResult(
+      sizeBefore: var_sizeBefore,
+      sizeAfter: var_sizeAfter,
+    );
+  }
+
+  @protected
+  MissingModule sse_decode_missing_module(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_sourceUrl = sse_decode_opt_String(deserializer);
+    return MissingModule(id: var_id, name: var_name, sourceUrl: var_sourceUrl);
+  }
+
+  @protected
+  ModuleCallOutput sse_decode_module_call_output(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_result = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_stack = sse_decode_opt_String(deserializer);
+    var var_logs = sse_decode_list_console_log(deserializer);
+    return ModuleCallOutput(
+      result: var_result,
+      error: var_error,
+      stack: var_stack,
+      logs: var_logs,
+    );
+  }
+
+  @protected
+  ModuleCapabilities sse_decode_module_capabilities(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_search = sse_decode_bool(deserializer);
+    var var_comments = sse_decode_bool(deserializer);
+    var var_login = sse_decode_bool(deserializer);
+    var var_download = sse_decode_bool(deserializer);
+    var var_processImage = sse_decode_bool(deserializer);
+    return ModuleCapabilities(
+      search: var_search,
+      comments: var_comments,
+      login: var_login,
+      download: var_download,
+      processImage: var_processImage,
+    );
+  }
+
+  @protected
+  ModuleError sse_decode_module_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_moduleId = sse_decode_String(deserializer);
+        return ModuleError_NotFound(moduleId: var_moduleId);
+      case 1:
+        var var_moduleId = sse_decode_String(deserializer);
+        return ModuleError_Disabled(moduleId: var_moduleId);
+      case 2:
+        var var_message = sse_decode_String(deserializer);
+        var var_stack = sse_decode_String(deserializer);
+        return ModuleError_JsException(message: var_message, stack: var_stack);
+      case 3:
+        var var_moduleId = sse_decode_String(deserializer);
+        var var_functions = sse_decode_list_String(deserializer);
+        return ModuleError_MissingFunctions(
+          moduleId: var_moduleId,
+          functions: var_functions,
+        );
+      case 4:
+        var var_moduleId = sse_decode_String(deserializer);
+        var var_funcName = sse_decode_String(deserializer);
+        return ModuleError_Unsupported(
+          moduleId: var_moduleId,
+          funcName: var_funcName,
+        );
+      case 5:
+        var var_funcName = sse_decode_String(deserializer);
+        var var_timeoutMs = sse_decode_u_64(deserializer);
+        return ModuleError_Timeout(
+          funcName: var_funcName,
+          timeoutMs: var_timeoutMs,
+        );
+      case 6:
+        var var_message = sse_decode_String(deserializer);
+        return ModuleError_HttpError(message: var_message);
+      case 7:
+        var var_target = sse_decode_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return ModuleError_ParseError(target: var_target, message: var_message);
+      case 8:
+        return ModuleError_Cancelled();
+      case 9:
+        var var_message = sse_decode_String(deserializer);
+        return ModuleError_Internal(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ModuleInfo sse_decode_module_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_version = sse_decode_String(deserializer);
+    var var_author = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_icon = sse_decode_opt_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_sourceUrl = sse_decode_opt_String(deserializer);
+    var var_sandbox = sse_decode_bool(deserializer);
+    var var_permissions = sse_decode_list_String(deserializer);
+    return ModuleInfo(
+      id: var_id,
+      name: var_name,
+      version: var_version,
+      author: var_author,
+      description: var_description,
+      icon: var_icon,
+      enabled: var_enabled,
+      sourceUrl: var_sourceUrl,
+      sandbox: var_sandbox,
+      permissions: var_permissions,
+    );
+  }
+
+  @protected
+  ModuleLoadError sse_decode_module_load_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ModuleLoadError(moduleId: var_moduleId, message: var_message);
+  }
+
+  @protected
+  ModulePreloadResult sse_decode_module_preload_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_loaded = sse_decode_list_String(deserializer);
+    var var_skipped = sse_decode_list_String(deserializer);
+    var var_failed = sse_decode_list_module_load_error(deserializer);
+    return ModulePreloadResult(
+      loaded: var_loaded,
+      skipped: var_skipped,
+      failed: var_failed,
+    );
+  }
+
+  @protected
+  ModuleSearchError sse_decode_module_search_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ModuleSearchError(moduleId: var_moduleId, message: var_message);
+  }
+
+  @protected
+  ModuleSearchPage sse_decode_module_search_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_page = sse_decode_comics_page(deserializer);
+    return ModuleSearchPage(moduleId: var_moduleId, page: var_page);
+  }
+
+  @protected
+  ModuleUpdateInfo sse_decode_module_update_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_currentVersion = sse_decode_String(deserializer);
+    var var_latestVersion = sse_decode_String(deserializer);
+    return ModuleUpdateInfo(
+      moduleId: var_moduleId,
+      currentVersion: var_currentVersion,
+      latestVersion: var_latestVersion,
+    );
+  }
+
+  @protected
+  ModuleUpdateResult sse_decode_module_update_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ModuleUpdateResult_UpToDate();
+      case 1:
+        var var_from = sse_decode_String(deserializer);
+        var var_to = sse_decode_String(deserializer);
+        return ModuleUpdateResult_Updated(from: var_from, to: var_to);
+      case 2:
+        var var_message = sse_decode_String(deserializer);
+        return ModuleUpdateResult_Failed(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  MultipartPart sse_decode_multipart_part(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_opt_String(deserializer);
+    var var_data = sse_decode_opt_String(deserializer);
+    var var_filename = sse_decode_opt_String(deserializer);
+    var var_contentType = sse_decode_opt_String(deserializer);
+    return MultipartPart(
+      name: var_name,
+      value: var_value,
+      data: var_data,
+      filename: var_filename,
+      contentType: var_contentType,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ChapterDownload? sse_decode_opt_box_autoadd_chapter_download(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_chapter_download(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ChapterProgress? sse_decode_opt_box_autoadd_chapter_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_chapter_progress(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ComicSimple? sse_decode_opt_box_autoadd_comic_simple(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_comic_simple(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReadPosition? sse_decode_opt_box_autoadd_read_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_read_position(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RemoteImageInfo? sse_decode_opt_box_autoadd_remote_image_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_remote_image_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WebCacheItem? sse_decode_opt_box_autoadd_web_cache_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_web_cache_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PageInfo sse_decode_page_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_total = sse_decode_i_32(deserializer);
+    var var_limit = sse_decode_i_32(deserializer);
+    var var_page = sse_decode_i_32(deserializer);
+    var var_pages = sse_decode_i_32(deserializer);
+    return PageInfo(
+      total: var_total,
+      limit: var_limit,
+      page: var_page,
+      pages: var_pages,
     );
   }
 
   @protected
-  ComicsPage dco_decode_comics_page(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ComicsPage(
-      pageInfo: dco_decode_page_info(arr[0]),
-      docs: dco_decode_list_comic_simple(arr[1]),
+  Picture sse_decode_picture(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_media = sse_decode_remote_image_info(deserializer);
+    var var_metadata = sse_decode_Map_String_String_None(deserializer);
+    return Picture(id: var_id, media: var_media, metadata: var_metadata);
+  }
+
+  @protected
+  PicturePage sse_decode_picture_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_picture(deserializer);
+    return PicturePage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  PropertyItem sse_decode_property_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return PropertyItem(key: var_key, value: var_value);
+  }
+
+  @protected
+  PropertyPage sse_decode_property_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pageInfo = sse_decode_page_info(deserializer);
+    var var_docs = sse_decode_list_property_item(deserializer);
+    return PropertyPage(pageInfo: var_pageInfo, docs: var_docs);
+  }
+
+  @protected
+  ReadPosition sse_decode_read_position(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_epId = sse_decode_String(deserializer);
+    var var_pageIndex = sse_decode_i_32(deserializer);
+    return ReadPosition(epId: var_epId, pageIndex: var_pageIndex);
+  }
+
+  @protected
+  ReadingHistory sse_decode_reading_history(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moduleId = sse_decode_String(deserializer);
+    var var_comicId = sse_decode_String(deserializer);
+    var var_epId = sse_decode_String(deserializer);
+    var var_pageIndex = sse_decode_i_32(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_thumbUrl = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return ReadingHistory(
+      moduleId: var_moduleId,
+      comicId: var_comicId,
+      epId: var_epId,
+      pageIndex: var_pageIndex,
+      title: var_title,
+      thumbUrl: var_thumbUrl,
+      updatedAt: var_updatedAt,
     );
   }
 
   @protected
-  Ep dco_decode_ep(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return Ep(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      order: dco_decode_i_32(arr[2]),
-      updatedAt: dco_decode_String(arr[3]),
+  (String, int) sse_decode_record_string_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_i_32(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RemoteImageInfo sse_decode_remote_image_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_originalName = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_fileServer = sse_decode_String(deserializer);
+    var var_headers = sse_decode_Map_String_String_None(deserializer);
+    return RemoteImageInfo(
+      originalName: var_originalName,
+      path: var_path,
+      fileServer: var_fileServer,
+      headers: var_headers,
     );
   }
 
   @protected
-  EpPage dco_decode_ep_page(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return EpPage(
-      pageInfo: dco_decode_page_info(arr[0]),
-      docs: dco_decode_list_ep(arr[1]),
+  RuntimeLimits sse_decode_runtime_limits(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_memoryLimitMb = sse_decode_u_32(deserializer);
+    var var_stackSizeKb = sse_decode_u_32(deserializer);
+    return RuntimeLimits(
+      memoryLimitMb: var_memoryLimitMb,
+      stackSizeKb: var_stackSizeKb,
     );
   }
 
   @protected
-  HttpResponseDto dco_decode_http_response_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return HttpResponseDto(
-      status: dco_decode_u_16(arr[0]),
-      headers: dco_decode_Map_String_String_None(arr[1]),
-      body: dco_decode_String(arr[2]),
-      contentType: dco_decode_String(arr[3]),
+  SearchRecord sse_decode_search_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keyword = sse_decode_String(deserializer);
+    var var_count = sse_decode_i_32(deserializer);
+    var var_searchedAt = sse_decode_i_64(deserializer);
+    return SearchRecord(
+      keyword: var_keyword,
+      count: var_count,
+      searchedAt: var_searchedAt,
     );
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  SortOption sse_decode_sort_option(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_value = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    return SortOption(value: var_value, name: var_name);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  Tag sse_decode_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_count = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return Tag(id: var_id, title: var_title, count: var_count);
   }
 
   @protected
-  ImageCacheStats dco_decode_image_cache_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ImageCacheStats(
-      totalCount: dco_decode_u_64(arr[0]),
-      validCount: dco_decode_u_64(arr[1]),
-      expiredCount: dco_decode_u_64(arr[2]),
-      totalSize: dco_decode_u_64(arr[3]),
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UsageStats sse_decode_usage_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_historyComics = sse_decode_u_64(deserializer);
+    var var_modules = sse_decode_u_64(deserializer);
+    var var_enabledModules = sse_decode_u_64(deserializer);
+    var var_downloadedChapters = sse_decode_u_64(deserializer);
+    var var_imageCacheCount = sse_decode_u_64(deserializer);
+    var var_imageCacheSize = sse_decode_u_64(deserializer);
+    var var_webCacheCount = sse_decode_u_64(deserializer);
+    var var_webCacheSize = sse_decode_u_64(deserializer);
+    var var_databaseSize = sse_decode_u_64(deserializer);
+    return UsageStats(
+      historyComics: var_historyComics,
+      modules: var_modules,
+      enabledModules: var_enabledModules,
+      downloadedChapters: var_downloadedChapters,
+      imageCacheCount: var_imageCacheCount,
+      imageCacheSize: var_imageCacheSize,
+      webCacheCount: var_webCacheCount,
+      webCacheSize: var_webCacheSize,
+      databaseSize: var_databaseSize,
     );
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  UserDataImportSummary sse_decode_user_data_import_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_properties = sse_decode_u_32(deserializer);
+    var var_readingHistory = sse_decode_u_32(deserializer);
+    var var_searchHistory = sse_decode_u_32(deserializer);
+    var var_missingModules = sse_decode_list_missing_module(deserializer);
+    return UserDataImportSummary(
+      properties: var_properties,
+      readingHistory: var_readingHistory,
+      searchHistory: var_searchHistory,
+      missingModules: var_missingModules,
+    );
   }
 
   @protected
-  List<Category> dco_decode_list_category(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_category).toList();
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  List<ComicSimple> dco_decode_list_comic_simple(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_comic_simple).toList();
+  WebCacheItem sse_decode_web_cache_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_body = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    return WebCacheItem(
+      url: var_url,
+      body: var_body,
+      contentType: var_contentType,
+    );
   }
 
   @protected
-  List<Ep> dco_decode_list_ep(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ep).toList();
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  List<ModuleInfo> dco_decode_list_module_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_module_info).toList();
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
   }
 
   @protected
-  List<Picture> dco_decode_list_picture(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_picture).toList();
+  void sse_encode_Map_String_i_32_None(
+    Map<String, int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_i_32(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_chapter_download_Sse(
+    RustStreamSink<ChapterDownload> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_download,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_download_progress_Sse(
+    RustStreamSink<DownloadProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void sse_encode_StreamSink_image_prefetch_item_Sse(
+    RustStreamSink<ImagePrefetchItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_image_prefetch_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  List<PropertyItem> dco_decode_list_property_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_property_item).toList();
+  void sse_encode_aggregated_search_result(
+    AggregatedSearchResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_module_search_page(self.results, serializer);
+    sse_encode_list_module_search_error(self.errors, serializer);
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  void sse_encode_batch_call(BatchCall self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.funcName, serializer);
+    sse_encode_String(self.argsJson, serializer);
   }
 
   @protected
-  List<SortOption> dco_decode_list_sort_option(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_sort_option).toList();
+  void sse_encode_batch_call_result(
+    BatchCallResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.result, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  ModuleInfo dco_decode_module_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return ModuleInfo(
-      id: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      version: dco_decode_String(arr[2]),
-      author: dco_decode_String(arr[3]),
-      description: dco_decode_String(arr[4]),
-      icon: dco_decode_opt_String(arr[5]),
-      enabled: dco_decode_bool(arr[6]),
-      sourceUrl: dco_decode_opt_String(arr[7]),
-    );
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_box_autoadd_chapter_download(
+    ChapterDownload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_chapter_download(self, serializer);
   }
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  void sse_encode_box_autoadd_chapter_progress(
+    ChapterProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_chapter_progress(self, serializer);
   }
 
   @protected
-  RemoteImageInfo? dco_decode_opt_box_autoadd_remote_image_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_remote_image_info(raw);
+  void sse_encode_box_autoadd_comic_simple(
+    ComicSimple self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_comic_simple(self, serializer);
   }
 
   @protected
-  PageInfo dco_decode_page_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PageInfo(
-      total: dco_decode_i_32(arr[0]),
-      limit: dco_decode_i_32(arr[1]),
-      page: dco_decode_i_32(arr[2]),
-      pages: dco_decode_i_32(arr[3]),
-    );
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
   }
 
   @protected
-  Picture dco_decode_picture(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return Picture(
-      id: dco_decode_String(arr[0]),
-      media: dco_decode_remote_image_info(arr[1]),
-      metadata: dco_decode_Map_String_String_None(arr[2]),
-    );
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  PicturePage dco_decode_picture_page(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PicturePage(
-      pageInfo: dco_decode_page_info(arr[0]),
-      docs: dco_decode_list_picture(arr[1]),
-    );
+  void sse_encode_box_autoadd_read_position(
+    ReadPosition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_read_position(self, serializer);
   }
 
   @protected
-  PropertyItem dco_decode_property_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PropertyItem(
-      key: dco_decode_String(arr[0]),
-      value: dco_decode_String(arr[1]),
-    );
+  void sse_encode_box_autoadd_remote_image_info(
+    RemoteImageInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_remote_image_info(self, serializer);
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  RemoteImageInfo dco_decode_remote_image_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return RemoteImageInfo(
-      originalName: dco_decode_String(arr[0]),
-      path: dco_decode_String(arr[1]),
-      fileServer: dco_decode_String(arr[2]),
-      headers: dco_decode_Map_String_String_None(arr[3]),
-    );
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  SortOption dco_decode_sort_option(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SortOption(
-      value: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-    );
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self, serializer);
   }
 
   @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_web_cache_item(
+    WebCacheItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_web_cache_item(self, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_category(Category self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_opt_box_autoadd_remote_image_info(self.thumb, serializer);
+    sse_encode_bool(self.isWeb, serializer);
+    sse_encode_bool(self.active, serializer);
+    sse_encode_opt_String(self.link, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_chapter_download(
+    ChapterDownload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.comicId, serializer);
+    sse_encode_String(self.epId, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_i_32(self.totalPages, serializer);
+    sse_encode_i_32(self.downloadedPages, serializer);
+    sse_encode_String(self.dirPath, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_chapter_progress(
+    ChapterProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.comicId, serializer);
+    sse_encode_String(self.epId, serializer);
+    sse_encode_i_32(self.lastPage, serializer);
+    sse_encode_i_32(self.totalPages, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_comic_detail(ComicDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_i_32(self.pagesCount, serializer);
+    sse_encode_i_32(self.epsCount, serializer);
+    sse_encode_bool(self.finished, serializer);
+    sse_encode_list_String(self.categories, serializer);
+    sse_encode_remote_image_info(self.thumb, serializer);
+    sse_encode_i_32(self.likesCount, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.chineseTeam, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_String(self.updatedAt, serializer);
+    sse_encode_String(self.createdAt, serializer);
+    sse_encode_bool(self.allowDownload, serializer);
+    sse_encode_i_32(self.viewsCount, serializer);
+    sse_encode_bool(self.isFavourite, serializer);
+    sse_encode_bool(self.isLiked, serializer);
+    sse_encode_i_32(self.commentsCount, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_comic_simple(ComicSimple self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_i_32(self.pagesCount, serializer);
+    sse_encode_i_32(self.epsCount, serializer);
+    sse_encode_bool(self.finished, serializer);
+    sse_encode_list_String(self.categories, serializer);
+    sse_encode_remote_image_info(self.thumb, serializer);
+    sse_encode_i_32(self.likesCount, serializer);
+    sse_encode_opt_String(self.moduleId, serializer);
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_comics_page(ComicsPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_page_info(self.pageInfo, serializer);
+    sse_encode_list_comic_simple(self.docs, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_comment(Comment self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_opt_box_autoadd_remote_image_info(self.avatar, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_String(self.createdAt, serializer);
+    sse_encode_i_32(self.likes, serializer);
+    sse_encode_i_32(self.replyCount, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_comment_page(CommentPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_page_info(self.pageInfo, serializer);
+    sse_encode_list_comment(self.docs, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_console_log(ConsoleLog self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
+    sse_encode_String(self.level, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
   }
 
   @protected
-  RemoteImageInfo sse_decode_box_autoadd_remote_image_info(
-    SseDeserializer deserializer,
+  void sse_encode_download_progress(
+    DownloadProgress self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_remote_image_info(deserializer));
+    sse_encode_u_64(self.received, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.total, serializer);
   }
 
   @protected
-  Category sse_decode_category(SseDeserializer deserializer) {
+  void sse_encode_ep(Ep self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_description = sse_decode_String(deserializer);
-    var var_thumb = sse_decode_opt_box_autoadd_remote_image_info(deserializer);
-    var var_isWeb = sse_decode_bool(deserializer);
-    var var_active = sse_decode_bool(deserializer);
-    var var_link = sse_decode_opt_String(deserializer);
-    return Category(
-      id: var_id,
-      title: var_title,
-      description: var_description,
-      thumb: var_thumb,
-      isWeb: var_isWeb,
-      active: var_active,
-      link: var_link,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_i_32(self.order, serializer);
+    sse_encode_String(self.updatedAt, serializer);
   }
 
   @protected
-  ComicDetail sse_decode_comic_detail(SseDeserializer deserializer) {
+  void sse_encode_ep_page(EpPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_author = sse_decode_String(deserializer);
-    var var_pagesCount = sse_decode_i_32(deserializer);
-    var var_epsCount = sse_decode_i_32(deserializer);
-    var var_finished = sse_decode_bool(deserializer);
-    var var_categories = sse_decode_list_String(deserializer);
-    var var_thumb = sse_decode_remote_image_info(deserializer);
-    var var_likesCount = sse_decode_i_32(deserializer);
-    var var_description = sse_decode_String(deserializer);
-    var var_chineseTeam = sse_decode_String(deserializer);
-    var var_tags = sse_decode_list_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_allowDownload = sse_decode_bool(deserializer);
-    var var_viewsCount = sse_decode_i_32(deserializer);
-    var var_isFavourite = sse_decode_bool(deserializer);
-    var var_isLiked = sse_decode_bool(deserializer);
-    var var_commentsCount = sse_decode_i_32(deserializer);
-    return ComicDetail(
-      id: var_id,
-      title: var_title,
-      author: var_author,
-      pagesCount: var_pagesCount,
-      epsCount: var_epsCount,
-      finished: var_finished,
-      categories: var_categories,
-      thumb: var_thumb,
-      likesCount: var_likesCount,
-      description: var_description,
-      chineseTeam: var_chineseTeam,
-      tags: var_tags,
-      updatedAt: var_updatedAt,
-      createdAt: var_createdAt,
-      allowDownload: var_allowDownload,
-      viewsCount: var_viewsCount,
-      isFavourite: var_isFavourite,
-      isLiked: var_isLiked,
-      commentsCount: var_commentsCount,
-    );
+    sse_encode_page_info(self.pageInfo, serializer);
+    sse_encode_list_ep(self.docs, serializer);
   }
 
   @protected
-  ComicSimple sse_decode_comic_simple(SseDeserializer deserializer) {
+  void sse_encode_function_metrics(
+    FunctionMetrics self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_author = sse_decode_String(deserializer);
-    var var_pagesCount = sse_decode_i_32(deserializer);
-    var var_epsCount = sse_decode_i_32(deserializer);
-    var var_finished = sse_decode_bool(deserializer);
-    var var_categories = sse_decode_list_String(deserializer);
-    var var_thumb = sse_decode_remote_image_info(deserializer);
-    var var_likesCount = sse_decode_i_32(deserializer);
-    return ComicSimple(
-      id: var_id,
-      title: var_title,
-      author: var_author,
-      pagesCount: var_pagesCount,
-      epsCount: var_epsCount,
-      finished: var_finished,
-      categories: var_categories,
-      thumb: var_thumb,
-      likesCount: var_likesCount,
-    );
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.function, serializer);
+    sse_encode_u_64(self.calls, serializer);
+    sse_encode_u_64(self.errors, serializer);
+    sse_encode_u_64(self.totalMs, serializer);
+    sse_encode_u_64(self.avgMs, serializer);
+    sse_encode_u_64(self.maxMs, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
   }
 
   @protected
-  ComicsPage sse_decode_comics_page(SseDeserializer deserializer) {
+  void sse_encode_history_page(HistoryPage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pageInfo = sse_decode_page_info(deserializer);
-    var var_docs = sse_decode_list_comic_simple(deserializer);
-    return ComicsPage(pageInfo: var_pageInfo, docs: var_docs);
+    sse_encode_page_info(self.pageInfo, serializer);
+    sse_encode_list_reading_history(self.docs, serializer);
   }
 
   @protected
-  Ep sse_decode_ep(SseDeserializer deserializer) {
+  void sse_encode_http_defaults(HttpDefaults self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_order = sse_decode_i_32(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return Ep(
-      id: var_id,
-      title: var_title,
-      order: var_order,
-      updatedAt: var_updatedAt,
-    );
+    sse_encode_u_64(self.timeoutSecs, serializer);
+    sse_encode_String(self.userAgent, serializer);
   }
 
   @protected
-  EpPage sse_decode_ep_page(SseDeserializer deserializer) {
+  void sse_encode_http_pool_settings(
+    HttpPoolSettings self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pageInfo = sse_decode_page_info(deserializer);
-    var var_docs = sse_decode_list_ep(deserializer);
-    return EpPage(pageInfo: var_pageInfo, docs: var_docs);
+    sse_encode_u_32(self.poolMaxIdlePerHost, serializer);
+    sse_encode_u_64(self.poolIdleTimeoutSecs, serializer);
+    sse_encode_u_64(self.connectTimeoutSecs, serializer);
   }
 
   @protected
-  HttpResponseDto sse_decode_http_response_dto(SseDeserializer deserializer) {
+  void sse_encode_http_response_dto(
+    HttpResponseDto self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_status = sse_decode_u_16(deserializer);
-    var var_headers = sse_decode_Map_String_String_None(deserializer);
-    var var_body = sse_decode_String(deserializer);
-    var var_contentType = sse_decode_String(deserializer);
-    return HttpResponseDto(
-      status: var_status,
-      headers: var_headers,
-      body: var_body,
-      contentType: var_contentType,
-    );
+    sse_encode_u_16(self.status, serializer);
+    sse_encode_Map_String_String_None(self.headers, serializer);
+    sse_encode_String(self.body, serializer);
+    sse_encode_String(self.contentType, serializer);
+    sse_encode_String(self.finalUrl, serializer);
+    sse_encode_u_64(self.elapsedMs, serializer);
+    sse_encode_usize(self.bodySize, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  ImageCacheStats sse_decode_image_cache_stats(SseDeserializer deserializer) {
+  void sse_encode_image_cache_entry(
+    ImageCacheEntry self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalCount = sse_decode_u_64(deserializer);
-    var var_validCount = sse_decode_u_64(deserializer);
-    var var_expiredCount = sse_decode_u_64(deserializer);
-    var var_totalSize = sse_decode_u_64(deserializer);
-    return ImageCacheStats(
-      totalCount: var_totalCount,
-      validCount: var_validCount,
-      expiredCount: var_expiredCount,
-      totalSize: var_totalSize,
-    );
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_i_64(self.fileSize, serializer);
+    sse_encode_i_64(self.expireAt, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_image_cache_page(
+    ImageCachePage self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_page_info(self.pageInfo, serializer);
+    sse_encode_list_image_cache_entry(self.docs, serializer);
   }
 
   @protected
-  List<Category> sse_decode_list_category(SseDeserializer deserializer) {
+  void sse_encode_image_cache_stats(
+    ImageCacheStats self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Category>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_category(deserializer));
-    }
-    return ans_;
+    sse_encode_u_64(self.totalCount, serializer);
+    sse_encode_u_64(self.validCount, serializer);
+    sse_encode_u_64(self.expiredCount, serializer);
+    sse_encode_u_64(self.totalSize, serializer);
   }
 
   @protected
-  List<ComicSimple> sse_decode_list_comic_simple(SseDeserializer deserializer) {
+  void sse_encode_image_prefetch_item(
+    ImagePrefetchItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ComicSimple>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_comic_simple(deserializer));
-    }
-    return ans_;
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_bool(self.cached, serializer);
   }
 
   @protected
-  List<Ep> sse_decode_list_ep(SseDeserializer deserializer) {
+  void sse_encode_image_prefetch_summary(
+    ImagePrefetchSummary self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.succeeded, serializer);
+    sse_encode_u_32(self.failed, serializer);
+    sse_encode_u_32(self.skipped, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Ep>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ep(deserializer));
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<ModuleInfo> sse_decode_list_module_info(SseDeserializer deserializer) {
+  void sse_encode_list_batch_call(
+    List<BatchCall> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ModuleInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_module_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_call(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<Picture> sse_decode_list_picture(SseDeserializer deserializer) {
+  void sse_encode_list_batch_call_result(
+    List<BatchCallResult> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Picture>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_picture(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_call_result(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_list_category(List<Category> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_category(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_chapter_download(
+    List<ChapterDownload> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter_download(item, serializer);
+    }
   }
 
   @protected
-  List<PropertyItem> sse_decode_list_property_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_comic_simple(
+    List<ComicSimple> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PropertyItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_property_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_comic_simple(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_list_comment(List<Comment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_comment(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<SortOption> sse_decode_list_sort_option(SseDeserializer deserializer) {
+  void sse_encode_list_console_log(
+    List<ConsoleLog> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SortOption>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sort_option(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_console_log(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  ModuleInfo sse_decode_module_info(SseDeserializer deserializer) {
+  void sse_encode_list_ep(List<Ep> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_version = sse_decode_String(deserializer);
-    var var_author = sse_decode_String(deserializer);
-    var var_description = sse_decode_String(deserializer);
-    var var_icon = sse_decode_opt_String(deserializer);
-    var var_enabled = sse_decode_bool(deserializer);
-    var var_sourceUrl = sse_decode_opt_String(deserializer);
-    return ModuleInfo(
-      id: var_id,
-      name: var_name,
-      version: var_version,
-      author: var_author,
-      description: var_description,
-      icon: var_icon,
-      enabled: var_enabled,
-      sourceUrl: var_sourceUrl,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ep(item, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_function_metrics(
+    List<FunctionMetrics> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_function_metrics(item, serializer);
     }
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_image_cache_entry(
+    List<ImageCacheEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_image_cache_entry(item, serializer);
     }
   }
 
   @protected
-  RemoteImageInfo? sse_decode_opt_box_autoadd_remote_image_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_missing_module(
+    List<MissingModule> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_remote_image_info(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_missing_module(item, serializer);
     }
   }
 
   @protected
-  PageInfo sse_decode_page_info(SseDeserializer deserializer) {
+  void sse_encode_list_module_info(
+    List<ModuleInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_total = sse_decode_i_32(deserializer);
-    var var_limit = sse_decode_i_32(deserializer);
-    var var_page = sse_decode_i_32(deserializer);
-    var var_pages = sse_decode_i_32(deserializer);
-    return PageInfo(
-      total: var_total,
-      limit: var_limit,
-      page: var_page,
-      pages: var_pages,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_module_info(item, serializer);
+    }
   }
 
   @protected
-  Picture sse_decode_picture(SseDeserializer deserializer) {
+  void sse_encode_list_module_load_error(
+    List<ModuleLoadError> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_media = sse_decode_remote_image_info(deserializer);
-    var var_metadata = sse_decode_Map_String_String_None(deserializer);
-    return Picture(id: var_id, media: var_media, metadata: var_metadata);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_module_load_error(item, serializer);
+    }
   }
 
   @protected
-  PicturePage sse_decode_picture_page(SseDeserializer deserializer) {
+  void sse_encode_list_module_search_error(
+    List<ModuleSearchError> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pageInfo = sse_decode_page_info(deserializer);
-    var var_docs = sse_decode_list_picture(deserializer);
-    return PicturePage(pageInfo: var_pageInfo, docs: var_docs);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_module_search_error(item, serializer);
+    }
   }
 
   @protected
-  PropertyItem sse_decode_property_item(SseDeserializer deserializer) {
+  void sse_encode_list_module_search_page(
+    List<ModuleSearchPage> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_String(deserializer);
-    var var_value = sse_decode_String(deserializer);
-    return PropertyItem(key: var_key, value: var_value);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_module_search_page(item, serializer);
+    }
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
+  void sse_encode_list_module_update_info(
+    List<ModuleUpdateInfo> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_module_update_info(item, serializer);
+    }
   }
 
   @protected
-  RemoteImageInfo sse_decode_remote_image_info(SseDeserializer deserializer) {
+  void sse_encode_list_multipart_part(
+    List<MultipartPart> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_originalName = sse_decode_String(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    var var_fileServer = sse_decode_String(deserializer);
-    var var_headers = sse_decode_Map_String_String_None(deserializer);
-    return RemoteImageInfo(
-      originalName: var_originalName,
-      path: var_path,
-      fileServer: var_fileServer,
-      headers: var_headers,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_multipart_part(item, serializer);
+    }
   }
 
   @protected
-  SortOption sse_decode_sort_option(SseDeserializer deserializer) {
+  void sse_encode_list_picture(List<Picture> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_value = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    return SortOption(value: var_value, name: var_name);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_picture(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(
+      self is Uint32List ? self : Uint32List.fromList(self),
+    );
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_property_item(
+    List<PropertyItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_property_item(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_reading_history(
+    List<ReadingHistory> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reading_history(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_record_string_i_32(
+    List<(String, int)> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_i_32(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_search_record(
+    List<SearchRecord> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_record(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_sort_option(
+    List<SortOption> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sort_option(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_list_tag(List<Tag> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_remote_image_info(
-    RemoteImageInfo self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_login_result(LoginResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_remote_image_info(self, serializer);
+    sse_encode_bool(self.success, serializer);
+    sse_encode_opt_String(self.message, serializer);
   }
 
   @protected
-  void sse_encode_category(Category self, SseSerializer serializer) {
+  void sse_encode_maintenance_result(
+    MaintenanceResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_opt_box_autoadd_remote_image_info(self.thumb, serializer);
-    sse_encode_bool(self.isWeb, serializer);
-    sse_encode_bool(self.active, serializer);
-    sse_encode_opt_String(self.link, serializer);
+    sse_encode_u_64(self.sizeBefore, serializer);
+    sse_encode_u_64(self.sizeAfter, serializer);
   }
 
   @protected
-  void sse_encode_comic_detail(ComicDetail self, SseSerializer serializer) {
+  void sse_encode_missing_module(MissingModule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.author, serializer);
-    sse_encode_i_32(self.pagesCount, serializer);
-    sse_encode_i_32(self.epsCount, serializer);
-    sse_encode_bool(self.finished, serializer);
-    sse_encode_list_String(self.categories, serializer);
-    sse_encode_remote_image_info(self.thumb, serializer);
-    sse_encode_i_32(self.likesCount, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_String(self.chineseTeam, serializer);
-    sse_encode_list_String(self.tags, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_bool(self.allowDownload, serializer);
-    sse_encode_i_32(self.viewsCount, serializer);
-    sse_encode_bool(self.isFavourite, serializer);
-    sse_encode_bool(self.isLiked, serializer);
-    sse_encode_i_32(self.commentsCount, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.sourceUrl, serializer);
   }
 
   @protected
-  void sse_encode_comic_simple(ComicSimple self, SseSerializer serializer) {
+  void sse_encode_module_call_output(
+    ModuleCallOutput self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.author, serializer);
-    sse_encode_i_32(self.pagesCount, serializer);
-    sse_encode_i_32(self.epsCount, serializer);
-    sse_encode_bool(self.finished, serializer);
-    sse_encode_list_String(self.categories, serializer);
-    sse_encode_remote_image_info(self.thumb, serializer);
-    sse_encode_i_32(self.likesCount, serializer);
+    sse_encode_opt_String(self.result, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_opt_String(self.stack, serializer);
+    sse_encode_list_console_log(self.logs, serializer);
   }
 
   @protected
-  void sse_encode_comics_page(ComicsPage self, SseSerializer serializer) {
+  void sse_encode_module_capabilities(
+    ModuleCapabilities self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_page_info(self.pageInfo, serializer);
-    sse_encode_list_comic_simple(self.docs, serializer);
+    sse_encode_bool(self.search, serializer);
+    sse_encode_bool(self.comments, serializer);
+    sse_encode_bool(self.login, serializer);
+    sse_encode_bool(self.download, serializer);
+    sse_encode_bool(self.processImage, serializer);
   }
 
   @protected
-  void sse_encode_ep(Ep self, SseSerializer serializer) {
+  void sse_encode_module_error(ModuleError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_i_32(self.order, serializer);
-    sse_encode_String(self.updatedAt, serializer);
+    switch (self) {
+      case ModuleError_NotFound(moduleId: final moduleId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(moduleId, serializer);
+      case ModuleError_Disabled(moduleId: final moduleId):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(moduleId, serializer);
+      case ModuleError_JsException(message: final message, stack: final stack):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_String(stack, serializer);
+      case ModuleError_MissingFunctions(
+        moduleId: final moduleId,
+        functions: final functions,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(moduleId, serializer);
+        sse_encode_list_String(functions, serializer);
+      case ModuleError_Unsupported(
+        moduleId: final moduleId,
+        funcName: final funcName,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(moduleId, serializer);
+        sse_encode_String(funcName, serializer);
+      case ModuleError_Timeout(
+        funcName: final funcName,
+        timeoutMs: final timeoutMs,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(funcName, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+      case ModuleError_HttpError(message: final message):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(message, serializer);
+      case ModuleError_ParseError(target: final target, message: final message):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(target, serializer);
+        sse_encode_String(message, serializer);
+      case ModuleError_Cancelled():
+        sse_encode_i_32(8, serializer);
+      case ModuleError_Internal(message: final message):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(message, serializer);
+    }
   }
 
   @protected
-  void sse_encode_ep_page(EpPage self, SseSerializer serializer) {
+  void sse_encode_module_info(ModuleInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_page_info(self.pageInfo, serializer);
-    sse_encode_list_ep(self.docs, serializer);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.version, serializer);
+    sse_encode_String(self.author, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_opt_String(self.icon, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_opt_String(self.sourceUrl, serializer);
+    sse_encode_bool(self.sandbox, serializer);
+    sse_encode_list_String(self.permissions, serializer);
   }
 
   @protected
-  void sse_encode_http_response_dto(
-    HttpResponseDto self,
+  void sse_encode_module_load_error(
+    ModuleLoadError self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_16(self.status, serializer);
-    sse_encode_Map_String_String_None(self.headers, serializer);
-    sse_encode_String(self.body, serializer);
-    sse_encode_String(self.contentType, serializer);
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_module_preload_result(
+    ModulePreloadResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_list_String(self.loaded, serializer);
+    sse_encode_list_String(self.skipped, serializer);
+    sse_encode_list_module_load_error(self.failed, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_module_search_error(
+    ModuleSearchError self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  void sse_encode_image_cache_stats(
-    ImageCacheStats self,
+  void sse_encode_module_search_page(
+    ModuleSearchPage self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.totalCount, serializer);
-    sse_encode_u_64(self.validCount, serializer);
-    sse_encode_u_64(self.expiredCount, serializer);
-    sse_encode_u_64(self.totalSize, serializer);
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_comics_page(self.page, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_module_update_info(
+    ModuleUpdateInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.currentVersion, serializer);
+    sse_encode_String(self.latestVersion, serializer);
   }
 
   @protected
-  void sse_encode_list_category(List<Category> self, SseSerializer serializer) {
+  void sse_encode_module_update_result(
+    ModuleUpdateResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_category(item, serializer);
+    switch (self) {
+      case ModuleUpdateResult_UpToDate():
+        sse_encode_i_32(0, serializer);
+      case ModuleUpdateResult_Updated(from: final from, to: final to):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(from, serializer);
+        sse_encode_String(to, serializer);
+      case ModuleUpdateResult_Failed(message: final message):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(message, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_comic_simple(
-    List<ComicSimple> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_multipart_part(MultipartPart self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_comic_simple(item, serializer);
-    }
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.value, serializer);
+    sse_encode_opt_String(self.data, serializer);
+    sse_encode_opt_String(self.filename, serializer);
+    sse_encode_opt_String(self.contentType, serializer);
   }
 
   @protected
-  void sse_encode_list_ep(List<Ep> self, SseSerializer serializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ep(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_module_info(
-    List<ModuleInfo> self,
+  void sse_encode_opt_box_autoadd_chapter_download(
+    ChapterDownload? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_module_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_chapter_download(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_picture(List<Picture> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_chapter_progress(
+    ChapterProgress? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_picture(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_chapter_progress(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_opt_box_autoadd_comic_simple(
+    ComicSimple? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_comic_simple(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_property_item(
-    List<PropertyItem> self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_property_item(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_opt_box_autoadd_read_position(
+    ReadPosition? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_read_position(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_sort_option(
-    List<SortOption> self,
+  void sse_encode_opt_box_autoadd_remote_image_info(
+    RemoteImageInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sort_option(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_remote_image_info(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_module_info(ModuleInfo self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.version, serializer);
-    sse_encode_String(self.author, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_opt_String(self.icon, serializer);
-    sse_encode_bool(self.enabled, serializer);
-    sse_encode_opt_String(self.sourceUrl, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_u_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
+      sse_encode_box_autoadd_u_8(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_remote_image_info(
-    RemoteImageInfo? self,
+  void sse_encode_opt_box_autoadd_web_cache_item(
+    WebCacheItem? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_remote_image_info(self, serializer);
+      sse_encode_box_autoadd_web_cache_item(self, serializer);
     }
   }
 
@@ -3538,6 +9968,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.value, serializer);
   }
 
+  @protected
+  void sse_encode_property_page(PropertyPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_page_info(self.pageInfo, serializer);
+    sse_encode_list_property_item(self.docs, serializer);
+  }
+
+  @protected
+  void sse_encode_read_position(ReadPosition self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.epId, serializer);
+    sse_encode_i_32(self.pageIndex, serializer);
+  }
+
+  @protected
+  void sse_encode_reading_history(
+    ReadingHistory self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.moduleId, serializer);
+    sse_encode_String(self.comicId, serializer);
+    sse_encode_String(self.epId, serializer);
+    sse_encode_i_32(self.pageIndex, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.thumbUrl, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_i_32(
+    (String, int) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_i_32(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -3560,6 +10029,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Map_String_String_None(self.headers, serializer);
   }
 
+  @protected
+  void sse_encode_runtime_limits(RuntimeLimits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.memoryLimitMb, serializer);
+    sse_encode_u_32(self.stackSizeKb, serializer);
+  }
+
+  @protected
+  void sse_encode_search_record(SearchRecord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.keyword, serializer);
+    sse_encode_i_32(self.count, serializer);
+    sse_encode_i_64(self.searchedAt, serializer);
+  }
+
   @protected
   void sse_encode_sort_option(SortOption self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3567,6 +10051,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.name, serializer);
   }
 
+  @protected
+  void sse_encode_tag(Tag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.count, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3595,4 +10087,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
+
+  @protected
+  void sse_encode_usage_stats(UsageStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.historyComics, serializer);
+    sse_encode_u_64(self.modules, serializer);
+    sse_encode_u_64(self.enabledModules, serializer);
+    sse_encode_u_64(self.downloadedChapters, serializer);
+    sse_encode_u_64(self.imageCacheCount, serializer);
+    sse_encode_u_64(self.imageCacheSize, serializer);
+    sse_encode_u_64(self.webCacheCount, serializer);
+    sse_encode_u_64(self.webCacheSize, serializer);
+    sse_encode_u_64(self.databaseSize, serializer);
+  }
+
+  @protected
+  void sse_encode_user_data_import_summary(
+    UserDataImportSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.properties, serializer);
+    sse_encode_u_32(self.readingHistory, serializer);
+    sse_encode_u_32(self.searchHistory, serializer);
+    sse_encode_list_missing_module(self.missingModules, serializer);
+  }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_web_cache_item(WebCacheItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.body, serializer);
+    sse_encode_String(self.contentType, serializer);
+  }
 }
@@ -3,19 +3,32 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/backup_api.dart';
+import 'api/cancel_api.dart';
 import 'api/crypto_api.dart';
+import 'api/db_api.dart';
+import 'api/download_api.dart';
+import 'api/history_api.dart';
 import 'api/http_api.dart';
 import 'api/image_api.dart';
 import 'api/image_cache_api.dart';
 import 'api/init.dart';
+import 'api/log_api.dart';
 import 'api/module_api.dart';
+import 'api/progress_api.dart';
 import 'api/property_api.dart';
 import 'api/proxy_api.dart';
+import 'api/search_history_api.dart';
 import 'api/simple.dart';
+import 'api/stats_api.dart';
+import 'api/web_cache_api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
+import 'http/client.dart';
+import 'js_engine/runtime.dart';
+import 'modules/error.dart';
 import 'modules/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
@@ -33,446 +46,1408 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
 
+  @protected
+  Map<String, int> dco_decode_Map_String_i_32_None(dynamic raw);
+
+  @protected
+  RustStreamSink<ChapterDownload> dco_decode_StreamSink_chapter_download_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<DownloadProgress> dco_decode_StreamSink_download_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ImagePrefetchItem> dco_decode_StreamSink_image_prefetch_item_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AggregatedSearchResult dco_decode_aggregated_search_result(dynamic raw);
+
+  @protected
+  BatchCall dco_decode_batch_call(dynamic raw);
+
+  @protected
+  BatchCallResult dco_decode_batch_call_result(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+  ChapterDownload dco_decode_box_autoadd_chapter_download(dynamic raw);
 
   @protected
-  RemoteImageInfo dco_decode_box_autoadd_remote_image_info(dynamic raw);
+  ChapterProgress dco_decode_box_autoadd_chapter_progress(dynamic raw);
 
   @protected
-  Category dco_decode_category(dynamic raw);
+  ComicSimple dco_decode_box_autoadd_comic_simple(dynamic raw);
 
   @protected
-  ComicDetail dco_decode_comic_detail(dynamic raw);
+  int dco_decode_box_autoadd_i_32(dynamic raw);
 
   @protected
-  ComicSimple dco_decode_comic_simple(dynamic raw);
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
   @protected
-  ComicsPage dco_decode_comics_page(dynamic raw);
+  ReadPosition dco_decode_box_autoadd_read_position(dynamic raw);
 
   @protected
-  Ep dco_decode_ep(dynamic raw);
+  RemoteImageInfo dco_decode_box_autoadd_remote_image_info(dynamic raw);
 
   @protected
-  EpPage dco_decode_ep_page(dynamic raw);
+  int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  HttpResponseDto dco_decode_http_response_dto(dynamic raw);
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  int dco_decode_box_autoadd_u_8(dynamic raw);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  WebCacheItem dco_decode_box_autoadd_web_cache_item(dynamic raw);
 
   @protected
-  ImageCacheStats dco_decode_image_cache_stats(dynamic raw);
+  Category dco_decode_category(dynamic raw);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  ChapterDownload dco_decode_chapter_download(dynamic raw);
 
   @protected
-  List<Category> dco_decode_list_category(dynamic raw);
+  ChapterProgress dco_decode_chapter_progress(dynamic raw);
 
   @protected
-  List<ComicSimple> dco_decode_list_comic_simple(dynamic raw);
+  ComicDetail dco_decode_comic_detail(dynamic raw);
 
   @protected
-  List<Ep> dco_decode_list_ep(dynamic raw);
+  ComicSimple dco_decode_comic_simple(dynamic raw);
 
   @protected
-  List<ModuleInfo> dco_decode_list_module_info(dynamic raw);
+  ComicsPage dco_decode_comics_page(dynamic raw);
 
   @protected
-  List<Picture> dco_decode_list_picture(dynamic raw);
+  Comment dco_decode_comment(dynamic raw);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  CommentPage dco_decode_comment_page(dynamic raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  ConsoleLog dco_decode_console_log(dynamic raw);
 
   @protected
-  List<PropertyItem> dco_decode_list_property_item(dynamic raw);
+  DownloadProgress dco_decode_download_progress(dynamic raw);
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+  Ep dco_decode_ep(dynamic raw);
 
   @protected
-  List<SortOption> dco_decode_list_sort_option(dynamic raw);
+  EpPage dco_decode_ep_page(dynamic raw);
 
   @protected
-  ModuleInfo dco_decode_module_info(dynamic raw);
+  FunctionMetrics dco_decode_function_metrics(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  HistoryPage dco_decode_history_page(dynamic raw);
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+  HttpDefaults dco_decode_http_defaults(dynamic raw);
 
   @protected
-  RemoteImageInfo? dco_decode_opt_box_autoadd_remote_image_info(dynamic raw);
+  HttpPoolSettings dco_decode_http_pool_settings(dynamic raw);
 
   @protected
-  PageInfo dco_decode_page_info(dynamic raw);
+  HttpResponseDto dco_decode_http_response_dto(dynamic raw);
 
   @protected
-  Picture dco_decode_picture(dynamic raw);
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  PicturePage dco_decode_picture_page(dynamic raw);
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  PropertyItem dco_decode_property_item(dynamic raw);
+  ImageCacheEntry dco_decode_image_cache_entry(dynamic raw);
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+  ImageCachePage dco_decode_image_cache_page(dynamic raw);
 
   @protected
-  RemoteImageInfo dco_decode_remote_image_info(dynamic raw);
+  ImageCacheStats dco_decode_image_cache_stats(dynamic raw);
 
   @protected
-  SortOption dco_decode_sort_option(dynamic raw);
+  ImagePrefetchItem dco_decode_image_prefetch_item(dynamic raw);
 
   @protected
-  int dco_decode_u_16(dynamic raw);
+  ImagePrefetchSummary dco_decode_image_prefetch_summary(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  List<BatchCall> dco_decode_list_batch_call(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  List<BatchCallResult> dco_decode_list_batch_call_result(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  List<Category> dco_decode_list_category(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  List<ChapterDownload> dco_decode_list_chapter_download(dynamic raw);
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  );
+  List<ComicSimple> dco_decode_list_comic_simple(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  List<Comment> dco_decode_list_comment(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  List<ConsoleLog> dco_decode_list_console_log(dynamic raw);
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+  List<Ep> dco_decode_list_ep(dynamic raw);
 
   @protected
-  RemoteImageInfo sse_decode_box_autoadd_remote_image_info(
-    SseDeserializer deserializer,
-  );
+  List<FunctionMetrics> dco_decode_list_function_metrics(dynamic raw);
 
   @protected
-  Category sse_decode_category(SseDeserializer deserializer);
+  List<ImageCacheEntry> dco_decode_list_image_cache_entry(dynamic raw);
 
   @protected
-  ComicDetail sse_decode_comic_detail(SseDeserializer deserializer);
+  List<MissingModule> dco_decode_list_missing_module(dynamic raw);
 
   @protected
-  ComicSimple sse_decode_comic_simple(SseDeserializer deserializer);
+  List<ModuleInfo> dco_decode_list_module_info(dynamic raw);
 
   @protected
-  ComicsPage sse_decode_comics_page(SseDeserializer deserializer);
+  List<ModuleLoadError> dco_decode_list_module_load_error(dynamic raw);
 
   @protected
-  Ep sse_decode_ep(SseDeserializer deserializer);
+  List<ModuleSearchError> dco_decode_list_module_search_error(dynamic raw);
 
   @protected
-  EpPage sse_decode_ep_page(SseDeserializer deserializer);
+  List<ModuleSearchPage> dco_decode_list_module_search_page(dynamic raw);
 
   @protected
-  HttpResponseDto sse_decode_http_response_dto(SseDeserializer deserializer);
+  List<ModuleUpdateInfo> dco_decode_list_module_update_info(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  List<MultipartPart> dco_decode_list_multipart_part(dynamic raw);
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  List<Picture> dco_decode_list_picture(dynamic raw);
 
   @protected
-  ImageCacheStats sse_decode_image_cache_stats(SseDeserializer deserializer);
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
-  List<Category> sse_decode_list_category(SseDeserializer deserializer);
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  List<ComicSimple> sse_decode_list_comic_simple(SseDeserializer deserializer);
+  List<PropertyItem> dco_decode_list_property_item(dynamic raw);
 
   @protected
-  List<Ep> sse_decode_list_ep(SseDeserializer deserializer);
+  List<ReadingHistory> dco_decode_list_reading_history(dynamic raw);
 
   @protected
-  List<ModuleInfo> sse_decode_list_module_info(SseDeserializer deserializer);
+  List<(String, int)> dco_decode_list_record_string_i_32(dynamic raw);
 
   @protected
-  List<Picture> sse_decode_list_picture(SseDeserializer deserializer);
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  List<SearchRecord> dco_decode_list_search_record(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  List<SortOption> dco_decode_list_sort_option(dynamic raw);
 
   @protected
-  List<PropertyItem> sse_decode_list_property_item(
-    SseDeserializer deserializer,
-  );
+  List<Tag> dco_decode_list_tag(dynamic raw);
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  );
+  LoginResult dco_decode_login_result(dynamic raw);
 
   @protected
-  List<SortOption> sse_decode_list_sort_option(SseDeserializer deserializer);
+  MaintenanceResult dco_decode_maintenance_result(dynamic raw);
 
   @protected
-  ModuleInfo sse_decode_module_info(SseDeserializer deserializer);
+  MissingModule dco_decode_missing_module(dynamic raw);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  ModuleCallOutput dco_decode_module_call_output(dynamic raw);
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+  ModuleCapabilities dco_decode_module_capabilities(dynamic raw);
 
   @protected
-  RemoteImageInfo? sse_decode_opt_box_autoadd_remote_image_info(
-    SseDeserializer deserializer,
-  );
+  ModuleError dco_decode_module_error(dynamic raw);
 
   @protected
-  PageInfo sse_decode_page_info(SseDeserializer deserializer);
+  ModuleInfo dco_decode_module_info(dynamic raw);
 
   @protected
-  Picture sse_decode_picture(SseDeserializer deserializer);
+  ModuleLoadError dco_decode_module_load_error(dynamic raw);
 
   @protected
-  PicturePage sse_decode_picture_page(SseDeserializer deserializer);
+  ModulePreloadResult dco_decode_module_preload_result(dynamic raw);
 
   @protected
-  PropertyItem sse_decode_property_item(SseDeserializer deserializer);
+  ModuleSearchError dco_decode_module_search_error(dynamic raw);
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  );
+  ModuleSearchPage dco_decode_module_search_page(dynamic raw);
 
   @protected
-  RemoteImageInfo sse_decode_remote_image_info(SseDeserializer deserializer);
+  ModuleUpdateInfo dco_decode_module_update_info(dynamic raw);
 
   @protected
-  SortOption sse_decode_sort_option(SseDeserializer deserializer);
+  ModuleUpdateResult dco_decode_module_update_result(dynamic raw);
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+  MultipartPart dco_decode_multipart_part(dynamic raw);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  ChapterDownload? dco_decode_opt_box_autoadd_chapter_download(dynamic raw);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  ChapterProgress? dco_decode_opt_box_autoadd_chapter_progress(dynamic raw);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  ComicSimple? dco_decode_opt_box_autoadd_comic_simple(dynamic raw);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  );
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  ReadPosition? dco_decode_opt_box_autoadd_read_position(dynamic raw);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  RemoteImageInfo? dco_decode_opt_box_autoadd_remote_image_info(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  );
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_remote_image_info(
-    RemoteImageInfo self,
-    SseSerializer serializer,
-  );
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
-  void sse_encode_category(Category self, SseSerializer serializer);
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
   @protected
-  void sse_encode_comic_detail(ComicDetail self, SseSerializer serializer);
+  WebCacheItem? dco_decode_opt_box_autoadd_web_cache_item(dynamic raw);
 
   @protected
-  void sse_encode_comic_simple(ComicSimple self, SseSerializer serializer);
+  PageInfo dco_decode_page_info(dynamic raw);
 
   @protected
-  void sse_encode_comics_page(ComicsPage self, SseSerializer serializer);
+  Picture dco_decode_picture(dynamic raw);
 
   @protected
-  void sse_encode_ep(Ep self, SseSerializer serializer);
+  PicturePage dco_decode_picture_page(dynamic raw);
 
   @protected
-  void sse_encode_ep_page(EpPage self, SseSerializer serializer);
+  PropertyItem dco_decode_property_item(dynamic raw);
 
   @protected
-  void sse_encode_http_response_dto(
-    HttpResponseDto self,
-    SseSerializer serializer,
-  );
+  PropertyPage dco_decode_property_page(dynamic raw);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  ReadPosition dco_decode_read_position(dynamic raw);
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  ReadingHistory dco_decode_reading_history(dynamic raw);
 
   @protected
-  void sse_encode_image_cache_stats(
-    ImageCacheStats self,
-    SseSerializer serializer,
-  );
+  (String, int) dco_decode_record_string_i_32(dynamic raw);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  (String, String) dco_decode_record_string_string(dynamic raw);
 
   @protected
-  void sse_encode_list_category(List<Category> self, SseSerializer serializer);
+  RemoteImageInfo dco_decode_remote_image_info(dynamic raw);
 
   @protected
-  void sse_encode_list_comic_simple(
-    List<ComicSimple> self,
-    SseSerializer serializer,
-  );
+  RuntimeLimits dco_decode_runtime_limits(dynamic raw);
 
   @protected
-  void sse_encode_list_ep(List<Ep> self, SseSerializer serializer);
+  SearchRecord dco_decode_search_record(dynamic raw);
 
   @protected
-  void sse_encode_list_module_info(
-    List<ModuleInfo> self,
-    SseSerializer serializer,
-  );
+  SortOption dco_decode_sort_option(dynamic raw);
 
   @protected
-  void sse_encode_list_picture(List<Picture> self, SseSerializer serializer);
+  Tag dco_decode_tag(dynamic raw);
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  int dco_decode_u_16(dynamic raw);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+  int dco_decode_u_32(dynamic raw);
 
   @protected
-  void sse_encode_list_property_item(
-    List<PropertyItem> self,
-    SseSerializer serializer,
-  );
+  BigInt dco_decode_u_64(dynamic raw);
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  );
+  int dco_decode_u_8(dynamic raw);
 
   @protected
-  void sse_encode_list_sort_option(
-    List<SortOption> self,
-    SseSerializer serializer,
-  );
+  void dco_decode_unit(dynamic raw);
 
   @protected
-  void sse_encode_module_info(ModuleInfo self, SseSerializer serializer);
+  UsageStats dco_decode_usage_stats(dynamic raw);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  UserDataImportSummary dco_decode_user_data_import_summary(dynamic raw);
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  );
+  BigInt dco_decode_usize(dynamic raw);
 
   @protected
-  void sse_encode_opt_box_autoadd_remote_image_info(
-    RemoteImageInfo? self,
-    SseSerializer serializer,
-  );
+  WebCacheItem dco_decode_web_cache_item(dynamic raw);
 
   @protected
-  void sse_encode_page_info(PageInfo self, SseSerializer serializer);
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_picture(Picture self, SseSerializer serializer);
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_picture_page(PicturePage self, SseSerializer serializer);
+  Map<String, int> sse_decode_Map_String_i_32_None(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_property_item(PropertyItem self, SseSerializer serializer);
+  RustStreamSink<ChapterDownload> sse_decode_StreamSink_chapter_download_Sse(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
+  RustStreamSink<DownloadProgress> sse_decode_StreamSink_download_progress_Sse(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_remote_image_info(
-    RemoteImageInfo self,
-    SseSerializer serializer,
+  RustStreamSink<ImagePrefetchItem> sse_decode_StreamSink_image_prefetch_item_Sse(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_sort_option(SortOption self, SseSerializer serializer);
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+  AggregatedSearchResult sse_decode_aggregated_search_result(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  BatchCall sse_decode_batch_call(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+  BatchCallResult sse_decode_batch_call_result(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  ChapterDownload sse_decode_box_autoadd_chapter_download(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterProgress sse_decode_box_autoadd_chapter_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ComicSimple sse_decode_box_autoadd_comic_simple(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ReadPosition sse_decode_box_autoadd_read_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RemoteImageInfo sse_decode_box_autoadd_remote_image_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  WebCacheItem sse_decode_box_autoadd_web_cache_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Category sse_decode_category(SseDeserializer deserializer);
+
+  @protected
+  ChapterDownload sse_decode_chapter_download(SseDeserializer deserializer);
+
+  @protected
+  ChapterProgress sse_decode_chapter_progress(SseDeserializer deserializer);
+
+  @protected
+  ComicDetail sse_decode_comic_detail(SseDeserializer deserializer);
+
+  @protected
+  ComicSimple sse_decode_comic_simple(SseDeserializer deserializer);
+
+  @protected
+  ComicsPage sse_decode_comics_page(SseDeserializer deserializer);
+
+  @protected
+  Comment sse_decode_comment(SseDeserializer deserializer);
+
+  @protected
+  CommentPage sse_decode_comment_page(SseDeserializer deserializer);
+
+  @protected
+  ConsoleLog sse_decode_console_log(SseDeserializer deserializer);
+
+  @protected
+  DownloadProgress sse_decode_download_progress(SseDeserializer deserializer);
+
+  @protected
+  Ep sse_decode_ep(SseDeserializer deserializer);
+
+  @protected
+  EpPage sse_decode_ep_page(SseDeserializer deserializer);
+
+  @protected
+  FunctionMetrics sse_decode_function_metrics(SseDeserializer deserializer);
+
+  @protected
+  HistoryPage sse_decode_history_page(SseDeserializer deserializer);
+
+  @protected
+  HttpDefaults sse_decode_http_defaults(SseDeserializer deserializer);
+
+  @protected
+  HttpPoolSettings sse_decode_http_pool_settings(SseDeserializer deserializer);
+
+  @protected
+  HttpResponseDto sse_decode_http_response_dto(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageCacheEntry sse_decode_image_cache_entry(SseDeserializer deserializer);
+
+  @protected
+  ImageCachePage sse_decode_image_cache_page(SseDeserializer deserializer);
+
+  @protected
+  ImageCacheStats sse_decode_image_cache_stats(SseDeserializer deserializer);
+
+  @protected
+  ImagePrefetchItem sse_decode_image_prefetch_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImagePrefetchSummary sse_decode_image_prefetch_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<BatchCall> sse_decode_list_batch_call(SseDeserializer deserializer);
+
+  @protected
+  List<BatchCallResult> sse_decode_list_batch_call_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Category> sse_decode_list_category(SseDeserializer deserializer);
+
+  @protected
+  List<ChapterDownload> sse_decode_list_chapter_download(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ComicSimple> sse_decode_list_comic_simple(SseDeserializer deserializer);
+
+  @protected
+  List<Comment> sse_decode_list_comment(SseDeserializer deserializer);
+
+  @protected
+  List<ConsoleLog> sse_decode_list_console_log(SseDeserializer deserializer);
+
+  @protected
+  List<Ep> sse_decode_list_ep(SseDeserializer deserializer);
+
+  @protected
+  List<FunctionMetrics> sse_decode_list_function_metrics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ImageCacheEntry> sse_decode_list_image_cache_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MissingModule> sse_decode_list_missing_module(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModuleInfo> sse_decode_list_module_info(SseDeserializer deserializer);
+
+  @protected
+  List<ModuleLoadError> sse_decode_list_module_load_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModuleSearchError> sse_decode_list_module_search_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModuleSearchPage> sse_decode_list_module_search_page(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModuleUpdateInfo> sse_decode_list_module_update_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MultipartPart> sse_decode_list_multipart_part(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Picture> sse_decode_list_picture(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<PropertyItem> sse_decode_list_property_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReadingHistory> sse_decode_list_reading_history(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, int)> sse_decode_list_record_string_i_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SearchRecord> sse_decode_list_search_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SortOption> sse_decode_list_sort_option(SseDeserializer deserializer);
+
+  @protected
+  List<Tag> sse_decode_list_tag(SseDeserializer deserializer);
+
+  @protected
+  LoginResult sse_decode_login_result(SseDeserializer deserializer);
+
+  @protected
+  MaintenanceResult sse_decode_maintenance_result(SseDeserializer deserializer);
+
+  @protected
+  MissingModule sse_decode_missing_module(SseDeserializer deserializer);
+
+  @protected
+  ModuleCallOutput sse_decode_module_call_output(SseDeserializer deserializer);
+
+  @protected
+  ModuleCapabilities sse_decode_module_capabilities(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModuleError sse_decode_module_error(SseDeserializer deserializer);
+
+  @protected
+  ModuleInfo sse_decode_module_info(SseDeserializer deserializer);
+
+  @protected
+  ModuleLoadError sse_decode_module_load_error(SseDeserializer deserializer);
+
+  @protected
+  ModulePreloadResult sse_decode_module_preload_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModuleSearchError sse_decode_module_search_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModuleSearchPage sse_decode_module_search_page(SseDeserializer deserializer);
+
+  @protected
+  ModuleUpdateInfo sse_decode_module_update_info(SseDeserializer deserializer);
+
+  @protected
+  ModuleUpdateResult sse_decode_module_update_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MultipartPart sse_decode_multipart_part(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ChapterDownload? sse_decode_opt_box_autoadd_chapter_download(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterProgress? sse_decode_opt_box_autoadd_chapter_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ComicSimple? sse_decode_opt_box_autoadd_comic_simple(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ReadPosition? sse_decode_opt_box_autoadd_read_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RemoteImageInfo? sse_decode_opt_box_autoadd_remote_image_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  WebCacheItem? sse_decode_opt_box_autoadd_web_cache_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PageInfo sse_decode_page_info(SseDeserializer deserializer);
+
+  @protected
+  Picture sse_decode_picture(SseDeserializer deserializer);
+
+  @protected
+  PicturePage sse_decode_picture_page(SseDeserializer deserializer);
+
+  @protected
+  PropertyItem sse_decode_property_item(SseDeserializer deserializer);
+
+  @protected
+  PropertyPage sse_decode_property_page(SseDeserializer deserializer);
+
+  @protected
+  ReadPosition sse_decode_read_position(SseDeserializer deserializer);
+
+  @protected
+  ReadingHistory sse_decode_reading_history(SseDeserializer deserializer);
+
+  @protected
+  (String, int) sse_decode_record_string_i_32(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RemoteImageInfo sse_decode_remote_image_info(SseDeserializer deserializer);
+
+  @protected
+  RuntimeLimits sse_decode_runtime_limits(SseDeserializer deserializer);
+
+  @protected
+  SearchRecord sse_decode_search_record(SseDeserializer deserializer);
+
+  @protected
+  SortOption sse_decode_sort_option(SseDeserializer deserializer);
+
+  @protected
+  Tag sse_decode_tag(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UsageStats sse_decode_usage_stats(SseDeserializer deserializer);
+
+  @protected
+  UserDataImportSummary sse_decode_user_data_import_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WebCacheItem sse_decode_web_cache_item(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_i_32_None(
+    Map<String, int> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_chapter_download_Sse(
+    RustStreamSink<ChapterDownload> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_download_progress_Sse(
+    RustStreamSink<DownloadProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_image_prefetch_item_Sse(
+    RustStreamSink<ImagePrefetchItem> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_aggregated_search_result(
+    AggregatedSearchResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_batch_call(BatchCall self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_call_result(
+    BatchCallResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_chapter_download(
+    ChapterDownload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_chapter_progress(
+    ChapterProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_comic_simple(
+    ComicSimple self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_read_position(
+    ReadPosition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_remote_image_info(
+    RemoteImageInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_web_cache_item(
+    WebCacheItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_category(Category self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_download(
+    ChapterDownload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_progress(
+    ChapterProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_comic_detail(ComicDetail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_comic_simple(ComicSimple self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_comics_page(ComicsPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_comment(Comment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_comment_page(CommentPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_console_log(ConsoleLog self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_download_progress(
+    DownloadProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_ep(Ep self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ep_page(EpPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_function_metrics(
+    FunctionMetrics self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_history_page(HistoryPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_http_defaults(HttpDefaults self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_http_pool_settings(
+    HttpPoolSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_http_response_dto(
+    HttpResponseDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_cache_entry(
+    ImageCacheEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_image_cache_page(
+    ImageCachePage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_image_cache_stats(
+    ImageCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_image_prefetch_item(
+    ImagePrefetchItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_image_prefetch_summary(
+    ImagePrefetchSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_batch_call(
+    List<BatchCall> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_batch_call_result(
+    List<BatchCallResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_category(List<Category> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_chapter_download(
+    List<ChapterDownload> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_comic_simple(
+    List<ComicSimple> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_comment(List<Comment> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_console_log(
+    List<ConsoleLog> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_ep(List<Ep> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_function_metrics(
+    List<FunctionMetrics> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_image_cache_entry(
+    List<ImageCacheEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_missing_module(
+    List<MissingModule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_module_info(
+    List<ModuleInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_module_load_error(
+    List<ModuleLoadError> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_module_search_error(
+    List<ModuleSearchError> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_module_search_page(
+    List<ModuleSearchPage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_module_update_info(
+    List<ModuleUpdateInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_multipart_part(
+    List<MultipartPart> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_picture(List<Picture> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_property_item(
+    List<PropertyItem> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_reading_history(
+    List<ReadingHistory> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_i_32(
+    List<(String, int)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_search_record(
+    List<SearchRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sort_option(
+    List<SortOption> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_tag(List<Tag> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_login_result(LoginResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_maintenance_result(
+    MaintenanceResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_missing_module(MissingModule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_module_call_output(
+    ModuleCallOutput self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_capabilities(
+    ModuleCapabilities self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_error(ModuleError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_module_info(ModuleInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_module_load_error(
+    ModuleLoadError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_preload_result(
+    ModulePreloadResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_search_error(
+    ModuleSearchError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_search_page(
+    ModuleSearchPage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_update_info(
+    ModuleUpdateInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_module_update_result(
+    ModuleUpdateResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_multipart_part(MultipartPart self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_chapter_download(
+    ChapterDownload? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_chapter_progress(
+    ChapterProgress? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_comic_simple(
+    ComicSimple? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_read_position(
+    ReadPosition? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_remote_image_info(
+    RemoteImageInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_web_cache_item(
+    WebCacheItem? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_page_info(PageInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_picture(Picture self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_picture_page(PicturePage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_property_item(PropertyItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_property_page(PropertyPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_read_position(ReadPosition self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reading_history(
+    ReadingHistory self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_i_32(
+    (String, int) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_remote_image_info(
+    RemoteImageInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_runtime_limits(RuntimeLimits self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_record(SearchRecord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sort_option(SortOption self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag(Tag self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usage_stats(UsageStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_user_data_import_summary(
+    UserDataImportSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_cache_item(WebCacheItem self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 全局默认的超时和 User-Agent
+class HttpDefaults {
+  /// 未指定超时的请求使用的超时（秒）
+  final BigInt timeoutSecs;
+
+  /// 请求头未设置 User-Agent 时使用的值
+  final String userAgent;
+
+  const HttpDefaults({required this.timeoutSecs, required this.userAgent});
+
+  @override
+  int get hashCode => timeoutSecs.hashCode ^ userAgent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HttpDefaults &&
+          runtimeType == other.runtimeType &&
+          timeoutSecs == other.timeoutSecs &&
+          userAgent == other.userAgent;
+}
+
+/// 连接池和连接超时设置
+///
+/// 合理范围（超出时按边界截断）：
+/// - `pool_max_idle_per_host`: 0–64，默认 10；并发下载较多时可适当调大，0 表示不复用连接
+/// - `pool_idle_timeout_secs`: 5–300 秒，默认 90；空闲连接超过该时间后关闭
+/// - `connect_timeout_secs`: 1–60 秒，默认 10
+class HttpPoolSettings {
+  /// 每个主机保留的最大空闲连接数
+  final int poolMaxIdlePerHost;
+
+  /// 空闲连接的保持时间（秒）
+  final BigInt poolIdleTimeoutSecs;
+
+  /// 建立连接的超时（秒）
+  final BigInt connectTimeoutSecs;
+
+  const HttpPoolSettings({
+    required this.poolMaxIdlePerHost,
+    required this.poolIdleTimeoutSecs,
+    required this.connectTimeoutSecs,
+  });
+
+  @override
+  int get hashCode =>
+      poolMaxIdlePerHost.hashCode ^
+      poolIdleTimeoutSecs.hashCode ^
+      connectTimeoutSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HttpPoolSettings &&
+          runtimeType == other.runtimeType &&
+          poolMaxIdlePerHost == other.poolMaxIdlePerHost &&
+          poolIdleTimeoutSecs == other.poolIdleTimeoutSecs &&
+          connectTimeoutSecs == other.connectTimeoutSecs;
+}
+
+/// multipart/form-data 的一个字段
+///
+/// 文本字段设置 `value`；文件字段设置 `data`（base64 编码），
+/// 可选 `filename` 和 `content_type`
+class MultipartPart {
+  final String name;
+  final String? value;
+  final String? data;
+  final String? filename;
+  final String? contentType;
+
+  const MultipartPart({
+    required this.name,
+    this.value,
+    this.data,
+    this.filename,
+    this.contentType,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      value.hashCode ^
+      data.hashCode ^
+      filename.hashCode ^
+      contentType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MultipartPart &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value &&
+          data == other.data &&
+          filename == other.filename &&
+          contentType == other.contentType;
+}
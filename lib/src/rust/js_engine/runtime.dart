@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 运行时的内存和栈限制
+class RuntimeLimits {
+  /// 内存上限（MB）
+  final int memoryLimitMb;
+
+  /// 最大栈大小（KB）
+  final int stackSizeKb;
+
+  const RuntimeLimits({required this.memoryLimitMb, required this.stackSizeKb});
+
+  @override
+  int get hashCode => memoryLimitMb.hashCode ^ stackSizeKb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RuntimeLimits &&
+          runtimeType == other.runtimeType &&
+          memoryLimitMb == other.memoryLimitMb &&
+          stackSizeKb == other.stackSizeKb;
+}
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+/// 模块调用错误
+///
+/// 通过 FRB 传到 Flutter 端后可按类型区分处理（如提示启用模块、重试网络请求等）
+@freezed
+sealed class ModuleError with _$ModuleError implements FrbException {
+  const ModuleError._();
+
+  /// 模块不存在或未加载
+  const factory ModuleError.notFound({required String moduleId}) =
+      ModuleError_NotFound;
+  /// 模块已禁用
+  const factory ModuleError.disabled({required String moduleId}) =
+      ModuleError_Disabled;
+  /// JS 抛出异常（包括 Promise 被 reject）
+  const factory ModuleError.jsException({
+    required String message,
+    required String stack,
+  }) = ModuleError_JsException;
+  /// 模块缺少必需的入口函数
+  const factory ModuleError.missingFunctions({
+    required String moduleId,
+    required List<String> functions,
+  }) = ModuleError_MissingFunctions;
+  /// 模块未实现该可选功能
+  const factory ModuleError.unsupported({
+    required String moduleId,
+    required String funcName,
+  }) = ModuleError_Unsupported;
+  /// 执行超时
+  const factory ModuleError.timeout({
+    required String funcName,
+    required BigInt timeoutMs,
+  }) = ModuleError_Timeout;
+  /// 网络请求失败
+  const factory ModuleError.httpError({required String message}) =
+      ModuleError_HttpError;
+  /// 模块返回的数据无法解析
+  const factory ModuleError.parseError({
+    required String target,
+    required String message,
+  }) = ModuleError_ParseError;
+  /// 调用被取消（见 `crate::cancel`）
+  const factory ModuleError.cancelled() = ModuleError_Cancelled;
+  /// 其他内部错误（数据库、IO 等）
+  const factory ModuleError.internal({required String message}) =
+      ModuleError_Internal;
+}
@@ -0,0 +1,684 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ModuleError {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModuleError()';
+}
+
+
+}
+
+/// @nodoc
+class $ModuleErrorCopyWith<$Res>  {
+$ModuleErrorCopyWith(ModuleError _, $Res Function(ModuleError) __);
+}
+
+
+/// @nodoc
+
+
+class ModuleError_NotFound extends ModuleError {
+  const ModuleError_NotFound({required this.moduleId}): super._();
+  
+
+ final  String moduleId;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_NotFoundCopyWith<ModuleError_NotFound> get copyWith => _$ModuleError_NotFoundCopyWithImpl<ModuleError_NotFound>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_NotFound&&(identical(other.moduleId, moduleId) || other.moduleId == moduleId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,moduleId);
+
+@override
+String toString() {
+  return 'ModuleError.notFound(moduleId: $moduleId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_NotFoundCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_NotFoundCopyWith(ModuleError_NotFound value, $Res Function(ModuleError_NotFound) _then) = _$ModuleError_NotFoundCopyWithImpl;
+@useResult
+$Res call({
+ String moduleId
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_NotFoundCopyWithImpl<$Res>
+    implements $ModuleError_NotFoundCopyWith<$Res> {
+  _$ModuleError_NotFoundCopyWithImpl(this._self, this._then);
+
+  final ModuleError_NotFound _self;
+  final $Res Function(ModuleError_NotFound) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? moduleId = null,}) {
+  return _then(ModuleError_NotFound(
+moduleId: null == moduleId ? _self.moduleId : moduleId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_Disabled extends ModuleError {
+  const ModuleError_Disabled({required this.moduleId}): super._();
+  
+
+ final  String moduleId;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_DisabledCopyWith<ModuleError_Disabled> get copyWith => _$ModuleError_DisabledCopyWithImpl<ModuleError_Disabled>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_Disabled&&(identical(other.moduleId, moduleId) || other.moduleId == moduleId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,moduleId);
+
+@override
+String toString() {
+  return 'ModuleError.disabled(moduleId: $moduleId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_DisabledCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_DisabledCopyWith(ModuleError_Disabled value, $Res Function(ModuleError_Disabled) _then) = _$ModuleError_DisabledCopyWithImpl;
+@useResult
+$Res call({
+ String moduleId
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_DisabledCopyWithImpl<$Res>
+    implements $ModuleError_DisabledCopyWith<$Res> {
+  _$ModuleError_DisabledCopyWithImpl(this._self, this._then);
+
+  final ModuleError_Disabled _self;
+  final $Res Function(ModuleError_Disabled) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? moduleId = null,}) {
+  return _then(ModuleError_Disabled(
+moduleId: null == moduleId ? _self.moduleId : moduleId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_JsException extends ModuleError {
+  const ModuleError_JsException({required this.message, required this.stack}): super._();
+  
+
+ final  String message;
+ final  String stack;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_JsExceptionCopyWith<ModuleError_JsException> get copyWith => _$ModuleError_JsExceptionCopyWithImpl<ModuleError_JsException>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_JsException&&(identical(other.message, message) || other.message == message)&&(identical(other.stack, stack) || other.stack == stack));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message,stack);
+
+@override
+String toString() {
+  return 'ModuleError.jsException(message: $message, stack: $stack)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_JsExceptionCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_JsExceptionCopyWith(ModuleError_JsException value, $Res Function(ModuleError_JsException) _then) = _$ModuleError_JsExceptionCopyWithImpl;
+@useResult
+$Res call({
+ String message, String stack
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_JsExceptionCopyWithImpl<$Res>
+    implements $ModuleError_JsExceptionCopyWith<$Res> {
+  _$ModuleError_JsExceptionCopyWithImpl(this._self, this._then);
+
+  final ModuleError_JsException _self;
+  final $Res Function(ModuleError_JsException) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,Object? stack = null,}) {
+  return _then(ModuleError_JsException(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,stack: null == stack ? _self.stack : stack // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_MissingFunctions extends ModuleError {
+  const ModuleError_MissingFunctions({required this.moduleId, required final  List<String> functions}): _functions = functions, super._();
+  
+
+ final  String moduleId;
+ final  List<String> _functions;
+ List<String> get functions {
+  if (_functions is EqualUnmodifiableListView) return _functions;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_functions);
+}
+
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_MissingFunctionsCopyWith<ModuleError_MissingFunctions> get copyWith => _$ModuleError_MissingFunctionsCopyWithImpl<ModuleError_MissingFunctions>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_MissingFunctions&&(identical(other.moduleId, moduleId) || other.moduleId == moduleId)&&const DeepCollectionEquality().equals(other._functions, _functions));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,moduleId,const DeepCollectionEquality().hash(_functions));
+
+@override
+String toString() {
+  return 'ModuleError.missingFunctions(moduleId: $moduleId, functions: $functions)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_MissingFunctionsCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_MissingFunctionsCopyWith(ModuleError_MissingFunctions value, $Res Function(ModuleError_MissingFunctions) _then) = _$ModuleError_MissingFunctionsCopyWithImpl;
+@useResult
+$Res call({
+ String moduleId, List<String> functions
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_MissingFunctionsCopyWithImpl<$Res>
+    implements $ModuleError_MissingFunctionsCopyWith<$Res> {
+  _$ModuleError_MissingFunctionsCopyWithImpl(this._self, this._then);
+
+  final ModuleError_MissingFunctions _self;
+  final $Res Function(ModuleError_MissingFunctions) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? moduleId = null,Object? functions = null,}) {
+  return _then(ModuleError_MissingFunctions(
+moduleId: null == moduleId ? _self.moduleId : moduleId // ignore: cast_nullable_to_non_nullable
+as String,functions: null == functions ? _self._functions : functions // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_Unsupported extends ModuleError {
+  const ModuleError_Unsupported({required this.moduleId, required this.funcName}): super._();
+  
+
+ final  String moduleId;
+ final  String funcName;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_UnsupportedCopyWith<ModuleError_Unsupported> get copyWith => _$ModuleError_UnsupportedCopyWithImpl<ModuleError_Unsupported>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_Unsupported&&(identical(other.moduleId, moduleId) || other.moduleId == moduleId)&&(identical(other.funcName, funcName) || other.funcName == funcName));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,moduleId,funcName);
+
+@override
+String toString() {
+  return 'ModuleError.unsupported(moduleId: $moduleId, funcName: $funcName)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_UnsupportedCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_UnsupportedCopyWith(ModuleError_Unsupported value, $Res Function(ModuleError_Unsupported) _then) = _$ModuleError_UnsupportedCopyWithImpl;
+@useResult
+$Res call({
+ String moduleId, String funcName
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_UnsupportedCopyWithImpl<$Res>
+    implements $ModuleError_UnsupportedCopyWith<$Res> {
+  _$ModuleError_UnsupportedCopyWithImpl(this._self, this._then);
+
+  final ModuleError_Unsupported _self;
+  final $Res Function(ModuleError_Unsupported) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? moduleId = null,Object? funcName = null,}) {
+  return _then(ModuleError_Unsupported(
+moduleId: null == moduleId ? _self.moduleId : moduleId // ignore: cast_nullable_to_non_nullable
+as String,funcName: null == funcName ? _self.funcName : funcName // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_Timeout extends ModuleError {
+  const ModuleError_Timeout({required this.funcName, required this.timeoutMs}): super._();
+  
+
+ final  String funcName;
+ final  BigInt timeoutMs;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_TimeoutCopyWith<ModuleError_Timeout> get copyWith => _$ModuleError_TimeoutCopyWithImpl<ModuleError_Timeout>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_Timeout&&(identical(other.funcName, funcName) || other.funcName == funcName)&&(identical(other.timeoutMs, timeoutMs) || other.timeoutMs == timeoutMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,funcName,timeoutMs);
+
+@override
+String toString() {
+  return 'ModuleError.timeout(funcName: $funcName, timeoutMs: $timeoutMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_TimeoutCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_TimeoutCopyWith(ModuleError_Timeout value, $Res Function(ModuleError_Timeout) _then) = _$ModuleError_TimeoutCopyWithImpl;
+@useResult
+$Res call({
+ String funcName, BigInt timeoutMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_TimeoutCopyWithImpl<$Res>
+    implements $ModuleError_TimeoutCopyWith<$Res> {
+  _$ModuleError_TimeoutCopyWithImpl(this._self, this._then);
+
+  final ModuleError_Timeout _self;
+  final $Res Function(ModuleError_Timeout) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? funcName = null,Object? timeoutMs = null,}) {
+  return _then(ModuleError_Timeout(
+funcName: null == funcName ? _self.funcName : funcName // ignore: cast_nullable_to_non_nullable
+as String,timeoutMs: null == timeoutMs ? _self.timeoutMs : timeoutMs // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_HttpError extends ModuleError {
+  const ModuleError_HttpError({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_HttpErrorCopyWith<ModuleError_HttpError> get copyWith => _$ModuleError_HttpErrorCopyWithImpl<ModuleError_HttpError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_HttpError&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'ModuleError.httpError(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_HttpErrorCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_HttpErrorCopyWith(ModuleError_HttpError value, $Res Function(ModuleError_HttpError) _then) = _$ModuleError_HttpErrorCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_HttpErrorCopyWithImpl<$Res>
+    implements $ModuleError_HttpErrorCopyWith<$Res> {
+  _$ModuleError_HttpErrorCopyWithImpl(this._self, this._then);
+
+  final ModuleError_HttpError _self;
+  final $Res Function(ModuleError_HttpError) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(ModuleError_HttpError(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_ParseError extends ModuleError {
+  const ModuleError_ParseError({required this.target, required this.message}): super._();
+  
+
+ final  String target;
+ final  String message;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_ParseErrorCopyWith<ModuleError_ParseError> get copyWith => _$ModuleError_ParseErrorCopyWithImpl<ModuleError_ParseError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_ParseError&&(identical(other.target, target) || other.target == target)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,target,message);
+
+@override
+String toString() {
+  return 'ModuleError.parseError(target: $target, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_ParseErrorCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_ParseErrorCopyWith(ModuleError_ParseError value, $Res Function(ModuleError_ParseError) _then) = _$ModuleError_ParseErrorCopyWithImpl;
+@useResult
+$Res call({
+ String target, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_ParseErrorCopyWithImpl<$Res>
+    implements $ModuleError_ParseErrorCopyWith<$Res> {
+  _$ModuleError_ParseErrorCopyWithImpl(this._self, this._then);
+
+  final ModuleError_ParseError _self;
+  final $Res Function(ModuleError_ParseError) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? target = null,Object? message = null,}) {
+  return _then(ModuleError_ParseError(
+target: null == target ? _self.target : target // ignore: cast_nullable_to_non_nullable
+as String,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleError_Cancelled extends ModuleError {
+  const ModuleError_Cancelled(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_Cancelled);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModuleError.cancelled()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ModuleError_Internal extends ModuleError {
+  const ModuleError_Internal({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleError_InternalCopyWith<ModuleError_Internal> get copyWith => _$ModuleError_InternalCopyWithImpl<ModuleError_Internal>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleError_Internal&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'ModuleError.internal(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleError_InternalCopyWith<$Res> implements $ModuleErrorCopyWith<$Res> {
+  factory $ModuleError_InternalCopyWith(ModuleError_Internal value, $Res Function(ModuleError_Internal) _then) = _$ModuleError_InternalCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleError_InternalCopyWithImpl<$Res>
+    implements $ModuleError_InternalCopyWith<$Res> {
+  _$ModuleError_InternalCopyWithImpl(this._self, this._then);
+
+  final ModuleError_Internal _self;
+  final $Res Function(ModuleError_Internal) _then;
+
+/// Create a copy of ModuleError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(ModuleError_Internal(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+// dart format on
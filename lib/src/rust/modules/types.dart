@@ -5,6 +5,65 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'types.freezed.dart';
+
+/// 多模块聚合搜索结果，单个模块失败不影响其他模块
+class AggregatedSearchResult {
+  final List<ModuleSearchPage> results;
+  final List<ModuleSearchError> errors;
+
+  const AggregatedSearchResult({required this.results, required this.errors});
+
+  @override
+  int get hashCode => results.hashCode ^ errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AggregatedSearchResult &&
+          runtimeType == other.runtimeType &&
+          results == other.results &&
+          errors == other.errors;
+}
+
+/// 批量调用中的一次函数调用
+class BatchCall {
+  final String funcName;
+  final String argsJson;
+
+  const BatchCall({required this.funcName, required this.argsJson});
+
+  @override
+  int get hashCode => funcName.hashCode ^ argsJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchCall &&
+          runtimeType == other.runtimeType &&
+          funcName == other.funcName &&
+          argsJson == other.argsJson;
+}
+
+/// 批量调用中单次调用的结果，result 和 error 只有一个有值
+class BatchCallResult {
+  final String? result;
+  final String? error;
+
+  const BatchCallResult({this.result, this.error});
+
+  @override
+  int get hashCode => result.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchCallResult &&
+          runtimeType == other.runtimeType &&
+          result == other.result &&
+          error == other.error;
+}
 
 /// 分类 (参考 pikapika Category)
 class Category {
@@ -154,6 +213,9 @@ class ComicSimple {
   final RemoteImageInfo thumb;
   final int likesCount;
 
+  /// 来源模块，聚合搜索时填充
+  final String? moduleId;
+
   const ComicSimple({
     required this.id,
     required this.title,
@@ -164,6 +226,7 @@ class ComicSimple {
     required this.categories,
     required this.thumb,
     required this.likesCount,
+    this.moduleId,
   });
 
   @override
@@ -176,7 +239,8 @@ class ComicSimple {
       finished.hashCode ^
       categories.hashCode ^
       thumb.hashCode ^
-      likesCount.hashCode;
+      likesCount.hashCode ^
+      moduleId.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -191,7 +255,8 @@ class ComicSimple {
           finished == other.finished &&
           categories == other.categories &&
           thumb == other.thumb &&
-          likesCount == other.likesCount;
+          likesCount == other.likesCount &&
+          moduleId == other.moduleId;
 }
 
 /// 漫画列表分页 (参考 pikapika ComicsPage)
@@ -213,6 +278,97 @@ class ComicsPage {
           docs == other.docs;
 }
 
+/// 评论 (参考 pikapika Comment)
+class Comment {
+  final String id;
+  final String author;
+  final RemoteImageInfo? avatar;
+  final String content;
+  final String createdAt;
+  final int likes;
+  final int replyCount;
+
+  const Comment({
+    required this.id,
+    required this.author,
+    this.avatar,
+    required this.content,
+    required this.createdAt,
+    required this.likes,
+    required this.replyCount,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      author.hashCode ^
+      avatar.hashCode ^
+      content.hashCode ^
+      createdAt.hashCode ^
+      likes.hashCode ^
+      replyCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Comment &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          author == other.author &&
+          avatar == other.avatar &&
+          content == other.content &&
+          createdAt == other.createdAt &&
+          likes == other.likes &&
+          replyCount == other.replyCount;
+}
+
+/// 评论分页
+class CommentPage {
+  final PageInfo pageInfo;
+  final List<Comment> docs;
+
+  const CommentPage({required this.pageInfo, required this.docs});
+
+  @override
+  int get hashCode => pageInfo.hashCode ^ docs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CommentPage &&
+          runtimeType == other.runtimeType &&
+          pageInfo == other.pageInfo &&
+          docs == other.docs;
+}
+
+/// 模块脚本输出的一行控制台日志
+class ConsoleLog {
+  /// log / warn / error / debug
+  final String level;
+  final String message;
+
+  /// 毫秒时间戳
+  final PlatformInt64 timestamp;
+
+  const ConsoleLog({
+    required this.level,
+    required this.message,
+    required this.timestamp,
+  });
+
+  @override
+  int get hashCode => level.hashCode ^ message.hashCode ^ timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConsoleLog &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          message == other.message &&
+          timestamp == other.timestamp;
+}
+
 /// 章节 (参考 pikapika Ep)
 class Ep {
   final String id;
@@ -261,6 +417,166 @@ class EpPage {
           docs == other.docs;
 }
 
+/// 模块函数的调用统计（仅保存在内存中，重启后清零）
+class FunctionMetrics {
+  final String moduleId;
+  final String function;
+
+  /// 调用次数
+  final BigInt calls;
+
+  /// 失败次数
+  final BigInt errors;
+
+  /// 累计耗时（毫秒）
+  final BigInt totalMs;
+
+  /// 平均耗时（毫秒）
+  final BigInt avgMs;
+
+  /// 最长耗时（毫秒）
+  final BigInt maxMs;
+
+  /// 最近一次失败的错误信息
+  final String? lastError;
+
+  const FunctionMetrics({
+    required this.moduleId,
+    required this.function,
+    required this.calls,
+    required this.errors,
+    required this.totalMs,
+    required this.avgMs,
+    required this.maxMs,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      moduleId.hashCode ^
+      function.hashCode ^
+      calls.hashCode ^
+      errors.hashCode ^
+      totalMs.hashCode ^
+      avgMs.hashCode ^
+      maxMs.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FunctionMetrics &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          function == other.function &&
+          calls == other.calls &&
+          errors == other.errors &&
+          totalMs == other.totalMs &&
+          avgMs == other.avgMs &&
+          maxMs == other.maxMs &&
+          lastError == other.lastError;
+}
+
+/// 模块登录结果
+class LoginResult {
+  final bool success;
+  final String? message;
+
+  const LoginResult({required this.success, this.message});
+
+  @override
+  int get hashCode => success.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoginResult &&
+          runtimeType == other.runtimeType &&
+          success == other.success &&
+          message == other.message;
+}
+
+/// 带控制台日志的函数调用结果
+class ModuleCallOutput {
+  /// 函数返回值（JSON），调用失败时为 None
+  final String? result;
+
+  /// 调用失败时的错误信息
+  final String? error;
+
+  /// JS 异常的调用栈
+  final String? stack;
+  final List<ConsoleLog> logs;
+
+  const ModuleCallOutput({
+    this.result,
+    this.error,
+    this.stack,
+    required this.logs,
+  });
+
+  @override
+  int get hashCode =>
+      result.hashCode ^ error.hashCode ^ stack.hashCode ^ logs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModuleCallOutput &&
+          runtimeType == other.runtimeType &&
+          result == other.result &&
+          error == other.error &&
+          stack == other.stack &&
+          logs == other.logs;
+}
+
+/// 模块支持的可选功能
+///
+/// 默认根据模块是否定义对应函数判断，模块导出的 `capabilities` 对象优先
+class ModuleCapabilities {
+  /// search
+  final bool search;
+
+  /// getComments
+  final bool comments;
+
+  /// login
+  final bool login;
+
+  /// getPictures，可通过 `capabilities.download = false` 禁止下载
+  final bool download;
+
+  /// processImage
+  final bool processImage;
+
+  const ModuleCapabilities({
+    required this.search,
+    required this.comments,
+    required this.login,
+    required this.download,
+    required this.processImage,
+  });
+
+  @override
+  int get hashCode =>
+      search.hashCode ^
+      comments.hashCode ^
+      login.hashCode ^
+      download.hashCode ^
+      processImage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModuleCapabilities &&
+          runtimeType == other.runtimeType &&
+          search == other.search &&
+          comments == other.comments &&
+          login == other.login &&
+          download == other.download &&
+          processImage == other.processImage;
+}
+
 /// 模块信息
 class ModuleInfo {
   final String id;
@@ -272,6 +588,12 @@ class ModuleInfo {
   final bool enabled;
   final String? sourceUrl;
 
+  /// 是否以沙箱模式运行
+  final bool sandbox;
+
+  /// 授予的权限（http / storage / crypto），安装时可提示用户
+  final List<String> permissions;
+
   const ModuleInfo({
     required this.id,
     required this.name,
@@ -281,6 +603,8 @@ class ModuleInfo {
     this.icon,
     required this.enabled,
     this.sourceUrl,
+    required this.sandbox,
+    required this.permissions,
   });
 
   @override
@@ -292,7 +616,9 @@ class ModuleInfo {
       description.hashCode ^
       icon.hashCode ^
       enabled.hashCode ^
-      sourceUrl.hashCode;
+      sourceUrl.hashCode ^
+      sandbox.hashCode ^
+      permissions.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -306,7 +632,139 @@ class ModuleInfo {
           description == other.description &&
           icon == other.icon &&
           enabled == other.enabled &&
-          sourceUrl == other.sourceUrl;
+          sourceUrl == other.sourceUrl &&
+          sandbox == other.sandbox &&
+          permissions == other.permissions;
+}
+
+/// 单个模块的预加载错误
+class ModuleLoadError {
+  final String moduleId;
+  final String message;
+
+  const ModuleLoadError({required this.moduleId, required this.message});
+
+  @override
+  int get hashCode => moduleId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModuleLoadError &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          message == other.message;
+}
+
+/// 模块预加载结果
+class ModulePreloadResult {
+  /// 本次加载成功的模块
+  final List<String> loaded;
+
+  /// 已经加载过、跳过的模块
+  final List<String> skipped;
+
+  /// 加载失败的模块（含已禁用的模块）
+  final List<ModuleLoadError> failed;
+
+  const ModulePreloadResult({
+    required this.loaded,
+    required this.skipped,
+    required this.failed,
+  });
+
+  @override
+  int get hashCode => loaded.hashCode ^ skipped.hashCode ^ failed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModulePreloadResult &&
+          runtimeType == other.runtimeType &&
+          loaded == other.loaded &&
+          skipped == other.skipped &&
+          failed == other.failed;
+}
+
+/// 单个模块的聚合搜索错误
+class ModuleSearchError {
+  final String moduleId;
+  final String message;
+
+  const ModuleSearchError({required this.moduleId, required this.message});
+
+  @override
+  int get hashCode => moduleId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModuleSearchError &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          message == other.message;
+}
+
+/// 单个模块的聚合搜索结果
+class ModuleSearchPage {
+  final String moduleId;
+  final ComicsPage page;
+
+  const ModuleSearchPage({required this.moduleId, required this.page});
+
+  @override
+  int get hashCode => moduleId.hashCode ^ page.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModuleSearchPage &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          page == other.page;
+}
+
+/// 可用的模块更新
+class ModuleUpdateInfo {
+  final String moduleId;
+  final String currentVersion;
+  final String latestVersion;
+
+  const ModuleUpdateInfo({
+    required this.moduleId,
+    required this.currentVersion,
+    required this.latestVersion,
+  });
+
+  @override
+  int get hashCode =>
+      moduleId.hashCode ^ currentVersion.hashCode ^ latestVersion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModuleUpdateInfo &&
+          runtimeType == other.runtimeType &&
+          moduleId == other.moduleId &&
+          currentVersion == other.currentVersion &&
+          latestVersion == other.latestVersion;
+}
+
+/// 模块更新结果
+@freezed
+sealed class ModuleUpdateResult with _$ModuleUpdateResult {
+  const ModuleUpdateResult._();
+
+  /// 已是最新版本
+  const factory ModuleUpdateResult.upToDate() = ModuleUpdateResult_UpToDate;
+  /// 已更新
+  const factory ModuleUpdateResult.updated({
+    required String from,
+    required String to,
+  }) = ModuleUpdateResult_Updated;
+  /// 更新失败（下载失败或远程脚本校验不通过），已保留原版本
+  const factory ModuleUpdateResult.failed({required String message}) =
+      ModuleUpdateResult_Failed;
 }
 
 /// 分页信息 (参考 pikapika Page)
@@ -437,3 +895,27 @@ class SortOption {
           value == other.value &&
           name == other.name;
 }
+
+/// 标签（题材等），与分类是不同的浏览维度
+class Tag {
+  /// 传给 getComicsByTag 的标识
+  final String id;
+  final String title;
+
+  /// 该标签下的漫画数量（可选，用于标签云）
+  final int? count;
+
+  const Tag({required this.id, required this.title, this.count});
+
+  @override
+  int get hashCode => id.hashCode ^ title.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Tag &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          count == other.count;
+}
@@ -0,0 +1,208 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'types.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ModuleUpdateResult {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleUpdateResult);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModuleUpdateResult()';
+}
+
+
+}
+
+/// @nodoc
+class $ModuleUpdateResultCopyWith<$Res>  {
+$ModuleUpdateResultCopyWith(ModuleUpdateResult _, $Res Function(ModuleUpdateResult) __);
+}
+
+
+/// @nodoc
+
+
+class ModuleUpdateResult_UpToDate extends ModuleUpdateResult {
+  const ModuleUpdateResult_UpToDate(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleUpdateResult_UpToDate);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModuleUpdateResult.upToDate()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ModuleUpdateResult_Updated extends ModuleUpdateResult {
+  const ModuleUpdateResult_Updated({required this.from, required this.to}): super._();
+  
+
+ final  String from;
+ final  String to;
+
+/// Create a copy of ModuleUpdateResult
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleUpdateResult_UpdatedCopyWith<ModuleUpdateResult_Updated> get copyWith => _$ModuleUpdateResult_UpdatedCopyWithImpl<ModuleUpdateResult_Updated>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleUpdateResult_Updated&&(identical(other.from, from) || other.from == from)&&(identical(other.to, to) || other.to == to));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,from,to);
+
+@override
+String toString() {
+  return 'ModuleUpdateResult.updated(from: $from, to: $to)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleUpdateResult_UpdatedCopyWith<$Res> implements $ModuleUpdateResultCopyWith<$Res> {
+  factory $ModuleUpdateResult_UpdatedCopyWith(ModuleUpdateResult_Updated value, $Res Function(ModuleUpdateResult_Updated) _then) = _$ModuleUpdateResult_UpdatedCopyWithImpl;
+@useResult
+$Res call({
+ String from, String to
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleUpdateResult_UpdatedCopyWithImpl<$Res>
+    implements $ModuleUpdateResult_UpdatedCopyWith<$Res> {
+  _$ModuleUpdateResult_UpdatedCopyWithImpl(this._self, this._then);
+
+  final ModuleUpdateResult_Updated _self;
+  final $Res Function(ModuleUpdateResult_Updated) _then;
+
+/// Create a copy of ModuleUpdateResult
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? from = null,Object? to = null,}) {
+  return _then(ModuleUpdateResult_Updated(
+from: null == from ? _self.from : from // ignore: cast_nullable_to_non_nullable
+as String,to: null == to ? _self.to : to // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModuleUpdateResult_Failed extends ModuleUpdateResult {
+  const ModuleUpdateResult_Failed({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of ModuleUpdateResult
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModuleUpdateResult_FailedCopyWith<ModuleUpdateResult_Failed> get copyWith => _$ModuleUpdateResult_FailedCopyWithImpl<ModuleUpdateResult_Failed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModuleUpdateResult_Failed&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'ModuleUpdateResult.failed(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModuleUpdateResult_FailedCopyWith<$Res> implements $ModuleUpdateResultCopyWith<$Res> {
+  factory $ModuleUpdateResult_FailedCopyWith(ModuleUpdateResult_Failed value, $Res Function(ModuleUpdateResult_Failed) _then) = _$ModuleUpdateResult_FailedCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModuleUpdateResult_FailedCopyWithImpl<$Res>
+    implements $ModuleUpdateResult_FailedCopyWith<$Res> {
+  _$ModuleUpdateResult_FailedCopyWithImpl(this._self, this._then);
+
+  final ModuleUpdateResult_Failed _self;
+  final $Res Function(ModuleUpdateResult_Failed) _then;
+
+/// Create a copy of ModuleUpdateResult
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(ModuleUpdateResult_Failed(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+// dart format on
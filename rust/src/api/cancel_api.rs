@@ -0,0 +1,12 @@
+use flutter_rust_bridge::frb;
+
+/// 取消使用该令牌的进行中操作，令牌不存在（未开始或已结束）时返回 false
+/// 
+/// 令牌由 Flutter 端生成（如 UUID），传给以下操作：
+/// - `get_pictures_cancelable`
+/// - `download_to_file`
+/// - `start_chapter_download`（状态记为 cancelled，已下载的图片保留，可续传）
+#[frb(sync)]
+pub fn cancel(token: String) -> bool {
+    crate::cancel::cancel(&token)
+}
@@ -57,3 +57,15 @@ pub fn crypto_md5_bytes(data: Vec<u8>) -> String {
 pub fn crypto_sha256_bytes(data: Vec<u8>) -> String {
     crypto::sha256_hash(&data)
 }
+
+/// CRC32 校验和（字节数组，返回 8 位十六进制）
+#[frb(sync)]
+pub fn crypto_crc32_bytes(data: Vec<u8>) -> String {
+    crypto::crc32_hex(&data)
+}
+
+/// Adler32 校验和（字节数组，返回 8 位十六进制）
+#[frb(sync)]
+pub fn crypto_adler32_bytes(data: Vec<u8>) -> String {
+    crypto::adler32_hex(&data)
+}
@@ -0,0 +1,57 @@
+use flutter_rust_bridge::frb;
+
+use crate::api::property_api;
+use crate::database;
+
+pub(crate) const DATABASE_ENCRYPTED_SETTING_KEY: &str = "database_encrypted";
+
+/// 数据库维护结果
+#[derive(Debug, Clone)]
+pub struct MaintenanceResult {
+    /// 维护前数据库文件大小（字节）
+    pub size_before: u64,
+    /// 维护后数据库文件大小（字节）
+    pub size_after: u64,
+}
+
+/// 执行数据库维护（VACUUM + ANALYZE），返回前后的文件大小
+/// 
+/// 维护期间所有数据库访问都会等待，建议在空闲时（如清理缓存后）调用
+#[frb]
+pub async fn run_maintenance() -> anyhow::Result<MaintenanceResult> {
+    let size_before = database::database_file_size().await?;
+    database::vacuum().await?;
+    let size_after = database::database_file_size().await?;
+    tracing::info!("[Database] Maintenance finished: {} -> {} bytes", size_before, size_after);
+    Ok(MaintenanceResult { size_before, size_after })
+}
+
+/// 记录数据库是否以加密方式打开（初始化时调用）
+pub(crate) async fn save_encryption_state(encrypted: bool) -> anyhow::Result<()> {
+    property_api::save_app_setting(DATABASE_ENCRYPTED_SETTING_KEY.to_string(), encrypted.to_string()).await
+}
+
+/// 数据库是否已启用 SQLCipher 加密
+#[frb]
+pub async fn is_database_encrypted() -> anyhow::Result<bool> {
+    let value = property_api::load_app_setting(DATABASE_ENCRYPTED_SETTING_KEY.to_string()).await?;
+    Ok(value.as_deref() == Some("true"))
+}
+
+/// 当前构建是否支持数据库加密
+#[frb(sync)]
+pub fn is_database_encryption_supported() -> bool {
+    cfg!(feature = "sqlcipher")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_maintenance() {
+        database::init_test_database().await;
+        let result = run_maintenance().await.unwrap();
+        assert!(result.size_after > 0);
+    }
+}
@@ -0,0 +1,504 @@
+use flutter_rust_bridge::frb;
+use sea_orm::{EntityTrait, QueryFilter, QueryOrder, ColumnTrait, Set};
+use sea_orm::sea_query::OnConflict;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::api::{module_api, property_api};
+use crate::cancel::{self, CancelToken};
+use crate::database::{self, entities::download};
+use crate::frb_generated::StreamSink;
+use crate::http::{HttpClient, HttpRequest};
+use crate::js_engine::bindings::http::DEFAULT_HEADERS_KEY;
+use crate::modules::{ModuleError, ModuleManager, Picture};
+
+const STATUS_DOWNLOADING: &str = "downloading";
+const STATUS_COMPLETED: &str = "completed";
+const STATUS_FAILED: &str = "failed";
+const STATUS_CANCELLED: &str = "cancelled";
+
+/// 下载进度
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    /// 已接收字节数
+    pub received: u64,
+    /// 总字节数（服务器未返回 Content-Length 时为 None）
+    pub total: Option<u64>,
+}
+
+/// 流式下载文件到指定路径，并通过 StreamSink 报告进度
+/// 
+/// 下载失败或通过 `cancel_api::cancel(cancel_token)` 取消时会清理未完成的文件
+#[frb]
+pub async fn download_to_file(
+    url: String,
+    headers: HashMap<String, String>,
+    dest_path: String,
+    cancel_token: Option<String>,
+    sink: StreamSink<DownloadProgress>,
+) -> anyhow::Result<()> {
+    let registered = cancel::register(cancel_token);
+    let dest = PathBuf::from(&dest_path);
+    if let Some(parent) = dest.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    
+    let client = HttpClient::shared()?;
+    let cancel = registered.as_ref().map(|r| r.token());
+    let received = client.download_to_file_cancelable(&url, headers, &dest, cancel, |received, total| {
+        // Flutter 端取消订阅后 add 会失败，忽略即可
+        let _ = sink.add(DownloadProgress { received, total });
+    }).await?;
+    
+    tracing::info!("[Download] {} -> {} ({} bytes)", url, dest_path, received);
+    Ok(())
+}
+
+/// 模块发起的下载请求：合并模块默认请求头（传入的同名请求头优先，不区分大小写），
+/// 并使用模块的 Cookie 和代理
+pub(crate) async fn module_download_request(
+    module_id: &str,
+    url: &str,
+    headers: HashMap<String, String>,
+) -> HttpRequest {
+    let defaults = match property_api::load_property(module_id.to_string(), DEFAULT_HEADERS_KEY.to_string()).await {
+        Ok(Some(raw)) => serde_json::from_str::<HashMap<String, String>>(&raw).unwrap_or_else(|e| {
+            tracing::warn!("[Download] Invalid default headers for module {}: {}", module_id, e);
+            HashMap::new()
+        }),
+        Ok(None) => HashMap::new(),
+        Err(e) => {
+            tracing::warn!("[Download] Failed to load default headers for module {}: {}", module_id, e);
+            HashMap::new()
+        }
+    };
+    
+    let mut merged: HashMap<String, String> = defaults
+        .into_iter()
+        .filter(|(key, _)| !headers.keys().any(|k| k.eq_ignore_ascii_case(key)))
+        .collect();
+    merged.extend(headers);
+    
+    HttpRequest {
+        module_id: Some(module_id.to_string()),
+        enable_cookies: true,
+        ..HttpClient::download_request(url, merged)
+    }
+}
+
+/// 章节下载记录
+#[derive(Debug, Clone)]
+pub struct ChapterDownload {
+    pub module_id: String,
+    pub comic_id: String,
+    pub ep_id: String,
+    /// downloading / completed / failed / cancelled
+    pub status: String,
+    pub total_pages: i32,
+    pub downloaded_pages: i32,
+    /// 章节图片保存目录，图片按 0001.jpg、0002.jpg... 顺序命名
+    pub dir_path: String,
+}
+
+impl From<download::Model> for ChapterDownload {
+    fn from(model: download::Model) -> Self {
+        Self {
+            module_id: model.module_id,
+            comic_id: model.comic_id,
+            ep_id: model.ep_id,
+            status: model.status,
+            total_pages: model.total_pages,
+            downloaded_pages: model.downloaded_pages,
+            dir_path: model.dir_path,
+        }
+    }
+}
+
+/// 保存下载记录（存在则更新，保留创建时间）
+async fn save_download(record: &ChapterDownload) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let now = Utc::now().naive_utc();
+    let active_model = download::ActiveModel {
+        module_id: Set(record.module_id.clone()),
+        comic_id: Set(record.comic_id.clone()),
+        ep_id: Set(record.ep_id.clone()),
+        status: Set(record.status.clone()),
+        total_pages: Set(record.total_pages),
+        downloaded_pages: Set(record.downloaded_pages),
+        dir_path: Set(record.dir_path.clone()),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    download::Entity::insert(active_model)
+        .on_conflict(
+            OnConflict::columns([
+                download::Column::ModuleId,
+                download::Column::ComicId,
+                download::Column::EpId,
+            ])
+            .update_columns([
+                download::Column::Status,
+                download::Column::TotalPages,
+                download::Column::DownloadedPages,
+                download::Column::DirPath,
+                download::Column::UpdatedAt,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(&*conn)
+        .await?;
+    
+    Ok(())
+}
+
+/// 章节下载目录：cache/downloads/{module_id}/{md5(comic_id)}/{md5(ep_id)}
+fn chapter_dir(module_id: &str, comic_id: &str, ep_id: &str) -> anyhow::Result<PathBuf> {
+    let cache_dir = crate::get_cache_dir()
+        .ok_or_else(|| anyhow::anyhow!("Cache dir not initialized"))?;
+    Ok(cache_dir
+        .join("downloads")
+        .join(module_id)
+        .join(format!("{:x}", md5::compute(comic_id)))
+        .join(format!("{:x}", md5::compute(ep_id))))
+}
+
+/// 从图片 URL 推断扩展名，无法识别时使用 jpg
+pub(crate) fn image_extension(url: &str) -> &str {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    match path.rsplit_once('.') {
+        Some((_, ext)) if !ext.is_empty() && ext.len() <= 4 && !ext.contains('/')
+            && ext.chars().all(|c| c.is_ascii_alphanumeric()) => ext,
+        _ => "jpg",
+    }
+}
+
+/// 通过模块 getPictures 获取章节的全部图片
+async fn fetch_all_pictures(
+    manager: &ModuleManager,
+    module_id: &str,
+    comic_id: &str,
+    ep_id: &str,
+    cancel: Option<&CancelToken>,
+) -> anyhow::Result<Vec<Picture>> {
+    let mut pictures = Vec::new();
+    let mut page = 1;
+    loop {
+        let picture_page = module_api::get_pictures_with_cancel(manager, module_id, comic_id, ep_id, page, cancel).await?;
+        let is_empty = picture_page.docs.is_empty();
+        pictures.extend(picture_page.docs);
+        if is_empty || page >= picture_page.page_info.pages {
+            break;
+        }
+        page += 1;
+    }
+    Ok(pictures)
+}
+
+/// 下载整个章节到缓存目录，并通过 StreamSink 报告每页的进度
+/// 
+/// 已存在的图片文件会被跳过，因此失败或取消后重新调用即可续传
+#[frb]
+pub async fn start_chapter_download(
+    module_id: String,
+    comic_id: String,
+    ep_id: String,
+    cancel_token: Option<String>,
+    sink: StreamSink<ChapterDownload>,
+) -> anyhow::Result<()> {
+    let manager = module_api::get_module_manager()?;
+    let registered = cancel::register(cancel_token);
+    let dir = chapter_dir(&module_id, &comic_id, &ep_id)?;
+    tokio::fs::create_dir_all(&dir).await?;
+    
+    let mut record = ChapterDownload {
+        module_id: module_id.clone(),
+        comic_id: comic_id.clone(),
+        ep_id: ep_id.clone(),
+        status: STATUS_DOWNLOADING.to_string(),
+        total_pages: 0,
+        downloaded_pages: 0,
+        dir_path: dir.to_string_lossy().to_string(),
+    };
+    save_download(&record).await?;
+    
+    // Flutter 端取消订阅后 add 会失败，忽略即可
+    let on_progress = |record: &ChapterDownload| {
+        let _ = sink.add(record.clone());
+    };
+    let result = download_pictures(manager, &mut record, &dir, registered.as_ref().map(|r| r.token()), &on_progress).await;
+    record.status = match &result {
+        Ok(()) => STATUS_COMPLETED,
+        Err(e) if is_cancelled(e) => STATUS_CANCELLED,
+        Err(_) => STATUS_FAILED,
+    }.to_string();
+    save_download(&record).await?;
+    let _ = sink.add(record.clone());
+    
+    match &result {
+        Ok(()) => tracing::info!("[Download] Chapter {}/{}/{} completed ({} pages)", module_id, comic_id, ep_id, record.total_pages),
+        Err(e) => tracing::warn!("[Download] Chapter {}/{}/{} failed: {}", module_id, comic_id, ep_id, e),
+    }
+    result
+}
+
+/// 错误是否由取消引起（HTTP 层的 Cancelled 或模块调用返回的 ModuleError::Cancelled）
+fn is_cancelled(error: &anyhow::Error) -> bool {
+    cancel::is_cancelled_error(error)
+        || matches!(error.downcast_ref::<ModuleError>(), Some(ModuleError::Cancelled))
+}
+
+/// 逐页下载章节图片，每页完成后更新下载记录并回调进度
+async fn download_pictures(
+    manager: &ModuleManager,
+    record: &mut ChapterDownload,
+    dir: &Path,
+    cancel: Option<&CancelToken>,
+    on_progress: &(dyn Fn(&ChapterDownload) + Send + Sync),
+) -> anyhow::Result<()> {
+    let pictures = fetch_all_pictures(manager, &record.module_id, &record.comic_id, &record.ep_id, cancel).await?;
+    record.total_pages = pictures.len() as i32;
+    save_download(record).await?;
+    on_progress(record);
+    
+    let client = HttpClient::shared()?;
+    for (index, picture) in pictures.into_iter().enumerate() {
+        let url = picture.media.to_url();
+        let dest = dir.join(format!("{:04}.{}", index + 1, image_extension(&url)));
+        if tokio::fs::metadata(&dest).await.is_err() {
+            let request = module_download_request(&record.module_id, &url, picture.media.headers).await;
+            client.download_to_file_with(request, &dest, cancel, |_, _| {}).await?;
+        }
+        
+        record.downloaded_pages = index as i32 + 1;
+        save_download(record).await?;
+        on_progress(record);
+    }
+    
+    Ok(())
+}
+
+/// 获取章节下载状态
+#[frb]
+pub async fn get_download_status(module_id: String, comic_id: String, ep_id: String) -> anyhow::Result<Option<ChapterDownload>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let record = download::Entity::find_by_id((module_id, comic_id, ep_id))
+        .one(&*conn)
+        .await?;
+    
+    Ok(record.map(ChapterDownload::from))
+}
+
+/// 列出下载记录，可按模块过滤，按更新时间倒序
+#[frb]
+pub async fn list_downloads(module_id: Option<String>) -> anyhow::Result<Vec<ChapterDownload>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let mut query = download::Entity::find();
+    if let Some(module_id) = module_id {
+        query = query.filter(download::Column::ModuleId.eq(module_id));
+    }
+    
+    let records = query
+        .order_by_desc(download::Column::UpdatedAt)
+        .all(&*conn)
+        .await?;
+    
+    Ok(records.into_iter().map(ChapterDownload::from).collect())
+}
+
+/// 删除章节下载记录及已下载的文件
+#[frb]
+pub async fn delete_download(module_id: String, comic_id: String, ep_id: String) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let id = (module_id, comic_id, ep_id);
+    
+    if let Some(record) = download::Entity::find_by_id(id.clone()).one(&*conn).await? {
+        if let Err(e) = tokio::fs::remove_dir_all(&record.dir_path).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e.into());
+            }
+        }
+        download::Entity::delete_by_id(id)
+            .exec(&*conn)
+            .await?;
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{TestResponse, TestServer};
+
+    #[test]
+    fn test_image_extension() {
+        assert_eq!(image_extension("https://example.com/a/001.webp?token=1"), "webp");
+        assert_eq!(image_extension("https://example.com/a/001.PNG"), "PNG");
+        assert_eq!(image_extension("https://example.com/image"), "jpg");
+        assert_eq!(image_extension("https://example.com/v1.2/image"), "jpg");
+    }
+
+    #[tokio::test]
+    async fn test_download_record_lifecycle() {
+        database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-download-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0001.jpg"), b"jpg").unwrap();
+
+        let record = ChapterDownload {
+            module_id: "test_download".to_string(),
+            comic_id: "comic".to_string(),
+            ep_id: "ep".to_string(),
+            status: STATUS_COMPLETED.to_string(),
+            total_pages: 1,
+            downloaded_pages: 1,
+            dir_path: dir.to_string_lossy().to_string(),
+        };
+        save_download(&record).await.unwrap();
+
+        let status = get_download_status("test_download".to_string(), "comic".to_string(), "ep".to_string())
+            .await.unwrap().unwrap();
+        assert_eq!(status.status, STATUS_COMPLETED);
+        assert_eq!(list_downloads(Some("test_download".to_string())).await.unwrap().len(), 1);
+
+        delete_download("test_download".to_string(), "comic".to_string(), "ep".to_string()).await.unwrap();
+        assert!(!dir.exists());
+        assert!(list_downloads(Some("test_download".to_string())).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_ids_do_not_collide() {
+        database::init_test_database().await;
+        let module_id = "test_download_ids".to_string();
+        let record = |comic_id: &str, ep_id: &str, dir_path: &str| ChapterDownload {
+            module_id: module_id.clone(),
+            comic_id: comic_id.to_string(),
+            ep_id: ep_id.to_string(),
+            status: STATUS_COMPLETED.to_string(),
+            total_pages: 1,
+            downloaded_pages: 1,
+            dir_path: dir_path.to_string(),
+        };
+
+        // 旧的 ':' 拼接方式下两者的 id 相同
+        save_download(&record("a:b", "c", "/tmp/first")).await.unwrap();
+        save_download(&record("a", "b:c", "/tmp/second")).await.unwrap();
+        assert_eq!(list_downloads(Some(module_id.clone())).await.unwrap().len(), 2);
+        let first = get_download_status(module_id.clone(), "a:b".to_string(), "c".to_string()).await.unwrap().unwrap();
+        assert_eq!(first.dir_path, "/tmp/first");
+        let second = get_download_status(module_id.clone(), "a".to_string(), "b:c".to_string()).await.unwrap().unwrap();
+        assert_eq!(second.dir_path, "/tmp/second");
+
+        delete_download(module_id.clone(), "a".to_string(), "b:c".to_string()).await.unwrap();
+        assert!(get_download_status(module_id.clone(), "a:b".to_string(), "c".to_string()).await.unwrap().is_some());
+        assert!(get_download_status(module_id, "a".to_string(), "b:c".to_string()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_module_download_request_uses_module_state() {
+        database::init_test_database().await;
+        let server = TestServer::start(|req| match req.header("cookie") {
+            Some("sid=abc") => TestResponse::ok("image"),
+            _ => TestResponse::status(403),
+        });
+        let module_id = "test_module_download_request";
+        property_api::save_property(
+            module_id.to_string(),
+            DEFAULT_HEADERS_KEY.to_string(),
+            r#"{"Referer": "https://example.com/", "X-Token": "default"}"#.to_string(),
+        ).await.unwrap();
+        let url = reqwest::Url::parse(&server.url("/")).unwrap();
+        crate::http::CookieManager::instance()
+            .store(module_id, &url, vec!["sid=abc".to_string()])
+            .await
+            .unwrap();
+
+        let mut headers = HashMap::new();
+        headers.insert("x-token".to_string(), "explicit".to_string());
+        let request = module_download_request(module_id, &server.url("/1.jpg"), headers).await;
+        let bytes = HttpClient::shared().unwrap().download_with(request).await.unwrap();
+        assert_eq!(bytes, b"image");
+
+        // 模块默认请求头被合并，传入的同名请求头优先
+        let sent = server.last_request().unwrap();
+        assert_eq!(sent.header("referer"), Some("https://example.com/"));
+        assert_eq!(sent.header("x-token"), Some("explicit"));
+
+        crate::http::CookieManager::instance().clear(module_id).await.unwrap();
+        property_api::delete_property(module_id.to_string(), DEFAULT_HEADERS_KEY.to_string()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_pictures_from_module() {
+        database::init_test_database().await;
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/1.png" => TestResponse::ok("page-1"),
+            "/2.webp" => TestResponse::ok("page-2"),
+            _ => TestResponse::status(404),
+        });
+
+        let modules_dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&modules_dir).unwrap();
+        let stubs: String = crate::js_engine::module_loader::REQUIRED_FUNCTIONS.iter()
+            .filter(|func| **func != "getPictures")
+            .map(|func| format!("async function {}() {{ return {{}}; }}\n", func))
+            .collect();
+        // 每页一张图片，共两页
+        let script = format!(r#"
+            const moduleInfo = {{ id: "download_module", name: "Download", version: "1.0.0" }};
+            {}
+            async function getPictures(args) {{
+                const path = args.page === 1 ? "{}" : "{}";
+                return {{
+                    total: 2, limit: 1, page: args.page, pages: 2,
+                    docs: [{{ id: String(args.page), media: {{ original_name: "", path: path, file_server: "" }} }}]
+                }};
+            }}
+        "#, stubs, server.url("/1.png"), server.url("/2.webp"));
+        std::fs::write(modules_dir.join("download_module.js"), script).unwrap();
+        let manager = ModuleManager::new(&modules_dir);
+        manager.register_module("download_module").await.unwrap();
+
+        let dir = std::env::temp_dir().join(format!("comics-download-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut record = ChapterDownload {
+            module_id: "download_module".to_string(),
+            comic_id: "comic".to_string(),
+            ep_id: "ep".to_string(),
+            status: STATUS_DOWNLOADING.to_string(),
+            total_pages: 0,
+            downloaded_pages: 0,
+            dir_path: dir.to_string_lossy().to_string(),
+        };
+        let progress = std::sync::Mutex::new(Vec::new());
+        let on_progress = |r: &ChapterDownload| progress.lock().unwrap().push(r.downloaded_pages);
+        download_pictures(&manager, &mut record, &dir, None, &on_progress).await.unwrap();
+
+        assert_eq!(std::fs::read(dir.join("0001.png")).unwrap(), b"page-1");
+        assert_eq!(std::fs::read(dir.join("0002.webp")).unwrap(), b"page-2");
+        assert_eq!(*progress.lock().unwrap(), vec![0, 1, 2]);
+        let saved = get_download_status("download_module".to_string(), "comic".to_string(), "ep".to_string())
+            .await.unwrap().unwrap();
+        assert_eq!((saved.total_pages, saved.downloaded_pages), (2, 2));
+
+        // 已下载的图片在续传时跳过
+        let before = server.requests().len();
+        std::fs::remove_file(dir.join("0002.webp")).unwrap();
+        download_pictures(&manager, &mut record, &dir, None, &|_| {}).await.unwrap();
+        assert_eq!(server.requests().len(), before + 1);
+        assert_eq!(server.last_request().unwrap().path, "/2.webp");
+    }
+}
@@ -0,0 +1,233 @@
+use flutter_rust_bridge::frb;
+use sea_orm::{EntityTrait, QueryFilter, QueryOrder, QuerySelect, ColumnTrait, ActiveModelTrait, PaginatorTrait, Set};
+use chrono::{Utc, Duration};
+
+use crate::database::{self, entities::reading_history};
+use crate::modules::PageInfo;
+
+/// 阅读历史条目
+#[derive(Debug, Clone)]
+pub struct ReadingHistory {
+    pub module_id: String,
+    pub comic_id: String,
+    pub ep_id: String,
+    pub page_index: i32,
+    pub title: Option<String>,
+    pub thumb_url: Option<String>,
+    /// 最近阅读时间（毫秒时间戳）
+    pub updated_at: i64,
+}
+
+impl From<reading_history::Model> for ReadingHistory {
+    fn from(model: reading_history::Model) -> Self {
+        Self {
+            module_id: model.module_id,
+            comic_id: model.comic_id,
+            ep_id: model.ep_id,
+            page_index: model.page_index,
+            title: model.title,
+            thumb_url: model.thumb_url,
+            updated_at: model.updated_at.and_utc().timestamp_millis(),
+        }
+    }
+}
+
+/// 上次阅读位置
+#[derive(Debug, Clone)]
+pub struct ReadPosition {
+    pub ep_id: String,
+    pub page_index: i32,
+}
+
+/// 记录阅读进度（每部漫画只保留最近一次阅读的章节和页码）
+#[frb]
+pub async fn record_read(module_id: String, comic_id: String, ep_id: String, page: i32) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let now = Utc::now().naive_utc();
+    let id = reading_history::Model::create_id(&module_id, &comic_id);
+    
+    let existing = reading_history::Entity::find_by_id(&id)
+        .one(&*conn)
+        .await?;
+    
+    if existing.is_some() {
+        // 更新，保留已有的标题和封面
+        let active_model = reading_history::ActiveModel {
+            id: Set(id),
+            ep_id: Set(ep_id),
+            page_index: Set(page),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        active_model.update(&*conn).await?;
+    } else {
+        let active_model = reading_history::ActiveModel {
+            id: Set(id),
+            module_id: Set(module_id),
+            comic_id: Set(comic_id),
+            ep_id: Set(ep_id),
+            page_index: Set(page),
+            title: Set(None),
+            thumb_url: Set(None),
+            updated_at: Set(now),
+        };
+        active_model.insert(&*conn).await?;
+    }
+    
+    Ok(())
+}
+
+/// 更新历史记录中的漫画标题和封面（用于历史列表展示）
+#[frb]
+pub async fn update_history_info(
+    module_id: String,
+    comic_id: String,
+    title: Option<String>,
+    thumb_url: Option<String>,
+) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let id = reading_history::Model::create_id(&module_id, &comic_id);
+    
+    if reading_history::Entity::find_by_id(&id).one(&*conn).await?.is_none() {
+        return Ok(());
+    }
+    
+    let active_model = reading_history::ActiveModel {
+        id: Set(id),
+        title: Set(title),
+        thumb_url: Set(thumb_url),
+        ..Default::default()
+    };
+    active_model.update(&*conn).await?;
+    
+    Ok(())
+}
+
+/// 分页获取阅读历史，按最近阅读时间倒序（page 从 1 开始）
+#[frb]
+pub async fn get_history(page: u32, limit: u32) -> anyhow::Result<Vec<ReadingHistory>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let offset = page.saturating_sub(1) as u64 * limit as u64;
+    
+    let records = reading_history::Entity::find()
+        .order_by_desc(reading_history::Column::UpdatedAt)
+        .offset(offset)
+        .limit(limit as u64)
+        .all(&*conn)
+        .await?;
+    
+    Ok(records.into_iter().map(ReadingHistory::from).collect())
+}
+
+/// 阅读历史分页结果
+#[derive(Debug, Clone)]
+pub struct HistoryPage {
+    pub page_info: PageInfo,
+    pub docs: Vec<ReadingHistory>,
+}
+
+/// 分页获取阅读历史（附带总数），按最近阅读时间倒序，page 从 1 开始
+#[frb]
+pub async fn get_history_paged(page: i32, limit: i32) -> anyhow::Result<HistoryPage> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let page = page.max(1);
+    let limit = limit.max(1);
+    
+    let paginator = reading_history::Entity::find()
+        .order_by_desc(reading_history::Column::UpdatedAt)
+        .paginate(&*conn, limit as u64);
+    let total = paginator.num_items().await?;
+    let records = paginator.fetch_page(page as u64 - 1).await?;
+    
+    Ok(HistoryPage {
+        page_info: PageInfo::new(page, limit, total as i32),
+        docs: records.into_iter().map(ReadingHistory::from).collect(),
+    })
+}
+
+/// 获取某部漫画上次阅读的位置
+#[frb]
+pub async fn get_last_position(module_id: String, comic_id: String) -> anyhow::Result<Option<ReadPosition>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let id = reading_history::Model::create_id(&module_id, &comic_id);
+    
+    let record = reading_history::Entity::find_by_id(&id)
+        .one(&*conn)
+        .await?;
+    
+    Ok(record.map(|r| ReadPosition {
+        ep_id: r.ep_id,
+        page_index: r.page_index,
+    }))
+}
+
+/// 清除指定天数之前的阅读历史，返回删除的条数
+#[frb]
+pub async fn clear_history_before(days: i64) -> anyhow::Result<u64> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    // 天数超出时间范围时返回错误而不是 panic
+    let before = Duration::try_days(days)
+        .and_then(|d| Utc::now().naive_utc().checked_sub_signed(d))
+        .ok_or_else(|| anyhow::anyhow!("days is out of range: {}", days))?;
+    
+    let conn = db.read().await;
+    let result = reading_history::Entity::delete_many()
+        .filter(reading_history::Column::UpdatedAt.lt(before))
+        .exec(&*conn)
+        .await?;
+    
+    Ok(result.rows_affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reading_history_upsert() {
+        database::init_test_database().await;
+        let module_id = "test_history".to_string();
+        let comic_id = "comic-1".to_string();
+
+        record_read(module_id.clone(), comic_id.clone(), "ep-1".to_string(), 3).await.unwrap();
+        update_history_info(module_id.clone(), comic_id.clone(), Some("Title".to_string()), None).await.unwrap();
+        record_read(module_id.clone(), comic_id.clone(), "ep-2".to_string(), 7).await.unwrap();
+
+        let position = get_last_position(module_id.clone(), comic_id.clone()).await.unwrap().unwrap();
+        assert_eq!(position.ep_id, "ep-2");
+        assert_eq!(position.page_index, 7);
+
+        let history: Vec<_> = get_history(1, 100).await.unwrap()
+            .into_iter()
+            .filter(|h| h.module_id == module_id)
+            .collect();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].title.as_deref(), Some("Title"));
+
+        clear_history_before(1).await.unwrap();
+        assert!(get_last_position(module_id.clone(), comic_id.clone()).await.unwrap().is_some());
+        clear_history_before(-1).await.unwrap();
+        assert!(get_last_position(module_id, comic_id).await.unwrap().is_none());
+
+        assert!(clear_history_before(i64::MAX).await.is_err());
+        assert!(clear_history_before(i64::MIN).await.is_err());
+        assert!(clear_history_before(200_000_000).await.is_err());
+    }
+}
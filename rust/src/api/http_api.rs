@@ -1,12 +1,21 @@
 use flutter_rust_bridge::frb;
 use std::collections::HashMap;
 
-use crate::http::{HttpClient, HttpRequest, HttpResponse};
+use crate::http::{CookieManager, HttpClient, HttpDefaults, HttpPoolSettings, HttpRequest, HttpResponse, MultipartPart};
+use crate::api::property_api;
+
+pub(crate) const TLS_INSECURE_SETTING_KEY: &str = "http_tls_insecure";
+const TIMEOUT_SETTING_KEY: &str = "http_timeout_secs";
+const USER_AGENT_SETTING_KEY: &str = "http_user_agent";
+const DNS_OVERRIDES_SETTING_KEY: &str = "http_dns_overrides";
+const POOL_MAX_IDLE_SETTING_KEY: &str = "http_pool_max_idle_per_host";
+const POOL_IDLE_TIMEOUT_SETTING_KEY: &str = "http_pool_idle_timeout_secs";
+const CONNECT_TIMEOUT_SETTING_KEY: &str = "http_connect_timeout_secs";
 
 /// 发送 HTTP GET 请求
 #[frb]
 pub async fn http_get(url: String, headers: HashMap<String, String>) -> anyhow::Result<HttpResponseDto> {
-    let client = HttpClient::new()?;
+    let client = HttpClient::shared()?;
     let response = client.get(&url, headers).await?;
     Ok(response.into())
 }
@@ -14,11 +23,23 @@ pub async fn http_get(url: String, headers: HashMap<String, String>) -> anyhow::
 /// 发送 HTTP POST 请求
 #[frb]
 pub async fn http_post(url: String, headers: HashMap<String, String>, body: Option<String>) -> anyhow::Result<HttpResponseDto> {
-    let client = HttpClient::new()?;
+    let client = HttpClient::shared()?;
     let response = client.post(&url, headers, body).await?;
     Ok(response.into())
 }
 
+/// 发送 multipart/form-data POST 请求
+#[frb]
+pub async fn http_post_multipart(
+    url: String,
+    headers: HashMap<String, String>,
+    parts: Vec<MultipartPart>,
+) -> anyhow::Result<HttpResponseDto> {
+    let client = HttpClient::shared()?;
+    let response = client.post_multipart(&url, headers, parts).await?;
+    Ok(response.into())
+}
+
 /// 发送自定义 HTTP 请求
 #[frb]
 pub async fn http_request(
@@ -28,13 +49,14 @@ pub async fn http_request(
     body: Option<String>,
     timeout_secs: u64,
 ) -> anyhow::Result<HttpResponseDto> {
-    let client = HttpClient::new()?;
+    let client = HttpClient::shared()?;
     let request = HttpRequest {
         url,
         method,
         headers,
         body,
         timeout_secs,
+        ..Default::default()
     };
     let response = client.request(request).await?;
     Ok(response.into())
@@ -43,10 +65,16 @@ pub async fn http_request(
 /// 下载文件
 #[frb]
 pub async fn http_download(url: String, headers: HashMap<String, String>) -> anyhow::Result<Vec<u8>> {
-    let client = HttpClient::new()?;
+    let client = HttpClient::shared()?;
     client.download(&url, headers).await
 }
 
+/// 清除模块的所有 Cookie
+#[frb]
+pub async fn clear_module_cookies(module_id: String) -> anyhow::Result<()> {
+    CookieManager::instance().clear(&module_id).await
+}
+
 /// HTTP 响应 DTO（用于 Flutter）
 #[derive(Debug, Clone)]
 pub struct HttpResponseDto {
@@ -54,6 +82,9 @@ pub struct HttpResponseDto {
     pub headers: HashMap<String, String>,
     pub body: String,
     pub content_type: String,
+    pub final_url: String,
+    pub elapsed_ms: u64,
+    pub body_size: usize,
 }
 
 impl From<HttpResponse> for HttpResponseDto {
@@ -63,6 +94,211 @@ impl From<HttpResponse> for HttpResponseDto {
             headers: resp.headers,
             body: resp.body,
             content_type: resp.content_type,
+            final_url: resp.final_url,
+            elapsed_ms: resp.elapsed_ms,
+            body_size: resp.body_size,
+        }
+    }
+}
+
+/// 设置是否全局跳过 TLS 证书校验
+/// 
+/// 默认校验证书。通过分流 IP 访问时优先使用 `set_dns_override`，仅在证书本身无法匹配时开启。
+#[frb]
+pub async fn set_tls_insecure(insecure: bool) -> anyhow::Result<()> {
+    HttpClient::set_default_accept_invalid_certs(insecure);
+    property_api::save_app_setting(TLS_INSECURE_SETTING_KEY.to_string(), insecure.to_string()).await?;
+    tracing::info!("TLS 证书校验设置已保存: insecure={}", insecure);
+    Ok(())
+}
+
+/// 获取是否全局跳过 TLS 证书校验
+#[frb(sync)]
+pub fn get_tls_insecure() -> bool {
+    HttpClient::default_accept_invalid_certs()
+}
+
+/// 设置是否输出完整的 HTTP 请求/响应日志（调试模块用，默认关闭，不持久化）
+/// 
+/// 开启后通过 tracing 记录请求的 URL、方法、请求头以及响应状态和耗时，
+/// Authorization、Cookie 和包含 token 的请求头会被隐藏
+#[frb(sync)]
+pub fn set_http_logging(enabled: bool) {
+    HttpClient::set_logging(enabled);
+    tracing::info!("HTTP 请求日志: {}", if enabled { "开启" } else { "关闭" });
+}
+
+/// 获取是否输出完整的 HTTP 请求/响应日志
+#[frb(sync)]
+pub fn get_http_logging() -> bool {
+    HttpClient::logging_enabled()
+}
+
+/// 设置全局默认超时和 User-Agent
+/// 
+/// # 参数
+/// - `timeout_secs`: 未指定超时的请求使用的超时（秒），为空或 0 恢复默认 30 秒
+/// - `user_agent`: 请求未设置 User-Agent 时使用的值，为空恢复默认的浏览器 UA
+#[frb]
+pub async fn set_http_defaults(timeout_secs: Option<u64>, user_agent: Option<String>) -> anyhow::Result<()> {
+    let timeout_secs = timeout_secs.filter(|t| *t > 0);
+    let user_agent = user_agent.as_ref()
+        .map(|s| s.trim())
+        .and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+    
+    let defaults = HttpDefaults::default();
+    HttpClient::set_defaults(HttpDefaults {
+        timeout_secs: timeout_secs.unwrap_or(defaults.timeout_secs),
+        user_agent: user_agent.clone().unwrap_or(defaults.user_agent),
+    });
+    
+    if let Some(timeout) = timeout_secs {
+        property_api::save_app_setting(TIMEOUT_SETTING_KEY.to_string(), timeout.to_string()).await?;
+    } else {
+        property_api::delete_app_setting(TIMEOUT_SETTING_KEY.to_string()).await?;
+    }
+    if let Some(ua) = &user_agent {
+        property_api::save_app_setting(USER_AGENT_SETTING_KEY.to_string(), ua.clone()).await?;
+    } else {
+        property_api::delete_app_setting(USER_AGENT_SETTING_KEY.to_string()).await?;
+    }
+    
+    tracing::info!("HTTP 默认设置已保存: timeout={:?}, user_agent={:?}", timeout_secs, user_agent);
+    Ok(())
+}
+
+/// 获取全局默认超时和 User-Agent
+#[frb(sync)]
+pub fn get_http_defaults() -> HttpDefaults {
+    HttpClient::defaults()
+}
+
+/// 设置连接池和连接超时，保存后重建共享客户端
+/// 
+/// # 参数
+/// - `pool_max_idle_per_host`: 每个主机保留的最大空闲连接数（0–64），为空恢复默认 10
+/// - `pool_idle_timeout_secs`: 空闲连接的保持时间（5–300 秒），为空恢复默认 90 秒
+/// - `connect_timeout_secs`: 建立连接的超时（1–60 秒），为空恢复默认 10 秒
+/// 
+/// 超出范围的值会被截断
+#[frb]
+pub async fn set_http_pool_settings(
+    pool_max_idle_per_host: Option<u32>,
+    pool_idle_timeout_secs: Option<u64>,
+    connect_timeout_secs: Option<u64>,
+) -> anyhow::Result<()> {
+    let defaults = HttpPoolSettings::default();
+    HttpClient::set_pool_settings(HttpPoolSettings {
+        pool_max_idle_per_host: pool_max_idle_per_host.unwrap_or(defaults.pool_max_idle_per_host),
+        pool_idle_timeout_secs: pool_idle_timeout_secs.unwrap_or(defaults.pool_idle_timeout_secs),
+        connect_timeout_secs: connect_timeout_secs.unwrap_or(defaults.connect_timeout_secs),
+    });
+    
+    let settings = HttpClient::pool_settings();
+    let values = [
+        (POOL_MAX_IDLE_SETTING_KEY, pool_max_idle_per_host.map(|_| settings.pool_max_idle_per_host as u64)),
+        (POOL_IDLE_TIMEOUT_SETTING_KEY, pool_idle_timeout_secs.map(|_| settings.pool_idle_timeout_secs)),
+        (CONNECT_TIMEOUT_SETTING_KEY, connect_timeout_secs.map(|_| settings.connect_timeout_secs)),
+    ];
+    for (key, value) in values {
+        match value {
+            Some(value) => property_api::save_app_setting(key.to_string(), value.to_string()).await?,
+            None => property_api::delete_app_setting(key.to_string()).await?,
         }
     }
+    
+    tracing::info!("HTTP 连接池设置已保存: {:?}", settings);
+    Ok(())
+}
+
+/// 获取连接池和连接超时设置
+#[frb(sync)]
+pub fn get_http_pool_settings() -> HttpPoolSettings {
+    HttpClient::pool_settings()
+}
+
+/// 把域名固定解析到指定 IP（如分流 IP），请求仍使用原域名的 Host 和 SNI
+/// 
+/// 证书按原域名校验，无需关闭 TLS 证书校验。
+/// 
+/// # 参数
+/// - `host`: 域名，不区分大小写
+/// - `ip`: IPv4 或 IPv6 地址，为空时移除该域名的固定解析
+#[frb]
+pub async fn set_dns_override(host: String, ip: Option<String>) -> anyhow::Result<()> {
+    let host = host.trim().to_lowercase();
+    if host.is_empty() {
+        return Err(anyhow::anyhow!("域名不能为空"));
+    }
+    let ip = match ip.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        Some(ip) => Some(ip.parse::<std::net::IpAddr>()
+            .map_err(|_| anyhow::anyhow!("无效的 IP 地址: {}", ip))?),
+        None => None,
+    };
+    HttpClient::set_dns_override(&host, ip);
+    
+    let overrides = get_dns_overrides();
+    if overrides.is_empty() {
+        property_api::delete_app_setting(DNS_OVERRIDES_SETTING_KEY.to_string()).await?;
+    } else {
+        property_api::save_app_setting(DNS_OVERRIDES_SETTING_KEY.to_string(), serde_json::to_string(&overrides)?).await?;
+    }
+    tracing::info!("DNS 固定解析已保存: {} -> {:?}", host, ip);
+    Ok(())
+}
+
+/// 获取所有固定解析（域名 -> IP）
+#[frb(sync)]
+pub fn get_dns_overrides() -> HashMap<String, String> {
+    HttpClient::dns_overrides()
+        .into_iter()
+        .map(|(host, ip)| (host, ip.to_string()))
+        .collect()
+}
+
+/// 初始化 HTTP 设置（从数据库加载）
+/// 在应用启动时调用（内部使用，不导出到 Flutter）
+pub(crate) async fn init_http_settings() -> anyhow::Result<()> {
+    let insecure = property_api::load_app_setting(TLS_INSECURE_SETTING_KEY.to_string())
+        .await?
+        .map(|v| v == "true")
+        .unwrap_or(false);
+    HttpClient::set_default_accept_invalid_certs(insecure);
+    
+    let defaults = HttpDefaults::default();
+    let timeout_secs = property_api::load_app_setting(TIMEOUT_SETTING_KEY.to_string())
+        .await?
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|t| *t > 0)
+        .unwrap_or(defaults.timeout_secs);
+    let user_agent = property_api::load_app_setting(USER_AGENT_SETTING_KEY.to_string())
+        .await?
+        .unwrap_or(defaults.user_agent);
+    HttpClient::set_defaults(HttpDefaults { timeout_secs, user_agent });
+    
+    let pool_defaults = HttpPoolSettings::default();
+    let pool_max_idle_per_host = property_api::load_app_setting(POOL_MAX_IDLE_SETTING_KEY.to_string())
+        .await?
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(pool_defaults.pool_max_idle_per_host);
+    let pool_idle_timeout_secs = property_api::load_app_setting(POOL_IDLE_TIMEOUT_SETTING_KEY.to_string())
+        .await?
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(pool_defaults.pool_idle_timeout_secs);
+    let connect_timeout_secs = property_api::load_app_setting(CONNECT_TIMEOUT_SETTING_KEY.to_string())
+        .await?
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(pool_defaults.connect_timeout_secs);
+    HttpClient::set_pool_settings(HttpPoolSettings { pool_max_idle_per_host, pool_idle_timeout_secs, connect_timeout_secs });
+    
+    let dns_overrides: HashMap<String, String> = property_api::load_app_setting(DNS_OVERRIDES_SETTING_KEY.to_string())
+        .await?
+        .and_then(|v| serde_json::from_str(&v).ok())
+        .unwrap_or_default();
+    HttpClient::set_dns_overrides(dns_overrides.into_iter()
+        .filter_map(|(host, ip)| ip.parse().ok().map(|ip| (host, ip)))
+        .collect());
+    
+    tracing::info!("HTTP 设置已从数据库加载: tls_insecure={}, timeout={}s", insecure, timeout_secs);
+    Ok(())
 }
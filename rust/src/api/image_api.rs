@@ -1,6 +1,6 @@
 use flutter_rust_bridge::frb;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-use image::RgbaImage;
+use image::{GenericImage, GenericImageView, RgbaImage};
 
 /// 图片信息
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -95,6 +95,290 @@ pub fn rearrange_image_rows(image_data_base64: String, rows: u32) -> anyhow::Res
     Ok(base64_result)
 }
 
+/// 将 RGBA 图像编码为 base64 PNG
+fn encode_png_base64(img: &RgbaImage) -> anyhow::Result<String> {
+    let mut png_data = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut png_data, img.width(), img.height());
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(img.as_raw())?;
+    }
+    Ok(BASE64.encode(&png_data))
+}
+
+/// 解码图片并重新排列列（与 rearrange_image_rows 对称，用于按竖条打乱的图片）
+/// 参数：
+/// - image_data_base64: base64 编码的图片数据
+/// - cols: 要分割的列数
+/// 返回：重新排列后的图片数据（base64 编码的 PNG）
+#[frb]
+pub fn rearrange_image_cols(image_data_base64: String, cols: u32) -> anyhow::Result<String> {
+    if cols == 0 {
+        return Err(anyhow::anyhow!("cols must be greater than 0"));
+    }
+    
+    let image_bytes = BASE64.decode(&image_data_base64)?;
+    let src = image::load_from_memory(&image_bytes)?.to_rgba8();
+    
+    let width = src.width();
+    let height = src.height();
+    let remainder = width % cols;
+    
+    tracing::debug!("[Image API] rearrange_image_cols: {}x{}, cols: {}, remainder: {}", width, height, cols, remainder);
+    
+    let mut dst = RgbaImage::new(width, height);
+    for x in 0..cols {
+        let mut copy_w = width / cols;
+        let mut px = copy_w * x;
+        let sx = width - (copy_w * (x + 1)) - remainder;
+        
+        // 余数分配给第一列，与行排列逻辑一致
+        if x == 0 {
+            copy_w += remainder;
+        } else {
+            px += remainder;
+        }
+        
+        dst.copy_from(&*src.view(sx, 0, copy_w, height), px, 0)?;
+    }
+    
+    encode_png_base64(&dst)
+}
+
+/// 按网格重新排列图片
+/// 参数：
+/// - image_data_base64: base64 编码的图片数据
+/// - cols, rows: 网格的列数和行数
+/// - permutation: 长度为 cols*rows，目标第 i 个格子（按行优先）取源图第 permutation[i] 个格子
+/// 返回：重新排列后的图片数据（base64 编码的 PNG）
+/// 
+/// 宽高不能整除时，余数分配给最后一列和最后一行；
+/// 尺寸不同的格子互换时只复制两者重叠的部分
+#[frb]
+pub fn rearrange_image_grid(image_data_base64: String, cols: u32, rows: u32, permutation: Vec<u32>) -> anyhow::Result<String> {
+    if cols == 0 || rows == 0 {
+        return Err(anyhow::anyhow!("cols and rows must be greater than 0"));
+    }
+    let cells = cols.checked_mul(rows)
+        .ok_or_else(|| anyhow::anyhow!("Grid {}x{} is too large", cols, rows))?;
+    if permutation.len() != cells as usize {
+        return Err(anyhow::anyhow!(
+            "Permutation length {} does not match {}x{} grid",
+            permutation.len(), cols, rows
+        ));
+    }
+    let mut seen = vec![false; cells as usize];
+    for &index in &permutation {
+        if index >= cells || seen[index as usize] {
+            return Err(anyhow::anyhow!("Invalid permutation: {:?}", permutation));
+        }
+        seen[index as usize] = true;
+    }
+    
+    let image_bytes = BASE64.decode(&image_data_base64)?;
+    let src = image::load_from_memory(&image_bytes)?.to_rgba8();
+    
+    let width = src.width();
+    let height = src.height();
+    let cell_w = width / cols;
+    let cell_h = height / rows;
+    if cell_w == 0 || cell_h == 0 {
+        return Err(anyhow::anyhow!("Image {}x{} is too small for a {}x{} grid", width, height, cols, rows));
+    }
+    
+    tracing::debug!("[Image API] rearrange_image_grid: {}x{}, grid: {}x{}, cell: {}x{}", width, height, cols, rows, cell_w, cell_h);
+    
+    // 最后一列/行包含余数
+    let cell_rect = |index: u32| {
+        let (col, row) = (index % cols, index / cols);
+        let w = if col == cols - 1 { width - cell_w * (cols - 1) } else { cell_w };
+        let h = if row == rows - 1 { height - cell_h * (rows - 1) } else { cell_h };
+        (col * cell_w, row * cell_h, w, h)
+    };
+    
+    let mut dst = src.clone();
+    for (dst_index, &src_index) in permutation.iter().enumerate() {
+        let (sx, sy, sw, sh) = cell_rect(src_index);
+        let (dx, dy, dw, dh) = cell_rect(dst_index as u32);
+        dst.copy_from(&*src.view(sx, sy, sw.min(dw), sh.min(dh)), dx, dy)?;
+    }
+    
+    encode_png_base64(&dst)
+}
+
+/// 转换图片格式
+/// 参数：
+/// - image_data_base64: base64 编码的图片数据
+/// - format: 目标格式，"jpeg"（或 "jpg"）、"png"、"webp"
+/// - quality: JPEG 质量（1-100），默认 85；PNG 与 WebP（无损）忽略此参数
+/// 返回：转换后的图片数据（base64 编码）
+#[frb]
+pub fn convert_image(image_data_base64: String, format: String, quality: Option<u8>) -> anyhow::Result<String> {
+    if let Some(q) = quality {
+        if !(1..=100).contains(&q) {
+            return Err(anyhow::anyhow!("Quality must be between 1 and 100, got {}", q));
+        }
+    }
+    
+    let image_bytes = BASE64.decode(&image_data_base64)?;
+    let img = image::load_from_memory(&image_bytes)?;
+    
+    let mut output = Vec::new();
+    match format.to_lowercase().as_str() {
+        "jpeg" | "jpg" => {
+            // JPEG 不支持透明通道
+            let rgb = img.to_rgb8();
+            let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut output, quality.unwrap_or(85));
+            rgb.write_with_encoder(encoder)?;
+        }
+        "png" => return encode_png_base64(&img.to_rgba8()),
+        "webp" => {
+            let rgba = img.to_rgba8();
+            let encoder = image::codecs::webp::WebPEncoder::new_lossless(&mut output);
+            rgba.write_with_encoder(encoder)?;
+        }
+        other => {
+            return Err(anyhow::anyhow!("Unsupported image format: {} (expected jpeg, png or webp)", other));
+        }
+    }
+    
+    tracing::debug!("[Image API] convert_image: {} -> {} bytes as {}", image_bytes.len(), output.len(), format);
+    Ok(BASE64.encode(&output))
+}
+
+/// 垂直拼接条漫图片并按最大高度重新切分
+/// 参数：
+/// - images_base64: base64 编码的图片列表（从上到下）
+/// - max_height: 每张输出图片的最大高度，避免生成过大的纹理
+/// 返回：切分后的图片列表（base64 编码的 PNG）
+/// 
+/// 输出宽度取所有图片的最大宽度，较窄的图片水平居中，两侧透明
+#[frb]
+pub fn concat_images_vertical(images_base64: Vec<String>, max_height: u32) -> anyhow::Result<Vec<String>> {
+    if images_base64.is_empty() {
+        return Err(anyhow::anyhow!("Image list is empty"));
+    }
+    if max_height == 0 {
+        return Err(anyhow::anyhow!("max_height must be greater than 0"));
+    }
+    
+    let mut strips = Vec::with_capacity(images_base64.len());
+    for data in &images_base64 {
+        let image_bytes = BASE64.decode(data)?;
+        strips.push(image::load_from_memory(&image_bytes)?.to_rgba8());
+    }
+    
+    let width = strips.iter().map(|s| s.width()).max().unwrap_or(0);
+    let total_height: u64 = strips.iter().map(|s| s.height() as u64).sum();
+    
+    tracing::debug!("[Image API] concat_images_vertical: {} strips, {}x{}, max_height: {}", strips.len(), width, total_height, max_height);
+    
+    let mut chunks = Vec::new();
+    let mut chunk_start = 0u64;
+    while chunk_start < total_height {
+        let chunk_end = (chunk_start + max_height as u64).min(total_height);
+        let mut chunk = RgbaImage::new(width, (chunk_end - chunk_start) as u32);
+        
+        // 将与当前分块重叠的部分逐条复制进来
+        let mut strip_top = 0u64;
+        for strip in &strips {
+            let strip_bottom = strip_top + strip.height() as u64;
+            let top = strip_top.max(chunk_start);
+            let bottom = strip_bottom.min(chunk_end);
+            if top < bottom {
+                let x = (width - strip.width()) / 2;
+                let view = strip.view(0, (top - strip_top) as u32, strip.width(), (bottom - top) as u32);
+                chunk.copy_from(&*view, x, (top - chunk_start) as u32)?;
+            }
+            strip_top = strip_bottom;
+        }
+        
+        chunks.push(encode_png_base64(&chunk)?);
+        chunk_start = chunk_end;
+    }
+    
+    Ok(chunks)
+}
+
+/// 读取 EXIF 方向标记（1-8），没有 EXIF 或标记时返回 None
+fn exif_orientation(image_bytes: &[u8]) -> Option<u32> {
+    let exif = exif::Reader::new()
+        .read_from_container(&mut std::io::Cursor::new(image_bytes))
+        .ok()?;
+    exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?
+        .value
+        .get_uint(0)
+}
+
+/// 按 EXIF 方向标记旋转/翻转图片
+fn apply_orientation(img: image::DynamicImage, orientation: u32) -> image::DynamicImage {
+    match orientation {
+        2 => img.fliph(),
+        3 => img.rotate180(),
+        4 => img.flipv(),
+        5 => img.rotate90().fliph(),
+        6 => img.rotate90(),
+        7 => img.rotate270().fliph(),
+        8 => img.rotate270(),
+        _ => img,
+    }
+}
+
+/// 根据 EXIF 方向标记自动校正图片方向
+/// 参数：base64 编码的图片数据
+/// 返回：校正后的图片数据（base64，保持原格式）；无需校正时原样返回
+#[frb]
+pub fn auto_orient_image(image_data_base64: String) -> anyhow::Result<String> {
+    let image_bytes = BASE64.decode(&image_data_base64)?;
+    let orientation = match exif_orientation(&image_bytes) {
+        Some(o) if (2..=8).contains(&o) => o,
+        _ => return Ok(image_data_base64),
+    };
+    
+    let format = image::guess_format(&image_bytes)?;
+    let img = apply_orientation(image::load_from_memory(&image_bytes)?, orientation);
+    tracing::debug!("[Image API] auto_orient_image: orientation {}, result {}x{}", orientation, img.width(), img.height());
+    
+    // JPEG 编码器不支持透明通道
+    let img = if format == image::ImageFormat::Jpeg {
+        image::DynamicImage::ImageRgb8(img.to_rgb8())
+    } else {
+        img
+    };
+    let mut output = Vec::new();
+    img.write_to(&mut std::io::Cursor::new(&mut output), format)?;
+    Ok(BASE64.encode(&output))
+}
+
+/// 读取图片的 EXIF 信息
+/// 参数：base64 编码的图片数据
+/// 返回：标签名到显示值的 JSON 对象字符串；没有 EXIF 时返回 "{}"
+#[frb]
+pub fn read_exif(image_data_base64: String) -> anyhow::Result<String> {
+    let image_bytes = BASE64.decode(&image_data_base64)?;
+    let exif = match exif::Reader::new().read_from_container(&mut std::io::Cursor::new(&image_bytes)) {
+        Ok(exif) => exif,
+        // 不含 EXIF 或容器格式不支持 EXIF
+        Err(exif::Error::NotFound(_)) | Err(exif::Error::InvalidFormat(_)) => return Ok("{}".to_string()),
+        Err(e) => return Err(anyhow::anyhow!("Failed to read EXIF: {}", e)),
+    };
+    
+    let mut tags = serde_json::Map::new();
+    for field in exif.fields() {
+        // 同名标签优先保留主图（PRIMARY）中的值
+        let name = field.tag.to_string();
+        if tags.contains_key(&name) && field.ifd_num != exif::In::PRIMARY {
+            continue;
+        }
+        let value = field.display_value().with_unit(&exif).to_string();
+        tags.insert(name, serde_json::Value::String(value));
+    }
+    
+    Ok(serde_json::Value::Object(tags).to_string())
+}
+
 /// 裁剪图片
 /// 参数：
 /// - image_data_base64: base64 编码的图片数据
@@ -190,3 +474,134 @@ pub fn compose_vertical(image_data_base64_list: String) -> anyhow::Result<String
     
     Ok(BASE64.encode(&png_data))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    /// 每个像素的 R 通道等于其 x 坐标，G 通道等于 y 坐标
+    fn gradient_image(width: u32, height: u32) -> String {
+        let img = RgbaImage::from_fn(width, height, |x, y| Rgba([x as u8, y as u8, 0, 255]));
+        encode_png_base64(&img).unwrap()
+    }
+
+    fn decode(data: &str) -> RgbaImage {
+        image::load_from_memory(&BASE64.decode(data).unwrap()).unwrap().to_rgba8()
+    }
+
+    #[test]
+    fn test_rearrange_image_cols() {
+        // 宽 5，分 2 列：余数 1 分配给第一列
+        let result = decode(&rearrange_image_cols(gradient_image(5, 1), 2).unwrap());
+        let xs: Vec<u8> = (0..5).map(|x| result.get_pixel(x, 0)[0]).collect();
+        assert_eq!(xs, vec![2, 3, 4, 0, 1]);
+    }
+
+    #[test]
+    fn test_rearrange_image_grid() {
+        // 4x4 分成 2x2 网格，交换对角格子
+        let result = decode(&rearrange_image_grid(gradient_image(4, 4), 2, 2, vec![3, 2, 1, 0]).unwrap());
+        assert_eq!(result.get_pixel(0, 0).0[..2], [2, 2]);
+        assert_eq!(result.get_pixel(2, 0).0[..2], [0, 2]);
+        assert_eq!(result.get_pixel(3, 3).0[..2], [1, 1]);
+
+        // 宽 7 分 3 列（2, 2, 3），余数归最后一列
+        let result = decode(&rearrange_image_grid(gradient_image(7, 1), 3, 1, vec![1, 0, 2]).unwrap());
+        let xs: Vec<u8> = (0..7).map(|x| result.get_pixel(x, 0)[0]).collect();
+        assert_eq!(xs, vec![2, 3, 0, 1, 4, 5, 6]);
+
+        // 5x5 分 2x2（最后一行/列为 3），交换左右两列
+        let result = decode(&rearrange_image_grid(gradient_image(5, 5), 2, 2, vec![1, 0, 3, 2]).unwrap());
+        let xs: Vec<u8> = (0..5).map(|x| result.get_pixel(x, 4)[0]).collect();
+        assert_eq!(xs, vec![2, 3, 0, 1, 4]);
+        // 每个像素都来自源图，底部余数行没有留黑
+        assert!(result.pixels().all(|p| p[3] == 255));
+        let ys: Vec<u8> = (0..5).map(|y| result.get_pixel(0, y)[1]).collect();
+        assert_eq!(ys, vec![0, 1, 2, 3, 4]);
+
+        assert!(rearrange_image_grid(gradient_image(4, 4), 2, 2, vec![0, 0, 1, 2]).is_err());
+        assert!(rearrange_image_grid(gradient_image(4, 4), 2, 2, vec![0, 1, 2]).is_err());
+        // 格子数溢出时返回错误而不是 panic
+        assert!(rearrange_image_grid(gradient_image(4, 4), u32::MAX, 2, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_concat_images_vertical() {
+        // 宽 4 高 3 与宽 2 高 4 的两张图，按最大高度 5 切分
+        let result = concat_images_vertical(vec![gradient_image(4, 3), gradient_image(2, 4)], 5).unwrap();
+        assert_eq!(result.len(), 2);
+
+        let first = decode(&result[0]);
+        let second = decode(&result[1]);
+        assert_eq!((first.width(), first.height()), (4, 5));
+        assert_eq!((second.width(), second.height()), (4, 2));
+
+        // 第一张图完整在第一块中
+        assert_eq!(first.get_pixel(3, 2).0, [3, 2, 0, 255]);
+        // 较窄的第二张图居中（x 偏移 1），两侧透明
+        assert_eq!(first.get_pixel(1, 3).0, [0, 0, 0, 255]);
+        assert_eq!(first.get_pixel(0, 3).0[3], 0);
+        assert_eq!(first.get_pixel(3, 3).0[3], 0);
+        // 第二张图剩余的两行落在第二块
+        assert_eq!(second.get_pixel(2, 1).0, [1, 3, 0, 255]);
+
+        assert!(concat_images_vertical(vec![], 100).is_err());
+        assert!(concat_images_vertical(vec![gradient_image(1, 1)], 0).is_err());
+    }
+
+    /// 生成带 EXIF 方向标记的 JPEG
+    fn jpeg_with_orientation(width: u32, height: u32, orientation: u16) -> String {
+        let jpeg = BASE64.decode(convert_image(gradient_image(width, height), "jpeg".to_string(), None).unwrap()).unwrap();
+
+        // TIFF 头（大端）+ 仅包含 Orientation 的 IFD0
+        let mut tiff = b"Exif\0\0MM\0\x2a\0\0\0\x08\0\x01".to_vec();
+        tiff.extend_from_slice(&[0x01, 0x12, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01]);
+        tiff.extend_from_slice(&orientation.to_be_bytes());
+        tiff.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+
+        // 在 SOI 之后插入 APP1 段
+        let mut result = jpeg[..2].to_vec();
+        result.extend_from_slice(&[0xFF, 0xE1]);
+        result.extend_from_slice(&((tiff.len() + 2) as u16).to_be_bytes());
+        result.extend_from_slice(&tiff);
+        result.extend_from_slice(&jpeg[2..]);
+        BASE64.encode(&result)
+    }
+
+    #[test]
+    fn test_auto_orient_image() {
+        // 方向 6：需要顺时针旋转 90 度，宽高互换
+        let rotated = jpeg_with_orientation(16, 8, 6);
+        let exif: serde_json::Value = serde_json::from_str(&read_exif(rotated.clone()).unwrap()).unwrap();
+        assert!(exif.get("Orientation").is_some());
+
+        let oriented = BASE64.decode(auto_orient_image(rotated).unwrap()).unwrap();
+        assert_eq!(image::guess_format(&oriented).unwrap(), image::ImageFormat::Jpeg);
+        let img = image::load_from_memory(&oriented).unwrap();
+        assert_eq!((img.width(), img.height()), (8, 16));
+
+        // 方向 1 与无 EXIF 的图片原样返回
+        let upright = jpeg_with_orientation(16, 8, 1);
+        assert_eq!(auto_orient_image(upright.clone()).unwrap(), upright);
+        let plain = gradient_image(4, 4);
+        assert_eq!(auto_orient_image(plain.clone()).unwrap(), plain);
+        assert_eq!(read_exif(plain).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_convert_image() {
+        let source = gradient_image(16, 8);
+        for (format, expected) in [("jpeg", image::ImageFormat::Jpeg), ("png", image::ImageFormat::Png), ("webp", image::ImageFormat::WebP)] {
+            let converted = BASE64.decode(convert_image(source.clone(), format.to_string(), Some(80)).unwrap()).unwrap();
+            assert_eq!(image::guess_format(&converted).unwrap(), expected);
+            let img = image::load_from_memory(&converted).unwrap();
+            assert_eq!((img.width(), img.height()), (16, 8));
+        }
+
+        assert!(convert_image(source.clone(), "jpeg".to_string(), Some(0)).is_err());
+        assert!(convert_image(source.clone(), "jpeg".to_string(), Some(101)).is_err());
+        let err = convert_image(source, "bmp".to_string(), None).unwrap_err();
+        assert!(err.to_string().contains("Unsupported image format"));
+    }
+}
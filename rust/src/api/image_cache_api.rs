@@ -1,9 +1,24 @@
 use flutter_rust_bridge::frb;
-use sea_orm::{EntityTrait, QueryFilter, ColumnTrait, ActiveModelTrait, Set};
+use sea_orm::{
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, ColumnTrait, ActiveModelTrait, ConnectionTrait,
+    PaginatorTrait, Set,
+};
 use chrono::{Utc, Duration};
 use tokio::fs;
 use crate::database::{self, entities::image_cache};
-use crate::api::module_api;
+use crate::api::{download_api, module_api};
+use crate::api::property_api;
+use crate::http::HttpClient;
+use crate::modules::{PageInfo, RemoteImageInfo};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::frb_generated::StreamSink;
+
+const IMAGE_CACHE_LIMIT_SETTING_KEY: &str = "image_cache_max_bytes";
+/// 默认图片缓存上限（512MB）
+const DEFAULT_IMAGE_CACHE_LIMIT: i64 = 512 * 1024 * 1024;
+/// 淘汰缓存时每批查询的记录数
+const EVICT_BATCH_SIZE: u64 = 200;
 
 /// 获取缓存的图片文件路径
 #[frb]
@@ -28,16 +43,16 @@ pub async fn get_cached_image(module_id: String, url: String) -> anyhow::Result<
                 return Ok(Some(cache.file_path));
             } else {
                 // 文件不存在，删除缓存记录
-                let _ = image_cache::Entity::delete_by_id(&cache_key)
-                    .exec(&*conn)
-                    .await;
+                if image_cache::Entity::delete_by_id(&cache_key).exec(&*conn).await.is_ok() {
+                    CACHE_SIZE.remove(cache.file_size);
+                }
             }
         } else {
             // 已过期，删除缓存记录和文件
             let _ = fs::remove_file(&cache.file_path).await;
-            let _ = image_cache::Entity::delete_by_id(&cache_key)
-                .exec(&*conn)
-                .await;
+            if image_cache::Entity::delete_by_id(&cache_key).exec(&*conn).await.is_ok() {
+                CACHE_SIZE.remove(cache.file_size);
+            }
         }
     }
     
@@ -54,51 +69,352 @@ pub async fn save_image_to_cache(
     file_size: i64,
     expire_days: Option<i64>, // 过期天数，默认 30 天
 ) -> anyhow::Result<()> {
+    let limit = get_image_cache_limit().await?;
     let db = database::get_database()
         .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
     
     let conn = db.read().await;
-    let cache_key = image_cache::Model::create_cache_key(&module_id, &url);
     let now = Utc::now().naive_utc();
     let expire_days = expire_days.unwrap_or(30);
-    let expire_at = now + Duration::days(expire_days);
+    let record = image_cache::ActiveModel {
+        cache_key: Set(image_cache::Model::create_cache_key(&module_id, &url)),
+        module_id: Set(module_id),
+        url: Set(url),
+        file_path: Set(file_path),
+        content_type: Set(content_type),
+        file_size: Set(file_size),
+        expire_at: Set(now + Duration::days(expire_days)),
+        created_at: Set(now),
+    };
+    save_image_record(&*conn, &CACHE_SIZE, record, limit).await
+}
+
+/// 图片缓存总大小（字节），None 表示尚未统计
+/// 
+/// 首次使用时聚合查询一次，之后随写入和删除增量更新，避免每次保存都对全表求和；
+/// 淘汰缓存时会重新统计以校正误差
+struct CacheSize(std::sync::Mutex<Option<i64>>);
+
+impl CacheSize {
+    const fn new() -> Self {
+        Self(std::sync::Mutex::new(None))
+    }
     
-    // 检查是否已存在
-    let existing = image_cache::Entity::find_by_id(&cache_key)
-        .one(&*conn)
+    /// 记录大小变化，返回当前总大小
+    async fn add(&self, conn: &impl ConnectionTrait, delta: i64) -> anyhow::Result<i64> {
+        let known = self.0.lock().unwrap().as_mut().map(|total| {
+            *total += delta;
+            *total
+        });
+        if let Some(total) = known {
+            return Ok(total);
+        }
+        // 统计结果已包含本次写入
+        let counted = image_cache_stats(conn).await?.total_size as i64;
+        let mut total = self.0.lock().unwrap();
+        let total = total.get_or_insert(counted - delta);
+        *total += delta;
+        Ok(*total)
+    }
+    
+    /// 删除单条记录后扣减，尚未统计时忽略
+    fn remove(&self, bytes: i64) {
+        if let Some(total) = self.0.lock().unwrap().as_mut() {
+            *total -= bytes;
+        }
+    }
+    
+    fn set(&self, total: i64) {
+        *self.0.lock().unwrap() = Some(total);
+    }
+    
+    /// 批量删除后重新统计
+    fn reset(&self) {
+        *self.0.lock().unwrap() = None;
+    }
+}
+
+static CACHE_SIZE: CacheSize = CacheSize::new();
+
+/// 插入或更新缓存记录（更新时保留 created_at），超出容量上限时淘汰最旧的缓存
+async fn save_image_record(
+    conn: &impl ConnectionTrait,
+    size: &CacheSize,
+    mut record: image_cache::ActiveModel,
+    limit: i64,
+) -> anyhow::Result<()> {
+    let existing = image_cache::Entity::find_by_id(record.cache_key.clone().unwrap())
+        .one(conn)
         .await?;
     
-    if existing.is_some() {
-        // 更新
-        let active_model = image_cache::ActiveModel {
-            cache_key: Set(cache_key),
-            module_id: Set(module_id),
-            url: Set(url),
-            file_path: Set(file_path),
-            content_type: Set(content_type),
-            file_size: Set(file_size),
-            expire_at: Set(expire_at),
-            created_at: sea_orm::ActiveValue::NotSet,
-        };
-        active_model.update(&*conn).await?;
-    } else {
-        // 插入
-        let active_model = image_cache::ActiveModel {
-            cache_key: Set(cache_key),
-            module_id: Set(module_id),
-            url: Set(url),
-            file_path: Set(file_path),
-            content_type: Set(content_type),
-            file_size: Set(file_size),
-            expire_at: Set(expire_at),
-            created_at: Set(now),
+    let file_size = record.file_size.clone().unwrap();
+    let delta = match existing {
+        Some(existing) => {
+            record.created_at = sea_orm::ActiveValue::NotSet;
+            record.update(conn).await?;
+            file_size - existing.file_size
+        }
+        None => {
+            record.insert(conn).await?;
+            file_size
+        }
+    };
+    
+    if size.add(conn, delta).await? > limit {
+        if let Err(e) = evict_image_cache(conn, size, limit).await {
+            tracing::warn!("[Image Cache] Failed to enforce cache limit: {}", e);
+        }
+    }
+    
+    Ok(())
+}
+
+/// 根据扩展名推断图片的 Content-Type
+fn content_type_for_extension(ext: &str) -> &'static str {
+    match ext.to_ascii_lowercase().as_str() {
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "avif" => "image/avif",
+        _ => "image/jpeg",
+    }
+}
+
+/// 下载远程图片并写入缓存，返回本地文件路径
+/// 
+/// 使用 RemoteImageInfo 中携带的 headers（Referer、Cookie 等）请求图片，
+/// 已有未过期的缓存时直接返回缓存路径
+#[frb]
+pub async fn fetch_and_cache_image(
+    module_id: String,
+    remote_image_info_json: String,
+    expire_days: Option<i64>,
+) -> anyhow::Result<String> {
+    let cache_dir = crate::get_cache_dir()
+        .ok_or_else(|| anyhow::anyhow!("Cache dir not initialized"))?;
+    let info: RemoteImageInfo = serde_json::from_str(&remote_image_info_json)?;
+    fetch_and_cache_image_to(&cache_dir.join("images"), module_id, info, expire_days).await
+}
+
+async fn fetch_and_cache_image_to(
+    dir: &Path,
+    module_id: String,
+    info: RemoteImageInfo,
+    expire_days: Option<i64>,
+) -> anyhow::Result<String> {
+    let url = info.to_url();
+    if let Some(path) = get_cached_image(module_id.clone(), url.clone()).await? {
+        return Ok(path);
+    }
+    
+    // 与 Flutter 端缓存使用相同的目录和文件名规则
+    let ext = download_api::image_extension(&url);
+    let dest = dir.join(format!("{}.{}", image_cache::Model::create_cache_key(&module_id, &url), ext));
+    fs::create_dir_all(dir).await?;
+    
+    let client = HttpClient::shared()?;
+    let request = download_api::module_download_request(&module_id, &url, info.headers).await;
+    let size = client.download_to_file_with(request, &dest, None, |_, _| {}).await?;
+    
+    let file_path = dest.to_string_lossy().to_string();
+    let content_type = content_type_for_extension(ext).to_string();
+    save_image_to_cache(
+        module_id,
+        url,
+        file_path.clone(),
+        content_type,
+        size as i64,
+        expire_days,
+    ).await?;
+    
+    Ok(file_path)
+}
+
+/// 章节预取中单张图片的完成情况
+#[derive(Debug, Clone)]
+pub struct ImagePrefetchItem {
+    /// 图片在章节中的下标
+    pub index: u32,
+    pub url: String,
+    /// 本地缓存路径，失败时为 None
+    pub path: Option<String>,
+    pub error: Option<String>,
+    /// 是否命中已有缓存
+    pub cached: bool,
+}
+
+/// 章节预取结果汇总
+#[derive(Debug, Clone, Default)]
+pub struct ImagePrefetchSummary {
+    pub succeeded: u32,
+    pub failed: u32,
+    /// 已有有效缓存而跳过下载的数量（计入 succeeded）
+    pub skipped: u32,
+}
+
+/// 并发预取整个章节的图片到缓存，并通过 StreamSink 报告每张图片的完成情况
+/// 
+/// picture_urls_json 为 RemoteImageInfo 对象或 URL 字符串组成的 JSON 数组，
+/// 已缓存且未过期的图片会被跳过，concurrency 为同时下载的数量（至少为 1）
+#[frb]
+pub async fn prefetch_chapter_images(
+    module_id: String,
+    picture_urls_json: String,
+    expire_days: Option<i64>,
+    concurrency: u32,
+    sink: StreamSink<ImagePrefetchItem>,
+) -> anyhow::Result<ImagePrefetchSummary> {
+    let cache_dir = crate::get_cache_dir()
+        .ok_or_else(|| anyhow::anyhow!("Cache dir not initialized"))?;
+    let images = parse_picture_list(&picture_urls_json)?;
+    let summary = prefetch_images_to(cache_dir.join("images"), module_id, images, expire_days, concurrency, |item| {
+        // Flutter 端取消订阅后 add 会失败，忽略即可
+        let _ = sink.add(item);
+    }).await;
+    Ok(summary)
+}
+
+/// 解析图片列表，元素可以是 RemoteImageInfo 对象或 URL 字符串
+fn parse_picture_list(json: &str) -> anyhow::Result<Vec<RemoteImageInfo>> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(json)?;
+    values.into_iter()
+        .map(|value| match value {
+            serde_json::Value::String(url) => Ok(RemoteImageInfo::from_url(url)),
+            other => Ok(serde_json::from_value(other)?),
+        })
+        .collect()
+}
+
+async fn prefetch_images_to<F>(
+    dir: PathBuf,
+    module_id: String,
+    images: Vec<RemoteImageInfo>,
+    expire_days: Option<i64>,
+    concurrency: u32,
+    mut on_item: F,
+) -> ImagePrefetchSummary
+where
+    F: FnMut(ImagePrefetchItem),
+{
+    let dir = Arc::new(dir);
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(concurrency.max(1) as usize));
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, info) in images.into_iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let dir = dir.clone();
+        let module_id = module_id.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let url = info.to_url();
+            let cached = matches!(get_cached_image(module_id.clone(), url.clone()).await, Ok(Some(_)));
+            let result = fetch_and_cache_image_to(&dir, module_id, info, expire_days).await;
+            ImagePrefetchItem {
+                index: index as u32,
+                url,
+                path: result.as_ref().ok().cloned(),
+                error: result.err().map(|e| e.to_string()),
+                cached,
+            }
+        });
+    }
+    
+    let mut summary = ImagePrefetchSummary::default();
+    while let Some(joined) = tasks.join_next().await {
+        let item = match joined {
+            Ok(item) => item,
+            Err(e) => {
+                tracing::warn!("[Image Cache] Prefetch task failed: {}", e);
+                summary.failed += 1;
+                continue;
+            }
         };
-        active_model.insert(&*conn).await?;
+        if item.error.is_some() {
+            summary.failed += 1;
+        } else {
+            summary.succeeded += 1;
+            if item.cached {
+                summary.skipped += 1;
+            }
+        }
+        on_item(item);
     }
     
+    tracing::info!("[Image Cache] Prefetched chapter for {}: {} succeeded ({} cached), {} failed",
+        module_id, summary.succeeded, summary.skipped, summary.failed);
+    summary
+}
+
+/// 设置图片缓存容量上限（字节），None 恢复默认值
+#[frb]
+pub async fn set_image_cache_limit(max_bytes: Option<i64>) -> anyhow::Result<()> {
+    match max_bytes {
+        Some(max_bytes) if max_bytes > 0 => {
+            property_api::save_app_setting(IMAGE_CACHE_LIMIT_SETTING_KEY.to_string(), max_bytes.to_string()).await?;
+            enforce_image_cache_limit(max_bytes).await?;
+        }
+        Some(max_bytes) => return Err(anyhow::anyhow!("Cache limit must be positive, got {}", max_bytes)),
+        None => property_api::delete_app_setting(IMAGE_CACHE_LIMIT_SETTING_KEY.to_string()).await?,
+    }
     Ok(())
 }
 
+/// 获取图片缓存容量上限（字节）
+#[frb]
+pub async fn get_image_cache_limit() -> anyhow::Result<i64> {
+    let value = property_api::load_app_setting(IMAGE_CACHE_LIMIT_SETTING_KEY.to_string()).await?;
+    Ok(value
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_IMAGE_CACHE_LIMIT))
+}
+
+/// 将图片缓存总大小限制在 max_bytes 以内
+/// 按 created_at 从旧到新删除缓存文件及记录，返回删除的条目数
+#[frb]
+pub async fn enforce_image_cache_limit(max_bytes: i64) -> anyhow::Result<u64> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    let conn = db.read().await;
+    evict_image_cache(&*conn, &CACHE_SIZE, max_bytes).await
+}
+
+async fn evict_image_cache(conn: &impl ConnectionTrait, size: &CacheSize, max_bytes: i64) -> anyhow::Result<u64> {
+    let mut total_size = image_cache_stats(conn).await?.total_size as i64;
+    if total_size <= max_bytes {
+        size.set(total_size);
+        return Ok(0);
+    }
+    
+    // 每次只取最旧的一批记录，删除后重新查询，降到上限以下即停止
+    let mut removed = 0u64;
+    while total_size > max_bytes {
+        let caches = image_cache::Entity::find()
+            .order_by_asc(image_cache::Column::CreatedAt)
+            .order_by_asc(image_cache::Column::CacheKey)
+            .limit(EVICT_BATCH_SIZE)
+            .all(conn)
+            .await?;
+        if caches.is_empty() {
+            break;
+        }
+        for cache in caches {
+            if total_size <= max_bytes {
+                break;
+            }
+            let _ = fs::remove_file(&cache.file_path).await;
+            image_cache::Entity::delete_by_id(&cache.cache_key)
+                .exec(conn)
+                .await?;
+            total_size -= cache.file_size;
+            removed += 1;
+        }
+    }
+    size.set(total_size);
+    
+    tracing::info!("[Image Cache] Evicted {} entries, cache size now {} bytes (limit {})", removed, total_size, max_bytes);
+    Ok(removed)
+}
+
 /// 清除指定模块的图片缓存
 #[frb]
 pub async fn clear_image_cache_by_module(module_id: String) -> anyhow::Result<u64> {
@@ -123,6 +439,7 @@ pub async fn clear_image_cache_by_module(module_id: String) -> anyhow::Result<u6
         .filter(image_cache::Column::ModuleId.eq(&module_id))
         .exec(&*conn)
         .await?;
+    CACHE_SIZE.reset();
     
     Ok(result.rows_affected)
 }
@@ -149,6 +466,7 @@ pub async fn clear_all_image_cache() -> anyhow::Result<u64> {
     let result = image_cache::Entity::delete_many()
         .exec(&*conn)
         .await?;
+    CACHE_SIZE.reset();
     
     Ok(result.rows_affected)
 }
@@ -178,6 +496,7 @@ pub async fn clear_expired_image_cache() -> anyhow::Result<u64> {
         .filter(image_cache::Column::ExpireAt.lt(now))
         .exec(&*conn)
         .await?;
+    CACHE_SIZE.reset();
     
     Ok(result.rows_affected)
 }
@@ -189,31 +508,83 @@ pub async fn get_image_cache_stats() -> anyhow::Result<ImageCacheStats> {
         .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
     
     let conn = db.read().await;
+    image_cache_stats(&*conn).await
+}
+
+/// 通过聚合查询统计缓存数量和大小，不加载缓存记录
+async fn image_cache_stats(conn: &impl ConnectionTrait) -> anyhow::Result<ImageCacheStats> {
     let now = Utc::now().naive_utc();
     
-    // 获取所有缓存记录
-    let all_caches = image_cache::Entity::find()
-        .all(&*conn)
+    let (total_count, total_size): (i64, Option<i64>) = image_cache::Entity::find()
+        .select_only()
+        .column_as(image_cache::Column::CacheKey.count(), "count")
+        .column_as(image_cache::Column::FileSize.sum(), "size")
+        .into_tuple()
+        .one(conn)
+        .await?
+        .unwrap_or((0, None));
+    
+    let expired_count = image_cache::Entity::find()
+        .filter(image_cache::Column::ExpireAt.lte(now))
+        .count(conn)
         .await?;
     
-    let mut total_size = 0u64;
-    let mut expired_count = 0u64;
-    let mut valid_count = 0u64;
-    
-    for cache in &all_caches {
-        total_size += cache.file_size as u64;
-        if cache.expire_at <= now {
-            expired_count += 1;
-        } else {
-            valid_count += 1;
-        }
-    }
-    
+    let total_count = total_count as u64;
     Ok(ImageCacheStats {
-        total_count: all_caches.len() as u64,
-        valid_count,
+        total_count,
+        valid_count: total_count.saturating_sub(expired_count),
         expired_count,
-        total_size,
+        total_size: total_size.unwrap_or(0) as u64,
+    })
+}
+
+/// 图片缓存条目
+#[derive(Debug, Clone)]
+pub struct ImageCacheEntry {
+    pub module_id: String,
+    pub url: String,
+    pub file_path: String,
+    pub file_size: i64,
+    /// 过期时间（毫秒时间戳）
+    pub expire_at: i64,
+}
+
+/// 图片缓存分页结果
+#[derive(Debug, Clone)]
+pub struct ImageCachePage {
+    pub page_info: PageInfo,
+    pub docs: Vec<ImageCacheEntry>,
+}
+
+/// 分页列出图片缓存，可按模块过滤，按创建时间倒序，page 从 1 开始
+#[frb]
+pub async fn list_image_cache(module_id: Option<String>, page: i32, limit: i32) -> anyhow::Result<ImageCachePage> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let page = page.max(1);
+    let limit = limit.max(1);
+    
+    let mut query = image_cache::Entity::find();
+    if let Some(module_id) = module_id {
+        query = query.filter(image_cache::Column::ModuleId.eq(module_id));
+    }
+    let paginator = query
+        .order_by_desc(image_cache::Column::CreatedAt)
+        .paginate(&*conn, limit as u64);
+    let total = paginator.num_items().await?;
+    let caches = paginator.fetch_page(page as u64 - 1).await?;
+    
+    Ok(ImageCachePage {
+        page_info: PageInfo::new(page, limit, total as i32),
+        docs: caches.into_iter().map(|c| ImageCacheEntry {
+            module_id: c.module_id,
+            url: c.url,
+            file_path: c.file_path,
+            file_size: c.file_size,
+            expire_at: c.expire_at.and_utc().timestamp_millis(),
+        }).collect(),
     })
 }
 
@@ -299,3 +670,188 @@ pub async fn process_image_with_module(
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{TestResponse, TestServer};
+
+    /// 独立的测试数据库，避免修改全局缓存上限影响其他测试
+    async fn isolated_database(name: &str) -> (PathBuf, sea_orm::DatabaseConnection) {
+        let dir = std::env::temp_dir().join(format!("comics-{}-{}", name, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let conn = database::connection::connect(&dir.join("cache.db")).await.unwrap();
+        database::migration::run_migrations(&conn).await.unwrap();
+        (dir, conn)
+    }
+
+    fn cache_record(url: &str, path: &str, file_size: i64) -> image_cache::ActiveModel {
+        let now = Utc::now().naive_utc();
+        image_cache::ActiveModel {
+            cache_key: Set(image_cache::Model::create_cache_key("test_image_cache_limit", url)),
+            module_id: Set("test_image_cache_limit".to_string()),
+            url: Set(url.to_string()),
+            file_path: Set(path.to_string()),
+            content_type: Set("image/jpeg".to_string()),
+            file_size: Set(file_size),
+            expire_at: Set(now + Duration::days(1)),
+            created_at: Set(now),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_image_cache_limit_evicts_oldest() {
+        let (dir, conn) = isolated_database("cache-limit").await;
+        let size = CacheSize::new();
+
+        let mut paths = Vec::new();
+        for i in 0..3 {
+            let path = dir.join(format!("{}.jpg", i));
+            fs::write(&path, vec![0u8; 100]).await.unwrap();
+            let path = path.to_string_lossy().to_string();
+            let url = format!("https://example.com/{}.jpg", i);
+            save_image_record(&conn, &size, cache_record(&url, &path, 100), 250).await.unwrap();
+            paths.push(path);
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        // 第三张写入后超过 250 字节，最早的一张被淘汰
+        assert!(fs::metadata(&paths[0]).await.is_err());
+        assert!(fs::metadata(&paths[1]).await.is_ok());
+        assert!(fs::metadata(&paths[2]).await.is_ok());
+        assert_eq!(image_cache::Entity::find().count(&conn).await.unwrap(), 2);
+        assert_eq!(*size.0.lock().unwrap(), Some(200));
+
+        // 更新已有条目只计入大小差值，且保留创建时间
+        let url = "https://example.com/1.jpg";
+        let key = image_cache::Model::create_cache_key("test_image_cache_limit", url);
+        let before = image_cache::Entity::find_by_id(&key).one(&conn).await.unwrap().unwrap();
+        save_image_record(&conn, &size, cache_record(url, &paths[1], 120), 250).await.unwrap();
+        let after = image_cache::Entity::find_by_id(&key).one(&conn).await.unwrap().unwrap();
+        assert_eq!(after.created_at, before.created_at);
+        assert_eq!(*size.0.lock().unwrap(), Some(220));
+        assert_eq!(image_cache_stats(&conn).await.unwrap().total_size, 220);
+    }
+
+    #[tokio::test]
+    async fn test_evict_image_cache_in_batches() {
+        let (dir, conn) = isolated_database("cache-evict-batches").await;
+        let size = CacheSize::new();
+
+        // 超过两批的记录，按 created_at 从旧到新排列
+        let total = EVICT_BATCH_SIZE as i64 * 2 + 50;
+        let base = Utc::now().naive_utc() - Duration::hours(1);
+        let records = (0..total).map(|i| {
+            let path = dir.join(format!("{}.jpg", i)).to_string_lossy().to_string();
+            let mut record = cache_record(&format!("https://example.com/{}.jpg", i), &path, 10);
+            record.created_at = Set(base + Duration::seconds(i));
+            record
+        });
+        image_cache::Entity::insert_many(records).exec(&conn).await.unwrap();
+
+        // 只保留最新的 5 条
+        let removed = evict_image_cache(&conn, &size, 50).await.unwrap();
+        assert_eq!(removed, total as u64 - 5);
+        assert_eq!(*size.0.lock().unwrap(), Some(50));
+
+        let remaining: Vec<String> = image_cache::Entity::find()
+            .order_by_asc(image_cache::Column::CreatedAt)
+            .all(&conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.url)
+            .collect();
+        let expected: Vec<String> = (total - 5..total).map(|i| format!("https://example.com/{}.jpg", i)).collect();
+        assert_eq!(remaining, expected);
+
+        // 已在上限以内时不再删除
+        assert_eq!(evict_image_cache(&conn, &size, 50).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_cache_image_uses_headers() {
+        database::init_test_database().await;
+        let server = TestServer::start(|req| {
+            let status = if req.header("referer") == Some("https://example.com/") { 200 } else { 403 };
+            TestResponse::ok("png-bytes").with_status(status)
+        });
+
+        let module_id = "test_fetch_cache".to_string();
+        let dir = std::env::temp_dir().join(format!("comics-images-{}", uuid::Uuid::new_v4()));
+        let mut headers = std::collections::HashMap::new();
+        headers.insert("Referer".to_string(), "https://example.com/".to_string());
+        let info = RemoteImageInfo::from_url_with_headers(server.url("/a/1.png"), headers);
+
+        let path = fetch_and_cache_image_to(&dir, module_id.clone(), info.clone(), None).await.unwrap();
+        assert!(path.ends_with(".png"));
+        assert_eq!(fs::read(&path).await.unwrap(), b"png-bytes");
+
+        // 第二次直接命中缓存，不再请求服务器
+        assert_eq!(fetch_and_cache_image_to(&dir, module_id.clone(), info, None).await.unwrap(), path);
+        assert_eq!(server.requests().len(), 1);
+
+        clear_image_cache_by_module(module_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_chapter_images() {
+        database::init_test_database().await;
+        // 第一轮请求三张图片（其中一张 404），第二轮预取应全部命中缓存
+        let server = TestServer::start(|req| {
+            if req.path.starts_with("/missing") { TestResponse::status(404) } else { TestResponse::ok("img") }
+        });
+
+        let module_id = "test_prefetch".to_string();
+        let dir = std::env::temp_dir().join(format!("comics-prefetch-{}", uuid::Uuid::new_v4()));
+        let images = parse_picture_list(&serde_json::json!([
+            server.url("/1.jpg"),
+            { "original_name": "", "file_server": "", "path": server.url("/2.webp") },
+            server.url("/missing.jpg"),
+        ]).to_string()).unwrap();
+
+        let mut items = Vec::new();
+        let summary = prefetch_images_to(dir.clone(), module_id.clone(), images.clone(), None, 2, |item| items.push(item)).await;
+        assert_eq!((summary.succeeded, summary.failed, summary.skipped), (2, 1, 0));
+        items.sort_by_key(|item| item.index);
+        assert!(items[1].path.as_ref().unwrap().ends_with(".webp"));
+        assert!(items[2].error.is_some());
+
+        let summary = prefetch_images_to(dir, module_id.clone(), images[..2].to_vec(), None, 2, |_| {}).await;
+        assert_eq!((summary.succeeded, summary.failed, summary.skipped), (2, 0, 2));
+        assert_eq!(server.requests().len(), 3);
+
+        clear_image_cache_by_module(module_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_image_cache_stats_aggregation() {
+        // 使用独立数据库，避免与其他测试的缓存记录互相影响
+        let (_, conn) = isolated_database("stats").await;
+
+        let now = Utc::now().naive_utc();
+        let models: Vec<image_cache::ActiveModel> = (0..3000).map(|i| image_cache::ActiveModel {
+            cache_key: Set(format!("key-{}", i)),
+            module_id: Set("stats".to_string()),
+            url: Set(format!("https://example.com/{}.jpg", i)),
+            file_path: Set(format!("/tmp/{}.jpg", i)),
+            content_type: Set("image/jpeg".to_string()),
+            file_size: Set(10),
+            // 前 1000 条已过期
+            expire_at: Set(if i < 1000 { now - Duration::days(1) } else { now + Duration::days(1) }),
+            created_at: Set(now),
+        }).collect();
+        for chunk in models.chunks(500) {
+            image_cache::Entity::insert_many(chunk.to_vec()).exec(&conn).await.unwrap();
+        }
+
+        let stats = image_cache_stats(&conn).await.unwrap();
+        assert_eq!(stats.total_count, 3000);
+        assert_eq!(stats.expired_count, 1000);
+        assert_eq!(stats.valid_count, 2000);
+        assert_eq!(stats.total_size, 30000);
+
+        let (_, empty) = isolated_database("stats-empty").await;
+        let stats = image_cache_stats(&empty).await.unwrap();
+        assert_eq!((stats.total_count, stats.total_size), (0, 0));
+    }
+}
@@ -8,6 +8,14 @@ pub async fn init_application(root_path: String) -> anyhow::Result<()> {
     crate::init_application(root_path).await
 }
 
+/// 初始化应用并使用 SQLCipher 密钥打开加密数据库
+/// 
+/// 需要以 sqlcipher feature 编译，密钥错误时返回错误，可使用正确的密钥重试
+#[frb]
+pub async fn init_application_with_key(root_path: String, db_key: Option<String>) -> anyhow::Result<()> {
+    crate::init_application_with_key(root_path, db_key).await
+}
+
 /// FRB 初始化
 #[frb(init)]
 pub fn init_frb() {
@@ -0,0 +1,223 @@
+use flutter_rust_bridge::frb;
+use once_cell::sync::{Lazy, OnceCell};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{reload, Layer, Registry};
+
+/// 默认日志级别
+const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::INFO;
+
+/// 默认保留的日志行数
+const DEFAULT_LOG_BUFFER_SIZE: usize = 1000;
+
+/// 日志级别过滤器的热更新句柄，日志初始化成功后才会设置
+static LEVEL_HANDLE: OnceCell<reload::Handle<LevelFilter, Registry>> = OnceCell::new();
+
+/// 最近的日志行，供 Flutter 导出用于问题反馈
+static LOG_BUFFER: Lazy<Mutex<LogBuffer>> = Lazy::new(|| Mutex::new(LogBuffer::new(DEFAULT_LOG_BUFFER_SIZE)));
+
+/// 有界的日志行缓冲，超出容量时丢弃最早的行
+struct LogBuffer {
+    lines: VecDeque<String>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    fn new(capacity: usize) -> Self {
+        Self { lines: VecDeque::new(), capacity }
+    }
+
+    fn push(&mut self, line: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.lines.len() >= self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.lines.len() > capacity {
+            self.lines.pop_front();
+        }
+    }
+
+    /// 最近的 max_lines 行，按时间顺序
+    fn recent(&self, max_lines: usize) -> Vec<String> {
+        let skip = self.lines.len().saturating_sub(max_lines);
+        self.lines.iter().skip(skip).cloned().collect()
+    }
+}
+
+/// 把日志事件格式化为一行文本
+#[derive(Default)]
+struct LineVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for LineVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+}
+
+/// 把日志写入 LOG_BUFFER 的 tracing 层，受全局日志级别过滤
+struct RingBufferLayer;
+
+impl<S: Subscriber> Layer<S> for RingBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = LineVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let line = format!(
+            "{} {:>5} {}: {}{}",
+            chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ"),
+            metadata.level(),
+            metadata.target(),
+            visitor.message,
+            visitor.fields,
+        );
+        if let Ok(mut buffer) = LOG_BUFFER.lock() {
+            buffer.push(line);
+        }
+    }
+}
+
+/// 初始化日志（只初始化一次），级别可通过 `set_log_level` 在运行时调整
+pub(crate) fn init_logging() {
+    let (filter, handle) = reload::Layer::new(DEFAULT_LOG_LEVEL);
+    let result = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(RingBufferLayer)
+        .try_init();
+    if result.is_ok() {
+        let _ = LEVEL_HANDLE.set(handle);
+    }
+}
+
+/// 解析日志级别，只接受 trace / debug / info / warn / error（不区分大小写）
+fn parse_level(level: &str) -> anyhow::Result<LevelFilter> {
+    match level.trim().to_lowercase().as_str() {
+        "trace" => Ok(LevelFilter::TRACE),
+        "debug" => Ok(LevelFilter::DEBUG),
+        "info" => Ok(LevelFilter::INFO),
+        "warn" => Ok(LevelFilter::WARN),
+        "error" => Ok(LevelFilter::ERROR),
+        _ => Err(anyhow::anyhow!("Unknown log level: {}", level)),
+    }
+}
+
+/// 设置日志级别，立即生效（不持久化，重启后恢复为 info）
+/// 
+/// # 参数
+/// - `level`: "trace"、"debug"、"info"、"warn" 或 "error"
+#[frb(sync)]
+pub fn set_log_level(level: String) -> anyhow::Result<()> {
+    let filter = parse_level(&level)?;
+    let handle = LEVEL_HANDLE.get()
+        .ok_or_else(|| anyhow::anyhow!("Logging not initialized"))?;
+    handle.reload(filter)?;
+    tracing::info!("日志级别已设置为 {}", filter);
+    Ok(())
+}
+
+/// 获取当前日志级别
+#[frb(sync)]
+pub fn get_log_level() -> String {
+    LEVEL_HANDLE.get()
+        .and_then(|handle| handle.clone_current())
+        .unwrap_or(DEFAULT_LOG_LEVEL)
+        .to_string()
+        .to_lowercase()
+}
+
+/// 获取最近的日志（按时间顺序，最多 max_lines 行）
+/// 
+/// 配合 `set_log_level("debug")` 复现问题后导出，用于问题反馈
+#[frb(sync)]
+pub fn get_recent_logs(max_lines: usize) -> Vec<String> {
+    LOG_BUFFER.lock()
+        .map(|buffer| buffer.recent(max_lines))
+        .unwrap_or_default()
+}
+
+/// 设置内存中保留的日志行数，为 0 时不再保留
+#[frb(sync)]
+pub fn set_log_buffer_size(size: usize) {
+    if let Ok(mut buffer) = LOG_BUFFER.lock() {
+        buffer.set_capacity(size);
+    }
+}
+
+/// 清空内存中的日志
+#[frb(sync)]
+pub fn clear_recent_logs() {
+    if let Ok(mut buffer) = LOG_BUFFER.lock() {
+        buffer.lines.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(parse_level("debug").unwrap(), LevelFilter::DEBUG);
+        assert_eq!(parse_level(" WARN ").unwrap(), LevelFilter::WARN);
+        assert!(parse_level("verbose").is_err());
+        assert!(parse_level("off").is_err());
+        assert!(set_log_level("loud".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_log_buffer_bounded() {
+        let mut buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(format!("line {}", i));
+        }
+        assert_eq!(buffer.recent(10), vec!["line 2", "line 3", "line 4"]);
+        assert_eq!(buffer.recent(1), vec!["line 4"]);
+
+        buffer.set_capacity(1);
+        assert_eq!(buffer.recent(10), vec!["line 4"]);
+        buffer.set_capacity(0);
+        buffer.push("dropped".to_string());
+        assert!(buffer.recent(10).is_empty());
+    }
+
+    #[test]
+    fn test_ring_buffer_layer_captures_events() {
+        let subscriber = tracing_subscriber::registry().with(RingBufferLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(module = "demo", "ring buffer test event");
+        });
+
+        let lines = get_recent_logs(usize::MAX);
+        let line = lines.iter().find(|l| l.contains("ring buffer test event")).unwrap();
+        assert!(line.contains("WARN"));
+        assert!(line.contains("module=demo"));
+    }
+}
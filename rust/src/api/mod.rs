@@ -7,3 +7,13 @@ pub mod crypto_api;
 pub mod image_cache_api;
 pub mod image_api;
 pub mod proxy_api;
+pub mod download_api;
+pub mod web_cache_api;
+pub mod history_api;
+pub mod search_history_api;
+pub mod backup_api;
+pub mod db_api;
+pub mod progress_api;
+pub mod stats_api;
+pub mod log_api;
+pub mod cancel_api;
@@ -1,18 +1,25 @@
 use flutter_rust_bridge::frb;
 use crate::modules::{
-    ModuleInfo, Category, ComicSimple, ComicDetail, 
-    ComicsPage, EpPage, PicturePage, SortOption,
+    ModuleInfo, Category, Tag, ComicSimple, ComicDetail, 
+    ComicsPage, EpPage, PicturePage, SortOption, ModuleError,
+    ModuleUpdateInfo, ModuleUpdateResult, ModuleCapabilities,
+    AggregatedSearchResult, ModuleSearchPage, ModuleSearchError, CommentPage, LoginResult,
+    ModuleCallOutput, FunctionMetrics, BatchCall, BatchCallResult,
+    ModuleLoadError, ModulePreloadResult,
 };
 
 // 由于 ModuleManager 需要状态管理，我们使用全局单例
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 use crate::modules::ModuleManager;
+use crate::js_engine::RuntimeLimits;
+use crate::cancel;
 
-static MODULE_MANAGER: OnceCell<Arc<RwLock<ModuleManager>>> = OnceCell::new();
+/// 模块管理器内部自行加锁（实例表、每个模块的运行时），这里不再包一层全局锁，
+/// 调用期间不会持有任何全局锁
+static MODULE_MANAGER: OnceCell<ModuleManager> = OnceCell::new();
 
-fn get_module_manager() -> anyhow::Result<&'static Arc<RwLock<ModuleManager>>> {
+pub(crate) fn get_module_manager() -> anyhow::Result<&'static ModuleManager> {
     MODULE_MANAGER.get()
         .ok_or_else(|| anyhow::anyhow!("Module manager not initialized. Call init_application first."))
 }
@@ -20,81 +27,112 @@ fn get_module_manager() -> anyhow::Result<&'static Arc<RwLock<ModuleManager>>> {
 /// 初始化模块管理器（内部使用）
 pub(crate) fn init_module_manager(modules_dir: &std::path::Path) -> anyhow::Result<()> {
     let manager = ModuleManager::new(modules_dir);
-    MODULE_MANAGER.set(Arc::new(RwLock::new(manager)))
+    MODULE_MANAGER.set(manager)
         .map_err(|_| anyhow::anyhow!("Module manager already initialized"))?;
     Ok(())
 }
 
+const MAX_LOADED_MODULES_SETTING_KEY: &str = "max_loaded_modules";
+
+/// 热重载的防抖时间
+const HOT_RELOAD_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// 开发用的模块脚本监听，None 表示未开启
+static HOT_RELOAD_WATCHER: once_cell::sync::Lazy<std::sync::Mutex<Option<crate::modules::hot_reload::HotReloadWatcher>>> =
+    once_cell::sync::Lazy::new(|| std::sync::Mutex::new(None));
+
+/// 加载运行时数量上限设置（内部使用，在应用启动时调用）
+pub(crate) async fn init_module_pool() -> anyhow::Result<()> {
+    let max = crate::api::property_api::load_app_setting(MAX_LOADED_MODULES_SETTING_KEY.to_string())
+        .await?
+        .and_then(|v| v.parse::<usize>().ok());
+    if let Some(max) = max {
+        let manager = get_module_manager()?;
+        manager.set_max_loaded(max).await;
+    }
+    Ok(())
+}
+
 // ============ Flutter API ============
 
 /// 获取所有已注册的模块列表
 #[frb]
 pub async fn get_modules() -> anyhow::Result<Vec<ModuleInfo>> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     m.list_modules().await
 }
 
 /// 扫描并注册所有模块
 #[frb]
 pub async fn scan_and_register_modules() -> anyhow::Result<Vec<ModuleInfo>> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     m.scan_and_register_all().await
 }
 
 /// 注册单个模块
 #[frb]
 pub async fn register_module(module_id: String) -> anyhow::Result<ModuleInfo> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     m.register_module(&module_id).await
 }
 
 /// 通过URL导入插件
 #[frb]
 pub async fn import_module_from_url(url: String) -> anyhow::Result<ModuleInfo> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     m.import_from_url(&url).await
 }
 
-/// 更新插件（如果有URL来源）
+/// 从URL安装插件，`overwrite` 为 false 时拒绝覆盖已安装的同名模块
 #[frb]
-pub async fn update_module(module_id: String) -> anyhow::Result<ModuleInfo> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.update_module(&module_id).await
+pub async fn install_module_from_url(url: String, overwrite: bool) -> anyhow::Result<ModuleInfo> {
+    let m = get_module_manager()?;
+    m.install_from_url(&url, overwrite).await
+}
+
+/// 更新插件（如果有URL来源），只有远程版本更新时才替换
+#[frb]
+pub async fn update_module(module_id: String) -> anyhow::Result<ModuleUpdateResult> {
+    let m = get_module_manager()?;
+    m.update_module(&module_id, false).await
 }
 
-/// 删除插件
+/// 强制从来源URL重新安装插件，忽略版本比较
+#[frb]
+pub async fn force_update_module(module_id: String) -> anyhow::Result<ModuleUpdateResult> {
+    let m = get_module_manager()?;
+    m.update_module(&module_id, true).await
+}
+
+/// 检查所有插件的可用更新（不应用）
+#[frb]
+pub async fn check_updates() -> anyhow::Result<Vec<ModuleUpdateInfo>> {
+    let m = get_module_manager()?;
+    m.check_updates().await
+}
+
+/// 删除插件（保留模块的配置和缓存）
 #[frb]
 pub async fn delete_module(module_id: String) -> anyhow::Result<()> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    
-    // 先卸载模块
-    m.unload_module(&module_id).await?;
-    
-    // 删除数据库记录
-    let db = database::get_database()
-        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
-    let conn = db.read().await;
-    
-    use sea_orm::EntityTrait;
-    database::entities::module_info::Entity::delete_by_id(&module_id)
-        .exec(&*conn)
-        .await?;
+    uninstall_module(module_id, false).await
+}
+
+/// 完全卸载插件：删除脚本文件和模块记录
+/// 
+/// `purge_data` 为 true 时同时清除模块的配置项、图片缓存和网页缓存；
+/// 模块正在执行调用时返回错误
+#[frb]
+pub async fn uninstall_module(module_id: String, purge_data: bool) -> anyhow::Result<()> {
+    let m = get_module_manager()?;
+    m.uninstall_module(&module_id).await?;
     
-    // 删除脚本文件
-    if let Some(modules_dir) = crate::get_modules_dir() {
-        let script_path = modules_dir.join(format!("{}.js", module_id));
-        if script_path.exists() {
-            tokio::fs::remove_file(script_path).await?;
-        }
+    if purge_data {
+        crate::api::property_api::clear_module_properties(module_id.clone()).await?;
+        crate::api::image_cache_api::clear_image_cache_by_module(module_id.clone()).await?;
+        crate::api::web_cache_api::clear_web_cache_by_module(module_id.clone()).await?;
+        tracing::info!("Module data purged: {}", module_id);
     }
     
-    tracing::info!("Module deleted: {}", module_id);
     Ok(())
 }
 
@@ -125,48 +163,50 @@ pub async fn set_module_source_url(module_id: String, source_url: Option<String>
 
 /// 加载模块
 #[frb]
-pub async fn load_module(module_id: String) -> anyhow::Result<()> {
+pub async fn load_module(module_id: String) -> Result<(), ModuleError> {
     tracing::debug!("[API] load_module: {}", module_id);
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     let result = m.load_module(&module_id).await;
     tracing::debug!("[API] load_module result: {:?}", result.is_ok());
-    result
+    Ok(result?)
 }
 
 /// 卸载模块
 #[frb]
 pub async fn unload_module(module_id: String) -> anyhow::Result<()> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     m.unload_module(&module_id).await
 }
 
 /// 启用/禁用模块
 #[frb]
 pub async fn set_module_enabled(module_id: String, enabled: bool) -> anyhow::Result<()> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     m.set_module_enabled(&module_id, enabled).await
 }
 
+/// 获取模块支持的可选功能（搜索、评论、登录、下载、图片处理）
+#[frb]
+pub async fn get_module_capabilities(module_id: String) -> Result<ModuleCapabilities, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_capabilities(&module_id).await?)
+}
+
 /// 获取模块的分类列表
 #[frb]
-pub async fn get_categories(module_id: String) -> anyhow::Result<Vec<Category>> {
+pub async fn get_categories(module_id: String) -> Result<Vec<Category>, ModuleError> {
     tracing::debug!("[API] get_categories: {}", module_id);
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
+    let m = get_module_manager()?;
     let result = m.get_categories(&module_id).await;
     tracing::debug!("[API] get_categories result: {:?}", result.as_ref().map(|v| v.len()));
-    result
+    Ok(result?)
 }
 
 /// 获取排序选项
 #[frb]
-pub async fn get_sort_options(module_id: String) -> anyhow::Result<Vec<SortOption>> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.get_sort_options(&module_id).await
+pub async fn get_sort_options(module_id: String) -> Result<Vec<SortOption>, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_sort_options(&module_id).await?)
 }
 
 /// 获取漫画列表 (参考 pikapika comics)
@@ -176,26 +216,49 @@ pub async fn get_comics(
     category_slug: String, 
     sort_by: String,
     page: i32
-) -> anyhow::Result<ComicsPage> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.get_comics(&module_id, &category_slug, &sort_by, page).await
+) -> Result<ComicsPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_comics(&module_id, &category_slug, &sort_by, page).await?)
+}
+
+/// 获取标签列表，模块不支持时返回空列表
+#[frb]
+pub async fn get_tags(module_id: String) -> Result<Vec<Tag>, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_tags(&module_id).await?)
+}
+
+/// 按标签获取漫画列表，模块不支持时返回 Unsupported 错误
+#[frb]
+pub async fn get_comics_by_tag(
+    module_id: String,
+    tag: String,
+    sort_by: String,
+    page: i32
+) -> Result<ComicsPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_comics_by_tag(&module_id, &tag, &sort_by, page).await?)
+}
+
+/// 获取作者的作品列表，模块不支持时以作者名搜索
+#[frb]
+pub async fn get_comics_by_author(module_id: String, author: String, page: i32) -> Result<ComicsPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_comics_by_author(&module_id, &author, page).await?)
 }
 
 /// 获取漫画详情 (参考 pikapika album/comicInfo)
 #[frb]
-pub async fn get_comic_detail(module_id: String, comic_id: String) -> anyhow::Result<ComicDetail> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.get_comic_detail(&module_id, &comic_id).await
+pub async fn get_comic_detail(module_id: String, comic_id: String) -> Result<ComicDetail, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_comic_detail(&module_id, &comic_id).await?)
 }
 
 /// 获取章节列表 (参考 pikapika eps)
 #[frb]
-pub async fn get_eps(module_id: String, comic_id: String, page: i32) -> anyhow::Result<EpPage> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.get_eps(&module_id, &comic_id, page).await
+pub async fn get_eps(module_id: String, comic_id: String, page: i32) -> Result<EpPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_eps(&module_id, &comic_id, page).await?)
 }
 
 /// 获取章节图片 (参考 pikapika pictures)
@@ -205,10 +268,37 @@ pub async fn get_pictures(
     comic_id: String, 
     ep_id: String,
     page: i32
-) -> anyhow::Result<PicturePage> {
-    let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.get_pictures(&module_id, &comic_id, &ep_id, page).await
+) -> Result<PicturePage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_pictures(&module_id, &comic_id, &ep_id, page).await?)
+}
+
+/// 获取章节图片，可通过 `cancel_api::cancel(cancel_token)` 取消
+/// 
+/// 取消后返回 `ModuleError::Cancelled`，正在执行的模块脚本会被中断
+#[frb]
+pub async fn get_pictures_cancelable(
+    module_id: String,
+    comic_id: String,
+    ep_id: String,
+    page: i32,
+    cancel_token: String,
+) -> Result<PicturePage, ModuleError> {
+    let m = get_module_manager()?;
+    let registered = cancel::register(Some(cancel_token));
+    get_pictures_with_cancel(m, &module_id, &comic_id, &ep_id, page, registered.as_ref().map(|r| r.token())).await
+}
+
+/// 内部使用：通过指定的模块管理器获取章节图片，可传入已注册的取消令牌
+pub(crate) async fn get_pictures_with_cancel(
+    manager: &ModuleManager,
+    module_id: &str,
+    comic_id: &str,
+    ep_id: &str,
+    page: i32,
+    cancel: Option<&cancel::CancelToken>,
+) -> Result<PicturePage, ModuleError> {
+    Ok(manager.get_pictures_cancelable(module_id, comic_id, ep_id, page, cancel).await?)
 }
 
 /// 搜索漫画 (参考 pikapika search)
@@ -218,18 +308,367 @@ pub async fn search_comics(
     keyword: String, 
     sort_by: String,
     page: i32
-) -> anyhow::Result<ComicsPage> {
+) -> Result<ComicsPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.search(&module_id, &keyword, &sort_by, page).await?)
+}
+
+/// 获取漫画评论，模块不支持评论时返回空页
+#[frb]
+pub async fn get_comments(module_id: String, comic_id: String, page: i32) -> Result<CommentPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_comments(&module_id, &comic_id, page).await?)
+}
+
+/// 获取相关推荐漫画（"猜你喜欢"），模块不支持时返回空列表
+#[frb]
+pub async fn get_recommend(module_id: String, comic_id: String, page: i32) -> Result<ComicsPage, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_recommend(&module_id, &comic_id, page).await?)
+}
+
+/// 随机获取一部漫画，模块没有漫画时返回 None
+#[frb]
+pub async fn get_random_comic(module_id: String) -> Result<Option<ComicSimple>, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_random_comic(&module_id).await?)
+}
+
+/// 发表评论，模块不支持时返回 Unsupported 错误
+#[frb]
+pub async fn post_comment(module_id: String, comic_id: String, content: String) -> Result<(), ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.post_comment(&module_id, &comic_id, &content).await?)
+}
+
+/// 登录模块，credentials_json 原样传给模块的 login 函数（如 {"username": "...", "password": "..."}）
+#[frb]
+pub async fn login(module_id: String, credentials_json: String) -> Result<LoginResult, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.login(&module_id, &credentials_json).await?)
+}
+
+/// 登出模块并清除保存的会话
+#[frb]
+pub async fn logout(module_id: String) -> Result<(), ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.logout(&module_id).await?)
+}
+
+/// 获取模块的登录状态
+#[frb]
+pub async fn get_login_status(module_id: String) -> Result<bool, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.get_login_status(&module_id).await?)
+}
+
+/// 切换服务端收藏状态，返回新状态；模块不支持时返回 Unsupported 错误，可改用本地收藏
+#[frb]
+pub async fn toggle_favourite(module_id: String, comic_id: String) -> Result<bool, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.toggle_favourite(&module_id, &comic_id).await?)
+}
+
+/// 切换服务端点赞状态，返回新状态；模块不支持时返回 Unsupported 错误
+#[frb]
+pub async fn toggle_like(module_id: String, comic_id: String) -> Result<bool, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.toggle_like(&module_id, &comic_id).await?)
+}
+
+const SEARCH_ALL_CONCURRENCY_SETTING_KEY: &str = "search_all_concurrency";
+/// 默认同时搜索的模块数
+const DEFAULT_SEARCH_ALL_CONCURRENCY: usize = 4;
+/// 聚合搜索时单个模块的超时时间
+const SEARCH_ALL_MODULE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// 设置聚合搜索的并发模块数，None 恢复默认值
+#[frb]
+pub async fn set_search_all_concurrency(limit: Option<u32>) -> anyhow::Result<()> {
+    match limit {
+        Some(limit) if limit > 0 => {
+            crate::api::property_api::save_app_setting(SEARCH_ALL_CONCURRENCY_SETTING_KEY.to_string(), limit.to_string()).await
+        }
+        Some(_) => Err(anyhow::anyhow!("Concurrency limit must be positive")),
+        None => crate::api::property_api::delete_app_setting(SEARCH_ALL_CONCURRENCY_SETTING_KEY.to_string()).await,
+    }
+}
+
+/// 获取聚合搜索的并发模块数
+#[frb]
+pub async fn get_search_all_concurrency() -> anyhow::Result<u32> {
+    let value = crate::api::property_api::load_app_setting(SEARCH_ALL_CONCURRENCY_SETTING_KEY.to_string()).await?;
+    Ok(value
+        .and_then(|v| v.parse::<u32>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_SEARCH_ALL_CONCURRENCY as u32))
+}
+
+/// 在所有已启用的模块中搜索
+/// 
+/// 各模块并发搜索（受并发数设置限制），每个模块单独超时；
+/// 单个模块失败只记录在 errors 中，不影响整体结果
+#[frb]
+pub async fn search_all(keyword: String, page: i32) -> anyhow::Result<AggregatedSearchResult> {
     let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.search(&module_id, &keyword, &sort_by, page).await
+    let modules: Vec<ModuleInfo> = manager.list_modules().await?
+        .into_iter()
+        .filter(|module| module.enabled)
+        .collect();
+    
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(get_search_all_concurrency().await? as usize));
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, module) in modules.into_iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let keyword = keyword.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let search = manager.search_with_timeout(&module.id, &keyword, "", page, Some(SEARCH_ALL_MODULE_TIMEOUT));
+            let result = match tokio::time::timeout(SEARCH_ALL_MODULE_TIMEOUT, search).await {
+                Ok(result) => result.map_err(|e| ModuleError::from(e).to_string()),
+                Err(_) => Err(format!("Search timed out after {:?}", SEARCH_ALL_MODULE_TIMEOUT)),
+            };
+            (index, module.id, result)
+        });
+    }
+    
+    let mut outcomes = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        outcomes.push(joined?);
+    }
+    // 保持模块列表的顺序
+    outcomes.sort_by_key(|(index, _, _)| *index);
+    
+    let mut aggregated = AggregatedSearchResult {
+        results: Vec::new(),
+        errors: Vec::new(),
+    };
+    for (_, module_id, result) in outcomes {
+        match result {
+            Ok(mut page) => {
+                for comic in &mut page.docs {
+                    comic.module_id = Some(module_id.clone());
+                }
+                aggregated.results.push(ModuleSearchPage { module_id, page });
+            }
+            Err(message) => {
+                tracing::warn!("[API] search_all failed for {}: {}", module_id, message);
+                aggregated.errors.push(ModuleSearchError { module_id, message });
+            }
+        }
+    }
+    
+    Ok(aggregated)
+}
+
+/// 预加载模块运行时，避免首次使用时解析脚本造成的卡顿
+/// 
+/// ids 为空时预加载所有已启用的模块；已加载的模块会跳过，已禁用的模块记为失败。
+/// 各模块并发加载，加载数量超过运行时上限时较早加载的会被卸载
+#[frb]
+pub async fn preload_modules(ids: Vec<String>) -> anyhow::Result<ModulePreloadResult> {
+    preload_modules_with(get_module_manager()?, ids).await
+}
+
+async fn preload_modules_with(manager: &'static ModuleManager, ids: Vec<String>) -> anyhow::Result<ModulePreloadResult> {
+    let ids = if ids.is_empty() {
+        manager.list_modules().await?
+            .into_iter()
+            .filter(|module| module.enabled)
+            .map(|module| module.id)
+            .collect()
+    } else {
+        ids
+    };
+    
+    let mut result = ModulePreloadResult::default();
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, module_id) in ids.into_iter().enumerate() {
+        if manager.is_loaded(&module_id).await {
+            result.skipped.push(module_id);
+            continue;
+        }
+        tasks.spawn(async move {
+            let loaded = manager.load_module(&module_id).await
+                .map_err(|e| ModuleError::from(e).to_string());
+            (index, module_id, loaded)
+        });
+    }
+    
+    let mut outcomes = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        outcomes.push(joined?);
+    }
+    outcomes.sort_by_key(|(index, _, _)| *index);
+    
+    for (_, module_id, loaded) in outcomes {
+        match loaded {
+            Ok(()) => result.loaded.push(module_id),
+            Err(message) => {
+                tracing::warn!("[API] preload failed for {}: {}", module_id, message);
+                result.failed.push(ModuleLoadError { module_id, message });
+            }
+        }
+    }
+    
+    Ok(result)
 }
 
 /// 调用模块的任意函数（高级 API）
 #[frb]
-pub async fn call_module_function(module_id: String, func_name: String, args_json: String) -> anyhow::Result<String> {
+pub async fn call_module_function(module_id: String, func_name: String, args_json: String) -> Result<String, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.call_function(&module_id, &func_name, &args_json, None).await?)
+}
+
+/// 在同一模块上依次调用多个函数（如打开漫画时的详情、章节和首页图片），减少跨边界调用次数
+/// 
+/// 单个调用失败不影响其他调用，结果与 calls 一一对应
+#[frb]
+pub async fn batch_call(module_id: String, calls: Vec<BatchCall>) -> Result<Vec<BatchCallResult>, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.batch_call(&module_id, &calls).await?)
+}
+
+/// 调用模块的任意函数，并指定执行超时（毫秒）
+#[frb]
+pub async fn call_module_function_with_timeout(
+    module_id: String,
+    func_name: String,
+    args_json: String,
+    timeout_ms: u64,
+) -> Result<String, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.call_function(&module_id, &func_name, &args_json, Some(std::time::Duration::from_millis(timeout_ms))).await?)
+}
+
+/// 调用模块的任意函数，并返回调用期间的 console 日志（模块调试用）
+#[frb]
+pub async fn call_module_function_with_logs(
+    module_id: String,
+    func_name: String,
+    args_json: String,
+) -> Result<ModuleCallOutput, ModuleError> {
+    let m = get_module_manager()?;
+    Ok(m.call_function_with_logs(&module_id, &func_name, &args_json, None).await?)
+}
+
+/// 设置最多同时保留的模块运行时数量（至少为 1）
+/// 
+/// 超出上限时卸载最久未使用的模块，被卸载的模块下次调用时会重新加载
+#[frb]
+pub async fn set_max_loaded_modules(max: u32) -> anyhow::Result<()> {
+    let max = max.max(1);
+    let manager = get_module_manager()?;
+    manager.set_max_loaded(max as usize).await;
+    crate::api::property_api::save_app_setting(MAX_LOADED_MODULES_SETTING_KEY.to_string(), max.to_string()).await
+}
+
+/// 获取最多同时保留的模块运行时数量
+#[frb]
+pub async fn get_max_loaded_modules() -> anyhow::Result<u32> {
     let manager = get_module_manager()?;
-    let m = manager.read().await;
-    m.call_function(&module_id, &func_name, &args_json).await
+    let max = manager.max_loaded();
+    Ok(max as u32)
+}
+
+/// 开启或关闭模块脚本热重载（开发用，默认关闭，不持久化）
+/// 
+/// 开启后监听模块目录，`.js` 文件变化时卸载对应模块的运行时，下次调用时重新加载脚本
+#[frb]
+pub async fn set_dev_hot_reload(enabled: bool) -> anyhow::Result<()> {
+    let mut watcher = HOT_RELOAD_WATCHER.lock()
+        .map_err(|e| anyhow::anyhow!("获取热重载锁失败: {}", e))?;
+    if !enabled {
+        if watcher.take().is_some() {
+            tracing::info!("模块热重载已关闭");
+        }
+        return Ok(());
+    }
+    if watcher.is_some() {
+        return Ok(());
+    }
+    
+    let modules_dir = crate::get_modules_dir()
+        .ok_or_else(|| anyhow::anyhow!("Modules dir not initialized"))?;
+    *watcher = Some(crate::modules::hot_reload::HotReloadWatcher::start(modules_dir, HOT_RELOAD_DEBOUNCE, |module_id| async move {
+        if let Ok(manager) = get_module_manager() {
+            let _ = manager.unload_module(&module_id).await;
+        }
+    })?);
+    tracing::info!("模块热重载已开启");
+    Ok(())
+}
+
+/// 模块脚本热重载是否已开启
+#[frb(sync)]
+pub fn is_dev_hot_reload_enabled() -> bool {
+    HOT_RELOAD_WATCHER.lock()
+        .map(|w| w.is_some())
+        .unwrap_or(false)
+}
+
+/// 设置模块运行时的内存上限（MB）和最大栈大小（KB），都为空时恢复默认值（64MB / 1024KB）
+/// 
+/// 只设置其中一项时另一项使用默认值；修改后模块会在下次调用时按新限制重新加载
+#[frb]
+pub async fn set_module_runtime_limits(
+    module_id: String,
+    memory_limit_mb: Option<u32>,
+    stack_size_kb: Option<u32>,
+) -> anyhow::Result<()> {
+    let limits = if memory_limit_mb.is_none() && stack_size_kb.is_none() {
+        None
+    } else {
+        let defaults = RuntimeLimits::default();
+        Some(RuntimeLimits {
+            memory_limit_mb: memory_limit_mb.unwrap_or(defaults.memory_limit_mb),
+            stack_size_kb: stack_size_kb.unwrap_or(defaults.stack_size_kb),
+        })
+    };
+    let m = get_module_manager()?;
+    m.set_runtime_limits(&module_id, limits).await
+}
+
+/// 获取模块运行时的内存和栈限制
+#[frb]
+pub async fn get_module_runtime_limits(module_id: String) -> anyhow::Result<RuntimeLimits> {
+    let m = get_module_manager()?;
+    m.runtime_limits(&module_id).await
+}
+
+/// 获取模块函数的调用统计（次数、耗时、最近错误），重启后清零
+#[frb]
+pub async fn get_module_metrics() -> anyhow::Result<Vec<FunctionMetrics>> {
+    let m = get_module_manager()?;
+    Ok(m.metrics())
+}
+
+/// 试运行模块脚本：在全新的 JS 运行时中加载脚本并调用指定函数
+/// 
+/// 不注册模块、不写入模块目录，使用与正式调用相同的内存限制和默认超时。
+/// 返回函数结果、console 日志以及异常调用栈。
+/// 脚本中的 storage 使用内存存储，试运行结束后丢弃
+#[frb]
+pub async fn test_module_script(script: String, func_name: String, args_json: String) -> anyhow::Result<ModuleCallOutput> {
+    const TEST_MODULE_ID: &str = "__test__";
+    
+    let runtime = crate::js_engine::JsRuntime::new()?;
+    runtime.use_memory_storage()?;
+    crate::js_engine::bindings::console::begin_capture();
+    if let Err(e) = runtime.load_module(TEST_MODULE_ID, &script) {
+        let logs = crate::js_engine::bindings::console::end_capture();
+        return Ok(ModuleCallOutput::from_result(Err(e), logs));
+    }
+    let load_logs = crate::js_engine::bindings::console::end_capture();
+    
+    runtime.reset_call_state(TEST_MODULE_ID)?;
+    let (result, logs) = runtime.call_function_json_with_logs(
+        &func_name,
+        &args_json,
+        crate::js_engine::runtime::DEFAULT_CALL_TIMEOUT,
+    );
+    Ok(ModuleCallOutput::from_result(result, load_logs.into_iter().chain(logs).collect()))
 }
 
 // ============ Storage API ============
@@ -310,3 +749,72 @@ pub async fn remove_module_storage(module_id: String, key: String) -> anyhow::Re
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_module_script_dry_run() {
+        let script = r#"
+            console.log("loaded");
+            function add(args) {
+                console.debug("adding", args.a, args.b);
+                return { sum: args.a + args.b };
+            }
+            function fail() {
+                throw new Error("bad input");
+            }
+        "#.to_string();
+
+        let output = test_module_script(script.clone(), "add".to_string(), r#"{"a":1,"b":2}"#.to_string()).await.unwrap();
+        assert_eq!(output.result.as_deref(), Some(r#"{"sum":3}"#));
+        let messages: Vec<&str> = output.logs.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, vec!["loaded", "adding 1 2"]);
+
+        let output = test_module_script(script, "fail".to_string(), "{}".to_string()).await.unwrap();
+        assert!(output.result.is_none());
+        assert!(output.error.unwrap().contains("bad input"));
+        assert!(output.stack.unwrap().contains("fail"));
+
+        // storage 写入不落到数据库
+        crate::database::init_test_database().await;
+        let script = r#"function save() { storage.set("k", "v"); return storage.get("k"); }"#.to_string();
+        let output = test_module_script(script, "save".to_string(), "{}".to_string()).await.unwrap();
+        assert_eq!(output.result.as_deref(), Some(r#""v""#));
+        assert_eq!(get_module_storage("__test__".to_string(), "k".to_string()).await.unwrap(), None);
+
+        let output = test_module_script("function (".to_string(), "add".to_string(), "{}".to_string()).await.unwrap();
+        assert!(output.error.is_some());
+        assert!(output.logs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_preload_modules() {
+        crate::database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let stubs: String = crate::js_engine::module_loader::REQUIRED_FUNCTIONS.iter()
+            .map(|func| format!("async function {}() {{ return {{}}; }}\n", func))
+            .collect();
+        let manager = ModuleManager::new(&dir);
+        for id in ["preload_a", "preload_b", "preload_disabled"] {
+            let script = format!("const moduleInfo = {{ id: \"{}\", name: \"{}\", version: \"1.0.0\" }};\n{}", id, id, stubs);
+            std::fs::write(dir.join(format!("{}.js", id)), script).unwrap();
+            manager.register_module(id).await.unwrap();
+        }
+        manager.set_module_enabled("preload_disabled", false).await.unwrap();
+        manager.load_module("preload_a").await.unwrap();
+        let manager: &'static ModuleManager = Box::leak(Box::new(manager));
+
+        let ids = ["preload_a", "preload_b", "preload_disabled", "preload_missing"]
+            .map(String::from)
+            .to_vec();
+        let result = preload_modules_with(manager, ids).await.unwrap();
+        assert_eq!(result.skipped, vec!["preload_a"]);
+        assert_eq!(result.loaded, vec!["preload_b"]);
+        let failed: Vec<&str> = result.failed.iter().map(|f| f.module_id.as_str()).collect();
+        assert_eq!(failed, vec!["preload_disabled", "preload_missing"]);
+        assert!(manager.is_loaded("preload_b").await);
+    }
+}
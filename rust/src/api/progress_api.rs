@@ -0,0 +1,156 @@
+use flutter_rust_bridge::frb;
+use sea_orm::{EntityTrait, QueryFilter, ColumnTrait, Set};
+use sea_orm::sea_query::OnConflict;
+use chrono::Utc;
+use std::collections::HashMap;
+
+use crate::database::{self, entities::chapter_progress};
+
+/// 章节阅读进度
+#[derive(Debug, Clone)]
+pub struct ChapterProgress {
+    pub module_id: String,
+    pub comic_id: String,
+    pub ep_id: String,
+    pub last_page: i32,
+    /// 章节总页数（未知时为 0）
+    pub total_pages: i32,
+    /// 更新时间（毫秒时间戳）
+    pub updated_at: i64,
+}
+
+impl From<chapter_progress::Model> for ChapterProgress {
+    fn from(model: chapter_progress::Model) -> Self {
+        Self {
+            module_id: model.module_id,
+            comic_id: model.comic_id,
+            ep_id: model.ep_id,
+            last_page: model.last_page,
+            total_pages: model.total_pages,
+            updated_at: model.updated_at.and_utc().timestamp_millis(),
+        }
+    }
+}
+
+/// 保存章节阅读进度（存在则更新）
+#[frb]
+pub async fn set_progress(
+    module_id: String,
+    comic_id: String,
+    ep_id: String,
+    last_page: i32,
+    total_pages: i32,
+) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let active_model = chapter_progress::ActiveModel {
+        module_id: Set(module_id),
+        comic_id: Set(comic_id),
+        ep_id: Set(ep_id),
+        last_page: Set(last_page),
+        total_pages: Set(total_pages),
+        updated_at: Set(Utc::now().naive_utc()),
+    };
+    // 单条 INSERT ... ON CONFLICT DO UPDATE，并发保存同一章节时不会主键冲突
+    chapter_progress::Entity::insert(active_model)
+        .on_conflict(
+            OnConflict::columns([
+                chapter_progress::Column::ModuleId,
+                chapter_progress::Column::ComicId,
+                chapter_progress::Column::EpId,
+            ])
+            .update_columns([
+                chapter_progress::Column::LastPage,
+                chapter_progress::Column::TotalPages,
+                chapter_progress::Column::UpdatedAt,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(&*conn)
+        .await?;
+    
+    Ok(())
+}
+
+/// 获取单个章节的阅读进度
+#[frb]
+pub async fn get_progress(module_id: String, comic_id: String, ep_id: String) -> anyhow::Result<Option<ChapterProgress>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let progress = chapter_progress::Entity::find_by_id((module_id, comic_id, ep_id))
+        .one(&*conn)
+        .await?;
+    
+    Ok(progress.map(ChapterProgress::from))
+}
+
+/// 获取漫画所有章节的阅读进度，返回 ep_id -> last_page
+#[frb]
+pub async fn get_comic_progress(module_id: String, comic_id: String) -> anyhow::Result<HashMap<String, i32>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let records = chapter_progress::Entity::find()
+        .filter(chapter_progress::Column::ModuleId.eq(&module_id))
+        .filter(chapter_progress::Column::ComicId.eq(&comic_id))
+        .all(&*conn)
+        .await?;
+    
+    Ok(records.into_iter().map(|r| (r.ep_id, r.last_page)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_chapter_progress_upsert() {
+        database::init_test_database().await;
+        let module_id = "test_progress".to_string();
+        let comic_id = "comic".to_string();
+
+        set_progress(module_id.clone(), comic_id.clone(), "ep1".to_string(), 3, 20).await.unwrap();
+        set_progress(module_id.clone(), comic_id.clone(), "ep1".to_string(), 12, 20).await.unwrap();
+        set_progress(module_id.clone(), comic_id.clone(), "ep2".to_string(), 1, 0).await.unwrap();
+        set_progress(module_id.clone(), "other".to_string(), "ep1".to_string(), 7, 10).await.unwrap();
+
+        let progress = get_progress(module_id.clone(), comic_id.clone(), "ep1".to_string()).await.unwrap().unwrap();
+        assert_eq!((progress.last_page, progress.total_pages), (12, 20));
+        assert!(get_progress(module_id.clone(), comic_id.clone(), "ep3".to_string()).await.unwrap().is_none());
+
+        let comic = get_comic_progress(module_id, comic_id).await.unwrap();
+        assert_eq!(comic.len(), 2);
+        assert_eq!(comic["ep1"], 12);
+        assert_eq!(comic["ep2"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_chapter_progress_ids_do_not_collide() {
+        database::init_test_database().await;
+        let module_id = "test_progress_ids".to_string();
+
+        // 旧的 ':' 拼接方式下两者的 id 相同
+        set_progress(module_id.clone(), "a:b".to_string(), "c".to_string(), 1, 0).await.unwrap();
+        set_progress(module_id.clone(), "a".to_string(), "b:c".to_string(), 2, 0).await.unwrap();
+        assert_eq!(get_progress(module_id.clone(), "a:b".to_string(), "c".to_string()).await.unwrap().unwrap().last_page, 1);
+        assert_eq!(get_progress(module_id.clone(), "a".to_string(), "b:c".to_string()).await.unwrap().unwrap().last_page, 2);
+
+        // 并发保存同一章节不会因主键冲突失败
+        let tasks: Vec<_> = (0..10).map(|page| {
+            let module_id = module_id.clone();
+            tokio::spawn(async move {
+                set_progress(module_id, "concurrent".to_string(), "ep".to_string(), page, 10).await
+            })
+        }).collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        let comic = get_comic_progress(module_id, "concurrent".to_string()).await.unwrap();
+        assert_eq!(comic.len(), 1);
+    }
+}
@@ -1,8 +1,9 @@
 use flutter_rust_bridge::frb;
-use sea_orm::{EntityTrait, QueryFilter, ColumnTrait, ActiveModelTrait, Set};
+use sea_orm::{EntityTrait, QueryFilter, QueryOrder, ColumnTrait, ActiveModelTrait, PaginatorTrait, Set};
 use chrono::Utc;
 
 use crate::database::{self, entities::property};
+use crate::modules::PageInfo;
 
 /// 保存属性
 #[frb]
@@ -97,6 +98,32 @@ pub async fn list_properties(module_id: String) -> anyhow::Result<Vec<PropertyIt
     }).collect())
 }
 
+/// 分页列出模块的属性（按 key 排序，page 从 1 开始）
+#[frb]
+pub async fn list_properties_paged(module_id: String, page: i32, limit: i32) -> anyhow::Result<PropertyPage> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let page = page.max(1);
+    let limit = limit.max(1);
+    
+    let paginator = property::Entity::find()
+        .filter(property::Column::ModuleId.eq(&module_id))
+        .order_by_asc(property::Column::Key)
+        .paginate(&*conn, limit as u64);
+    let total = paginator.num_items().await?;
+    let properties = paginator.fetch_page(page as u64 - 1).await?;
+    
+    Ok(PropertyPage {
+        page_info: PageInfo::new(page, limit, total as i32),
+        docs: properties.into_iter().map(|p| PropertyItem {
+            key: p.key,
+            value: p.value,
+        }).collect(),
+    })
+}
+
 /// 按前缀列出属性
 #[frb]
 pub async fn list_properties_by_prefix(module_id: String, prefix: String) -> anyhow::Result<Vec<PropertyItem>> {
@@ -117,6 +144,38 @@ pub async fn list_properties_by_prefix(module_id: String, prefix: String) -> any
     }).collect())
 }
 
+/// 按前缀删除属性，返回删除的条数
+#[frb]
+pub async fn delete_properties_by_prefix(module_id: String, prefix: String) -> anyhow::Result<u64> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    
+    // SQL 的 LIKE 不区分大小写，且前缀中的 `_`、`%` 会被当作通配符，
+    // 只用来粗筛，再按原样的前缀精确过滤后按 id 删除
+    let ids: Vec<String> = property::Entity::find()
+        .filter(property::Column::ModuleId.eq(&module_id))
+        .filter(property::Column::Key.starts_with(&prefix))
+        .all(&*conn)
+        .await?
+        .into_iter()
+        .filter(|p| p.key.starts_with(&prefix))
+        .map(|p| p.id)
+        .collect();
+    
+    let mut deleted = 0;
+    for chunk in ids.chunks(500) {
+        let result = property::Entity::delete_many()
+            .filter(property::Column::Id.is_in(chunk.to_vec()))
+            .exec(&*conn)
+            .await?;
+        deleted += result.rows_affected;
+    }
+    
+    Ok(deleted)
+}
+
 /// 清除模块的所有属性
 #[frb]
 pub async fn clear_module_properties(module_id: String) -> anyhow::Result<u64> {
@@ -140,10 +199,17 @@ pub struct PropertyItem {
     pub value: String,
 }
 
+/// 属性分页结果
+#[derive(Debug, Clone)]
+pub struct PropertyPage {
+    pub page_info: PageInfo,
+    pub docs: Vec<PropertyItem>,
+}
+
 // ========== 应用设置 API ==========
 // 使用特殊的 module_id "__app__" 来存储应用级别的设置
 
-const APP_MODULE_ID: &str = "__app__";
+pub(crate) const APP_MODULE_ID: &str = "__app__";
 
 /// 保存应用设置
 #[frb]
@@ -168,3 +234,66 @@ pub async fn delete_app_setting(key: String) -> anyhow::Result<()> {
 pub async fn list_app_settings() -> anyhow::Result<Vec<PropertyItem>> {
     list_properties(APP_MODULE_ID.to_string()).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_properties_paged() {
+        database::init_test_database().await;
+        let module_id = "test_properties_paged".to_string();
+        for i in 0..5 {
+            save_property(module_id.clone(), format!("key{}", i), i.to_string()).await.unwrap();
+        }
+
+        let first = list_properties_paged(module_id.clone(), 1, 2).await.unwrap();
+        assert_eq!((first.page_info.total, first.page_info.pages), (5, 3));
+        assert_eq!(first.docs.iter().map(|p| p.key.as_str()).collect::<Vec<_>>(), vec!["key0", "key1"]);
+
+        let last = list_properties_paged(module_id.clone(), 3, 2).await.unwrap();
+        assert_eq!(last.docs.len(), 1);
+        assert_eq!(last.docs[0].key, "key4");
+
+        clear_module_properties(module_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_properties_by_prefix() {
+        database::init_test_database().await;
+        let module_id = "test_properties_prefix_delete".to_string();
+        for key in ["cache:a", "cache:b", "config:theme", "cachex"] {
+            save_property(module_id.clone(), key.to_string(), "1".to_string()).await.unwrap();
+        }
+
+        let deleted = delete_properties_by_prefix(module_id.clone(), "cache:".to_string()).await.unwrap();
+        assert_eq!(deleted, 2);
+
+        let mut keys: Vec<String> = list_properties(module_id.clone()).await.unwrap()
+            .into_iter().map(|p| p.key).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["cachex", "config:theme"]);
+
+        clear_module_properties(module_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_properties_by_prefix_is_literal() {
+        database::init_test_database().await;
+        let module_id = "test_properties_prefix_literal".to_string();
+        for key in ["Cache_a", "Cache_b", "CacheXa", "cache_a", "CACHE-a", "100%_done", "100x_done"] {
+            save_property(module_id.clone(), key.to_string(), "1".to_string()).await.unwrap();
+        }
+
+        // `_`、`%` 不是通配符，大小写敏感
+        assert_eq!(delete_properties_by_prefix(module_id.clone(), "Cache_".to_string()).await.unwrap(), 2);
+        assert_eq!(delete_properties_by_prefix(module_id.clone(), "100%".to_string()).await.unwrap(), 1);
+
+        let mut keys: Vec<String> = list_properties(module_id.clone()).await.unwrap()
+            .into_iter().map(|p| p.key).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["100x_done", "CACHE-a", "CacheXa", "cache_a"]);
+
+        clear_module_properties(module_id).await.unwrap();
+    }
+}
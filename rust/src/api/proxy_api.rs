@@ -1,8 +1,13 @@
 use flutter_rust_bridge::frb;
-use crate::http::proxy::ProxyManager;
+use sea_orm::{EntityTrait, QueryFilter, ColumnTrait};
+use crate::http::proxy::{ProxyConfig, ProxyManager, ProxyRoute};
 use crate::api::property_api;
+use crate::database::{self, entities::property};
 
-const PROXY_SETTING_KEY: &str = "proxy_url";
+pub(crate) const PROXY_SETTING_KEY: &str = "proxy_url";
+const PROXY_BYPASS_SETTING_KEY: &str = "proxy_bypass";
+/// 模块默认代理保存在模块自身的属性中
+const MODULE_PROXY_KEY: &str = "__proxy__";
 
 /// 设置代理
 /// 
@@ -14,8 +19,9 @@ pub async fn set_proxy(url: Option<String>) -> anyhow::Result<()> {
         .map(|s| s.trim())
         .and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
     
-    // 更新代理管理器
-    ProxyManager::instance().set_proxy(proxy_url.clone())?;
+    // 更新代理管理器（保留当前绕过列表）
+    let bypass = ProxyManager::instance().get_bypass();
+    ProxyManager::instance().set_proxy(proxy_url.clone(), Some(bypass.as_str().to_string()))?;
     
     // 保存到数据库
     if let Some(url) = &proxy_url {
@@ -24,7 +30,8 @@ pub async fn set_proxy(url: Option<String>) -> anyhow::Result<()> {
         property_api::delete_app_setting(PROXY_SETTING_KEY.to_string()).await?;
     }
     
-    tracing::info!("代理设置已保存: {:?}", proxy_url);
+    // ProxyConfig 的 Debug 输出会隐藏凭据
+    tracing::info!("代理设置已保存: {:?}", proxy_url.map(ProxyConfig::new));
     Ok(())
 }
 
@@ -41,7 +48,8 @@ pub async fn get_proxy() -> anyhow::Result<Option<String>> {
     
     // 如果数据库中有，同步到代理管理器
     if let Some(ref url) = url {
-        ProxyManager::instance().set_proxy(Some(url.clone()))?;
+        let bypass = ProxyManager::instance().get_bypass();
+        ProxyManager::instance().set_proxy(Some(url.clone()), Some(bypass.as_str().to_string()))?;
     }
     
     Ok(url)
@@ -56,18 +64,103 @@ pub async fn clear_proxy() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// 设置代理绕过列表
+/// 
+/// # 参数
+/// - `bypass`: 逗号分隔的主机名、IP 或 CIDR（如 "localhost,.lan,192.168.0.0/16"），这些主机不走代理。为空则清除。
+#[frb]
+pub async fn set_proxy_bypass(bypass: Option<String>) -> anyhow::Result<()> {
+    let bypass = bypass.as_ref()
+        .map(|s| s.trim())
+        .and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+    
+    let proxy_url = ProxyManager::instance().get_proxy().map(|c| c.url);
+    ProxyManager::instance().set_proxy(proxy_url, bypass.clone())?;
+    
+    if let Some(list) = &bypass {
+        property_api::save_app_setting(PROXY_BYPASS_SETTING_KEY.to_string(), list.clone()).await?;
+    } else {
+        property_api::delete_app_setting(PROXY_BYPASS_SETTING_KEY.to_string()).await?;
+    }
+    
+    tracing::info!("代理绕过列表已保存: {:?}", bypass);
+    Ok(())
+}
+
+/// 获取代理绕过列表
+#[frb(sync)]
+pub fn get_proxy_bypass() -> String {
+    ProxyManager::instance().get_bypass().as_str().to_string()
+}
+
+/// 设置模块默认代理
+/// 
+/// # 参数
+/// - `module_id`: 模块 ID
+/// - `url`: 代理 URL（http:// 或 socks5://），"direct" 表示该模块不走全局代理；为空则跟随全局设置
+#[frb]
+pub async fn set_module_proxy(module_id: String, url: Option<String>) -> anyhow::Result<()> {
+    let proxy_url = url.as_ref()
+        .map(|s| s.trim())
+        .and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+    
+    ProxyManager::instance().set_module_proxy(&module_id, proxy_url.clone())?;
+    
+    if let Some(url) = &proxy_url {
+        property_api::save_property(module_id.clone(), MODULE_PROXY_KEY.to_string(), url.clone()).await?;
+    } else {
+        property_api::delete_property(module_id.clone(), MODULE_PROXY_KEY.to_string()).await?;
+    }
+    
+    tracing::info!("模块 {} 代理设置已保存: {:?}", module_id, ProxyManager::instance().get_module_proxy(&module_id));
+    Ok(())
+}
+
+/// 获取模块默认代理设置
+#[frb(sync)]
+pub fn get_module_proxy(module_id: String) -> Option<String> {
+    match ProxyManager::instance().get_module_proxy(&module_id)? {
+        ProxyRoute::Global => None,
+        ProxyRoute::Direct => Some(crate::http::proxy::DIRECT_PROXY.to_string()),
+        ProxyRoute::Proxy(config) => Some(config.url),
+    }
+}
+
+/// 加载所有模块的默认代理
+async fn init_module_proxies() -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let items = property::Entity::find()
+        .filter(property::Column::Key.eq(MODULE_PROXY_KEY))
+        .all(&*conn)
+        .await?;
+    
+    for item in items {
+        // 无效的历史数据不应阻止启动
+        if let Err(e) = ProxyManager::instance().set_module_proxy(&item.module_id, Some(item.value)) {
+            tracing::warn!("模块 {} 的代理设置无效: {}", item.module_id, e);
+        }
+    }
+    
+    Ok(())
+}
+
 /// 初始化代理设置（从数据库加载）
 /// 在应用启动时调用（内部使用，不导出到 Flutter）
 pub(crate) async fn init_proxy() -> anyhow::Result<()> {
     let url = property_api::load_app_setting(PROXY_SETTING_KEY.to_string()).await?;
+    let bypass = property_api::load_app_setting(PROXY_BYPASS_SETTING_KEY.to_string()).await?;
     
-    if let Some(url) = url {
-        ProxyManager::instance().set_proxy(Some(url))?;
+    if url.is_some() {
         tracing::info!("代理设置已从数据库加载");
     } else {
-        ProxyManager::instance().clear_proxy()?;
         tracing::info!("未找到代理设置，使用默认配置（无代理）");
     }
+    ProxyManager::instance().set_proxy(url, bypass)?;
+    
+    init_module_proxies().await?;
     
     Ok(())
 }
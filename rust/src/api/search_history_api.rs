@@ -0,0 +1,117 @@
+use flutter_rust_bridge::frb;
+use sea_orm::{EntityTrait, QueryFilter, QueryOrder, QuerySelect, ColumnTrait, ActiveModelTrait, Set};
+use chrono::Utc;
+
+use crate::database::{self, entities::search_history};
+
+/// 搜索记录
+#[derive(Debug, Clone)]
+pub struct SearchRecord {
+    pub keyword: String,
+    pub count: i32,
+    /// 最近搜索时间（毫秒时间戳）
+    pub searched_at: i64,
+}
+
+/// 记录一次搜索（重复的关键词会累加次数并更新时间）
+#[frb]
+pub async fn record_search(module_id: String, keyword: String) -> anyhow::Result<()> {
+    let keyword = keyword.trim().to_string();
+    if keyword.is_empty() {
+        return Ok(());
+    }
+    
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let now = Utc::now().naive_utc();
+    let id = search_history::Model::create_id(&module_id, &keyword);
+    
+    let existing = search_history::Entity::find_by_id(&id)
+        .one(&*conn)
+        .await?;
+    
+    if let Some(existing) = existing {
+        let active_model = search_history::ActiveModel {
+            id: Set(id),
+            searched_at: Set(now),
+            count: Set(existing.count + 1),
+            ..Default::default()
+        };
+        active_model.update(&*conn).await?;
+    } else {
+        let active_model = search_history::ActiveModel {
+            id: Set(id),
+            module_id: Set(module_id),
+            keyword: Set(keyword),
+            searched_at: Set(now),
+            count: Set(1),
+        };
+        active_model.insert(&*conn).await?;
+    }
+    
+    Ok(())
+}
+
+/// 获取最近的搜索记录，按搜索次数、最近搜索时间倒序
+#[frb]
+pub async fn recent_searches(module_id: String, limit: u32) -> anyhow::Result<Vec<SearchRecord>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let records = search_history::Entity::find()
+        .filter(search_history::Column::ModuleId.eq(&module_id))
+        .order_by_desc(search_history::Column::Count)
+        .order_by_desc(search_history::Column::SearchedAt)
+        .limit(limit as u64)
+        .all(&*conn)
+        .await?;
+    
+    Ok(records.into_iter().map(|r| SearchRecord {
+        keyword: r.keyword,
+        count: r.count,
+        searched_at: r.searched_at.and_utc().timestamp_millis(),
+    }).collect())
+}
+
+/// 清除指定模块的搜索记录
+#[frb]
+pub async fn clear_search_history(module_id: String) -> anyhow::Result<u64> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let result = search_history::Entity::delete_many()
+        .filter(search_history::Column::ModuleId.eq(&module_id))
+        .exec(&*conn)
+        .await?;
+    
+    Ok(result.rows_affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_search_history_dedup() {
+        database::init_test_database().await;
+        let module_id = "test_search_history".to_string();
+
+        record_search(module_id.clone(), "one piece".to_string()).await.unwrap();
+        record_search(module_id.clone(), "naruto".to_string()).await.unwrap();
+        record_search(module_id.clone(), " one piece ".to_string()).await.unwrap();
+        record_search(module_id.clone(), "  ".to_string()).await.unwrap();
+
+        let records = recent_searches(module_id.clone(), 10).await.unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].keyword, "one piece");
+        assert_eq!(records[0].count, 2);
+        assert_eq!(records[1].keyword, "naruto");
+
+        assert_eq!(clear_search_history(module_id.clone()).await.unwrap(), 2);
+        assert!(recent_searches(module_id, 10).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use flutter_rust_bridge::frb;
+use sea_orm::{EntityTrait, QueryFilter, QuerySelect, ColumnTrait, PaginatorTrait};
+use sea_orm::sea_query::Expr;
+
+use crate::database::{self, entities::{download, image_cache, module_info, reading_history, web_cache}};
+
+/// 应用使用统计
+#[derive(Debug, Clone)]
+pub struct UsageStats {
+    /// 阅读历史中的漫画数量
+    pub history_comics: u64,
+    /// 已安装模块数量
+    pub modules: u64,
+    pub enabled_modules: u64,
+    /// 已下载完成的章节数量
+    pub downloaded_chapters: u64,
+    pub image_cache_count: u64,
+    /// 图片缓存总大小（字节）
+    pub image_cache_size: u64,
+    pub web_cache_count: u64,
+    /// 网页缓存总大小（字节）
+    pub web_cache_size: u64,
+    /// 数据库文件大小（字节，含 WAL）
+    pub database_size: u64,
+}
+
+/// 获取使用统计
+/// 
+/// 数量和大小都通过聚合查询计算，不会加载缓存记录。
+/// 收藏保存在各模块对应的站点上，本地无法统计
+#[frb]
+pub async fn get_usage_stats() -> anyhow::Result<UsageStats> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    let conn = db.read().await;
+    
+    let history_comics = reading_history::Entity::find().count(&*conn).await?;
+    let modules = module_info::Entity::find().count(&*conn).await?;
+    let enabled_modules = module_info::Entity::find()
+        .filter(module_info::Column::Enabled.eq(true))
+        .count(&*conn)
+        .await?;
+    let downloaded_chapters = download::Entity::find()
+        .filter(download::Column::Status.eq("completed"))
+        .count(&*conn)
+        .await?;
+    
+    let (image_cache_count, image_cache_size): (i64, Option<i64>) = image_cache::Entity::find()
+        .select_only()
+        .column_as(image_cache::Column::CacheKey.count(), "count")
+        .column_as(image_cache::Column::FileSize.sum(), "size")
+        .into_tuple()
+        .one(&*conn)
+        .await?
+        .unwrap_or((0, None));
+    
+    let (web_cache_count, web_cache_size): (i64, Option<i64>) = web_cache::Entity::find()
+        .select_only()
+        .column_as(web_cache::Column::CacheKey.count(), "count")
+        .column_as(Expr::cust("SUM(LENGTH(CAST(response_body AS BLOB)))"), "size")
+        .into_tuple()
+        .one(&*conn)
+        .await?
+        .unwrap_or((0, None));
+    drop(conn);
+    
+    Ok(UsageStats {
+        history_comics,
+        modules,
+        enabled_modules,
+        downloaded_chapters,
+        image_cache_count: image_cache_count as u64,
+        image_cache_size: image_cache_size.unwrap_or(0) as u64,
+        web_cache_count: web_cache_count as u64,
+        web_cache_size: web_cache_size.unwrap_or(0) as u64,
+        database_size: database::database_file_size().await?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::web_cache_api;
+
+    #[tokio::test]
+    async fn test_usage_stats() {
+        database::init_test_database().await;
+        let before = get_usage_stats().await.unwrap();
+        web_cache_api::save_web_cache(
+            "test_stats".to_string(),
+            "https://example.com/stats".to_string(),
+            "中文".to_string(),
+            "text/html".to_string(),
+            60,
+        ).await.unwrap();
+
+        let after = get_usage_stats().await.unwrap();
+        assert!(after.web_cache_count >= 1);
+        assert!(after.web_cache_size >= 6);
+        assert!(after.database_size > 0);
+        assert!(after.web_cache_size >= before.web_cache_size);
+
+        web_cache_api::clear_web_cache_by_module("test_stats".to_string()).await.unwrap();
+    }
+}
@@ -0,0 +1,194 @@
+use flutter_rust_bridge::frb;
+use sea_orm::{EntityTrait, QueryFilter, ColumnTrait, ActiveModelTrait, Set};
+use chrono::{Utc, Duration};
+use crate::database::{self, entities::web_cache};
+
+/// 缓存有效期上限（10 年），超出部分会被截断，避免时间计算溢出
+const MAX_EXPIRE_SECS: i64 = 10 * 365 * 24 * 3600;
+
+/// 网页缓存条目
+#[derive(Debug, Clone)]
+pub struct WebCacheItem {
+    pub url: String,
+    pub body: String,
+    pub content_type: String,
+}
+
+/// 获取缓存的网页内容（已过期的条目会被删除并返回 None）
+#[frb]
+pub async fn get_web_cache(module_id: String, url: String) -> anyhow::Result<Option<WebCacheItem>> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let cache_key = web_cache::Model::create_cache_key(&module_id, &url);
+    
+    let cache = web_cache::Entity::find_by_id(&cache_key)
+        .one(&*conn)
+        .await?;
+    
+    if let Some(cache) = cache {
+        if cache.expire_at > Utc::now().naive_utc() {
+            return Ok(Some(WebCacheItem {
+                url: cache.url,
+                body: cache.response_body,
+                content_type: cache.content_type,
+            }));
+        }
+        // 已过期，删除缓存记录
+        let _ = web_cache::Entity::delete_by_id(&cache_key)
+            .exec(&*conn)
+            .await;
+    }
+    
+    Ok(None)
+}
+
+/// 保存网页内容到缓存
+#[frb]
+pub async fn save_web_cache(
+    module_id: String,
+    url: String,
+    body: String,
+    content_type: String,
+    expire_secs: i64,
+) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let cache_key = web_cache::Model::create_cache_key(&module_id, &url);
+    let now = Utc::now().naive_utc();
+    let expire_at = now + Duration::seconds(expire_secs.clamp(-MAX_EXPIRE_SECS, MAX_EXPIRE_SECS));
+    
+    // 检查是否已存在
+    let existing = web_cache::Entity::find_by_id(&cache_key)
+        .one(&*conn)
+        .await?;
+    
+    let mut active_model = web_cache::ActiveModel {
+        cache_key: Set(cache_key),
+        module_id: Set(module_id),
+        url: Set(url),
+        response_body: Set(body),
+        content_type: Set(content_type),
+        expire_at: Set(expire_at),
+        ..Default::default()
+    };
+    if existing.is_some() {
+        // 更新时保留首次缓存的时间
+        active_model.update(&*conn).await?;
+    } else {
+        active_model.created_at = Set(now);
+        active_model.insert(&*conn).await?;
+    }
+    
+    Ok(())
+}
+
+/// 删除指定网页缓存
+#[frb]
+pub async fn remove_web_cache(module_id: String, url: String) -> anyhow::Result<()> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let cache_key = web_cache::Model::create_cache_key(&module_id, &url);
+    web_cache::Entity::delete_by_id(&cache_key)
+        .exec(&*conn)
+        .await?;
+    
+    Ok(())
+}
+
+/// 清除指定模块的网页缓存
+#[frb]
+pub async fn clear_web_cache_by_module(module_id: String) -> anyhow::Result<u64> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let result = web_cache::Entity::delete_many()
+        .filter(web_cache::Column::ModuleId.eq(&module_id))
+        .exec(&*conn)
+        .await?;
+    
+    Ok(result.rows_affected)
+}
+
+/// 清除过期的网页缓存
+#[frb]
+pub async fn clear_expired_web_cache() -> anyhow::Result<u64> {
+    let db = database::get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    
+    let conn = db.read().await;
+    let now = Utc::now().naive_utc();
+    let result = web_cache::Entity::delete_many()
+        .filter(web_cache::Column::ExpireAt.lt(now))
+        .exec(&*conn)
+        .await?;
+    
+    Ok(result.rows_affected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_web_cache_roundtrip() {
+        database::init_test_database().await;
+        let module_id = "test_web_cache".to_string();
+        let url = "https://example.com/categories".to_string();
+
+        save_web_cache(module_id.clone(), url.clone(), "[1,2]".to_string(), "application/json".to_string(), 60).await.unwrap();
+        let item = get_web_cache(module_id.clone(), url.clone()).await.unwrap().unwrap();
+        assert_eq!(item.body, "[1,2]");
+        assert_eq!(item.content_type, "application/json");
+
+        // 过期条目不再返回
+        save_web_cache(module_id.clone(), url.clone(), "[3]".to_string(), "application/json".to_string(), -1).await.unwrap();
+        assert!(get_web_cache(module_id.clone(), url.clone()).await.unwrap().is_none());
+
+        save_web_cache(module_id.clone(), url.clone(), "[4]".to_string(), "application/json".to_string(), 60).await.unwrap();
+        assert_eq!(clear_web_cache_by_module(module_id.clone()).await.unwrap(), 1);
+        assert!(get_web_cache(module_id, url).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_web_cache_update_keeps_created_at() {
+        database::init_test_database().await;
+        let module_id = "test_web_cache_created_at".to_string();
+        let url = "https://example.com/detail".to_string();
+        let key = web_cache::Model::create_cache_key(&module_id, &url);
+
+        save_web_cache(module_id.clone(), url.clone(), "a".to_string(), "text/plain".to_string(), 60).await.unwrap();
+        let db = database::get_database().unwrap();
+        let first = web_cache::Entity::find_by_id(&key).one(&*db.read().await).await.unwrap().unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        save_web_cache(module_id.clone(), url.clone(), "b".to_string(), "text/html".to_string(), 120).await.unwrap();
+        let second = web_cache::Entity::find_by_id(&key).one(&*db.read().await).await.unwrap().unwrap();
+
+        assert_eq!(second.created_at, first.created_at);
+        assert!(second.expire_at > first.expire_at);
+        assert_eq!(second.response_body, "b");
+        assert_eq!(second.content_type, "text/html");
+
+        clear_web_cache_by_module(module_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_web_cache_clamps_expire_secs() {
+        database::init_test_database().await;
+        let module_id = "test_web_cache_clamp".to_string();
+        let url = "https://example.com/forever".to_string();
+
+        save_web_cache(module_id.clone(), url.clone(), "a".to_string(), "text/plain".to_string(), i64::MAX).await.unwrap();
+        assert_eq!(get_web_cache(module_id.clone(), url.clone()).await.unwrap().unwrap().body, "a");
+
+        save_web_cache(module_id.clone(), url.clone(), "b".to_string(), "text/plain".to_string(), i64::MIN).await.unwrap();
+        assert!(get_web_cache(module_id, url).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! 取消令牌
+//!
+//! Flutter 端生成令牌字符串并传给可取消的操作，之后调用 `cancel_api::cancel(token)` 中止。
+//! 可取消的操作：
+//! - `module_api::get_pictures_cancelable`：中断模块 JS 执行（包括等待异步请求）
+//! - `download_api::download_to_file` / `start_chapter_download`：中止下载并删除未完成的文件
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// 操作已被取消
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Operation cancelled")]
+pub struct Cancelled;
+
+#[derive(Default)]
+struct CancelInner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+/// 可在线程间共享的取消令牌
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<CancelInner>,
+}
+
+impl std::fmt::Debug for CancelToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancelToken").field("cancelled", &self.is_cancelled()).finish()
+    }
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 取消，唤醒所有等待中的操作
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// 等待直到被取消
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.inner.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// 执行 future，令牌被取消时立即返回 `Cancelled`（future 被丢弃）
+pub async fn cancelable<F: Future>(token: Option<&CancelToken>, future: F) -> anyhow::Result<F::Output> {
+    match token {
+        None => Ok(future.await),
+        Some(token) => tokio::select! {
+            output = future => Ok(output),
+            _ = token.cancelled() => Err(Cancelled.into()),
+        },
+    }
+}
+
+/// 错误是否由取消引起
+pub fn is_cancelled_error(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<Cancelled>().is_some()
+}
+
+/// 进行中的操作：令牌字符串 -> 取消令牌
+static ACTIVE_TOKENS: Lazy<Mutex<HashMap<String, CancelToken>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 已注册的令牌，drop 时从全局表中移除
+pub struct RegisteredToken {
+    id: String,
+    token: CancelToken,
+}
+
+impl RegisteredToken {
+    pub fn token(&self) -> &CancelToken {
+        &self.token
+    }
+}
+
+impl Drop for RegisteredToken {
+    fn drop(&mut self) {
+        if let Ok(mut tokens) = ACTIVE_TOKENS.lock() {
+            // 只移除自己注册的令牌，同名令牌可能已被新的操作替换
+            if tokens.get(&self.id).is_some_and(|t| Arc::ptr_eq(&t.inner, &self.token.inner)) {
+                tokens.remove(&self.id);
+            }
+        }
+    }
+}
+
+/// 注册令牌，操作结束时 drop 返回值即可注销
+pub fn register(id: Option<String>) -> Option<RegisteredToken> {
+    let id = id.filter(|id| !id.is_empty())?;
+    let token = CancelToken::new();
+    if let Ok(mut tokens) = ACTIVE_TOKENS.lock() {
+        tokens.insert(id.clone(), token.clone());
+    }
+    Some(RegisteredToken { id, token })
+}
+
+/// 取消指定令牌对应的操作，令牌不存在（未开始或已结束）时返回 false
+pub fn cancel(id: &str) -> bool {
+    let token = ACTIVE_TOKENS.lock().ok().and_then(|tokens| tokens.get(id).cloned());
+    match token {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_registered_token() {
+        let registered = register(Some("test-cancel-token".to_string())).unwrap();
+        let token = registered.token().clone();
+        tokio::spawn(async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert!(cancel("test-cancel-token"));
+        });
+
+        let started = std::time::Instant::now();
+        let err = cancelable(Some(&token), tokio::time::sleep(Duration::from_secs(10))).await.unwrap_err();
+        assert!(is_cancelled_error(&err));
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        drop(registered);
+        assert!(!cancel("test-cancel-token"));
+        assert!(register(None).is_none());
+        assert_eq!(cancelable(None, async { 1 }).await.unwrap(), 1);
+    }
+}
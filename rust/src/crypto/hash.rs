@@ -4,9 +4,19 @@ use aes::Aes256;
 use aes::cipher::{BlockDecrypt, KeyInit, generic_array::GenericArray};
 use hmac::Hmac;
 use hmac::digest::Mac;
+use rand::RngCore;
+use rand::rngs::OsRng;
+use flate2::read::GzDecoder;
+use std::io::Read;
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// 单次生成随机字节的上限（1MB），防止模块申请过大内存
+pub const MAX_RANDOM_BYTES: usize = 1024 * 1024;
+
+/// 单次 gzip 解压输出的上限（64MB），防止压缩炸弹
+pub const MAX_GUNZIP_BYTES: usize = 64 * 1024 * 1024;
+
 /// 计算 MD5 哈希
 pub fn md5_hash(data: &[u8]) -> String {
     let digest = md5::compute(data);
@@ -77,6 +87,59 @@ pub fn hex_decode(data: &str) -> anyhow::Result<Vec<u8>> {
         .map_err(|e| anyhow::anyhow!("Hex decode error: {}", e))
 }
 
+/// 计算 CRC32 校验和（IEEE）
+pub fn crc32(data: &[u8]) -> u32 {
+    crc32fast::hash(data)
+}
+
+/// 计算 CRC32 校验和，返回 8 位大端十六进制字符串
+pub fn crc32_hex(data: &[u8]) -> String {
+    format!("{:08x}", crc32(data))
+}
+
+/// 计算 Adler32 校验和
+pub fn adler32(data: &[u8]) -> u32 {
+    adler::adler32_slice(data)
+}
+
+/// 计算 Adler32 校验和，返回 8 位大端十六进制字符串
+pub fn adler32_hex(data: &[u8]) -> String {
+    format!("{:08x}", adler32(data))
+}
+
+/// 解压 gzip 数据（如 raw_body 请求返回的原始响应体）
+/// 
+/// 解压后超过 MAX_GUNZIP_BYTES 时返回错误
+pub fn gunzip(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    GzDecoder::new(data)
+        .take(MAX_GUNZIP_BYTES as u64 + 1)
+        .read_to_end(&mut buf)
+        .map_err(|e| anyhow::anyhow!("Gunzip error: {}", e))?;
+    if buf.len() > MAX_GUNZIP_BYTES {
+        return Err(anyhow::anyhow!("Gunzip output exceeds limit {}", MAX_GUNZIP_BYTES));
+    }
+    Ok(buf)
+}
+
+/// 生成安全随机字节（来自操作系统随机源）
+pub fn random_bytes(len: usize) -> anyhow::Result<Vec<u8>> {
+    if len > MAX_RANDOM_BYTES {
+        return Err(anyhow::anyhow!(
+            "Random bytes length {} exceeds limit {}",
+            len, MAX_RANDOM_BYTES
+        ));
+    }
+    let mut buf = vec![0u8; len];
+    OsRng.fill_bytes(&mut buf);
+    Ok(buf)
+}
+
+/// 生成 UUID v4（带连字符的标准格式）
+pub fn uuid_v4() -> String {
+    uuid::Uuid::new_v4().hyphenated().to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,6 +172,55 @@ mod tests {
         let decoded = hex_decode(&encoded).unwrap();
         assert_eq!(decoded, b"hello");
     }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        // 输出为大端十六进制，高位补零
+        assert_eq!(crc32_hex(b"123456789"), "cbf43926");
+        assert_eq!(crc32_hex(b"a"), "e8b7be43");
+    }
+
+    #[test]
+    fn test_adler32() {
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+        assert_eq!(adler32_hex(b""), "00000001");
+        assert_eq!(adler32_hex(b"Wikipedia"), "11e60398");
+    }
+
+    #[test]
+    fn test_gunzip() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"hello gzip").unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert_eq!(gunzip(&compressed).unwrap(), b"hello gzip");
+        assert!(gunzip(b"not gzip").is_err());
+    }
+
+    #[test]
+    fn test_random_bytes() {
+        assert_eq!(random_bytes(0).unwrap().len(), 0);
+        assert_eq!(random_bytes(32).unwrap().len(), 32);
+        assert_ne!(random_bytes(32).unwrap(), random_bytes(32).unwrap());
+        assert!(random_bytes(MAX_RANDOM_BYTES + 1).is_err());
+    }
+
+    #[test]
+    fn test_uuid_v4() {
+        let id = uuid_v4();
+        assert_eq!(id.len(), 36);
+        let parts: Vec<&str> = id.split('-').collect();
+        assert_eq!(parts.iter().map(|p| p.len()).collect::<Vec<_>>(), vec![8, 4, 4, 4, 12]);
+        // 版本号位
+        assert!(parts[2].starts_with('4'));
+        assert_ne!(id, uuid_v4());
+    }
 }
 
 /// AES-256-ECB 解密
@@ -1,3 +1,5 @@
 pub mod hash;
+pub mod text;
 
 pub use hash::*;
+pub use text::*;
@@ -0,0 +1,120 @@
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use encoding_rs::Encoding;
+
+/// URL 百分号编码
+/// 除字母数字外的所有字符都会被编码（空格编码为 `%20`，`+` 编码为 `%2B`）
+pub fn url_encode(data: &str) -> String {
+    utf8_percent_encode(data, NON_ALPHANUMERIC).to_string()
+}
+
+/// URL 百分号解码
+/// 注意：`+` 不会被解码为空格，保持原样
+pub fn url_decode(data: &str) -> anyhow::Result<String> {
+    percent_decode_str(data)
+        .decode_utf8()
+        .map(|s| s.into_owned())
+        .map_err(|e| anyhow::anyhow!("URL decode error: {}", e))
+}
+
+/// 根据编码名称查找编码（支持 gbk/gb2312/gb18030/big5/shift_jis/utf-8 等 WHATWG 标签）
+fn lookup_encoding(label: &str) -> anyhow::Result<&'static Encoding> {
+    Encoding::for_label(label.trim().as_bytes())
+        .ok_or_else(|| anyhow::anyhow!("Unsupported encoding: {}", label))
+}
+
+/// 将指定编码的字节解码为 UTF-8 字符串
+/// 无法解码的字节会被替换为 U+FFFD
+pub fn decode_bytes(data: &[u8], label: &str) -> anyhow::Result<String> {
+    let encoding = lookup_encoding(label)?;
+    let (text, _, had_errors) = encoding.decode(data);
+    if had_errors {
+        tracing::warn!("Malformed {} sequence replaced while decoding", encoding.name());
+    }
+    Ok(text.into_owned())
+}
+
+/// 将指定编码的字节解码为 UTF-8 字符串，遇到无法解码的字节时返回错误
+pub fn decode_bytes_strict(data: &[u8], label: &str) -> anyhow::Result<String> {
+    let encoding = lookup_encoding(label)?;
+    let (text, _, had_errors) = encoding.decode(data);
+    if had_errors {
+        return Err(anyhow::anyhow!("Malformed {} sequence", encoding.name()));
+    }
+    Ok(text.into_owned())
+}
+
+/// 编码标签对应的规范名称（小写，如 `gbk` 的规范名称为 `gbk`，`latin1` 为 `windows-1252`）
+pub fn encoding_name(label: &str) -> anyhow::Result<String> {
+    Ok(lookup_encoding(label)?.name().to_ascii_lowercase())
+}
+
+/// 将 UTF-8 字符串编码为指定编码的字节
+pub fn encode_string(data: &str, label: &str) -> anyhow::Result<Vec<u8>> {
+    let encoding = lookup_encoding(label)?;
+    let (bytes, _, had_errors) = encoding.encode(data);
+    if had_errors {
+        tracing::warn!("Unmappable characters replaced while encoding to {}", encoding.name());
+    }
+    Ok(bytes.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(url_encode("abc123"), "abc123");
+        assert_eq!(url_encode("a b"), "a%20b");
+        assert_eq!(url_encode("a+b"), "a%2Bb");
+        assert_eq!(url_encode("k=v&x=y"), "k%3Dv%26x%3Dy");
+        assert_eq!(url_encode("漫画"), "%E6%BC%AB%E7%94%BB");
+        // 已编码的输入会被再次编码，不做猜测
+        assert_eq!(url_encode("a%20b"), "a%2520b");
+    }
+
+    #[test]
+    fn test_url_decode() {
+        assert_eq!(url_decode("a%20b").unwrap(), "a b");
+        assert_eq!(url_decode("a+b").unwrap(), "a+b");
+        assert_eq!(url_decode("a%2Bb").unwrap(), "a+b");
+        assert_eq!(url_decode("%E6%BC%AB%E7%94%BB").unwrap(), "漫画");
+        assert_eq!(url_decode("plain").unwrap(), "plain");
+        // 无效 UTF-8 序列
+        assert!(url_decode("%FF%FE").is_err());
+    }
+
+    #[test]
+    fn test_url_roundtrip() {
+        let input = "a b+c/d?e=f&g=%h";
+        assert_eq!(url_decode(&url_encode(input)).unwrap(), input);
+    }
+
+    #[test]
+    fn test_decode_bytes() {
+        // "中文"
+        assert_eq!(decode_bytes(&[0xD6, 0xD0, 0xCE, 0xC4], "gbk").unwrap(), "中文");
+        assert_eq!(decode_bytes(&[0xD6, 0xD0, 0xCE, 0xC4], "GB2312").unwrap(), "中文");
+        assert_eq!(decode_bytes(&[0xD6, 0xD0, 0xCE, 0xC4], "gb18030").unwrap(), "中文");
+        assert_eq!(decode_bytes(&[0xA4, 0xA4, 0xA4, 0xE5], "big5").unwrap(), "中文");
+        // "日本"
+        assert_eq!(decode_bytes(&[0x93, 0xFA, 0x96, 0x7B], "shift_jis").unwrap(), "日本");
+        assert_eq!(decode_bytes("漫画".as_bytes(), "utf-8").unwrap(), "漫画");
+        assert!(decode_bytes(b"abc", "not-an-encoding").is_err());
+    }
+
+    #[test]
+    fn test_decode_bytes_strict() {
+        assert_eq!(decode_bytes_strict("漫画".as_bytes(), "utf-8").unwrap(), "漫画");
+        assert!(decode_bytes_strict(&[0xE6, 0xBC], "utf-8").is_err());
+        assert_eq!(encoding_name("UTF8").unwrap(), "utf-8");
+        assert_eq!(encoding_name("gb2312").unwrap(), "gbk");
+    }
+
+    #[test]
+    fn test_encode_string() {
+        assert_eq!(encode_string("中文", "gbk").unwrap(), vec![0xD6, 0xD0, 0xCE, 0xC4]);
+        assert_eq!(encode_string("中文", "big5").unwrap(), vec![0xA4, 0xA4, 0xA4, 0xE5]);
+        assert!(encode_string("abc", "not-an-encoding").is_err());
+    }
+}
@@ -1,8 +1,62 @@
 use std::path::Path;
-use sea_orm::{Database, DatabaseConnection, ConnectOptions};
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, ConnectOptions};
+use sea_orm::sqlx::sqlite::{SqliteJournalMode, SqliteSynchronous};
 use std::time::Duration;
 
+/// SQLite 连接参数（对应 PRAGMA journal_mode / busy_timeout / synchronous）
+#[derive(Debug, Clone)]
+pub struct SqlitePragmas {
+    /// 使用 WAL 日志模式，读写互不阻塞
+    pub wal: bool,
+    /// 数据库被锁时的等待时间，超时后才返回 "database is locked"
+    pub busy_timeout: Duration,
+    /// synchronous=NORMAL，WAL 模式下安全且写入更快
+    pub synchronous_normal: bool,
+    /// SQLCipher 加密密钥（PRAGMA key），需要启用 sqlcipher feature
+    pub key: Option<String>,
+}
+
+impl Default for SqlitePragmas {
+    fn default() -> Self {
+        Self {
+            wal: true,
+            busy_timeout: Duration::from_millis(5000),
+            synchronous_normal: true,
+            key: None,
+        }
+    }
+}
+
+/// 为 ConnectOptions 设置 SQLite PRAGMA
+pub trait SqliteConnectOptionsExt {
+    fn sqlite_pragmas(&mut self, pragmas: SqlitePragmas) -> &mut Self;
+}
+
+impl SqliteConnectOptionsExt for ConnectOptions {
+    fn sqlite_pragmas(&mut self, pragmas: SqlitePragmas) -> &mut Self {
+        self.map_sqlx_sqlite_opts(move |opts| {
+            // sqlx 保证 key 是连接后执行的第一条 PRAGMA
+            let opts = match &pragmas.key {
+                Some(key) => opts.pragma("key", format!("'{}'", key.replace('\'', "''"))),
+                None => opts,
+            };
+            opts.journal_mode(if pragmas.wal { SqliteJournalMode::Wal } else { SqliteJournalMode::Delete })
+                .busy_timeout(pragmas.busy_timeout)
+                .synchronous(if pragmas.synchronous_normal { SqliteSynchronous::Normal } else { SqliteSynchronous::Full })
+        })
+    }
+}
+
 pub async fn connect(db_path: &Path) -> anyhow::Result<DatabaseConnection> {
+    connect_with(db_path, SqlitePragmas::default()).await
+}
+
+pub async fn connect_with(db_path: &Path, pragmas: SqlitePragmas) -> anyhow::Result<DatabaseConnection> {
+    if pragmas.key.is_some() && !cfg!(feature = "sqlcipher") {
+        return Err(anyhow::anyhow!("Database encryption requires building with the `sqlcipher` feature"));
+    }
+    let encrypted = pragmas.key.is_some();
+    
     let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
     
     let mut opt = ConnectOptions::new(&db_url);
@@ -10,11 +64,95 @@ pub async fn connect(db_path: &Path) -> anyhow::Result<DatabaseConnection> {
         .min_connections(1)
         .connect_timeout(Duration::from_secs(10))
         .idle_timeout(Duration::from_secs(300))
-        .sqlx_logging(false);
+        .sqlx_logging(false)
+        .sqlite_pragmas(pragmas);
     
     let conn = Database::connect(opt).await?;
     
+    if encrypted {
+        // 密钥错误时首次读取才会失败
+        if let Err(e) = conn.execute_unprepared("SELECT count(*) FROM sqlite_master").await {
+            let _ = conn.close().await;
+            return Err(anyhow::anyhow!("Failed to open encrypted database, the key may be wrong: {}", e));
+        }
+    }
+    
     tracing::info!("Database connected: {}", db_url);
     
     Ok(conn)
 }
+
+/// 以只读方式打开数据库（用于检查备份文件），不修改日志模式
+pub async fn connect_read_only(db_path: &Path, key: Option<String>) -> anyhow::Result<DatabaseConnection> {
+    let db_url = format!("sqlite:{}?mode=ro", db_path.display());
+    
+    let mut opt = ConnectOptions::new(&db_url);
+    opt.max_connections(1)
+        .sqlx_logging(false);
+    if let Some(key) = key {
+        opt.map_sqlx_sqlite_opts(move |opts| opts.pragma("key", format!("'{}'", key.replace('\'', "''"))));
+    }
+    
+    Ok(Database::connect(opt).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, Statement};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_wal_concurrent_writers() {
+        let dir = std::env::temp_dir().join(format!("comics-wal-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let conn = connect(&dir.join("wal.db")).await.unwrap();
+
+        let mode = conn.query_one(Statement::from_string(DbBackend::Sqlite, "PRAGMA journal_mode"))
+            .await.unwrap().unwrap()
+            .try_get_by_index::<String>(0).unwrap();
+        assert_eq!(mode, "wal");
+
+        conn.execute_unprepared("CREATE TABLE t (id INTEGER PRIMARY KEY, writer INTEGER)").await.unwrap();
+        let writers = (0..2).map(|writer| {
+            let conn = conn.clone();
+            tokio::spawn(async move {
+                for _ in 0..50 {
+                    conn.execute(Statement::from_sql_and_values(
+                        DbBackend::Sqlite,
+                        "INSERT INTO t (writer) VALUES (?)",
+                        [writer.into()],
+                    )).await?;
+                }
+                Ok::<_, sea_orm::DbErr>(())
+            })
+        }).collect::<Vec<_>>();
+        for writer in writers {
+            writer.await.unwrap().unwrap();
+        }
+
+        let count = conn.query_one(Statement::from_string(DbBackend::Sqlite, "SELECT COUNT(*) FROM t"))
+            .await.unwrap().unwrap()
+            .try_get_by_index::<i64>(0).unwrap();
+        assert_eq!(count, 100);
+    }
+
+    #[tokio::test]
+    async fn test_encryption_key() {
+        let dir = std::env::temp_dir().join(format!("comics-key-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("encrypted.db");
+        let pragmas = |key: &str| SqlitePragmas { key: Some(key.to_string()), ..Default::default() };
+
+        if !cfg!(feature = "sqlcipher") {
+            assert!(connect_with(&path, pragmas("secret")).await.is_err());
+            return;
+        }
+
+        let conn = connect_with(&path, pragmas("secret")).await.unwrap();
+        conn.execute_unprepared("CREATE TABLE t (id INTEGER)").await.unwrap();
+        conn.close().await.unwrap();
+
+        assert!(connect_with(&path, pragmas("wrong")).await.is_err());
+        assert!(connect_with(&path, pragmas("secret")).await.is_ok());
+    }
+}
@@ -0,0 +1,23 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "chapter_progress")]
+pub struct Model {
+    // (module_id, comic_id, ep_id) 组合主键
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub module_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub comic_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub ep_id: String,
+    pub last_page: i32,       // 最后阅读的页码
+    pub total_pages: i32,     // 章节总页数（未知时为 0）
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "downloads")]
+pub struct Model {
+    // (module_id, comic_id, ep_id) 组合主键
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub module_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub comic_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub ep_id: String,
+    pub status: String,       // downloading / completed / failed
+    pub total_pages: i32,
+    pub downloaded_pages: i32,
+    pub dir_path: String,     // 章节图片保存目录
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,8 +2,16 @@ pub mod property;
 pub mod module_info;
 pub mod web_cache;
 pub mod image_cache;
+pub mod reading_history;
+pub mod download;
+pub mod search_history;
+pub mod chapter_progress;
 
 pub use property::Entity as PropertyEntity;
 pub use module_info::Entity as ModuleInfoEntity;
 pub use web_cache::Entity as WebCacheEntity;
 pub use image_cache::Entity as ImageCacheEntity;
+pub use reading_history::Entity as ReadingHistoryEntity;
+pub use download::Entity as DownloadEntity;
+pub use search_history::Entity as SearchHistoryEntity;
+pub use chapter_progress::Entity as ChapterProgressEntity;
@@ -9,10 +9,14 @@ pub struct Model {
     pub id: String,           // 模块唯一ID
     pub name: String,         // 模块名称
     pub version: String,      // 版本号
+    pub author: Option<String>, // 作者
     pub description: String,  // 描述
     pub script_path: String,  // JS 文件路径
     pub source_url: Option<String>, // 来源URL，用于更新
+    pub icon: Option<String>, // 图标（URL 或 data URI）
     pub enabled: bool,        // 是否启用
+    pub sandbox: bool,        // 是否以沙箱模式运行（禁用 eval / Function 构造器）
+    pub permissions: Option<String>, // 授予的权限（JSON 数组），为空表示全部权限
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
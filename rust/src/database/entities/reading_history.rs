@@ -0,0 +1,28 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "reading_history")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,           // module_id:comic_id 组合
+    pub module_id: String,
+    pub comic_id: String,
+    pub ep_id: String,        // 最近阅读的章节
+    pub page_index: i32,      // 章节内的页码
+    pub title: Option<String>,
+    pub thumb_url: Option<String>,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn create_id(module_id: &str, comic_id: &str) -> String {
+        format!("{}:{}", module_id, comic_id)
+    }
+}
@@ -0,0 +1,25 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDateTime;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "search_history")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,           // module_id:keyword 组合
+    pub module_id: String,
+    pub keyword: String,
+    pub searched_at: NaiveDateTime, // 最近一次搜索时间
+    pub count: i32,           // 搜索次数
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn create_id(module_id: &str, keyword: &str) -> String {
+        format!("{}:{}", module_id, keyword)
+    }
+}
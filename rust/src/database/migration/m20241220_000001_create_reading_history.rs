@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ReadingHistory 表，每部漫画只保留一条记录
+        manager.create_table(
+            Table::create()
+                .table(ReadingHistory::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(ReadingHistory::Id).string().not_null().primary_key())
+                .col(ColumnDef::new(ReadingHistory::ModuleId).string().not_null())
+                .col(ColumnDef::new(ReadingHistory::ComicId).string().not_null())
+                .col(ColumnDef::new(ReadingHistory::EpId).string().not_null())
+                .col(ColumnDef::new(ReadingHistory::PageIndex).integer().not_null().default(0))
+                .col(ColumnDef::new(ReadingHistory::Title).string().null())
+                .col(ColumnDef::new(ReadingHistory::ThumbUrl).text().null())
+                .col(ColumnDef::new(ReadingHistory::UpdatedAt).date_time().not_null())
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_reading_history_module_comic")
+                .table(ReadingHistory::Table)
+                .col(ReadingHistory::ModuleId)
+                .col(ReadingHistory::ComicId)
+                .unique()
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_reading_history_updated_at")
+                .table(ReadingHistory::Table)
+                .col(ReadingHistory::UpdatedAt)
+                .to_owned()
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(ReadingHistory::Table).to_owned()).await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ReadingHistory {
+    Table,
+    Id,
+    ModuleId,
+    ComicId,
+    EpId,
+    PageIndex,
+    Title,
+    ThumbUrl,
+    UpdatedAt,
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Downloads 表，每个章节一条记录，以 (module_id, comic_id, ep_id) 为主键
+        manager.create_table(
+            Table::create()
+                .table(Downloads::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Downloads::ModuleId).string().not_null())
+                .col(ColumnDef::new(Downloads::ComicId).string().not_null())
+                .col(ColumnDef::new(Downloads::EpId).string().not_null())
+                .col(ColumnDef::new(Downloads::Status).string().not_null())
+                .col(ColumnDef::new(Downloads::TotalPages).integer().not_null().default(0))
+                .col(ColumnDef::new(Downloads::DownloadedPages).integer().not_null().default(0))
+                .col(ColumnDef::new(Downloads::DirPath).string().not_null())
+                .col(ColumnDef::new(Downloads::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(Downloads::UpdatedAt).date_time().not_null())
+                .primary_key(
+                    Index::create()
+                        .col(Downloads::ModuleId)
+                        .col(Downloads::ComicId)
+                        .col(Downloads::EpId)
+                )
+                .to_owned()
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Downloads::Table).to_owned()).await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Downloads {
+    Table,
+    ModuleId,
+    ComicId,
+    EpId,
+    Status,
+    TotalPages,
+    DownloadedPages,
+    DirPath,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SearchHistory 表，同一模块下的关键词只保留一条记录
+        manager.create_table(
+            Table::create()
+                .table(SearchHistory::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(SearchHistory::Id).string().not_null().primary_key())
+                .col(ColumnDef::new(SearchHistory::ModuleId).string().not_null())
+                .col(ColumnDef::new(SearchHistory::Keyword).string().not_null())
+                .col(ColumnDef::new(SearchHistory::SearchedAt).date_time().not_null())
+                .col(ColumnDef::new(SearchHistory::Count).integer().not_null().default(1))
+                .to_owned()
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_search_history_module_searched_at")
+                .table(SearchHistory::Table)
+                .col(SearchHistory::ModuleId)
+                .col(SearchHistory::SearchedAt)
+                .to_owned()
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(SearchHistory::Table).to_owned()).await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum SearchHistory {
+    Table,
+    Id,
+    ModuleId,
+    Keyword,
+    SearchedAt,
+    Count,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 添加 author 字段到 module_info 表
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ModuleInfo::Table)
+                    .add_column(ColumnDef::new(ModuleInfo::Author).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 已有记录回填为空字符串，重新注册时会从脚本中读取
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(ModuleInfo::Table)
+                    .value(ModuleInfo::Author, "")
+                    .and_where(Expr::col(ModuleInfo::Author).is_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ModuleInfo::Table)
+                    .drop_column(ModuleInfo::Author)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ModuleInfo {
+    Table,
+    Author,
+}
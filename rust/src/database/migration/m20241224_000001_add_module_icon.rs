@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 添加 icon 字段到 module_info 表（URL 或 data URI）
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ModuleInfo::Table)
+                    .add_column(ColumnDef::new(ModuleInfo::Icon).text().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ModuleInfo::Table)
+                    .drop_column(ModuleInfo::Icon)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ModuleInfo {
+    Table,
+    Icon,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ChapterProgress 表，每个章节一条阅读进度，以 (module_id, comic_id, ep_id) 为主键
+        manager.create_table(
+            Table::create()
+                .table(ChapterProgress::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(ChapterProgress::ModuleId).string().not_null())
+                .col(ColumnDef::new(ChapterProgress::ComicId).string().not_null())
+                .col(ColumnDef::new(ChapterProgress::EpId).string().not_null())
+                .col(ColumnDef::new(ChapterProgress::LastPage).integer().not_null().default(0))
+                .col(ColumnDef::new(ChapterProgress::TotalPages).integer().not_null().default(0))
+                .col(ColumnDef::new(ChapterProgress::UpdatedAt).date_time().not_null())
+                .primary_key(
+                    Index::create()
+                        .col(ChapterProgress::ModuleId)
+                        .col(ChapterProgress::ComicId)
+                        .col(ChapterProgress::EpId)
+                )
+                .to_owned()
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(ChapterProgress::Table).to_owned()).await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ChapterProgress {
+    Table,
+    ModuleId,
+    ComicId,
+    EpId,
+    LastPage,
+    TotalPages,
+    UpdatedAt,
+}
@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 添加 permissions 字段到 module_info 表（JSON 数组，NULL 表示全部权限）
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ModuleInfo::Table)
+                    .add_column(ColumnDef::new(ModuleInfo::Permissions).text().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ModuleInfo::Table)
+                    .drop_column(ModuleInfo::Permissions)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ModuleInfo {
+    Table,
+    Permissions,
+}
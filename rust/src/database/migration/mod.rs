@@ -2,6 +2,14 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20241205_000001_create_tables;
 mod m20241211_000001_add_source_url;
+mod m20241220_000001_create_reading_history;
+mod m20241221_000001_create_downloads;
+mod m20241222_000001_create_search_history;
+mod m20241223_000001_add_module_author;
+mod m20241224_000001_add_module_icon;
+mod m20241225_000001_create_chapter_progress;
+mod m20241226_000001_add_module_sandbox;
+mod m20241227_000001_add_module_permissions;
 
 pub struct Migrator;
 
@@ -11,6 +19,14 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20241205_000001_create_tables::Migration),
             Box::new(m20241211_000001_add_source_url::Migration),
+            Box::new(m20241220_000001_create_reading_history::Migration),
+            Box::new(m20241221_000001_create_downloads::Migration),
+            Box::new(m20241222_000001_create_search_history::Migration),
+            Box::new(m20241223_000001_add_module_author::Migration),
+            Box::new(m20241224_000001_add_module_icon::Migration),
+            Box::new(m20241225_000001_create_chapter_progress::Migration),
+            Box::new(m20241226_000001_add_module_sandbox::Migration),
+            Box::new(m20241227_000001_add_module_permissions::Migration),
         ]
     }
 }
@@ -2,22 +2,32 @@ pub mod connection;
 pub mod entities;
 pub mod migration;
 
-use std::path::Path;
-use sea_orm::DatabaseConnection;
+use std::path::{Path, PathBuf};
+use sea_orm::{ConnectionTrait, DatabaseConnection};
 use once_cell::sync::OnceCell;
 use tokio::sync::RwLock;
 
 static DATABASE: OnceCell<RwLock<DatabaseConnection>> = OnceCell::new();
+static DATABASE_PATH: OnceCell<PathBuf> = OnceCell::new();
+static DATABASE_PRAGMAS: OnceCell<connection::SqlitePragmas> = OnceCell::new();
 
 pub async fn init_database(db_dir: &Path) -> anyhow::Result<()> {
+    init_database_with_key(db_dir, None).await
+}
+
+/// 初始化数据库，key 不为空时使用 SQLCipher 加密打开
+pub async fn init_database_with_key(db_dir: &Path, key: Option<String>) -> anyhow::Result<()> {
     let db_path = db_dir.join("comics.db");
-    let conn = connection::connect(&db_path).await?;
+    let pragmas = connection::SqlitePragmas { key, ..Default::default() };
+    let conn = connection::connect_with(&db_path, pragmas.clone()).await?;
     
     // 运行迁移
     migration::run_migrations(&conn).await?;
     
     DATABASE.set(RwLock::new(conn))
         .map_err(|_| anyhow::anyhow!("Database already initialized"))?;
+    let _ = DATABASE_PATH.set(db_path.clone());
+    let _ = DATABASE_PRAGMAS.set(pragmas);
     
     tracing::info!("Database initialized at: {:?}", db_path);
     
@@ -28,8 +38,69 @@ pub fn get_database() -> Option<&'static RwLock<DatabaseConnection>> {
     DATABASE.get()
 }
 
+/// 数据库文件路径
+pub fn get_database_path() -> Option<&'static PathBuf> {
+    DATABASE_PATH.get()
+}
+
+/// 打开当前数据库使用的连接参数（包含加密密钥）
+pub fn connection_pragmas() -> connection::SqlitePragmas {
+    DATABASE_PRAGMAS.get().cloned().unwrap_or_default()
+}
+
+/// 获取数据库连接的读锁
 pub async fn get_db_conn() -> anyhow::Result<tokio::sync::RwLockReadGuard<'static, DatabaseConnection>> {
-    get_database()
-        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))
-        .map(|db| db.blocking_read())
+    let db = get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    Ok(db.read().await)
+}
+
+/// 数据库文件及 WAL 文件的总大小（字节）
+pub async fn database_file_size() -> anyhow::Result<u64> {
+    let path = get_database_path()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    let mut size = tokio::fs::metadata(path).await?.len();
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+    if let Ok(metadata) = tokio::fs::metadata(PathBuf::from(wal)).await {
+        size += metadata.len();
+    }
+    Ok(size)
+}
+
+/// 执行 VACUUM 回收空间并 ANALYZE 更新查询统计
+/// 
+/// 持有写锁执行，会等待进行中的读操作结束，期间其他数据库访问被阻塞
+pub async fn vacuum() -> anyhow::Result<()> {
+    let db = get_database()
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+    let conn = db.write().await;
+    conn.execute_unprepared("VACUUM").await?;
+    conn.execute_unprepared("ANALYZE").await?;
+    // WAL 模式下需要检查点才能让主文件真正缩小
+    conn.execute_unprepared("PRAGMA wal_checkpoint(TRUNCATE)").await?;
+    Ok(())
+}
+
+/// 测试用：在临时目录初始化数据库（进程内只初始化一次）
+#[cfg(test)]
+pub(crate) async fn init_test_database() {
+    static INIT: tokio::sync::OnceCell<()> = tokio::sync::OnceCell::const_new();
+    INIT.get_or_init(|| async {
+        let dir = std::env::temp_dir().join(format!("comics-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        init_database(&dir).await.unwrap();
+    }).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_db_conn_in_async_context() {
+        init_test_database().await;
+        let conn = get_db_conn().await.unwrap();
+        conn.execute_unprepared("SELECT 1").await.unwrap();
+    }
 }
@@ -1,10 +1,17 @@
-use reqwest::{Client, Method, Response};
+use reqwest::{Client, ClientBuilder, Method, Response};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use once_cell::sync::Lazy;
+use std::time::{Duration, Instant};
 
-use crate::http::proxy::ProxyManager;
+use crate::cancel::{cancelable, CancelToken};
+use crate::http::cookie::CookieManager;
+use crate::http::proxy::{ProxyManager, ProxyRoute};
 
 /// HTTP 请求配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,10 +24,278 @@ pub struct HttpRequest {
     pub body: Option<String>,
     #[serde(default = "default_timeout")]
     pub timeout_secs: u64,
+    /// 跳过 TLS 证书校验（仅用于已知的分流 IP 场景）
+    #[serde(default)]
+    pub insecure: bool,
+    /// 是否使用模块的持久化 Cookie（需要同时提供 module_id）
+    #[serde(default)]
+    pub enable_cookies: bool,
+    /// 发起请求的模块 ID，用于隔离 Cookie 等模块级状态
+    #[serde(default)]
+    pub module_id: Option<String>,
+    /// 失败重试次数（连接错误、超时、5xx），默认不重试
+    #[serde(default)]
+    pub retries: u32,
+    /// 重试的基础退避时间（毫秒），每次重试翻倍并加入随机抖动
+    #[serde(default)]
+    pub retry_backoff_ms: u64,
+    /// 是否允许重试非幂等请求（POST/PATCH）
+    #[serde(default)]
+    pub retry_non_idempotent: bool,
+    /// 是否自动跟随重定向；关闭时直接返回 3xx 响应（保留 Location 头）
+    #[serde(default = "default_true")]
+    pub follow_redirects: bool,
+    /// 最大重定向次数
+    #[serde(default = "default_max_redirects")]
+    pub max_redirects: u32,
+    /// 本次请求使用的代理，覆盖模块及全局代理；"direct" 表示直连
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// 是否声明接受压缩（Accept-Encoding: gzip, deflate, br）；关闭时请求 identity
+    /// 
+    /// 无论是否声明，压缩的响应都会自动解压（raw_body 除外）
+    #[serde(default = "default_true")]
+    pub accept_compression: bool,
+    /// 返回服务器发送的原始字节，不自动解压（保留 Content-Encoding 头）
+    #[serde(default)]
+    pub raw_body: bool,
+    /// 认证信息，生成 Authorization 头（覆盖请求头中的同名头）
+    #[serde(default)]
+    pub auth: Option<HttpAuth>,
+    /// multipart/form-data 字段；设置后忽略 body，Content-Type（含 boundary）自动生成
+    #[serde(default)]
+    pub multipart: Option<Vec<MultipartPart>>,
+}
+
+/// 请求认证方式
+/// 
+/// JSON 形式为 `{"basic": {"user": "...", "pass": "..."}}` 或 `{"bearer": "token"}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HttpAuth {
+    Basic { user: String, #[serde(default)] pass: String },
+    Bearer(String),
+}
+
+impl HttpAuth {
+    /// Authorization 头的值
+    pub fn header_value(&self) -> String {
+        match self {
+            Self::Basic { user, pass } => {
+                format!("Basic {}", crate::crypto::base64_encode(format!("{}:{}", user, pass).as_bytes()))
+            }
+            Self::Bearer(token) => format!("Bearer {}", token.trim()),
+        }
+    }
 }
 
 fn default_timeout() -> u64 {
-    30
+    HttpClient::defaults().timeout_secs
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_redirects() -> u32 {
+    DEFAULT_MAX_REDIRECTS
+}
+
+/// 默认最大重定向次数（与 reqwest 默认策略一致）
+const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+impl Default for HttpRequest {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            method: "GET".to_string(),
+            headers: HashMap::new(),
+            body: None,
+            timeout_secs: default_timeout(),
+            insecure: false,
+            enable_cookies: false,
+            module_id: None,
+            retries: 0,
+            retry_backoff_ms: 0,
+            retry_non_idempotent: false,
+            follow_redirects: true,
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            proxy: None,
+            accept_compression: true,
+            raw_body: false,
+            auth: None,
+            multipart: None,
+        }
+    }
+}
+
+/// 请求体（文本或 multipart 字段，重定向和重试时需要重新构建）
+#[derive(Debug, Clone)]
+enum RequestBody {
+    Text(String),
+    Multipart(Vec<MultipartPart>),
+}
+
+/// 声明接受压缩时使用的 Accept-Encoding
+const ACCEPT_COMPRESSED: &str = "gzip, deflate, br";
+
+/// 单次重试退避时间上限
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
+/// 下载请求的超时（秒）
+const DOWNLOAD_TIMEOUT_SECS: u64 = 300;
+/// 下载失败（连接错误、超时、5xx）的重试次数及基础退避时间
+const DOWNLOAD_RETRIES: u32 = 2;
+const DOWNLOAD_RETRY_BACKOFF_MS: u64 = 500;
+
+/// 全局默认是否跳过证书校验（默认校验证书）
+static ACCEPT_INVALID_CERTS: AtomicBool = AtomicBool::new(false);
+
+/// 是否输出完整的请求/响应日志（默认关闭，可随时切换）
+static HTTP_LOGGING: AtomicBool = AtomicBool::new(false);
+
+/// 默认请求超时（秒）
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// 默认 User-Agent，部分站点会拒绝空 UA 或 reqwest 默认 UA
+pub const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36";
+
+/// 全局默认的超时和 User-Agent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpDefaults {
+    /// 未指定超时的请求使用的超时（秒）
+    pub timeout_secs: u64,
+    /// 请求头未设置 User-Agent 时使用的值
+    pub user_agent: String,
+}
+
+impl Default for HttpDefaults {
+    fn default() -> Self {
+        Self {
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+}
+
+static HTTP_DEFAULTS: Lazy<RwLock<HttpDefaults>> = Lazy::new(|| RwLock::new(HttpDefaults::default()));
+
+/// 连接池和连接超时设置
+/// 
+/// 合理范围（超出时按边界截断）：
+/// - `pool_max_idle_per_host`: 0–64，默认 10；并发下载较多时可适当调大，0 表示不复用连接
+/// - `pool_idle_timeout_secs`: 5–300 秒，默认 90；空闲连接超过该时间后关闭
+/// - `connect_timeout_secs`: 1–60 秒，默认 10
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpPoolSettings {
+    /// 每个主机保留的最大空闲连接数
+    pub pool_max_idle_per_host: u32,
+    /// 空闲连接的保持时间（秒）
+    pub pool_idle_timeout_secs: u64,
+    /// 建立连接的超时（秒）
+    pub connect_timeout_secs: u64,
+}
+
+impl Default for HttpPoolSettings {
+    fn default() -> Self {
+        Self {
+            pool_max_idle_per_host: 10,
+            pool_idle_timeout_secs: 90,
+            connect_timeout_secs: 10,
+        }
+    }
+}
+
+impl HttpPoolSettings {
+    /// 把各项截断到合理范围
+    pub fn normalized(self) -> Self {
+        Self {
+            pool_max_idle_per_host: self.pool_max_idle_per_host.min(64),
+            pool_idle_timeout_secs: self.pool_idle_timeout_secs.clamp(5, 300),
+            connect_timeout_secs: self.connect_timeout_secs.clamp(1, 60),
+        }
+    }
+
+    /// 应用到客户端构建器
+    fn apply(&self, builder: ClientBuilder) -> ClientBuilder {
+        builder
+            .pool_max_idle_per_host(self.pool_max_idle_per_host as usize)
+            .pool_idle_timeout(Duration::from_secs(self.pool_idle_timeout_secs))
+            .connect_timeout(Duration::from_secs(self.connect_timeout_secs))
+    }
+}
+
+static POOL_SETTINGS: Lazy<RwLock<HttpPoolSettings>> = Lazy::new(|| RwLock::new(HttpPoolSettings::default()));
+
+/// 域名到 IP 的固定解析（小写域名 -> IP），用于通过分流 IP 访问站点，同时保留原域名的 Host 和 SNI
+static DNS_OVERRIDES: Lazy<RwLock<HashMap<String, IpAddr>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// 全局共享的 HTTP 客户端（复用连接池），配置变化时置空并重建
+static SHARED_CLIENT: Lazy<RwLock<Option<Arc<HttpClient>>>> = Lazy::new(|| RwLock::new(None));
+
+/// 决定客户端配置的请求参数，不同于共享客户端时按此缓存客户端
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ClientKey {
+    accept_invalid_certs: bool,
+    /// 自动跟随的最大重定向次数，None 表示不自动跟随
+    max_redirects: Option<u32>,
+    raw_body: bool,
+    /// 分流解析：域名 -> IP 地址
+    resolve: Option<(String, SocketAddr)>,
+    /// 请求级或模块级代理
+    route: ProxyRoute,
+}
+
+impl ClientKey {
+    /// 共享客户端对应的配置
+    fn shared(accept_invalid_certs: bool) -> Self {
+        Self {
+            accept_invalid_certs,
+            max_redirects: Some(DEFAULT_MAX_REDIRECTS),
+            raw_body: false,
+            resolve: None,
+            route: ProxyRoute::Global,
+        }
+    }
+}
+
+/// 按配置缓存的客户端，与共享客户端一同失效
+static ROUTE_CLIENTS: Lazy<RwLock<HashMap<ClientKey, Client>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// multipart/form-data 的一个字段
+/// 
+/// 文本字段设置 `value`；文件字段设置 `data`（base64 编码），
+/// 可选 `filename` 和 `content_type`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultipartPart {
+    pub name: String,
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub filename: Option<String>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+}
+
+impl MultipartPart {
+    fn to_part(&self) -> anyhow::Result<reqwest::multipart::Part> {
+        let mut part = match (&self.value, &self.data) {
+            (_, Some(data)) => reqwest::multipart::Part::bytes(crate::crypto::base64_decode(data)?),
+            (Some(value), None) => reqwest::multipart::Part::text(value.clone()),
+            (None, None) => {
+                return Err(anyhow::anyhow!("Multipart field '{}' has neither value nor data", self.name));
+            }
+        };
+        if let Some(filename) = &self.filename {
+            part = part.file_name(filename.clone());
+        }
+        if let Some(content_type) = &self.content_type {
+            part = part.mime_str(content_type)?;
+        }
+        Ok(part)
+    }
 }
 
 /// HTTP 响应
@@ -30,31 +305,231 @@ pub struct HttpResponse {
     pub headers: HashMap<String, String>,
     pub body: String,
     pub content_type: String,
+    /// 跟随重定向后的最终 URL
+    #[serde(default)]
+    pub final_url: String,
+    /// 从发送请求到读完响应体的耗时（毫秒）
+    #[serde(default)]
+    pub elapsed_ms: u64,
+    /// 响应体字节数（解码前）
+    #[serde(default)]
+    pub body_size: usize,
 }
 
 /// HTTP 客户端
 pub struct HttpClient {
     client: Client,
+    accept_invalid_certs: bool,
 }
 
 impl HttpClient {
+    /// 按全局默认设置创建客户端
     pub fn new() -> anyhow::Result<Self> {
-        Self::with_config(30, None)
+        Self::with_config(Self::defaults().timeout_secs, None)
     }
 
     pub fn with_config(timeout_secs: u64, user_agent: Option<String>) -> anyhow::Result<Self> {
-        let mut builder = Client::builder()
-            .timeout(Duration::from_secs(timeout_secs))
-            .connect_timeout(Duration::from_secs(10))
-            .pool_max_idle_per_host(10)
-            .danger_accept_invalid_certs(true);  // 禁用证书验证（用于分流IP访问）
+        let accept_invalid_certs = Self::default_accept_invalid_certs();
+        let mut builder = Self::base_builder(timeout_secs, accept_invalid_certs);
         
         if let Some(ua) = user_agent {
             builder = builder.user_agent(ua);
         }
         
-        // 从代理管理器获取代理配置
-        if let Some(proxy_result) = ProxyManager::instance().get_reqwest_proxy() {
+        let client = builder.build()?;
+        
+        Ok(Self { client, accept_invalid_certs })
+    }
+
+    /// 获取全局共享的 HTTP 客户端
+    /// 
+    /// 共享客户端复用连接池，避免每次请求重新握手。
+    /// 代理、证书或固定解析设置变化时会被重建。
+    pub fn shared() -> anyhow::Result<Arc<HttpClient>> {
+        {
+            let guard = SHARED_CLIENT.read()
+                .map_err(|e| anyhow::anyhow!("获取共享客户端锁失败: {}", e))?;
+            if let Some(client) = guard.as_ref() {
+                return Ok(client.clone());
+            }
+        }
+        
+        let mut guard = SHARED_CLIENT.write()
+            .map_err(|e| anyhow::anyhow!("获取共享客户端锁失败: {}", e))?;
+        if let Some(client) = guard.as_ref() {
+            return Ok(client.clone());
+        }
+        let client = Arc::new(Self::new()?);
+        *guard = Some(client.clone());
+        tracing::debug!("共享 HTTP 客户端已创建");
+        Ok(client)
+    }
+
+    /// 使共享客户端失效，下次获取时按最新配置重建
+    pub fn invalidate_shared() {
+        if let Ok(mut guard) = SHARED_CLIENT.write() {
+            *guard = None;
+        }
+        if let Ok(mut clients) = ROUTE_CLIENTS.write() {
+            clients.clear();
+        }
+    }
+
+    /// 设置全局默认是否跳过证书校验
+    pub fn set_default_accept_invalid_certs(accept: bool) {
+        ACCEPT_INVALID_CERTS.store(accept, Ordering::Relaxed);
+        Self::invalidate_shared();
+    }
+
+    /// 获取全局默认是否跳过证书校验
+    pub fn default_accept_invalid_certs() -> bool {
+        ACCEPT_INVALID_CERTS.load(Ordering::Relaxed)
+    }
+
+    /// 设置连接池和连接超时，超出合理范围的值会被截断
+    pub fn set_pool_settings(settings: HttpPoolSettings) {
+        if let Ok(mut guard) = POOL_SETTINGS.write() {
+            *guard = settings.normalized();
+        }
+        Self::invalidate_shared();
+    }
+
+    /// 获取连接池和连接超时设置
+    pub fn pool_settings() -> HttpPoolSettings {
+        POOL_SETTINGS.read()
+            .map(|s| s.clone())
+            .unwrap_or_default()
+    }
+
+    /// 把域名固定解析到指定 IP，`ip` 为 None 时移除
+    /// 
+    /// 请求仍使用原域名的 Host 和 SNI，因此可以正常校验证书
+    pub fn set_dns_override(host: &str, ip: Option<IpAddr>) {
+        let host = host.trim().to_lowercase();
+        if let Ok(mut guard) = DNS_OVERRIDES.write() {
+            match ip {
+                Some(ip) => guard.insert(host, ip),
+                None => guard.remove(&host),
+            };
+        }
+        Self::invalidate_shared();
+    }
+
+    /// 替换全部固定解析
+    pub fn set_dns_overrides(overrides: HashMap<String, IpAddr>) {
+        if let Ok(mut guard) = DNS_OVERRIDES.write() {
+            *guard = overrides.into_iter()
+                .map(|(host, ip)| (host.trim().to_lowercase(), ip))
+                .collect();
+        }
+        Self::invalidate_shared();
+    }
+
+    /// 获取当前的固定解析
+    pub fn dns_overrides() -> HashMap<String, IpAddr> {
+        DNS_OVERRIDES.read()
+            .map(|d| d.clone())
+            .unwrap_or_default()
+    }
+
+    /// 设置是否输出完整的请求/响应日志
+    pub fn set_logging(enabled: bool) {
+        HTTP_LOGGING.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 是否输出完整的请求/响应日志
+    pub fn logging_enabled() -> bool {
+        HTTP_LOGGING.load(Ordering::Relaxed)
+    }
+
+    /// 日志用的请求头副本，隐藏认证相关的值（Authorization、Cookie、包含 token 的头）
+    fn redact_headers(headers: &HashMap<String, String>) -> std::collections::BTreeMap<String, String> {
+        headers.iter()
+            .map(|(key, value)| {
+                let lower = key.to_lowercase();
+                let sensitive = lower.contains("authorization")
+                    || lower.contains("cookie")
+                    || lower.contains("token");
+                let value = if sensitive { "<redacted>".to_string() } else { value.clone() };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    /// 设置全局默认超时和 User-Agent
+    pub fn set_defaults(defaults: HttpDefaults) {
+        if let Ok(mut guard) = HTTP_DEFAULTS.write() {
+            *guard = defaults;
+        }
+        Self::invalidate_shared();
+    }
+
+    /// 获取全局默认超时和 User-Agent
+    pub fn defaults() -> HttpDefaults {
+        HTTP_DEFAULTS.read()
+            .map(|d| d.clone())
+            .unwrap_or_default()
+    }
+
+    /// 获取按配置缓存的客户端，首次使用时创建
+    fn cached_client(key: &ClientKey) -> anyhow::Result<Client> {
+        {
+            let clients = ROUTE_CLIENTS.read()
+                .map_err(|e| anyhow::anyhow!("获取客户端缓存锁失败: {}", e))?;
+            if let Some(client) = clients.get(key) {
+                return Ok(client.clone());
+            }
+        }
+        let client = Self::build_client(key)?;
+        let mut clients = ROUTE_CLIENTS.write()
+            .map_err(|e| anyhow::anyhow!("获取客户端缓存锁失败: {}", e))?;
+        Ok(clients.entry(key.clone()).or_insert(client).clone())
+    }
+
+    /// 按配置创建客户端（请求级超时在发送时设置）
+    fn build_client(key: &ClientKey) -> anyhow::Result<Client> {
+        let redirect = match key.max_redirects {
+            Some(max) => reqwest::redirect::Policy::limited(max as usize),
+            None => reqwest::redirect::Policy::none(),
+        };
+        let mut builder = Self::route_builder(Self::defaults().timeout_secs, key.accept_invalid_certs, &key.route)
+            .redirect(redirect);
+        if let Some((host, addr)) = &key.resolve {
+            builder = builder.resolve(host, *addr);
+        }
+        // 原始模式关闭自动解压，返回未解码的字节
+        if key.raw_body {
+            builder = builder.no_gzip().no_brotli().no_deflate();
+        }
+        Ok(builder.build()?)
+    }
+
+    /// 创建基础的客户端构建器（超时、连接池、证书校验、全局代理）
+    fn base_builder(timeout_secs: u64, accept_invalid_certs: bool) -> ClientBuilder {
+        Self::route_builder(timeout_secs, accept_invalid_certs, &ProxyRoute::Global)
+    }
+
+    /// 创建使用指定代理的客户端构建器
+    fn route_builder(timeout_secs: u64, accept_invalid_certs: bool, route: &ProxyRoute) -> ClientBuilder {
+        Self::route_builder_with(timeout_secs, accept_invalid_certs, route, &Self::defaults().user_agent)
+    }
+
+    /// 创建使用指定代理和默认 User-Agent 的客户端构建器
+    fn route_builder_with(timeout_secs: u64, accept_invalid_certs: bool, route: &ProxyRoute, user_agent: &str) -> ClientBuilder {
+        let builder = Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .user_agent(user_agent)
+            .danger_accept_invalid_certs(accept_invalid_certs);
+        let builder = Self::pool_settings().apply(builder);
+        let mut builder = Self::apply_dns_overrides(builder, &Self::dns_overrides());
+        
+        let proxy_result = match route {
+            // 从代理管理器获取全局代理配置
+            ProxyRoute::Global => ProxyManager::instance().get_reqwest_proxy(),
+            ProxyRoute::Direct => return builder.no_proxy(),
+            ProxyRoute::Proxy(config) => Some(ProxyManager::instance().build_reqwest_proxy(config)),
+        };
+        if let Some(proxy_result) = proxy_result {
             match proxy_result {
                 Ok(proxy) => {
                     builder = builder.proxy(proxy);
@@ -66,14 +541,123 @@ impl HttpClient {
             }
         }
         
-        let client = builder.build()?;
-        
-        Ok(Self { client })
+        builder
+    }
+
+    /// 把固定解析应用到客户端构建器（端口为 0 时使用 URL 中的端口）
+    fn apply_dns_overrides(mut builder: ClientBuilder, overrides: &HashMap<String, IpAddr>) -> ClientBuilder {
+        for (host, ip) in overrides {
+            builder = builder.resolve(host, SocketAddr::new(*ip, 0));
+        }
+        builder
     }
 
     /// 发送 HTTP 请求
     pub async fn request(&self, req: HttpRequest) -> anyhow::Result<HttpResponse> {
-        let method = match req.method.to_uppercase().as_str() {
+        let started = Instant::now();
+        let response = self.send(req).await?;
+        Self::parse_response(response, started).await
+    }
+
+    /// 发送 HTTP 请求，返回原始字节（不做 UTF-8 解码）
+    /// 返回 (状态码, 响应头, 响应体字节)
+    pub async fn request_bytes(&self, req: HttpRequest) -> anyhow::Result<(u16, HashMap<String, String>, Vec<u8>)> {
+        let (status, headers, body, _) = self.request_bytes_with_url(req).await?;
+        Ok((status, headers, body))
+    }
+
+    /// 发送 HTTP 请求，返回原始字节及跟随重定向后的最终 URL
+    /// 返回 (状态码, 响应头, 响应体字节, 最终 URL)
+    pub async fn request_bytes_with_url(&self, req: HttpRequest) -> anyhow::Result<(u16, HashMap<String, String>, Vec<u8>, String)> {
+        let response = self.send(req).await?;
+        let status = response.status().as_u16();
+        let headers = Self::collect_headers(&response);
+        let final_url = response.url().to_string();
+        let body = response.bytes().await?;
+        Ok((status, headers, body.to_vec(), final_url))
+    }
+
+    /// 发送请求，按配置对连接错误、超时和 5xx 进行重试
+    async fn send(&self, req: HttpRequest) -> anyhow::Result<Response> {
+        let max_attempts = if req.retry_non_idempotent || Self::is_idempotent(&req.method) {
+            req.retries + 1
+        } else {
+            1
+        };
+        
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let result = self.send_once(req.clone()).await;
+            
+            let retry_reason = match &result {
+                Ok(response) if response.status().is_server_error() => {
+                    Some(format!("HTTP {}", response.status()))
+                }
+                Ok(_) => None,
+                Err(e) => Self::retryable_error(e),
+            };
+            
+            match retry_reason {
+                Some(reason) if attempt < max_attempts => {
+                    let delay = Self::backoff_delay(req.retry_backoff_ms, attempt) + Self::jitter(req.retry_backoff_ms);
+                    tracing::warn!(
+                        "请求失败 ({})，{:?} 后重试 ({}/{}): {}",
+                        reason, delay, attempt, max_attempts - 1, req.url
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                _ => {
+                    return result.map_err(|e| {
+                        if attempt > 1 {
+                            anyhow::anyhow!("Request failed after {} attempts: {}", attempt, e)
+                        } else {
+                            e
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    /// 是否为幂等方法（可安全重试）
+    fn is_idempotent(method: &str) -> bool {
+        matches!(
+            method.to_uppercase().as_str(),
+            "GET" | "HEAD" | "OPTIONS" | "PUT" | "DELETE"
+        )
+    }
+
+    /// 判断错误是否可重试，返回重试原因
+    fn retryable_error(error: &anyhow::Error) -> Option<String> {
+        let e = error.downcast_ref::<reqwest::Error>()?;
+        if e.is_timeout() {
+            Some("timeout".to_string())
+        } else if e.is_connect() {
+            Some("connect error".to_string())
+        } else {
+            None
+        }
+    }
+
+    /// 第 attempt 次失败后的退避时间：base * 2^(attempt-1)，有上限
+    fn backoff_delay(base_ms: u64, attempt: u32) -> Duration {
+        let factor = 1u64 << (attempt.saturating_sub(1)).min(16);
+        Duration::from_millis(base_ms.saturating_mul(factor)).min(MAX_RETRY_BACKOFF)
+    }
+
+    /// 随机抖动：0 ~ base/2 毫秒
+    fn jitter(base_ms: u64) -> Duration {
+        use rand::Rng;
+        if base_ms < 2 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(rand::thread_rng().gen_range(0..=base_ms / 2))
+    }
+
+    /// 构建并发送单次请求
+    async fn send_once(&self, req: HttpRequest) -> anyhow::Result<Response> {
+        let mut method = match req.method.to_uppercase().as_str() {
             "GET" => Method::GET,
             "POST" => Method::POST,
             "PUT" => Method::PUT,
@@ -84,78 +668,201 @@ impl HttpClient {
             _ => return Err(anyhow::anyhow!("Unsupported HTTP method: {}", req.method)),
         };
 
+        // 请求级别可以显式跳过证书校验
+        let accept_invalid_certs = req.insecure || self.accept_invalid_certs;
+
+        // 代理优先级：请求级 > 模块级 > 全局
+        let proxy_route = ProxyManager::instance().resolve(req.proxy.as_deref(), req.module_id.as_deref())?;
+
+        // 使用模块 Cookie 时手动跟随重定向，以便保存每一跳的 Set-Cookie 并为下一跳附加 Cookie
+        let cookie_scope = if req.enable_cookies { req.module_id.clone() } else { None };
+        let manual_redirects = cookie_scope.is_some() && req.follow_redirects;
+
         // 处理分流：当 URL 使用 IP 且存在 Host 头时，改用域名 + resolve 映射以确保 TLS SNI 正确
-        let mut effective_client = &self.client;
         let mut effective_url_str = req.url.clone();
-        let mut tmp_client_opt: Option<Client> = None;
+        let mut resolve = None;
         if let Ok(mut url) = Url::parse(&req.url) {
             if let Some(host_str) = url.host_str() {
                 // 拷贝 IP host，避免与后续对 url 的可变借用冲突
                 let host = host_str.to_string();
                 // 检查是否为 IP 地址
-                let is_ip = host.parse::<std::net::IpAddr>().is_ok();
-                if is_ip {
+                if let Ok(ip_addr) = host.parse::<IpAddr>() {
                     if let Some(host_header) = req.headers.get("Host") {
                         // 使用域名替换 URL 的 host，以便发送时使用正确的 SNI
                         url.set_host(Some(host_header)).ok();
                         effective_url_str = url.to_string();
 
-                        // 构建带有 resolve(domain -> ip) 的临时客户端
-                        let mut builder = Client::builder()
-                            .timeout(Duration::from_secs(req.timeout_secs))
-                            .connect_timeout(Duration::from_secs(10))
-                            .pool_max_idle_per_host(10)
-                            .danger_accept_invalid_certs(true)
-                            .resolve(
-                                host_header.as_str(),
-                                {
-                                    // 根据协议选择端口
-                                    let default_port = match url.scheme() {
-                                        "https" => 443,
-                                        _ => 80,
-                                    };
-                                    let ip_addr: std::net::IpAddr = host.parse().unwrap();
-                                    std::net::SocketAddr::new(ip_addr, url.port().unwrap_or(default_port))
-                                },
-                            );
-
-                        // 保持代理配置
-                        if let Some(proxy_result) = ProxyManager::instance().get_reqwest_proxy() {
-                            if let Ok(proxy) = proxy_result {
-                                builder = builder.proxy(proxy);
-                            }
-                        }
+                        // 根据协议选择端口
+                        let default_port = match url.scheme() {
+                            "https" => 443,
+                            _ => 80,
+                        };
+                        resolve = Some((host_header.clone(), SocketAddr::new(ip_addr, url.port().unwrap_or(default_port))));
+                        tracing::debug!("使用自定义域名解析: {} -> {}", host_header, host);
+                    }
+                }
+            }
+        }
 
-                        // 构建临时客户端
-                        if let Ok(c) = builder.build() {
-                            tmp_client_opt = Some(c);
-                        }
-                        if let Some(c) = &tmp_client_opt {
-                            effective_client = c;
-                            tracing::debug!("使用自定义域名解析: {} -> {}", host_header, host);
-                        }
+        // 证书校验、重定向策略、代理、原始模式或分流解析与当前客户端不一致时，使用按配置缓存的客户端
+        let key = ClientKey {
+            accept_invalid_certs,
+            max_redirects: if req.follow_redirects && !manual_redirects { Some(req.max_redirects) } else { None },
+            raw_body: req.raw_body,
+            resolve,
+            route: proxy_route,
+        };
+        let effective_client = if key == ClientKey::shared(self.accept_invalid_certs) {
+            self.client.clone()
+        } else {
+            Self::cached_client(&key)?
+        };
+
+        let mut headers = req.headers;
+        let mut body = match (req.multipart, req.body) {
+            (Some(parts), _) => {
+                // Content-Type 由 multipart 自动生成（包含 boundary），忽略手动设置的值
+                headers.retain(|k, _| !k.eq_ignore_ascii_case("content-type"));
+                Some(RequestBody::Multipart(parts))
+            }
+            (None, Some(text)) => Some(RequestBody::Text(text)),
+            (None, None) => None,
+        };
+        if let Some(auth) = &req.auth {
+            headers.retain(|k, _| !k.eq_ignore_ascii_case("authorization"));
+            headers.insert("Authorization".to_string(), auth.header_value());
+        }
+
+        // 未显式设置 Accept-Encoding 时按 accept_compression 声明（Range 请求不协商压缩）
+        let has_header = |name: &str| headers.keys().any(|k| k.eq_ignore_ascii_case(name));
+        if !has_header("accept-encoding") && !has_header("range") {
+            let encoding = if req.accept_compression { ACCEPT_COMPRESSED } else { "identity" };
+            headers.insert("Accept-Encoding".to_string(), encoding.to_string());
+        }
+
+        let mut url = Url::parse(&effective_url_str)?;
+        let mut redirects = 0;
+        loop {
+            let response = Self::send_hop(
+                &effective_client,
+                method.clone(),
+                &url,
+                &headers,
+                body.as_ref(),
+                req.timeout_secs,
+                cookie_scope.as_deref(),
+            ).await?;
+
+            if !manual_redirects || !response.status().is_redirection() {
+                return Ok(response);
+            }
+            let Some(location) = response.headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+            else {
+                return Ok(response);
+            };
+            if redirects >= req.max_redirects {
+                return Err(anyhow::anyhow!("重定向次数超过上限 {}: {}", req.max_redirects, req.url));
+            }
+            redirects += 1;
+
+            let next = response.url().join(&location)?;
+            // 303，以及 POST 的 301/302 改为 GET 并丢弃请求体（与浏览器和 reqwest 的行为一致）
+            let status = response.status().as_u16();
+            if status == 303 || (matches!(status, 301 | 302) && method == Method::POST) {
+                method = Method::GET;
+                body = None;
+                headers.retain(|k, _| !k.eq_ignore_ascii_case("content-type") && !k.eq_ignore_ascii_case("content-length"));
+            }
+            // 跳转到其他主机时不再携带认证头
+            if next.host_str() != url.host_str() {
+                headers.retain(|k, _| !k.eq_ignore_ascii_case("authorization"));
+            }
+            url = next;
+        }
+    }
+
+    /// 发送一跳请求：附加模块 Cookie、记录日志，并保存响应中的 Set-Cookie
+    async fn send_hop(
+        client: &Client,
+        method: Method,
+        url: &Url,
+        headers: &HashMap<String, String>,
+        body: Option<&RequestBody>,
+        timeout_secs: u64,
+        cookie_scope: Option<&str>,
+    ) -> anyhow::Result<Response> {
+        // 模块 Cookie：与显式传入的 Cookie 头合并
+        let mut headers = headers.clone();
+        if let Some(module_id) = cookie_scope {
+            if let Some(jar_cookie) = CookieManager::instance().cookie_header(module_id, url).await? {
+                let jar_cookie = jar_cookie.to_str().unwrap_or_default().to_string();
+                match headers.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case("cookie")) {
+                    Some((_, existing)) => *existing = format!("{}; {}", existing, jar_cookie),
+                    None => {
+                        headers.insert("Cookie".to_string(), jar_cookie);
                     }
                 }
             }
         }
 
-        let mut request_builder = effective_client
-            .request(method, &effective_url_str)
-            .timeout(Duration::from_secs(req.timeout_secs));
+        let mut request_builder = client
+            .request(method.clone(), url.clone())
+            .timeout(Duration::from_secs(timeout_secs));
 
         // 添加 headers
-        for (key, value) in &req.headers {
+        for (key, value) in &headers {
             request_builder = request_builder.header(key.as_str(), value.as_str());
         }
 
         // 添加 body
-        if let Some(body) = req.body {
-            request_builder = request_builder.body(body);
+        match body {
+            Some(RequestBody::Text(text)) => {
+                request_builder = request_builder.body(text.clone());
+            }
+            Some(RequestBody::Multipart(parts)) => {
+                let mut form = reqwest::multipart::Form::new();
+                for part in parts {
+                    form = form.part(part.name.clone(), part.to_part()?);
+                }
+                request_builder = request_builder.multipart(form);
+            }
+            None => {}
         }
 
-        let response = request_builder.send().await?;
-        
-        Self::parse_response(response).await
+        let logging = Self::logging_enabled();
+        let started = Instant::now();
+        if logging {
+            tracing::info!("[HTTP] --> {} {} headers={:?}", method, url, Self::redact_headers(&headers));
+        }
+
+        let result = request_builder.send().await;
+        if logging {
+            let elapsed_ms = started.elapsed().as_millis();
+            match &result {
+                Ok(response) => tracing::info!(
+                    "[HTTP] <-- {} {} ({}ms) headers={:?}",
+                    response.status().as_u16(), response.url(), elapsed_ms,
+                    Self::redact_headers(&Self::collect_headers(response))
+                ),
+                Err(e) => tracing::info!("[HTTP] <-- {} failed ({}ms): {}", url, elapsed_ms, e),
+            }
+        }
+        let response = result?;
+
+        // 保存响应中的 Set-Cookie
+        if let Some(module_id) = cookie_scope {
+            let set_cookies: Vec<String> = response.headers()
+                .get_all(reqwest::header::SET_COOKIE)
+                .iter()
+                .filter_map(|v| v.to_str().ok().map(|s| s.to_string()))
+                .collect();
+            CookieManager::instance().store(module_id, response.url(), set_cookies).await?;
+        }
+
+        Ok(response)
     }
 
     /// GET 请求
@@ -165,7 +872,8 @@ impl HttpClient {
             method: "GET".to_string(),
             headers,
             body: None,
-            timeout_secs: 30,
+            timeout_secs: default_timeout(),
+            ..Default::default()
         }).await
     }
 
@@ -176,21 +884,116 @@ impl HttpClient {
             method: "POST".to_string(),
             headers,
             body,
-            timeout_secs: 30,
+            timeout_secs: default_timeout(),
+            ..Default::default()
         }).await
     }
 
-    /// 下载文件（返回字节）
-    pub async fn download(&self, url: &str, headers: HashMap<String, String>) -> anyhow::Result<Vec<u8>> {
-        let mut request_builder = self.client
-            .get(url)
-            .timeout(Duration::from_secs(300));
+    /// multipart/form-data POST 请求
+    pub async fn post_multipart(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        parts: Vec<MultipartPart>,
+    ) -> anyhow::Result<HttpResponse> {
+        self.request(HttpRequest {
+            url: url.to_string(),
+            method: "POST".to_string(),
+            headers,
+            // 上传文件可能较慢，至少给 60 秒
+            timeout_secs: default_timeout().max(60),
+            multipart: Some(parts),
+            ..Default::default()
+        }).await
+    }
 
-        for (key, value) in &headers {
-            request_builder = request_builder.header(key.as_str(), value.as_str());
+    /// 获取资源大小（字节），不下载响应体
+    /// 先发 HEAD 请求，服务器不支持 HEAD 时退回 Range 为 0 字节的 GET；长度未知时返回 None
+    pub async fn content_length(&self, url: &str, headers: HashMap<String, String>) -> Option<u64> {
+        self.content_length_of(HttpRequest {
+            url: url.to_string(),
+            headers,
+            ..Default::default()
+        }).await
+    }
+
+    /// 按完整请求配置探测资源大小（保留模块的 Cookie、代理等设置）
+    pub(crate) async fn content_length_of(&self, req: HttpRequest) -> Option<u64> {
+        // 不声明压缩，否则拿到的是压缩后的长度，且 reqwest 解压时会移除 Content-Length
+        let head = HttpRequest {
+            method: "HEAD".to_string(),
+            body: None,
+            accept_compression: false,
+            ..req.clone()
+        };
+        match self.send(head).await {
+            Ok(response) if response.status().is_success() => {
+                if let Some(length) = Self::header_content_length(&response) {
+                    return Some(length);
+                }
+            }
+            Ok(response) => tracing::debug!("HEAD {} 返回 {}，改用 Range 请求", req.url, response.status()),
+            Err(e) => tracing::debug!("HEAD {} 失败，改用 Range 请求: {}", req.url, e),
+        }
+
+        let mut headers: HashMap<String, String> = req.headers
+            .iter()
+            .filter(|(key, _)| !key.eq_ignore_ascii_case("range"))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        headers.insert("Range".to_string(), "bytes=0-0".to_string());
+        let get = HttpRequest {
+            method: "GET".to_string(),
+            headers,
+            body: None,
+            accept_compression: false,
+            ..req
+        };
+        // 不读取响应体，直接丢弃连接
+        let response = self.send(get).await.ok()?;
+        match response.status().as_u16() {
+            // Content-Range: bytes 0-0/12345，总长度未知时为 *
+            206 => response.headers()
+                .get(reqwest::header::CONTENT_RANGE)?
+                .to_str().ok()?
+                .rsplit_once('/')?
+                .1.trim()
+                .parse().ok(),
+            // 服务器忽略 Range，返回完整内容
+            200 => Self::header_content_length(&response),
+            _ => None,
+        }
+    }
+
+    fn header_content_length(response: &Response) -> Option<u64> {
+        response.headers()
+            .get(reqwest::header::CONTENT_LENGTH)?
+            .to_str().ok()?
+            .trim()
+            .parse().ok()
+    }
+
+    /// 下载请求的默认配置：较长的超时、不协商压缩（保证进度中的总字节准确），并对失败进行重试
+    pub(crate) fn download_request(url: &str, headers: HashMap<String, String>) -> HttpRequest {
+        HttpRequest {
+            url: url.to_string(),
+            headers,
+            timeout_secs: DOWNLOAD_TIMEOUT_SECS,
+            accept_compression: false,
+            retries: DOWNLOAD_RETRIES,
+            retry_backoff_ms: DOWNLOAD_RETRY_BACKOFF_MS,
+            ..Default::default()
         }
+    }
 
-        let response = request_builder.send().await?;
+    /// 下载文件（返回字节）
+    pub async fn download(&self, url: &str, headers: HashMap<String, String>) -> anyhow::Result<Vec<u8>> {
+        self.download_with(Self::download_request(url, headers)).await
+    }
+
+    /// 按完整请求配置下载（与 request 相同的重试、代理和模块 Cookie 处理）
+    pub(crate) async fn download_with(&self, req: HttpRequest) -> anyhow::Result<Vec<u8>> {
+        let response = self.send(req).await?;
         
         if !response.status().is_success() {
             return Err(anyhow::anyhow!("Download failed with status: {}", response.status()));
@@ -200,30 +1003,146 @@ impl HttpClient {
         Ok(bytes.to_vec())
     }
 
-    async fn parse_response(response: Response) -> anyhow::Result<HttpResponse> {
-        let status = response.status().as_u16();
+    /// 流式下载到文件，通过回调报告进度 (已接收字节, 总字节)
+    /// 
+    /// 先写入 `.part` 临时文件，完成后重命名；失败时删除临时文件。
+    /// 服务器未返回 Content-Length 时总字节为 None。
+    pub async fn download_to_file<F>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        dest_path: &Path,
+        on_progress: F,
+    ) -> anyhow::Result<u64>
+    where
+        F: FnMut(u64, Option<u64>),
+    {
+        self.download_to_file_cancelable(url, headers, dest_path, None, on_progress).await
+    }
+
+    /// 可取消的流式下载，取消时返回 `Cancelled` 并删除临时文件
+    pub async fn download_to_file_cancelable<F>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        dest_path: &Path,
+        cancel: Option<&CancelToken>,
+        on_progress: F,
+    ) -> anyhow::Result<u64>
+    where
+        F: FnMut(u64, Option<u64>),
+    {
+        self.download_to_file_with(Self::download_request(url, headers), dest_path, cancel, on_progress).await
+    }
+
+    /// 按完整请求配置流式下载到文件（与 request 相同的重试、代理和模块 Cookie 处理）
+    pub(crate) async fn download_to_file_with<F>(
+        &self,
+        req: HttpRequest,
+        dest_path: &Path,
+        cancel: Option<&CancelToken>,
+        mut on_progress: F,
+    ) -> anyhow::Result<u64>
+    where
+        F: FnMut(u64, Option<u64>),
+    {
+        let part_path = dest_path.with_extension(match dest_path.extension() {
+            Some(ext) => format!("{}.part", ext.to_string_lossy()),
+            None => "part".to_string(),
+        });
+        
+        let result = self.stream_to_file(req, &part_path, cancel, &mut on_progress).await;
+        match result {
+            Ok(received) => {
+                tokio::fs::rename(&part_path, dest_path).await?;
+                Ok(received)
+            }
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&part_path).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn stream_to_file<F>(
+        &self,
+        req: HttpRequest,
+        path: &Path,
+        cancel: Option<&CancelToken>,
+        on_progress: &mut F,
+    ) -> anyhow::Result<u64>
+    where
+        F: FnMut(u64, Option<u64>),
+    {
+        use tokio::io::AsyncWriteExt;
+        
+        let mut response = cancelable(cancel, self.send(req)).await??;
         
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("Download failed with status: {}", response.status()));
+        }
+
+        let total = response.content_length();
+        let mut received = 0u64;
+        let mut file = tokio::fs::File::create(path).await?;
+        
+        on_progress(received, total);
+        while let Some(chunk) = cancelable(cancel, response.chunk()).await?? {
+            file.write_all(&chunk).await?;
+            received += chunk.len() as u64;
+            on_progress(received, total);
+        }
+        file.flush().await?;
+        
+        Ok(received)
+    }
+
+    fn collect_headers(response: &Response) -> HashMap<String, String> {
         let mut headers = HashMap::new();
         for (key, value) in response.headers().iter() {
             if let Ok(v) = value.to_str() {
                 headers.insert(key.to_string(), v.to_string());
             }
         }
+        headers
+    }
+
+    async fn parse_response(response: Response, started: Instant) -> anyhow::Result<HttpResponse> {
+        let status = response.status().as_u16();
+        let headers = Self::collect_headers(&response);
+        let final_url = response.url().to_string();
 
         let content_type = headers
             .get("content-type")
             .cloned()
             .unwrap_or_else(|| "text/plain".to_string());
 
-        let body = response.text().await?;
+        // 与 Response::text 一致：按 Content-Type 的 charset 解码，默认 UTF-8
+        let bytes = response.bytes().await?;
+        let body = Self::decode_body(&bytes, &content_type);
 
         Ok(HttpResponse {
             status,
             headers,
             body,
             content_type,
+            final_url,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            body_size: bytes.len(),
         })
     }
+
+    fn decode_body(bytes: &[u8], content_type: &str) -> String {
+        let encoding = content_type
+            .split(';')
+            .skip(1)
+            .filter_map(|param| param.split_once('='))
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case("charset"))
+            .and_then(|(_, value)| encoding_rs::Encoding::for_label(value.trim().trim_matches('"').as_bytes()))
+            .unwrap_or(encoding_rs::UTF_8);
+        let (text, _, _) = encoding.decode(bytes);
+        text.into_owned()
+    }
 }
 
 impl Default for HttpClient {
@@ -235,6 +1154,7 @@ impl Default for HttpClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::http::test_server::{TestResponse, TestServer};
 
     #[tokio::test]
     async fn test_http_client() {
@@ -242,4 +1162,492 @@ mod tests {
         let response = client.get("https://httpbin.org/get", HashMap::new()).await;
         assert!(response.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_post_multipart() {
+        let client = HttpClient::new().unwrap();
+        let parts = vec![
+            MultipartPart {
+                name: "field".to_string(),
+                value: Some("hello".to_string()),
+                data: None,
+                filename: None,
+                content_type: None,
+            },
+            MultipartPart {
+                name: "file".to_string(),
+                value: None,
+                data: Some(crate::crypto::base64_encode(&[0x89, 0x50, 0x4e, 0x47])),
+                filename: Some("a.png".to_string()),
+                content_type: Some("image/png".to_string()),
+            },
+        ];
+        let server = TestServer::start(|_| TestResponse::ok("ok"));
+        let response = client.post_multipart(&server.url("/post"), HashMap::new(), parts).await.unwrap();
+        assert_eq!(response.status, 200);
+
+        let request = server.last_request().unwrap();
+        assert!(request.header("content-type").unwrap().starts_with("multipart/form-data; boundary="));
+        let body = String::from_utf8_lossy(&request.body);
+        assert!(body.contains("name=\"field\"\r\n\r\nhello"));
+        assert!(body.contains("name=\"file\"; filename=\"a.png\""));
+        assert!(body.contains("Content-Type: image/png"));
+        assert!(request.body.windows(4).any(|w| w == [0x89, 0x50, 0x4e, 0x47]));
+    }
+
+    #[tokio::test]
+    async fn test_multipart_follows_send_path() {
+        crate::database::init_test_database().await;
+        // /upload 以 307 跳转到 /store（保留方法和请求体），/store 返回收到的 Content-Type
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/upload" => TestResponse::status(307).header("Location", "/store").header("Set-Cookie", "upload=1"),
+            _ => TestResponse::ok(req.header("content-type").unwrap_or_default().to_string()),
+        });
+        let parts = vec![MultipartPart {
+            name: "field".to_string(),
+            value: Some("hello".to_string()),
+            data: None,
+            filename: None,
+            content_type: None,
+        }];
+        let headers = HashMap::from([("Content-Type".to_string(), "text/plain".to_string())]);
+        let module_id = "test_multipart_cookies";
+        let response = HttpClient::new().unwrap()
+            .request(HttpRequest {
+                url: server.url("/upload"),
+                method: "POST".to_string(),
+                headers,
+                enable_cookies: true,
+                module_id: Some(module_id.to_string()),
+                multipart: Some(parts),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(response.body.starts_with("multipart/form-data; boundary="));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let stored = &requests[1];
+        assert_eq!(stored.method, "POST");
+        assert_eq!(stored.header("cookie"), Some("upload=1"));
+        let body = String::from_utf8_lossy(&stored.body);
+        assert!(body.contains("name=\"field\""));
+        assert!(body.contains("hello"));
+        CookieManager::instance().clear(module_id).await.unwrap();
+    }
+
+    #[test]
+    fn test_multipart_part_requires_content() {
+        let part = MultipartPart {
+            name: "empty".to_string(),
+            value: None,
+            data: None,
+            filename: None,
+            content_type: None,
+        };
+        assert!(part.to_part().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_redirect_final_url() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/redirect/2" => TestResponse::status(302).header("Location", "/redirect/1"),
+            "/redirect/1" | "/redirect-to" => TestResponse::status(302).header("Location", "/get"),
+            _ => TestResponse::ok("ok"),
+        });
+        let client = HttpClient::new().unwrap();
+        let response = client.get(&server.url("/redirect/2"), HashMap::new()).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.final_url, server.url("/get"));
+
+        let response = client.request(HttpRequest {
+            url: server.url("/redirect-to"),
+            follow_redirects: false,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(response.status, 302);
+        assert_eq!(response.headers.get("location").map(|s| s.as_str()), Some("/get"));
+        assert!(response.final_url.contains("/redirect-to"));
+    }
+
+    #[test]
+    fn test_request_redirect_defaults() {
+        let req: HttpRequest = serde_json::from_str(r#"{"url":"https://example.com","method":"GET"}"#).unwrap();
+        assert!(req.follow_redirects);
+        assert_eq!(req.max_redirects, DEFAULT_MAX_REDIRECTS);
+    }
+
+    #[tokio::test]
+    async fn test_direct_route_reuses_connections() {
+        // 请求级直连使用按代理缓存的客户端，连接可以复用
+        let server = TestServer::fixed("ok");
+        let client = HttpClient::new().unwrap();
+        for _ in 0..3 {
+            let response = client.request(HttpRequest {
+                url: server.url("/"),
+                proxy: Some("direct".to_string()),
+                ..Default::default()
+            }).await.unwrap();
+            assert_eq!(response.body, "ok");
+        }
+        assert_eq!(server.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_redirect_override_reuses_connections() {
+        // 不跟随重定向的请求使用缓存的客户端，连接可以复用
+        let server = TestServer::fixed("ok");
+        let client = HttpClient::new().unwrap();
+        for _ in 0..3 {
+            let response = client.request(HttpRequest {
+                url: server.url("/"),
+                follow_redirects: false,
+                ..Default::default()
+            }).await.unwrap();
+            assert_eq!(response.body, "ok");
+        }
+        assert_eq!(server.connections(), 1);
+    }
+
+    #[test]
+    fn test_request_proxy_override() {
+        let req: HttpRequest = serde_json::from_str(r#"{"url":"https://example.com","method":"GET"}"#).unwrap();
+        assert!(req.proxy.is_none());
+
+        let req: HttpRequest = serde_json::from_str(
+            r#"{"url":"https://example.com","method":"GET","proxy":"socks5://127.0.0.1:1080"}"#,
+        ).unwrap();
+        assert_eq!(req.proxy.as_deref(), Some("socks5://127.0.0.1:1080"));
+        assert!(HttpClient::route_builder(30, false, &ProxyRoute::parse("socks5://127.0.0.1:1080").unwrap()).build().is_ok());
+        assert!(HttpClient::route_builder(30, false, &ProxyRoute::Direct).build().is_ok());
+    }
+
+    #[test]
+    fn test_request_verifies_certs_by_default() {
+        let req: HttpRequest = serde_json::from_str(r#"{"url":"https://example.com","method":"GET"}"#).unwrap();
+        assert!(!req.insecure);
+        assert!(!HttpRequest::default().insecure);
+
+        let req: HttpRequest = serde_json::from_str(r#"{"url":"https://example.com","method":"GET","insecure":true}"#).unwrap();
+        assert!(req.insecure);
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        assert_eq!(HttpClient::backoff_delay(100, 1), Duration::from_millis(100));
+        assert_eq!(HttpClient::backoff_delay(100, 2), Duration::from_millis(200));
+        assert_eq!(HttpClient::backoff_delay(100, 4), Duration::from_millis(800));
+        assert_eq!(HttpClient::backoff_delay(0, 3), Duration::ZERO);
+        assert_eq!(HttpClient::backoff_delay(10_000, 10), MAX_RETRY_BACKOFF);
+        assert!(HttpClient::jitter(100) <= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_retry_only_idempotent_by_default() {
+        assert!(HttpClient::is_idempotent("GET"));
+        assert!(HttpClient::is_idempotent("put"));
+        assert!(!HttpClient::is_idempotent("POST"));
+        assert!(!HttpClient::is_idempotent("PATCH"));
+    }
+
+    #[test]
+    fn test_shared_client_reused_until_invalidated() {
+        let a = HttpClient::shared().unwrap();
+        let b = HttpClient::shared().unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        HttpClient::invalidate_shared();
+        let c = HttpClient::shared().unwrap();
+        assert!(!Arc::ptr_eq(&a, &c));
+    }
+
+    /// 用指定构建器创建客户端（不读写全局设置）
+    fn client_from(builder: ClientBuilder) -> HttpClient {
+        HttpClient {
+            client: builder.build().unwrap(),
+            accept_invalid_certs: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_defaults_apply_user_agent() {
+        let server = TestServer::echo();
+        let url = server.url("/");
+        assert_eq!(HttpDefaults::default().user_agent, DEFAULT_USER_AGENT);
+        assert_eq!(HttpDefaults::default().timeout_secs, DEFAULT_TIMEOUT_SECS);
+
+        let client = client_from(HttpClient::route_builder_with(30, false, &ProxyRoute::Global, DEFAULT_USER_AGENT));
+        let response = client.get(&url, HashMap::new()).await.unwrap();
+        assert!(response.body.contains(&format!("user-agent: {}", DEFAULT_USER_AGENT.to_lowercase())));
+
+        let client = client_from(HttpClient::route_builder_with(30, false, &ProxyRoute::Global, "ComicsTest/1.0"));
+        let response = client.get(&url, HashMap::new()).await.unwrap();
+        assert!(response.body.contains("user-agent: comicstest/1.0"));
+
+        // 请求头中的 User-Agent 优先
+        let headers = HashMap::from([("User-Agent".to_string(), "Custom/2.0".to_string())]);
+        let response = client.get(&url, headers).await.unwrap();
+        assert!(response.body.contains("user-agent: custom/2.0"));
+    }
+
+    /// "hello gzip" 的 gzip 压缩结果
+    const GZIP_HELLO: [u8; 30] = [
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xcb, 0x48, 0xcd, 0xc9, 0xc9,
+        0x57, 0x48, 0xaf, 0xca, 0x2c, 0x00, 0x00, 0x19, 0x6a, 0xd2, 0xdf, 0x0a, 0x00, 0x00, 0x00,
+    ];
+
+    #[tokio::test]
+    async fn test_compression_control() {
+        // 无论请求头如何，总是返回 gzip 压缩的响应
+        let server = TestServer::start(|_| TestResponse::ok(GZIP_HELLO.to_vec()).header("Content-Encoding", "gzip"));
+        let url = server.url("/");
+        let client = HttpClient::new().unwrap();
+        let accept_encoding = || server.last_request().unwrap().header("accept-encoding").unwrap().to_string();
+
+        let response = client.request(HttpRequest { url: url.clone(), ..Default::default() }).await.unwrap();
+        assert_eq!(response.body, "hello gzip");
+        assert_eq!(accept_encoding(), "gzip, deflate, br");
+
+        // 模块自行设置 Accept-Encoding 时，压缩的响应仍会被解压
+        let headers = HashMap::from([("Accept-Encoding".to_string(), "identity".to_string())]);
+        let response = client.request(HttpRequest { url: url.clone(), headers, ..Default::default() }).await.unwrap();
+        assert_eq!(response.body, "hello gzip");
+
+        let response = client.request(HttpRequest { url: url.clone(), accept_compression: false, ..Default::default() }).await.unwrap();
+        assert_eq!(response.body, "hello gzip");
+        assert_eq!(accept_encoding(), "identity");
+
+        // 原始模式返回未解压的字节
+        let (status, headers, body) = client.request_bytes(HttpRequest { url, raw_body: true, ..Default::default() }).await.unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body, GZIP_HELLO);
+        assert_eq!(headers.get("content-encoding").map(String::as_str), Some("gzip"));
+        assert_eq!(crate::crypto::gunzip(&body).unwrap(), b"hello gzip");
+    }
+
+    #[tokio::test]
+    async fn test_response_timing_and_size() {
+        // GBK 编码的 "你好"：4 个字节，解码后为 6 个 UTF-8 字节
+        let server = TestServer::start(|_| {
+            TestResponse::ok(b"\xc4\xe3\xba\xc3".to_vec())
+                .header("Content-Type", "text/plain; charset=gbk")
+                .delay(Duration::from_millis(100))
+        });
+
+        let response = HttpClient::new().unwrap()
+            .request(HttpRequest { url: server.url("/"), ..Default::default() })
+            .await
+            .unwrap();
+        assert_eq!(response.body, "你好");
+        assert_eq!(response.body_size, 4);
+        assert!(response.elapsed_ms >= 100);
+
+        // 旧格式的响应 JSON 仍可反序列化
+        let legacy: HttpResponse = serde_json::from_str(r#"{"status": 200, "headers": {}, "body": "", "content_type": "text/plain"}"#).unwrap();
+        assert_eq!(legacy.elapsed_ms, 0);
+        assert_eq!(legacy.body_size, 0);
+    }
+
+    #[tokio::test]
+    async fn test_content_length_probe() {
+        // /head 支持 HEAD；/range 拒绝 HEAD 但支持 Range；/missing 均返回 404
+        let server = TestServer::start(|req| {
+            let is_head = req.method == "HEAD";
+            match req.path.as_str() {
+                "/head" if is_head => TestResponse::ok("").header("Content-Length", "1234"),
+                "/range" if !is_head && req.header("range") == Some("bytes=0-0") => {
+                    TestResponse::ok("x").with_status(206).header("Content-Range", "bytes 0-0/5000")
+                }
+                "/range" => TestResponse::status(405),
+                _ => TestResponse::status(404),
+            }
+        });
+        let client = HttpClient::new().unwrap();
+        let url = |path: &str| server.url(&format!("/{}", path));
+
+        assert_eq!(client.content_length(&url("head"), HashMap::new()).await, Some(1234));
+        assert_eq!(client.content_length(&url("range"), HashMap::new()).await, Some(5000));
+        assert_eq!(client.content_length(&url("missing"), HashMap::new()).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_dns_override() {
+        // 返回收到的 Host 头
+        let server = TestServer::start(|req| TestResponse::ok(req.header("host").unwrap_or_default().to_string()));
+        let port = server.port();
+
+        // 在本地构建客户端，不修改全局固定解析和共享客户端
+        let overrides = HashMap::from([("comics-dns-test.invalid".to_string(), "127.0.0.1".parse().unwrap())]);
+        let client = client_from(HttpClient::apply_dns_overrides(Client::builder(), &overrides));
+        let url = format!("http://comics-dns-test.invalid:{}/", port);
+        let response = client.request(HttpRequest { url: url.clone(), ..Default::default() }).await.unwrap();
+        assert_eq!(response.body, format!("comics-dns-test.invalid:{}", port));
+
+        let client = client_from(HttpClient::apply_dns_overrides(Client::builder(), &HashMap::new()));
+        assert!(client.request(HttpRequest { url, ..Default::default() }).await.is_err());
+    }
+
+    #[test]
+    fn test_pool_settings_normalized() {
+        let settings = HttpPoolSettings {
+            pool_max_idle_per_host: 1000,
+            pool_idle_timeout_secs: 0,
+            connect_timeout_secs: 600,
+        }.normalized();
+        assert_eq!(settings, HttpPoolSettings {
+            pool_max_idle_per_host: 64,
+            pool_idle_timeout_secs: 5,
+            connect_timeout_secs: 60,
+        });
+        assert_eq!(HttpPoolSettings::default().normalized(), HttpPoolSettings::default());
+    }
+
+    #[tokio::test]
+    async fn test_pool_settings_applied() {
+        // 测试服务保持连接，并记录建立的连接数
+        let server = TestServer::fixed("ok");
+        let connections_for = |settings: HttpPoolSettings| {
+            let url = server.url("/");
+            let server = &server;
+            async move {
+                let client = client_from(settings.apply(Client::builder()));
+                let before = server.connections();
+                for _ in 0..3 {
+                    let response = client.request(HttpRequest { url: url.clone(), ..Default::default() }).await.unwrap();
+                    assert_eq!(response.body, "ok");
+                }
+                server.connections() - before
+            }
+        };
+
+        // 默认设置复用同一个连接
+        assert_eq!(connections_for(HttpPoolSettings::default()).await, 1);
+        // 不保留空闲连接时每次请求重新建立连接
+        assert_eq!(connections_for(HttpPoolSettings { pool_max_idle_per_host: 0, ..Default::default() }).await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_cookies_stored_on_redirect_hops() {
+        crate::database::init_test_database().await;
+        // 登录接口在 302 响应中下发 Cookie，跳转后的页面返回收到的 Cookie 头
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/login" => TestResponse::status(302)
+                .header("Location", "/home")
+                .header("Set-Cookie", "session=abc; Path=/"),
+            _ => TestResponse::ok(req.header("cookie").unwrap_or_default().to_string()),
+        });
+        let module_id = "test_redirect_cookies";
+        let response = HttpClient::new().unwrap()
+            .request(HttpRequest {
+                url: server.url("/login"),
+                enable_cookies: true,
+                module_id: Some(module_id.to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "session=abc");
+        assert_eq!(response.final_url, server.url("/home"));
+
+        let jar_cookie = CookieManager::instance()
+            .cookie_header(module_id, &Url::parse(&server.url("/")).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(jar_cookie.unwrap().to_str().unwrap(), "session=abc");
+        CookieManager::instance().clear(module_id).await.unwrap();
+    }
+
+    #[test]
+    fn test_auth_header_value() {
+        let basic: HttpAuth = serde_json::from_str(r#"{"basic": {"user": "Aladdin", "pass": "open sesame"}}"#).unwrap();
+        assert_eq!(basic.header_value(), "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
+
+        let bearer: HttpAuth = serde_json::from_str(r#"{"bearer": "abc.def"}"#).unwrap();
+        assert_eq!(bearer.header_value(), "Bearer abc.def");
+
+        let req: HttpRequest = serde_json::from_str(r#"{"url": "https://example.com", "method": "GET", "auth": {"basic": {"user": "u"}}}"#).unwrap();
+        assert_eq!(req.auth.unwrap().header_value(), "Basic dTo=");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_download_removes_partial_file() {
+        // 只发送一部分内容后停住
+        let server = TestServer::start(|_| TestResponse::ok("partial").truncated(1000));
+
+        let dir = std::env::temp_dir().join(format!("comics-cancel-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dest = dir.join("image.jpg");
+        let token = CancelToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            canceller.cancel();
+        });
+
+        let err = HttpClient::new().unwrap()
+            .download_to_file_cancelable(&server.url("/image.jpg"), HashMap::new(), &dest, Some(&token), |_, _| {})
+            .await
+            .unwrap_err();
+        assert!(crate::cancel::is_cancelled_error(&err));
+        assert!(!dest.exists());
+        assert!(!dir.join("image.jpg.part").exists());
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_download_retries_server_errors() {
+        // 第一次返回 503，重试后成功
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let server = TestServer::start(move |_| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                TestResponse::status(503)
+            } else {
+                TestResponse::ok("image-bytes")
+            }
+        });
+
+        let client = HttpClient::new().unwrap();
+        let bytes = client.download(&server.url("/a.jpg"), HashMap::new()).await.unwrap();
+        assert_eq!(bytes, b"image-bytes");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // 下载不协商压缩，保证进度中的总字节与文件大小一致
+        assert_eq!(server.last_request().unwrap().header("accept-encoding"), Some("identity"));
+
+        let dir = std::env::temp_dir().join(format!("comics-retry-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dest = dir.join("a.jpg");
+        attempts.store(0, Ordering::SeqCst);
+        let mut totals = Vec::new();
+        let received = client
+            .download_to_file(&server.url("/a.jpg"), HashMap::new(), &dest, |_, total| totals.push(total))
+            .await
+            .unwrap();
+        assert_eq!(received, 11);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(totals.last(), Some(&Some(11)));
+        assert_eq!(std::fs::read(&dest).unwrap(), b"image-bytes");
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_logging_redacts_credentials() {
+        assert!(!HttpClient::logging_enabled());
+
+        let headers = HashMap::from([
+            ("Authorization".to_string(), "Bearer secret".to_string()),
+            ("cookie".to_string(), "sid=secret".to_string()),
+            ("X-Access-Token".to_string(), "secret".to_string()),
+            ("Referer".to_string(), "https://example.com/".to_string()),
+        ]);
+        let redacted = HttpClient::redact_headers(&headers);
+        assert_eq!(redacted["Authorization"], "<redacted>");
+        assert_eq!(redacted["cookie"], "<redacted>");
+        assert_eq!(redacted["X-Access-Token"], "<redacted>");
+        assert_eq!(redacted["Referer"], "https://example.com/");
+        assert!(!format!("{:?}", redacted).contains("secret"));
+    }
 }
@@ -0,0 +1,208 @@
+use once_cell::sync::Lazy;
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::HeaderValue;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::api::property_api;
+
+/// Cookie 在 properties 表中的存储键
+const COOKIES_PROPERTY_KEY: &str = "__cookies__";
+
+/// 持久化的 Cookie 记录（原始 Set-Cookie 字符串 + 来源 URL）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredCookie {
+    url: String,
+    cookie: String,
+}
+
+impl StoredCookie {
+    /// 用于去重的键：主机 + Cookie 名称
+    fn dedup_key(&self) -> (String, String) {
+        let host = Url::parse(&self.url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_string()))
+            .unwrap_or_default();
+        let name = self.cookie
+            .split(';')
+            .next()
+            .and_then(|pair| pair.split('=').next())
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        (host, name)
+    }
+}
+
+/// Cookie 管理器（单例模式）
+/// 
+/// 每个模块拥有独立的 Cookie Jar，互不可见，并持久化到 properties 表，
+/// 以便登录状态在应用重启后仍然有效。
+pub struct CookieManager {
+    jars: RwLock<HashMap<String, Arc<Jar>>>,
+    /// 每个模块的持久化写锁，避免并发响应的读改写互相覆盖
+    write_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl CookieManager {
+    fn new() -> Self {
+        Self {
+            jars: RwLock::new(HashMap::new()),
+            write_locks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 获取全局 Cookie 管理器实例
+    pub fn instance() -> &'static CookieManager {
+        static INSTANCE: Lazy<CookieManager> = Lazy::new(CookieManager::new);
+        &INSTANCE
+    }
+
+    /// 获取模块的 Cookie Jar（首次访问时从数据库恢复）
+    pub async fn jar(&self, module_id: &str) -> anyhow::Result<Arc<Jar>> {
+        if let Some(jar) = self.cached_jar(module_id)? {
+            return Ok(jar);
+        }
+
+        let jar = Arc::new(Jar::default());
+        for stored in Self::load_stored(module_id).await? {
+            if let Ok(url) = Url::parse(&stored.url) {
+                jar.add_cookie_str(&stored.cookie, &url);
+            }
+        }
+
+        let mut jars = self.jars.write()
+            .map_err(|e| anyhow::anyhow!("获取 Cookie 锁失败: {}", e))?;
+        Ok(jars.entry(module_id.to_string()).or_insert(jar).clone())
+    }
+
+    fn cached_jar(&self, module_id: &str) -> anyhow::Result<Option<Arc<Jar>>> {
+        let jars = self.jars.read()
+            .map_err(|e| anyhow::anyhow!("获取 Cookie 锁失败: {}", e))?;
+        Ok(jars.get(module_id).cloned())
+    }
+
+    fn write_lock(&self, module_id: &str) -> anyhow::Result<Arc<tokio::sync::Mutex<()>>> {
+        let mut locks = self.write_locks.lock()
+            .map_err(|e| anyhow::anyhow!("获取 Cookie 锁失败: {}", e))?;
+        Ok(locks.entry(module_id.to_string()).or_default().clone())
+    }
+
+    /// 获取请求 URL 对应的 Cookie 头
+    pub async fn cookie_header(&self, module_id: &str, url: &Url) -> anyhow::Result<Option<HeaderValue>> {
+        let jar = self.jar(module_id).await?;
+        Ok(jar.cookies(url))
+    }
+
+    /// 保存响应中的 Set-Cookie
+    pub async fn store(&self, module_id: &str, url: &Url, set_cookies: Vec<String>) -> anyhow::Result<()> {
+        if set_cookies.is_empty() {
+            return Ok(());
+        }
+
+        let jar = self.jar(module_id).await?;
+        for cookie in &set_cookies {
+            jar.add_cookie_str(cookie, url);
+        }
+
+        // 持久化：相同主机下同名 Cookie 只保留最新的一条
+        let lock = self.write_lock(module_id)?;
+        let _guard = lock.lock().await;
+        let mut stored = Self::load_stored(module_id).await?;
+        for cookie in set_cookies {
+            let item = StoredCookie {
+                url: url.to_string(),
+                cookie,
+            };
+            let key = item.dedup_key();
+            stored.retain(|c| c.dedup_key() != key);
+            stored.push(item);
+        }
+        property_api::save_property(
+            module_id.to_string(),
+            COOKIES_PROPERTY_KEY.to_string(),
+            serde_json::to_string(&stored)?,
+        ).await?;
+
+        tracing::debug!("[Cookie] 模块 {} 保存了 Cookie，当前 {} 条", module_id, stored.len());
+        Ok(())
+    }
+
+    /// 清除模块的所有 Cookie
+    pub async fn clear(&self, module_id: &str) -> anyhow::Result<()> {
+        let lock = self.write_lock(module_id)?;
+        let _guard = lock.lock().await;
+        {
+            let mut jars = self.jars.write()
+                .map_err(|e| anyhow::anyhow!("获取 Cookie 锁失败: {}", e))?;
+            jars.remove(module_id);
+        }
+        property_api::delete_property(module_id.to_string(), COOKIES_PROPERTY_KEY.to_string()).await?;
+        tracing::info!("[Cookie] 模块 {} 的 Cookie 已清除", module_id);
+        Ok(())
+    }
+
+    async fn load_stored(module_id: &str) -> anyhow::Result<Vec<StoredCookie>> {
+        let value = property_api::load_property(module_id.to_string(), COOKIES_PROPERTY_KEY.to_string()).await?;
+        Ok(value
+            .and_then(|v| serde_json::from_str(&v).ok())
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stored_cookie_dedup_key() {
+        let a = StoredCookie {
+            url: "https://example.com/login".to_string(),
+            cookie: "session=abc; Path=/; HttpOnly".to_string(),
+        };
+        let b = StoredCookie {
+            url: "https://example.com/other".to_string(),
+            cookie: "session=def".to_string(),
+        };
+        let c = StoredCookie {
+            url: "https://other.com/".to_string(),
+            cookie: "session=abc".to_string(),
+        };
+        assert_eq!(a.dedup_key(), ("example.com".to_string(), "session".to_string()));
+        assert_eq!(a.dedup_key(), b.dedup_key());
+        assert_ne!(a.dedup_key(), c.dedup_key());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_concurrent_store_keeps_all_cookies() {
+        crate::database::init_test_database().await;
+        let module_id = "test_concurrent_cookies";
+        let url = Url::parse("https://example.com/").unwrap();
+        let manager = CookieManager::instance();
+
+        let tasks: Vec<_> = (0..20).map(|i| {
+            let url = url.clone();
+            tokio::spawn(async move {
+                manager.store(module_id, &url, vec![format!("c{}={}", i, i)]).await.unwrap();
+            })
+        }).collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(CookieManager::load_stored(module_id).await.unwrap().len(), 20);
+        manager.clear(module_id).await.unwrap();
+    }
+
+    #[test]
+    fn test_jars_are_scoped_per_module() {
+        let url = Url::parse("https://example.com/").unwrap();
+        let jar_a = Jar::default();
+        let jar_b = Jar::default();
+        jar_a.add_cookie_str("session=abc", &url);
+        assert!(jar_a.cookies(&url).is_some());
+        assert!(jar_b.cookies(&url).is_none());
+    }
+}
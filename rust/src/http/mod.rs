@@ -1,5 +1,9 @@
 pub mod client;
 pub mod proxy;
+pub mod cookie;
+#[cfg(test)]
+pub(crate) mod test_server;
 
-pub use client::{HttpClient, HttpRequest, HttpResponse};
-pub use proxy::{ProxyConfig, ProxyManager};
+pub use client::{HttpClient, HttpAuth, HttpDefaults, HttpPoolSettings, HttpRequest, HttpResponse, MultipartPart};
+pub use proxy::{ProxyBypass, ProxyConfig, ProxyManager, ProxyRoute};
+pub use cookie::CookieManager;
@@ -0,0 +1,252 @@
+//! 测试用的本地 HTTP 服务
+//!
+//! 每个连接一个线程，支持 keep-alive，由处理函数根据请求生成响应。
+//! 不依赖 Tokio 运行时，同步测试和异步测试都可以使用。
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// 服务收到的请求
+#[derive(Debug, Clone)]
+pub(crate) struct TestRequest {
+    pub method: String,
+    pub path: String,
+    /// 请求头（名称为小写）
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl TestRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+
+    /// 请求行和请求头的原文（小写），用于断言
+    pub fn head_text(&self) -> String {
+        let mut text = format!("{} {} http/1.1\r\n", self.method.to_lowercase(), self.path.to_lowercase());
+        let mut headers: Vec<_> = self.headers.iter().collect();
+        headers.sort();
+        for (name, value) in headers {
+            text.push_str(&format!("{}: {}\r\n", name, value.to_lowercase()));
+        }
+        text
+    }
+}
+
+/// 处理函数返回的响应
+#[derive(Debug, Clone)]
+pub(crate) struct TestResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Duration,
+    /// 声明的 Content-Length 大于实际内容时，发送后保持连接不再响应
+    declared_len: Option<usize>,
+}
+
+impl TestResponse {
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into(),
+            delay: Duration::ZERO,
+            declared_len: None,
+        }
+    }
+
+    pub fn status(status: u16) -> Self {
+        Self { status, ..Self::ok("") }
+    }
+
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// 响应前等待
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// 声明更大的 Content-Length，只发送部分内容后停住
+    pub fn truncated(mut self, declared_len: usize) -> Self {
+        self.declared_len = Some(declared_len);
+        self
+    }
+}
+
+type Handler = dyn Fn(&TestRequest) -> TestResponse + Send + Sync;
+
+/// 本地 HTTP 服务，随测试进程结束
+pub(crate) struct TestServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<TestRequest>>>,
+    connections: Arc<AtomicUsize>,
+}
+
+impl TestServer {
+    pub fn start(handler: impl Fn(&TestRequest) -> TestResponse + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let connections = Arc::new(AtomicUsize::new(0));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let received = requests.clone();
+        let accepted = connections.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let handler = handler.clone();
+                let received = received.clone();
+                std::thread::spawn(move || Self::serve_connection(stream, handler, received));
+            }
+        });
+
+        Self { port, requests, connections }
+    }
+
+    /// 总是返回固定内容
+    pub fn fixed(body: &'static str) -> Self {
+        Self::start(move |_| TestResponse::ok(body))
+    }
+
+    /// 把请求行和请求头（小写）作为响应体返回
+    pub fn echo() -> Self {
+        Self::start(|req| TestResponse::ok(req.head_text()))
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("http://127.0.0.1:{}{}", self.port, path)
+    }
+
+    /// 已收到的请求
+    pub fn requests(&self) -> Vec<TestRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn last_request(&self) -> Option<TestRequest> {
+        self.requests.lock().unwrap().last().cloned()
+    }
+
+    /// 已建立的连接数
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    fn serve_connection(stream: TcpStream, handler: Arc<Handler>, received: Arc<Mutex<Vec<TestRequest>>>) {
+        let mut writer = match stream.try_clone() {
+            Ok(writer) => writer,
+            Err(_) => return,
+        };
+        let mut reader = BufReader::new(stream);
+        while let Some(request) = Self::read_request(&mut reader) {
+            received.lock().unwrap().push(request.clone());
+            let close = request.header("connection").is_some_and(|v| v.eq_ignore_ascii_case("close"));
+            let response = handler(&request);
+            if !response.delay.is_zero() {
+                std::thread::sleep(response.delay);
+            }
+
+            let mut head = format!("HTTP/1.1 {} {}\r\n", response.status, Self::reason(response.status));
+            for (name, value) in &response.headers {
+                head.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            // 处理函数自行设置 Content-Length 时（如 HEAD 响应）不再追加
+            if !response.headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("content-length")) {
+                head.push_str(&format!("Content-Length: {}\r\n", response.declared_len.unwrap_or(response.body.len())));
+            }
+            head.push_str("\r\n");
+            if writer.write_all(head.as_bytes()).is_err() || writer.write_all(&response.body).is_err() {
+                return;
+            }
+            let _ = writer.flush();
+            if response.declared_len.is_some() {
+                std::thread::sleep(Duration::from_secs(30));
+                return;
+            }
+            if close {
+                return;
+            }
+        }
+    }
+
+    fn read_request(reader: &mut BufReader<TcpStream>) -> Option<TestRequest> {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let mut parts = line.split_whitespace();
+        let method = parts.next()?.to_string();
+        let path = parts.next()?.to_string();
+
+        let mut headers = HashMap::new();
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).ok()? == 0 {
+                return None;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+            }
+        }
+
+        let mut body = Vec::new();
+        if headers.get("transfer-encoding").is_some_and(|v| v.eq_ignore_ascii_case("chunked")) {
+            loop {
+                let mut size_line = String::new();
+                reader.read_line(&mut size_line).ok()?;
+                let size = usize::from_str_radix(size_line.trim(), 16).ok()?;
+                let mut chunk = vec![0u8; size + 2];
+                reader.read_exact(&mut chunk).ok()?;
+                if size == 0 {
+                    break;
+                }
+                body.extend_from_slice(&chunk[..size]);
+            }
+        } else if let Some(len) = headers.get("content-length").and_then(|v| v.parse::<usize>().ok()) {
+            body = vec![0u8; len];
+            reader.read_exact(&mut body).ok()?;
+        }
+
+        Some(TestRequest { method, path, headers, body })
+    }
+
+    fn reason(status: u16) -> &'static str {
+        match status {
+            200 => "OK",
+            206 => "Partial Content",
+            301 => "Moved Permanently",
+            302 => "Found",
+            303 => "See Other",
+            304 => "Not Modified",
+            307 => "Temporary Redirect",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            500 => "Internal Server Error",
+            503 => "Service Unavailable",
+            _ => "Status",
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use rquickjs::{Ctx, Function, Value};
+use anyhow::Result;
+
+use crate::api::web_cache_api;
+
+/// 注册 cache 对象到 JS 全局（通过 runtime.cache 访问）
+/// 
+/// 按 module_id 隔离的网页响应缓存，用于缓存分类、详情等开销较大的请求结果
+pub fn register(ctx: &Ctx<'_>) -> Result<()> {
+    let globals = ctx.globals();
+    
+    globals.set("__native_cache_get_sync__", Function::new(ctx.clone(), |module_id: String, url: String| -> Option<String> {
+        tracing::debug!("[JS Cache] get: module={}, url={}", module_id, url);
+        
+        let result = super::block_on(async move {
+            web_cache_api::get_web_cache(module_id, url).await
+        });
+        
+        match result {
+            Ok(Ok(item)) => item.map(|item| item.body),
+            Ok(Err(e)) => {
+                tracing::error!("[JS Cache] get failed: {:?}", e);
+                None
+            }
+            Err(_) => None,
+        }
+    })?)?;
+    
+    globals.set("__native_cache_set_sync__", Function::new(ctx.clone(), |module_id: String, url: String, body: String, ttl_secs: f64, content_type: String| -> bool {
+        tracing::debug!("[JS Cache] set: module={}, url={}, ttl={}s, type={}", module_id, url, ttl_secs, content_type);
+        
+        // JS 数字可能超出 i64 范围，按饱和方式转换，超长有效期由 save_web_cache 截断
+        let ttl_secs = ttl_secs as i64;
+        let result = super::block_on(async move {
+            web_cache_api::save_web_cache(module_id, url, body, content_type, ttl_secs).await
+        });
+        
+        match result {
+            Ok(Ok(())) => true,
+            Ok(Err(e)) => {
+                tracing::error!("[JS Cache] set failed: {:?}", e);
+                false
+            }
+            Err(_) => false,
+        }
+    })?)?;
+    
+    globals.set("__native_cache_remove_sync__", Function::new(ctx.clone(), |module_id: String, url: String| -> bool {
+        let result = super::block_on(async move {
+            web_cache_api::remove_web_cache(module_id, url).await
+        });
+        matches!(result, Ok(Ok(())))
+    })?)?;
+    
+    let cache_helper = r#"
+        // 挂在 globalThis 上，未授予 storage 权限时可以删除
+        globalThis.__cache__ = {
+            _moduleId: function() {
+                return typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+            },
+            // 返回缓存的字符串，不存在或已过期时返回 null
+            get: function(url) {
+                var result = __native_cache_get_sync__(__cache__._moduleId(), String(url));
+                return result === undefined ? null : result;
+            },
+            // ttl 单位为秒，默认 1 小时；非字符串内容会被 JSON.stringify
+            // contentType 默认字符串为 text/plain，其他内容为 application/json
+            set: function(url, body, ttl, contentType) {
+                var isText = typeof body === 'string';
+                var text = isText ? body : JSON.stringify(body);
+                var type = contentType ? String(contentType) : (isText ? 'text/plain' : 'application/json');
+                return __native_cache_set_sync__(__cache__._moduleId(), String(url), text, ttl || 3600, type);
+            },
+            remove: function(url) {
+                return __native_cache_remove_sync__(__cache__._moduleId(), String(url));
+            }
+        };
+    "#;
+    
+    let _: Value = ctx.eval(cache_helper)?;
+    
+    tracing::debug!("[JS Cache] Cache bindings registered");
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::web_cache_api;
+    use crate::database;
+    use crate::js_engine::JsRuntime;
+
+    #[tokio::test]
+    async fn test_runtime_cache_get_set() {
+        database::init_test_database().await;
+        web_cache_api::clear_web_cache_by_module("cache_binding_test".to_string()).await.unwrap();
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("cache_binding_test", r#"
+            function main() {
+                var missing = runtime.cache.get("/list");
+                var setText = runtime.cache.set("/list", "<ul></ul>");
+                var setJson = runtime.cache.set("/detail", { id: 1 }, 60);
+                var huge = runtime.cache.set("/forever", "x", 1e300);
+                var expired = runtime.cache.set("/old", "y", -1);
+                return {
+                    missing: missing,
+                    set: [setText, setJson, huge, expired],
+                    text: runtime.cache.get("/list"),
+                    json: JSON.parse(runtime.cache.get("/detail")),
+                    forever: runtime.cache.get("/forever"),
+                    old: runtime.cache.get("/old"),
+                    removed: runtime.cache.remove("/list"),
+                    afterRemove: runtime.cache.get("/list")
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["missing"], serde_json::Value::Null);
+        assert_eq!(result["set"], serde_json::json!([true, true, true, true]));
+        assert_eq!(result["text"], "<ul></ul>");
+        assert_eq!(result["json"], serde_json::json!({ "id": 1 }));
+        assert_eq!(result["forever"], "x");
+        assert_eq!(result["old"], serde_json::Value::Null);
+        assert_eq!(result["removed"], true);
+        assert_eq!(result["afterRemove"], serde_json::Value::Null);
+
+        let item = web_cache_api::get_web_cache("cache_binding_test".to_string(), "/detail".to_string()).await.unwrap().unwrap();
+        assert_eq!(item.content_type, "application/json");
+        web_cache_api::clear_web_cache_by_module("cache_binding_test".to_string()).await.unwrap();
+    }
+}
@@ -1,123 +1,164 @@
 use rquickjs::{Ctx, Function, Object, Value};
 use rquickjs::function::Rest;
 use anyhow::Result;
+use std::cell::RefCell;
+
+use crate::modules::ConsoleLog;
+
+/// 单次调用最多保留的日志行数，防止死循环打印耗尽内存
+const MAX_CAPTURED_LINES: usize = 1000;
+
+thread_local! {
+    /// 当前线程正在捕获的控制台日志，None 表示未开启捕获
+    static CAPTURED: RefCell<Option<Vec<ConsoleLog>>> = const { RefCell::new(None) };
+}
+
+/// 开始在当前线程捕获控制台日志（会丢弃之前未取出的日志）
+pub fn begin_capture() {
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(Vec::new()));
+}
+
+/// 结束捕获并取出已记录的日志
+pub fn end_capture() -> Vec<ConsoleLog> {
+    CAPTURED.with(|captured| captured.borrow_mut().take().unwrap_or_default())
+}
+
+/// 捕获开启时记录一行日志
+fn capture(level: &str, message: &str) {
+    CAPTURED.with(|captured| {
+        if let Some(lines) = captured.borrow_mut().as_mut() {
+            if lines.len() < MAX_CAPTURED_LINES {
+                lines.push(ConsoleLog {
+                    level: level.to_string(),
+                    message: message.to_string(),
+                    timestamp: chrono::Utc::now().timestamp_millis(),
+                });
+            }
+        }
+    });
+}
+
+/// 对象序列化辅助函数
+/// 
+/// 只有引用祖先对象时才输出 "[Circular]"（同一对象出现多次不算循环），Error 输出 "name: message"，函数输出 "[Function]"
+const STRINGIFY_HELPER: &str = r#"
+    (function(value) {
+        if (value instanceof Error) return value.name + ": " + value.message;
+        if (typeof value === "function") return "[Function]";
+        // 从根到当前对象的路径，this 为正在序列化的父对象
+        var ancestors = [];
+        return JSON.stringify(value, function(key, val) {
+            if (val instanceof Error) return val.name + ": " + val.message;
+            if (typeof val === "function") return "[Function]";
+            if (typeof val === "bigint") return val.toString();
+            if (typeof val === "object" && val !== null) {
+                ancestors.length = ancestors.indexOf(this) + 1;
+                if (ancestors.indexOf(val) !== -1) return "[Circular]";
+                ancestors.push(val);
+            }
+            return val;
+        });
+    })
+"#;
+
+/// 将单个 JS 值格式化为日志文本
+/// 
+/// 基本类型直接转换，数组/对象使用 JSON 序列化
+fn format_value(v: &Value) -> String {
+    // 尝试直接转换为字符串
+    if let Ok(s) = v.get::<String>() {
+        s
+    } else if let Ok(n) = v.get::<f64>() {
+        if n.fract() == 0.0 {
+            format!("{}", n as i64)
+        } else {
+            format!("{}", n)
+        }
+    } else if let Ok(b) = v.get::<bool>() {
+        b.to_string()
+    } else if v.is_null() {
+        "null".to_string()
+    } else if v.is_undefined() {
+        "undefined".to_string()
+    } else {
+        let ctx = v.ctx();
+        let stringified = ctx.globals()
+            .get::<_, Function>("__console_stringify__")
+            .and_then(|stringify| stringify.call::<_, Option<String>>((v.clone(),)));
+        match stringified {
+            Ok(Some(json)) => json,
+            Ok(None) => format!("[{}]", v.type_name()),
+            Err(_) => {
+                // 清除序列化时抛出的异常，避免影响脚本后续执行
+                let _ = ctx.catch();
+                format!("[{}]", v.type_name())
+            }
+        }
+    }
+}
+
+/// 格式化参数并输出到日志（同时写入捕获缓冲区）
+fn emit(level: &str, args: &[Value]) {
+    let message = args.iter()
+        .map(format_value)
+        .collect::<Vec<_>>()
+        .join(" ");
+    capture(level, &message);
+    match level {
+        "error" => tracing::error!("[JS] {}", message),
+        "warn" => tracing::warn!("[JS] {}", message),
+        "debug" => tracing::debug!("[JS] {}", message),
+        // console.log 使用 info 级别，更容易看到
+        _ => tracing::info!("[JS] {}", message),
+    }
+}
 
 /// 注册 console 对象
 pub fn register(ctx: &Ctx<'_>) -> Result<()> {
     let globals = ctx.globals();
     
-    let console = Object::new(ctx.clone())?;
-    
-    // console.log - 使用 info 级别，更容易看到
-    console.set("log", Function::new(ctx.clone(), |args: Rest<Value>| {
-        let message: String = args.0.iter()
-            .map(|v| {
-                // 尝试直接转换为字符串
-                if let Ok(s) = v.get::<String>() {
-                    s
-                } else if let Ok(n) = v.get::<f64>() {
-                    if n.fract() == 0.0 {
-                        format!("{}", n as i64)
-                    } else {
-                        format!("{}", n)
-                    }
-                } else if let Ok(b) = v.get::<bool>() {
-                    b.to_string()
-                } else if v.is_null() {
-                    "null".to_string()
-                } else if v.is_undefined() {
-                    "undefined".to_string()
-                } else {
-                    format!("{:?}", v)
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ");
-        tracing::info!("[JS] {}", message);
-    })?)?;
-    
-    // console.error
-    console.set("error", Function::new(ctx.clone(), |args: Rest<Value>| {
-        let message: String = args.0.iter()
-            .map(|v| {
-                if let Ok(s) = v.get::<String>() {
-                    s
-                } else if let Ok(n) = v.get::<f64>() {
-                    if n.fract() == 0.0 {
-                        format!("{}", n as i64)
-                    } else {
-                        format!("{}", n)
-                    }
-                } else if let Ok(b) = v.get::<bool>() {
-                    b.to_string()
-                } else if v.is_null() {
-                    "null".to_string()
-                } else if v.is_undefined() {
-                    "undefined".to_string()
-                } else {
-                    format!("{:?}", v)
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ");
-        tracing::error!("[JS] {}", message);
-    })?)?;
+    let stringify: Function = ctx.eval(STRINGIFY_HELPER)?;
+    globals.set("__console_stringify__", stringify)?;
     
-    // console.warn
-    console.set("warn", Function::new(ctx.clone(), |args: Rest<Value>| {
-        let message: String = args.0.iter()
-            .map(|v| {
-                if let Ok(s) = v.get::<String>() {
-                    s
-                } else if let Ok(n) = v.get::<f64>() {
-                    if n.fract() == 0.0 {
-                        format!("{}", n as i64)
-                    } else {
-                        format!("{}", n)
-                    }
-                } else if let Ok(b) = v.get::<bool>() {
-                    b.to_string()
-                } else if v.is_null() {
-                    "null".to_string()
-                } else if v.is_undefined() {
-                    "undefined".to_string()
-                } else {
-                    format!("{:?}", v)
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ");
-        tracing::warn!("[JS] {}", message);
-    })?)?;
+    let console = Object::new(ctx.clone())?;
     
-    // console.debug
-    console.set("debug", Function::new(ctx.clone(), |args: Rest<Value>| {
-        let message: String = args.0.iter()
-            .map(|v| {
-                if let Ok(s) = v.get::<String>() {
-                    s
-                } else if let Ok(n) = v.get::<f64>() {
-                    if n.fract() == 0.0 {
-                        format!("{}", n as i64)
-                    } else {
-                        format!("{}", n)
-                    }
-                } else if let Ok(b) = v.get::<bool>() {
-                    b.to_string()
-                } else if v.is_null() {
-                    "null".to_string()
-                } else if v.is_undefined() {
-                    "undefined".to_string()
-                } else {
-                    format!("{:?}", v)
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ");
-        tracing::debug!("[JS] {}", message);
-    })?)?;
+    for level in ["log", "error", "warn", "debug"] {
+        console.set(level, Function::new(ctx.clone(), move |args: Rest<Value>| {
+            emit(level, &args.0);
+        })?)?;
+    }
     
     globals.set("console", console)?;
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_engine::JsRuntime;
+
+    #[test]
+    fn test_console_logs_objects_as_json() {
+        let runtime = JsRuntime::new().unwrap();
+        begin_capture();
+        runtime.eval_string(r#"
+            var data = { comic: { id: "1", tags: ["a", "b"] }, count: 2, nothing: null };
+            console.log("data", data);
+            var loop = { name: "loop" };
+            loop.self = loop;
+            console.warn(loop, [1, { x: true }]);
+            console.error(new Error("oops"), 1.5, undefined);
+            var shared = { id: 1 };
+            console.log({ a: shared, b: shared, list: [shared, [shared]] });
+            "done";
+        "#).unwrap();
+        let logs = end_capture();
+
+        assert_eq!(logs[0].message, r#"data {"comic":{"id":"1","tags":["a","b"]},"count":2,"nothing":null}"#);
+        assert_eq!(logs[1].message, r#"{"name":"loop","self":"[Circular]"} [1,{"x":true}]"#);
+        assert_eq!(logs[2].message, "Error: oops 1.5 undefined");
+        assert_eq!(logs[2].level, "error");
+        assert_eq!(logs[3].message, r#"{"a":{"id":1},"b":{"id":1},"list":[{"id":1},[{"id":1}]]}"#);
+    }
+}
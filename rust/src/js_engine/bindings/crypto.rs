@@ -69,6 +69,76 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
         }
     })?)?;
     
+    // crypto.crc32(base64Data) -> string (8 位十六进制，大端)
+    crypto_obj.set("crc32", Function::new(ctx.clone(), |data: String| -> String {
+        match crypto::base64_decode(&data) {
+            Ok(bytes) => crypto::crc32_hex(&bytes),
+            Err(e) => {
+                tracing::error!("[JS Crypto] CRC32 error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // crypto.adler32(base64Data) -> string (8 位十六进制，大端)
+    crypto_obj.set("adler32", Function::new(ctx.clone(), |data: String| -> String {
+        match crypto::base64_decode(&data) {
+            Ok(bytes) => crypto::adler32_hex(&bytes),
+            Err(e) => {
+                tracing::error!("[JS Crypto] Adler32 error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // crypto.gunzip(base64Data) -> string (base64 编码的解压结果，失败返回空串)
+    // 用于解压 raw_body 请求返回的原始响应体
+    crypto_obj.set("gunzip", Function::new(ctx.clone(), |data: String| -> String {
+        match crypto::base64_decode(&data).and_then(|bytes| crypto::gunzip(&bytes)) {
+            Ok(bytes) => crypto::base64_encode(&bytes),
+            Err(e) => {
+                tracing::error!("[JS Crypto] gunzip error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // crypto.randomBytes(n) -> string (base64 编码的随机字节)
+    crypto_obj.set("randomBytes", Function::new(ctx.clone(), |len: i32| -> String {
+        if len < 0 {
+            tracing::error!("[JS Crypto] randomBytes: invalid length {}", len);
+            return String::new();
+        }
+        match crypto::random_bytes(len as usize) {
+            Ok(bytes) => crypto::base64_encode(&bytes),
+            Err(e) => {
+                tracing::error!("[JS Crypto] randomBytes error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // crypto.uuidv4() -> string
+    crypto_obj.set("uuidv4", Function::new(ctx.clone(), || -> String {
+        crypto::uuid_v4()
+    })?)?;
+    
+    // crypto.urlEncode(data) -> string (百分号编码，空格编码为 %20)
+    crypto_obj.set("urlEncode", Function::new(ctx.clone(), |data: String| -> String {
+        crypto::url_encode(&data)
+    })?)?;
+    
+    // crypto.urlDecode(data) -> string
+    crypto_obj.set("urlDecode", Function::new(ctx.clone(), |data: String| -> String {
+        match crypto::url_decode(&data) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Crypto] URL decode error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
     globals.set("__crypto__", crypto_obj)?;
     
     tracing::debug!("[JS Crypto] Crypto bindings registered");
@@ -0,0 +1,175 @@
+use rquickjs::{ArrayBuffer, Ctx, Exception, Function, Object, Value};
+use anyhow::Result;
+
+use crate::crypto;
+
+/// TextEncoder：字符串按指定编码转换为字节
+fn text_encode<'js>(ctx: Ctx<'js>, text: String, label: String) -> rquickjs::Result<ArrayBuffer<'js>> {
+    match crypto::encode_string(&text, &label) {
+        Ok(bytes) => ArrayBuffer::new(ctx, bytes),
+        Err(e) => Err(Exception::throw_range(&ctx, &e.to_string())),
+    }
+}
+
+/// TextDecoder：字节按指定编码转换为字符串，fatal 时遇到无效字节抛出 TypeError
+fn text_decode<'js>(ctx: Ctx<'js>, data: ArrayBuffer<'js>, label: String, fatal: bool) -> rquickjs::Result<String> {
+    let bytes = data.as_bytes()
+        .ok_or_else(|| Exception::throw_type(&ctx, "ArrayBuffer is detached"))?;
+    let result = if fatal {
+        crypto::decode_bytes_strict(bytes, &label)
+    } else {
+        crypto::decode_bytes(bytes, &label)
+    };
+    result.map_err(|e| Exception::throw_type(&ctx, &e.to_string()))
+}
+
+/// 编码标签的规范名称，未知编码抛出 RangeError
+fn text_label(ctx: Ctx<'_>, label: String) -> rquickjs::Result<String> {
+    crypto::encoding_name(&label).map_err(|e| Exception::throw_range(&ctx, &e.to_string()))
+}
+
+/// 字节转为 base64，用于和 base64 形式的字节接口互通
+fn bytes_to_base64<'js>(ctx: Ctx<'js>, data: ArrayBuffer<'js>) -> rquickjs::Result<String> {
+    data.as_bytes()
+        .map(crypto::base64_encode)
+        .ok_or_else(|| Exception::throw_type(&ctx, "ArrayBuffer is detached"))
+}
+
+/// 注册 encoding 对象到 JS 全局
+/// 
+/// 用于处理 GBK/Big5/Shift_JIS 等非 UTF-8 编码的网页
+pub fn register(ctx: &Ctx<'_>) -> Result<()> {
+    let globals = ctx.globals();
+    
+    let encoding_obj = Object::new(ctx.clone())?;
+    
+    // encoding.decode(base64Bytes, label) -> string
+    // 将指定编码的原始字节（base64）解码为字符串
+    encoding_obj.set("decode", Function::new(ctx.clone(), |data: String, label: String| -> String {
+        let result = crypto::base64_decode(&data)
+            .and_then(|bytes| crypto::decode_bytes(&bytes, &label));
+        match result {
+            Ok(text) => text,
+            Err(e) => {
+                tracing::error!("[JS Encoding] Decode error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // encoding.encode(text, label) -> string (base64 编码的字节)
+    // 将字符串编码为指定编码的字节，例如用于构造 GBK 搜索参数
+    encoding_obj.set("encode", Function::new(ctx.clone(), |text: String, label: String| -> String {
+        match crypto::encode_string(&text, &label) {
+            Ok(bytes) => crypto::base64_encode(&bytes),
+            Err(e) => {
+                tracing::error!("[JS Encoding] Encode error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    globals.set("__encoding__", encoding_obj)?;
+    
+    globals.set("__native_text_encode__", Function::new(ctx.clone(), text_encode)?)?;
+    globals.set("__native_text_decode__", Function::new(ctx.clone(), text_decode)?)?;
+    globals.set("__native_text_label__", Function::new(ctx.clone(), text_label)?)?;
+    globals.set("__native_bytes_to_base64__", Function::new(ctx.clone(), bytes_to_base64)?)?;
+    
+    // TextEncoder / TextDecoder 全局对象，以及 Uint8Array 与 base64 字节的互相转换
+    // encoding.encode / decode 和 fetch 的 base64 字节可以通过 toBase64 / fromBase64 转换
+    let text_helper = r#"
+        function __toArrayBuffer__(input) {
+            if (input === undefined || input === null) {
+                return new ArrayBuffer(0);
+            }
+            if (input instanceof ArrayBuffer) {
+                return input;
+            }
+            if (ArrayBuffer.isView(input)) {
+                return input.buffer.slice(input.byteOffset, input.byteOffset + input.byteLength);
+            }
+            if (Array.isArray(input)) {
+                return new Uint8Array(input).buffer;
+            }
+            throw new TypeError('Expected an ArrayBuffer, TypedArray or Array of bytes');
+        }
+
+        function TextEncoder() {
+            this.encoding = 'utf-8';
+        }
+        TextEncoder.prototype.encode = function(text) {
+            return new Uint8Array(__native_text_encode__(text === undefined ? '' : String(text), 'utf-8'));
+        };
+
+        function TextDecoder(label, options) {
+            this.encoding = __native_text_label__(label === undefined ? 'utf-8' : String(label));
+            this.fatal = !!(options && options.fatal);
+        }
+        TextDecoder.prototype.decode = function(input) {
+            return __native_text_decode__(__toArrayBuffer__(input), this.encoding, this.fatal);
+        };
+
+        __encoding__.toBase64 = function(bytes) {
+            return __native_bytes_to_base64__(__toArrayBuffer__(bytes));
+        };
+        // __native_bytes_from_base64__ 由 fetch 绑定注册
+        __encoding__.fromBase64 = function(data) {
+            return new Uint8Array(__native_bytes_from_base64__(String(data)));
+        };
+    "#;
+    let _: Value = ctx.eval(text_helper)?;
+    
+    tracing::debug!("[JS Encoding] Encoding bindings registered");
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::js_engine::JsRuntime;
+
+    #[test]
+    fn test_text_encoder_decoder() {
+        let runtime = JsRuntime::new().unwrap();
+        let result = runtime.eval_string(r#"
+            (function() {
+                var bytes = new TextEncoder().encode('漫画 ok');
+                var gbk = runtime.encoding.fromBase64(runtime.encoding.encode('中文', 'gbk'));
+                var fatal;
+                try {
+                    new TextDecoder('utf-8', { fatal: true }).decode(new Uint8Array([0xE6, 0xBC]));
+                    fatal = 'no error';
+                } catch (e) {
+                    fatal = e.name;
+                }
+                var unknown;
+                try { new TextDecoder('no-such-encoding'); } catch (e) { unknown = e.name; }
+                return JSON.stringify({
+                    isUint8: bytes instanceof Uint8Array,
+                    length: bytes.length,
+                    text: new TextDecoder().decode(bytes),
+                    fromBuffer: new TextDecoder().decode(bytes.buffer),
+                    fromArray: new TextDecoder().decode([104, 105]),
+                    subarray: new TextDecoder().decode(bytes.subarray(7)),
+                    gbk: new TextDecoder('gbk').decode(gbk),
+                    base64: runtime.encoding.toBase64(new TextEncoder().encode('hi')),
+                    fatal: fatal,
+                    unknown: unknown
+                });
+            })()
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(result["isUint8"], true);
+        assert_eq!(result["length"], 9);
+        assert_eq!(result["text"], "漫画 ok");
+        assert_eq!(result["fromBuffer"], "漫画 ok");
+        assert_eq!(result["fromArray"], "hi");
+        assert_eq!(result["subarray"], "ok");
+        assert_eq!(result["gbk"], "中文");
+        assert_eq!(result["base64"], "aGk=");
+        assert_eq!(result["fatal"], "TypeError");
+        assert_eq!(result["unknown"], "RangeError");
+    }
+}
@@ -0,0 +1,155 @@
+use rquickjs::{ArrayBuffer, Ctx, Exception, Function, Value};
+use anyhow::Result;
+
+use crate::crypto;
+use crate::http::{HttpClient, HttpRequest};
+
+/// base64 字节转换为 ArrayBuffer
+fn bytes_from_base64<'js>(ctx: Ctx<'js>, data: String) -> rquickjs::Result<ArrayBuffer<'js>> {
+    match crypto::base64_decode(&data) {
+        Ok(bytes) => ArrayBuffer::new(ctx, bytes),
+        Err(e) => Err(Exception::throw_message(&ctx, &format!("Invalid base64 body: {}", e))),
+    }
+}
+
+/// 注册 fetch 到 JS 全局
+///
+/// fetch 返回真正的 Promise，请求在后台执行，由事件循环完成，
+/// 因此多个请求可以通过 Promise.all 并发发出
+pub fn register(ctx: &Ctx<'_>) -> Result<()> {
+    let globals = ctx.globals();
+
+    globals.set("__native_bytes_from_base64__", Function::new(ctx.clone(), bytes_from_base64)?)?;
+
+    let fetch_helper = r#"
+        function __FetchResponse__(raw) {
+            var headers = raw.headers || {};
+            this.status = raw.status;
+            this.ok = raw.status >= 200 && raw.status < 300;
+            this.url = raw.url;
+            this.headers = {
+                raw: headers,
+                get: function(name) {
+                    var value = headers[String(name).toLowerCase()];
+                    return value === undefined ? null : value;
+                },
+                has: function(name) {
+                    return headers[String(name).toLowerCase()] !== undefined;
+                }
+            };
+            this._body = raw.body;
+        }
+        __FetchResponse__.prototype.text = function(charset) {
+            if (!charset) {
+                var match = /charset=([^;]+)/i.exec(this.headers.get('content-type') || '');
+                charset = match ? match[1].trim() : 'utf-8';
+            }
+            return Promise.resolve(__encoding__.decode(this._body, charset));
+        };
+        __FetchResponse__.prototype.json = function() {
+            return this.text().then(JSON.parse);
+        };
+        __FetchResponse__.prototype.arrayBuffer = function() {
+            return Promise.resolve(__native_bytes_from_base64__(this._body));
+        };
+        // 原始字节的 base64，便于传给 image/crypto 等绑定
+        __FetchResponse__.prototype.base64 = function() {
+            return Promise.resolve(this._body);
+        };
+
+        // options: {method, headers, body, timeout(秒), redirect: 'follow' | 'manual', proxy}
+        // 挂在 globalThis 上，未授予 http 权限时可以删除
+        globalThis.fetch = function(url, options) {
+            options = options || {};
+            var config = {
+                url: String(url),
+                method: (options.method || 'GET').toUpperCase(),
+                headers: options.headers || {},
+                body: options.body === undefined || options.body === null ? null : String(options.body),
+                follow_redirects: options.redirect !== 'manual'
+            };
+            if (options.timeout) config.timeout_secs = options.timeout;
+            if (options.proxy) config.proxy = options.proxy;
+            return __async__.spawn('fetch', http._prepare(config)).then(function(responseJson) {
+                return new __FetchResponse__(JSON.parse(responseJson));
+            });
+        };
+    "#;
+
+    let _: Value = ctx.eval(fetch_helper)?;
+
+    tracing::debug!("[JS Fetch] Fetch bindings registered");
+
+    Ok(())
+}
+
+/// 执行 fetch 请求，返回 {status, headers, body(base64), url}
+pub async fn execute_fetch(config_json: &str) -> Result<String> {
+    let request: HttpRequest = serde_json::from_str(config_json)?;
+    let client = HttpClient::shared()?;
+    // Response.url 为跟随重定向后的最终 URL
+    let (status, headers, body, url) = client.request_bytes_with_url(request).await?;
+    Ok(serde_json::json!({
+        "status": status,
+        "headers": headers,
+        "body": crypto::base64_encode(&body),
+        "url": url,
+    }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::js_engine::JsRuntime;
+    use crate::http::test_server::{TestResponse, TestServer};
+
+    #[test]
+    fn test_fetch_concurrent() {
+        let server = TestServer::start(|_| TestResponse::ok("hello").header("Content-Type", "text/plain; charset=utf-8"));
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("test", r#"
+            async function main(args) {
+                var responses = await Promise.all([
+                    fetch(args.url, { proxy: 'direct' }),
+                    fetch(args.url, { proxy: 'direct' })
+                ]);
+                var text = await responses[0].text();
+                var buffer = await responses[1].arrayBuffer();
+                return {
+                    status: responses[0].status,
+                    ok: responses[0].ok,
+                    type: responses[0].headers.get('Content-Type'),
+                    text: text,
+                    size: buffer.byteLength
+                };
+            }
+        "#).unwrap();
+
+        let args = serde_json::json!({ "url": server.url("/") }).to_string();
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", &args).unwrap()).unwrap();
+        assert_eq!(result["status"], 200);
+        assert_eq!(result["ok"], true);
+        assert_eq!(result["type"], "text/plain; charset=utf-8");
+        assert_eq!(result["text"], "hello");
+        assert_eq!(result["size"], 5);
+    }
+
+    #[test]
+    fn test_fetch_url_after_redirect() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/old" => TestResponse::status(302).header("Location", "/new"),
+            _ => TestResponse::ok("moved"),
+        });
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("test", r#"
+            async function main(args) {
+                var response = await fetch(args.url, { proxy: 'direct' });
+                return { url: response.url, text: await response.text() };
+            }
+        "#).unwrap();
+
+        let args = serde_json::json!({ "url": server.url("/old") }).to_string();
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", &args).unwrap()).unwrap();
+        assert_eq!(result["url"], server.url("/new"));
+        assert_eq!(result["text"], "moved");
+    }
+}
@@ -1,7 +1,9 @@
-use rquickjs::{Ctx, Function, Object};
+use rquickjs::{function::Opt, Ctx, Function, Object};
 use anyhow::Result;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 use serde_json::{json, Value as JsonValue};
+use sxd_xpath::nodeset::Node as XPathNode;
+use sxd_xpath::Value as XPathValue;
 
 /// 注册 html 解析对象到 JS 全局
 pub fn register(ctx: &Ctx<'_>) -> Result<()> {
@@ -46,6 +48,20 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
         }
     })?)?;
     
+    // html.attrOr(htmlString, selector, attrName, default) -> string | default
+    // 获取元素属性，属性不存在时返回默认值（空属性仍返回空字符串）
+    html_obj.set("attrOr", Function::new(ctx.clone(), |html_str: String, selector: String, attr: String, default: Opt<Option<String>>| -> Option<String> {
+        let default = default.0.flatten();
+        match get_attr(&html_str, &selector, &attr) {
+            Ok(Some(value)) => Some(value),
+            Ok(None) => default,
+            Err(e) => {
+                tracing::error!("[JS HTML] AttrOr error: {}", e);
+                default
+            }
+        }
+    })?)?;
+    
     // html.attrs(htmlString, selector, attrName) -> Array<string>
     // 获取所有匹配元素的属性
     html_obj.set("attrs", Function::new(ctx.clone(), |html_str: String, selector: String, attr: String| -> String {
@@ -96,6 +112,179 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
         }
     })?)?;
     
+    // html.outerHTML(htmlString, selector) -> string
+    // 获取元素完整 HTML（包含自身标签）
+    html_obj.set("outerHTML", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match get_outer_html(&html_str, &selector) {
+            Ok(Some(value)) => value,
+            Ok(None) => String::new(),
+            Err(e) => {
+                tracing::error!("[JS HTML] OuterHTML error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // html.selectNested(htmlString, outerSelector, innerSelector) -> Array<Array<{text, html, attrs}>>
+    // 对每个外层匹配元素，在其内部查询内层选择器
+    html_obj.set("selectNested", Function::new(ctx.clone(), |html_str: String, outer: String, inner: String| -> String {
+        match select_nested(&html_str, &outer, &inner) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS HTML] SelectNested error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.children(htmlString, selector) -> Array<{tag, text, html, attrs}>
+    // 获取第一个匹配元素的直接子元素
+    html_obj.set("children", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match get_children(&html_str, &selector) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS HTML] Children error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.parentText(htmlString, selector) -> string
+    // 获取第一个匹配元素的父元素文本
+    html_obj.set("parentText", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match get_parent_text(&html_str, &selector) {
+            Ok(Some(value)) => value,
+            Ok(None) => String::new(),
+            Err(e) => {
+                tracing::error!("[JS HTML] ParentText error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // html.nextSibling(htmlString, selector) -> {tag, text, html, attrs} | null
+    // 获取第一个匹配元素的下一个兄弟元素
+    html_obj.set("nextSibling", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match get_next_sibling(&html_str, &selector) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS HTML] NextSibling error: {}", e);
+                "null".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.selectIn(htmlString, selector, childSelector) -> Array<{tag, text, html, attrs}>
+    // 在第一个匹配元素内部查询子选择器，只解析一次文档
+    html_obj.set("selectIn", Function::new(ctx.clone(), |html_str: String, selector: String, child: String| -> String {
+        match select_in(&html_str, &selector, &child) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS HTML] SelectIn error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.selectOneAttr(htmlString, selector, childSelector, attrName) -> string
+    // 获取第一个匹配元素内部第一个子元素的属性
+    html_obj.set("selectOneAttr", Function::new(ctx.clone(), |html_str: String, selector: String, child: String, attr: String| -> String {
+        match select_one_attr(&html_str, &selector, &child, &attr) {
+            Ok(Some(value)) => value,
+            Ok(None) => String::new(),
+            Err(e) => {
+                tracing::error!("[JS HTML] SelectOneAttr error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // html.xpath(htmlString, expr) -> Array<{text, attrs}> | Array<string|number|boolean>
+    // 使用 XPath 查询，节点集返回文本与属性，标量结果返回单元素数组
+    html_obj.set("xpath", Function::new(ctx.clone(), |html_str: String, expr: String| -> String {
+        match xpath_select(&html_str, &expr) {
+            Ok(values) => serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string()),
+            Err(e) => {
+                tracing::error!("[JS HTML] XPath error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.xpathText(htmlString, expr) -> Array<string>
+    // 获取 XPath 匹配节点的文本
+    html_obj.set("xpathText", Function::new(ctx.clone(), |html_str: String, expr: String| -> String {
+        match xpath_texts(&html_str, &expr) {
+            Ok(values) => serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string()),
+            Err(e) => {
+                tracing::error!("[JS HTML] XPathText error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.xpathAttr(htmlString, expr, attrName) -> Array<string>
+    // 获取 XPath 匹配元素的属性
+    html_obj.set("xpathAttr", Function::new(ctx.clone(), |html_str: String, expr: String, attr: String| -> String {
+        match xpath_attrs(&html_str, &expr, &attr) {
+            Ok(values) => serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string()),
+            Err(e) => {
+                tracing::error!("[JS HTML] XPathAttr error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.parseTable(htmlString, tableSelector) -> Array<object> | Array<Array<string>>
+    // 解析表格，有表头时每行为以表头为键的对象，否则为按列排列的数组
+    html_obj.set("parseTable", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match parse_table(&html_str, &selector) {
+            Ok(rows) => serde_json::to_string(&rows).unwrap_or_else(|_| "[]".to_string()),
+            Err(e) => {
+                tracing::error!("[JS HTML] ParseTable error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.links(htmlString, selector) -> Array<{href, text, title}>
+    // 匹配元素为 a 时直接返回，否则返回其内部所有带 href 的 a，保持文档顺序
+    html_obj.set("links", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match get_links(&html_str, &selector) {
+            Ok(values) => serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string()),
+            Err(e) => {
+                tracing::error!("[JS HTML] Links error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.images(htmlString, selector) -> Array<{src, alt, dataSrc}>
+    // 匹配元素为 img 时直接返回，否则返回其内部所有 img；dataSrc 取懒加载属性
+    html_obj.set("images", Function::new(ctx.clone(), |html_str: String, selector: String| -> String {
+        match get_images(&html_str, &selector) {
+            Ok(values) => serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string()),
+            Err(e) => {
+                tracing::error!("[JS HTML] Images error: {}", e);
+                "[]".to_string()
+            }
+        }
+    })?)?;
+    
+    // html.imgSrc(htmlString, selector, attrs?) -> string | null
+    // 按优先级读取图片地址，默认依次为 data-src、data-original、data-lazy-src、src，返回第一个非空值
+    html_obj.set("imgSrc", Function::new(ctx.clone(), |html_str: String, selector: String, attrs: Opt<Vec<String>>| -> Option<String> {
+        let attrs = attrs.0.filter(|a| !a.is_empty())
+            .unwrap_or_else(|| IMG_SRC_ATTRS.iter().map(|a| a.to_string()).collect());
+        match get_img_src(&html_str, &selector, &attrs) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::error!("[JS HTML] ImgSrc error: {}", e);
+                None
+            }
+        }
+    })?)?;
+    
     globals.set("__html__", html_obj)?;
     
     // 创建 Document 类的 JavaScript 实现
@@ -286,3 +475,606 @@ fn get_inner_html(html_str: &str, selector_str: &str) -> Result<Option<String>>
         Ok(None)
     }
 }
+
+/// 获取元素完整 HTML
+fn get_outer_html(html_str: &str, selector_str: &str) -> Result<Option<String>> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    Ok(document.select(&selector).next().map(|el| el.html()))
+}
+
+/// 将元素转换为 {tag, text, html, attrs}
+fn element_to_json(element: ElementRef) -> JsonValue {
+    let mut attrs = serde_json::Map::new();
+    for (name, value) in element.value().attrs() {
+        attrs.insert(name.to_string(), json!(value));
+    }
+    
+    json!({
+        "tag": element.value().name(),
+        "text": element.text().collect::<Vec<_>>().join(""),
+        "html": element.inner_html(),
+        "attrs": attrs
+    })
+}
+
+/// 对每个外层匹配元素查询内层选择器，返回二维数组
+fn select_nested(html_str: &str, outer_str: &str, inner_str: &str) -> Result<String> {
+    let document = Html::parse_document(html_str);
+    let outer = Selector::parse(outer_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let inner = Selector::parse(inner_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    let results: Vec<Vec<JsonValue>> = document
+        .select(&outer)
+        .map(|el| el.select(&inner).map(element_to_json).collect())
+        .collect();
+    
+    Ok(serde_json::to_string(&results)?)
+}
+
+/// 获取第一个匹配元素的直接子元素
+fn get_children(html_str: &str, selector_str: &str) -> Result<String> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    let results: Vec<JsonValue> = match document.select(&selector).next() {
+        Some(element) => element.children().filter_map(ElementRef::wrap).map(element_to_json).collect(),
+        None => Vec::new(),
+    };
+    
+    Ok(serde_json::to_string(&results)?)
+}
+
+/// 获取第一个匹配元素的父元素文本
+fn get_parent_text(html_str: &str, selector_str: &str) -> Result<Option<String>> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    Ok(document
+        .select(&selector)
+        .next()
+        .and_then(|el| el.parent())
+        .and_then(ElementRef::wrap)
+        .map(|parent| parent.text().collect::<Vec<_>>().join("")))
+}
+
+/// 获取第一个匹配元素的下一个兄弟元素
+fn get_next_sibling(html_str: &str, selector_str: &str) -> Result<String> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    let sibling = document
+        .select(&selector)
+        .next()
+        .and_then(|el| el.next_siblings().find_map(ElementRef::wrap));
+    
+    match sibling {
+        Some(element) => Ok(serde_json::to_string(&element_to_json(element))?),
+        None => Ok("null".to_string()),
+    }
+}
+
+/// 在第一个匹配元素内部查询子选择器
+fn select_in(html_str: &str, selector_str: &str, child_str: &str) -> Result<String> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let child = Selector::parse(child_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    let results: Vec<JsonValue> = match document.select(&selector).next() {
+        Some(element) => element.select(&child).map(element_to_json).collect(),
+        None => Vec::new(),
+    };
+    
+    Ok(serde_json::to_string(&results)?)
+}
+
+/// 获取第一个匹配元素内部第一个子元素的属性
+fn select_one_attr(html_str: &str, selector_str: &str, child_str: &str, attr_name: &str) -> Result<Option<String>> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let child = Selector::parse(child_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    Ok(document
+        .select(&selector)
+        .next()
+        .and_then(|el| el.select(&child).next())
+        .and_then(|el| el.value().attr(attr_name).map(|s| s.to_string())))
+}
+
+/// 懒加载图片存放真实地址的属性
+const LAZY_SRC_ATTRS: [&str; 3] = ["data-src", "data-original", "data-lazy-src"];
+
+/// imgSrc 默认的属性优先级：先懒加载属性，最后是 src
+const IMG_SRC_ATTRS: [&str; 4] = ["data-src", "data-original", "data-lazy-src", "src"];
+
+/// 匹配元素本身为指定标签时返回自身，否则返回其内部所有该标签的元素（去重并保持文档顺序）
+fn select_tagged<'a>(document: &'a Html, selector_str: &str, tag: &str) -> Result<Vec<ElementRef<'a>>> {
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let tag_selector = Selector::parse(tag)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    
+    let mut elements: Vec<ElementRef> = Vec::new();
+    for element in document.select(&selector) {
+        if element.value().name() == tag {
+            elements.push(element);
+        } else {
+            elements.extend(element.select(&tag_selector));
+        }
+    }
+    let mut seen = std::collections::HashSet::new();
+    elements.retain(|el| seen.insert(el.id()));
+    Ok(elements)
+}
+
+/// 获取链接列表
+fn get_links(html_str: &str, selector_str: &str) -> Result<Vec<JsonValue>> {
+    let document = Html::parse_document(html_str);
+    Ok(select_tagged(&document, selector_str, "a")?
+        .into_iter()
+        .filter_map(|el| {
+            let href = el.value().attr("href")?;
+            Some(json!({
+                "href": href,
+                "text": normalized_text(el),
+                "title": el.value().attr("title"),
+            }))
+        })
+        .collect())
+}
+
+/// 获取图片列表
+fn get_images(html_str: &str, selector_str: &str) -> Result<Vec<JsonValue>> {
+    let document = Html::parse_document(html_str);
+    Ok(select_tagged(&document, selector_str, "img")?
+        .into_iter()
+        .map(|el| {
+            let data_src = LAZY_SRC_ATTRS.iter()
+                .filter_map(|attr| el.value().attr(attr))
+                .find(|v| !v.trim().is_empty());
+            json!({
+                "src": el.value().attr("src"),
+                "alt": el.value().attr("alt"),
+                "dataSrc": data_src,
+            })
+        })
+        .collect())
+}
+
+/// 获取第一个匹配图片的真实地址，按 attrs 顺序返回第一个非空属性
+fn get_img_src(html_str: &str, selector_str: &str, attrs: &[String]) -> Result<Option<String>> {
+    let document = Html::parse_document(html_str);
+    let image = select_tagged(&document, selector_str, "img")?.into_iter().next();
+    Ok(image.and_then(|el| {
+        attrs.iter()
+            .filter_map(|attr| el.value().attr(attr))
+            .map(str::trim)
+            .find(|v| !v.is_empty())
+            .map(|v| v.to_string())
+    }))
+}
+
+/// 合并空白后的元素文本
+fn normalized_text(element: ElementRef) -> String {
+    element.text().collect::<Vec<_>>().join(" ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 解析 colspan / rowspan，缺失或无效时为 1
+fn span_attr(element: ElementRef, name: &str) -> usize {
+    element.value().attr(name)
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .unwrap_or(1)
+        .clamp(1, 100)
+}
+
+/// 将上方跨行单元格延续到当前行，直到遇到没有跨行的列
+fn take_pending(cells: &mut Vec<(String, bool)>, pending: &mut [Option<(String, bool, usize)>]) {
+    while let Some(Some((text, header, remaining))) = pending.get_mut(cells.len()) {
+        cells.push((text.clone(), *header));
+        *remaining -= 1;
+        if *remaining == 0 {
+            pending[cells.len() - 1] = None;
+        }
+    }
+}
+
+/// 解析表格为行数据
+/// 
+/// 只处理属于该表格自身的行（忽略嵌套表格）。跨列的单元格在每一列重复出现，
+/// 跨行的单元格在后续行的同一列重复出现。第一行全部为 `th` 时作为表头，
+/// 返回以表头为键的对象（空表头使用列号，重复表头追加序号），否则返回数组
+fn parse_table(html_str: &str, selector_str: &str) -> Result<Vec<JsonValue>> {
+    let document = Html::parse_document(html_str);
+    let selector = Selector::parse(selector_str)
+        .map_err(|e| anyhow::anyhow!("Invalid selector: {:?}", e))?;
+    let table_selector = Selector::parse("table").expect("valid selector");
+    let row_selector = Selector::parse("tr").expect("valid selector");
+    
+    // 选择器匹配到的不是表格时，使用其内部的第一个表格
+    let table = document.select(&selector).next().and_then(|el| {
+        if el.value().name() == "table" { Some(el) } else { el.select(&table_selector).next() }
+    });
+    let Some(table) = table else {
+        return Ok(Vec::new());
+    };
+    
+    // (单元格文本, 是否为表头)
+    let mut grid: Vec<Vec<(String, bool)>> = Vec::new();
+    // 每列尚未结束的 rowspan：(文本, 是否为表头, 剩余行数)
+    let mut pending: Vec<Option<(String, bool, usize)>> = Vec::new();
+    
+    let rows = table.select(&row_selector).filter(|row| {
+        row.ancestors().filter_map(ElementRef::wrap).find(|el| el.value().name() == "table")
+            .is_some_and(|owner| owner.id() == table.id())
+    });
+    for row in rows {
+        let mut cells: Vec<(String, bool)> = Vec::new();
+        
+        for cell in row.children().filter_map(ElementRef::wrap) {
+            let header = match cell.value().name() {
+                "th" => true,
+                "td" => false,
+                _ => continue,
+            };
+            take_pending(&mut cells, &mut pending);
+            let text = normalized_text(cell);
+            let rowspan = span_attr(cell, "rowspan");
+            for _ in 0..span_attr(cell, "colspan") {
+                if rowspan > 1 {
+                    if pending.len() <= cells.len() {
+                        pending.resize(cells.len() + 1, None);
+                    }
+                    pending[cells.len()] = Some((text.clone(), header, rowspan - 1));
+                }
+                cells.push((text.clone(), header));
+            }
+        }
+        take_pending(&mut cells, &mut pending);
+        
+        if !cells.is_empty() {
+            grid.push(cells);
+        }
+    }
+    
+    let has_header = grid.first().is_some_and(|row| row.iter().all(|(_, header)| *header));
+    if !has_header {
+        return Ok(grid.into_iter()
+            .map(|row| json!(row.into_iter().map(|(text, _)| text).collect::<Vec<_>>()))
+            .collect());
+    }
+    
+    let mut keys: Vec<String> = Vec::new();
+    for (i, (text, _)) in grid[0].iter().enumerate() {
+        let base = if text.is_empty() { i.to_string() } else { text.clone() };
+        let mut key = base.clone();
+        let mut n = 2;
+        while keys.contains(&key) {
+            key = format!("{}_{}", base, n);
+            n += 1;
+        }
+        keys.push(key);
+    }
+    
+    Ok(grid.into_iter().skip(1).map(|row| {
+        let mut object = serde_json::Map::new();
+        for (i, (text, _)) in row.into_iter().enumerate() {
+            let key = keys.get(i).cloned().unwrap_or_else(|| i.to_string());
+            object.insert(key, json!(text));
+        }
+        JsonValue::Object(object)
+    }).collect())
+}
+
+/// 解析 HTML 并执行 XPath 表达式
+/// 
+/// XPath 由 sxd-xpath 执行，HTML 通过 sxd_html 转换为 sxd-document。
+/// 转换后的元素不带 XHTML 命名空间，表达式中直接使用标签名（如 `//div[@class='a']`），无需前缀
+fn evaluate_xpath<T>(html_str: &str, expr: &str, f: impl FnOnce(XPathValue) -> T) -> Result<T> {
+    let package = sxd_html::parse_html(html_str);
+    let document = package.as_document();
+    let value = sxd_xpath::evaluate_xpath(&document, expr)
+        .map_err(|e| anyhow::anyhow!("Invalid xpath: {:?}", e))?;
+    Ok(f(value))
+}
+
+/// XPath 节点的属性，非元素节点为空
+fn xpath_node_attrs(node: &XPathNode) -> serde_json::Map<String, JsonValue> {
+    let mut attrs = serde_json::Map::new();
+    if let XPathNode::Element(element) = node {
+        for attr in element.attributes() {
+            attrs.insert(attr.name().local_part().to_string(), json!(attr.value()));
+        }
+    }
+    attrs
+}
+
+/// 执行 XPath，节点集返回 {text, attrs}，标量结果返回单元素数组
+fn xpath_select(html_str: &str, expr: &str) -> Result<Vec<JsonValue>> {
+    evaluate_xpath(html_str, expr, |value| match value {
+        XPathValue::Nodeset(nodes) => nodes
+            .document_order()
+            .iter()
+            .map(|node| json!({
+                "text": node.string_value(),
+                "attrs": xpath_node_attrs(node)
+            }))
+            .collect(),
+        XPathValue::String(s) => vec![json!(s)],
+        XPathValue::Number(n) => vec![json!(n)],
+        XPathValue::Boolean(b) => vec![json!(b)],
+    })
+}
+
+/// 获取 XPath 匹配节点的文本
+fn xpath_texts(html_str: &str, expr: &str) -> Result<Vec<String>> {
+    evaluate_xpath(html_str, expr, |value| match value {
+        XPathValue::Nodeset(nodes) => nodes
+            .document_order()
+            .iter()
+            .map(|node| node.string_value())
+            .collect(),
+        other => vec![other.string()],
+    })
+}
+
+/// 获取 XPath 匹配元素的属性
+fn xpath_attrs(html_str: &str, expr: &str, attr_name: &str) -> Result<Vec<String>> {
+    evaluate_xpath(html_str, expr, |value| match value {
+        XPathValue::Nodeset(nodes) => nodes
+            .document_order()
+            .iter()
+            .filter_map(|node| match node {
+                XPathNode::Element(element) => element.attribute_value(attr_name).map(|s| s.to_string()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NESTED_LIST: &str = r#"
+        <ul class="list">
+            <li class="item"><a href="/c/1">One</a><span>tag-a</span><a href="/c/1/ep">Ep</a></li>
+            <li class="item"><a href="/c/2">Two</a></li>
+            <li class="item"><span>empty</span></li>
+        </ul>
+        <p class="note">Note</p>
+    "#;
+
+    #[test]
+    fn test_select_nested() {
+        let result: JsonValue = serde_json::from_str(&select_nested(NESTED_LIST, ".item", "a").unwrap()).unwrap();
+        let groups = result.as_array().unwrap();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].as_array().unwrap().len(), 2);
+        assert_eq!(groups[0][0]["attrs"]["href"], "/c/1");
+        assert_eq!(groups[0][1]["text"], "Ep");
+        assert_eq!(groups[1][0]["text"], "Two");
+        assert!(groups[2].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_children_and_parent() {
+        let children: JsonValue = serde_json::from_str(&get_children(NESTED_LIST, ".item").unwrap()).unwrap();
+        let tags: Vec<&str> = children.as_array().unwrap().iter().map(|c| c["tag"].as_str().unwrap()).collect();
+        assert_eq!(tags, vec!["a", "span", "a"]);
+
+        assert_eq!(get_parent_text(NESTED_LIST, "a[href='/c/2']").unwrap().as_deref(), Some("Two"));
+        assert_eq!(get_parent_text(NESTED_LIST, ".missing").unwrap(), None);
+
+        let sibling: JsonValue = serde_json::from_str(&get_next_sibling(NESTED_LIST, ".list").unwrap()).unwrap();
+        assert_eq!(sibling["attrs"]["class"], "note");
+        assert_eq!(get_next_sibling(NESTED_LIST, ".note").unwrap(), "null");
+    }
+
+    #[test]
+    fn test_outer_html_and_attr_or() {
+        let html = r#"<div><script id="data" type="application/json">{"a":1}</script><img src="" alt="x"></div>"#;
+        assert_eq!(
+            get_outer_html(html, "#data").unwrap().as_deref(),
+            Some(r#"<script id="data" type="application/json">{"a":1}</script>"#)
+        );
+        assert_eq!(get_outer_html(html, ".missing").unwrap(), None);
+
+        // 空属性与缺失属性需可区分
+        assert_eq!(get_attr(html, "img", "src").unwrap().as_deref(), Some(""));
+        assert_eq!(get_attr(html, "img", "title").unwrap(), None);
+
+        let runtime = crate::js_engine::JsRuntime::new().unwrap();
+        let result = runtime.eval_string(&format!(
+            "JSON.stringify([__html__.attrOr({0}, 'img', 'src', 'none'), __html__.attrOr({0}, 'img', 'title', 'none'), __html__.attrOr({0}, 'img', 'title')])",
+            serde_json::to_string(html).unwrap()
+        )).unwrap();
+        assert_eq!(result, r#"["","none",null]"#);
+    }
+
+    #[test]
+    fn test_xpath() {
+        let items = xpath_select(NESTED_LIST, "//li[contains(., 'Two')]/a").unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["text"], "Two");
+        assert_eq!(items[0]["attrs"]["href"], "/c/2");
+
+        assert_eq!(xpath_texts(NESTED_LIST, "//li/span").unwrap(), vec!["tag-a", "empty"]);
+        assert_eq!(xpath_attrs(NESTED_LIST, "//li[1]/a", "href").unwrap(), vec!["/c/1", "/c/1/ep"]);
+        assert_eq!(xpath_select(NESTED_LIST, "count(//li)").unwrap(), vec![json!(3.0)]);
+        assert!(xpath_select(NESTED_LIST, "//li[").is_err());
+    }
+
+    #[test]
+    fn test_parse_table() {
+        let html = r#"
+            <table class="info">
+                <thead><tr><th>Name</th><th>Info</th><th></th><th>Info</th></tr></thead>
+                <tbody>
+                    <tr><td rowspan="2">Alpha</td><td colspan="2">wide</td><td>x</td></tr>
+                    <tr><td>b1</td><td>b2</td><td>b3</td></tr>
+                    <tr><td>Gamma<table><tr><td>nested</td></tr></table></td><td>c1</td></tr>
+                </tbody>
+            </table>
+        "#;
+        let rows = parse_table(html, "table.info").unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], json!({"Name": "Alpha", "Info": "wide", "2": "wide", "Info_2": "x"}));
+        assert_eq!(rows[1], json!({"Name": "Alpha", "Info": "b1", "2": "b2", "Info_2": "b3"}));
+        assert_eq!(rows[2]["Name"], "Gamma nested");
+        assert_eq!(rows[2]["Info"], "c1");
+
+        // 无表头时返回数组
+        let plain = r#"<table><tr><th>作者</th><td> 某人 </td></tr><tr><th>状态</th><td>连载中</td></tr></table>"#;
+        assert_eq!(parse_table(plain, "table").unwrap(), vec![json!(["作者", "某人"]), json!(["状态", "连载中"])]);
+        assert!(parse_table(plain, ".missing").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_links_and_images() {
+        let html = r#"
+            <div class="pager">
+                <a href="?page=1" title="First">1</a>
+                <span>…</span>
+                <a href="?page=2">  2 </a>
+                <a class="disabled">3</a>
+            </div>
+            <div class="pics">
+                <img src="/blank.gif" data-original="/real/1.jpg" alt="p1">
+                <img src="/real/2.jpg">
+            </div>
+        "#;
+        let links = get_links(html, ".pager").unwrap();
+        assert_eq!(links, vec![
+            json!({"href": "?page=1", "text": "1", "title": "First"}),
+            json!({"href": "?page=2", "text": "2", "title": null}),
+        ]);
+        // 同时匹配外层和链接本身时不重复
+        assert_eq!(get_links(html, ".pager, .pager a").unwrap().len(), 2);
+
+        let images = get_images(html, ".pics img").unwrap();
+        assert_eq!(images[0], json!({"src": "/blank.gif", "alt": "p1", "dataSrc": "/real/1.jpg"}));
+        assert_eq!(images[1], json!({"src": "/real/2.jpg", "alt": null, "dataSrc": null}));
+    }
+
+    #[test]
+    fn test_img_src_prefers_lazy_attrs() {
+        let html = r#"
+            <div class="cover">
+                <img src="data:image/gif;base64,R0lGODlhAQABAAAAACw=" data-src=" https://cdn.example.com/cover.jpg " data-original="">
+            </div>
+            <img class="plain" src="/plain.jpg" data-src="">
+        "#;
+        let defaults: Vec<String> = IMG_SRC_ATTRS.iter().map(|a| a.to_string()).collect();
+        assert_eq!(get_img_src(html, ".cover", &defaults).unwrap().as_deref(), Some("https://cdn.example.com/cover.jpg"));
+        assert_eq!(get_img_src(html, ".plain", &defaults).unwrap().as_deref(), Some("/plain.jpg"));
+        assert_eq!(get_img_src(html, ".missing", &defaults).unwrap(), None);
+
+        let runtime = crate::js_engine::JsRuntime::new().unwrap();
+        let result = runtime.eval_string(&format!(
+            "JSON.stringify([__html__.imgSrc({0}, '.cover img'), __html__.imgSrc({0}, '.cover img', ['src'])])",
+            serde_json::to_string(html).unwrap()
+        )).unwrap();
+        assert_eq!(result, r#"["https://cdn.example.com/cover.jpg","data:image/gif;base64,R0lGODlhAQABAAAAACw="]"#);
+    }
+
+    /// 生成 500 个条目的列表页
+    fn large_list_page() -> String {
+        let items: String = (0..500)
+            .map(|i| format!(r#"<li class="item" id="item-{i}"><a href="/c/{i}"><img src="/t/{i}.jpg"></a><span class="title">Title {i}</span></li>"#))
+            .collect();
+        format!(r#"<html><body><ul class="list">{}</ul></body></html>"#, items)
+    }
+
+    #[test]
+    fn test_select_in_and_one_attr() {
+        assert_eq!(select_one_attr(NESTED_LIST, ".item", "a", "href").unwrap().as_deref(), Some("/c/1"));
+        assert_eq!(select_one_attr(NESTED_LIST, ".item", "a", "title").unwrap(), None);
+        assert_eq!(select_one_attr(NESTED_LIST, ".missing", "a", "href").unwrap(), None);
+
+        let result: JsonValue = serde_json::from_str(&select_in(NESTED_LIST, ".item", "a").unwrap()).unwrap();
+        assert_eq!(result.as_array().unwrap().len(), 2);
+        assert_eq!(result[1]["attrs"]["href"], "/c/1/ep");
+    }
+
+    #[test]
+    fn test_chained_selection_matches_root_queries() {
+        let page = large_list_page();
+        for i in (0..500).step_by(50) {
+            let id = format!("#item-{}", i);
+            let href = format!("/c/{}", i);
+
+            // 从根查询、在元素内部查询以及 selectIn/selectOneAttr 结果一致
+            assert_eq!(get_attr(&page, &format!("{} a", id), "href").unwrap().as_deref(), Some(href.as_str()));
+            assert_eq!(select_one_attr(&page, &id, "a", "href").unwrap().as_deref(), Some(href.as_str()));
+            assert_eq!(
+                select_one_attr(&page, &id, "img", "src").unwrap(),
+                get_attr(&page, &format!("{} img", id), "src").unwrap(),
+            );
+
+            let item = get_inner_html(&page, &id).unwrap().unwrap();
+            assert_eq!(get_attr(&item, "a", "href").unwrap().as_deref(), Some(href.as_str()));
+            assert_eq!(get_text(&item, ".title").unwrap(), get_text(&page, &format!("{} .title", id)).unwrap());
+
+            let children: JsonValue = serde_json::from_str(&select_in(&page, &id, "a, img, .title").unwrap()).unwrap();
+            let children = children.as_array().unwrap();
+            assert_eq!(children.len(), 3);
+            assert_eq!(children[0]["attrs"]["href"], href);
+            assert_eq!(children[1]["attrs"]["src"], format!("/t/{}.jpg", i));
+        }
+
+        // 内部查询不会越过第一个匹配元素
+        let all: JsonValue = serde_json::from_str(&select_in(&page, ".list", ".title").unwrap()).unwrap();
+        assert_eq!(all.as_array().unwrap().len(), 500);
+        let first: JsonValue = serde_json::from_str(&select_in(&page, ".item", ".title").unwrap()).unwrap();
+        assert_eq!(first.as_array().unwrap().len(), 1);
+    }
+
+    /// 对比逐字段从根重新解析与在元素内部查询的耗时
+    /// 运行：cargo test --release bench_chained_selection -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_chained_selection() {
+        let page = large_list_page();
+
+        let start = std::time::Instant::now();
+        for i in (0..500).step_by(50) {
+            let id = format!("#item-{}", i);
+            let _ = get_attr(&page, &format!("{} a", id), "href").unwrap();
+            let _ = get_attr(&page, &format!("{} img", id), "src").unwrap();
+            let _ = get_text(&page, &format!("{} .title", id)).unwrap();
+        }
+        let from_root = start.elapsed();
+
+        let start = std::time::Instant::now();
+        for i in (0..500).step_by(50) {
+            let item = get_inner_html(&page, &format!("#item-{}", i)).unwrap().unwrap();
+            let _ = get_attr(&item, "a", "href").unwrap();
+            let _ = get_attr(&item, "img", "src").unwrap();
+            let _ = get_text(&item, ".title").unwrap();
+        }
+        let inner_html = start.elapsed();
+
+        let start = std::time::Instant::now();
+        for i in (0..500).step_by(50) {
+            let _ = select_in(&page, &format!("#item-{}", i), "a, img, .title").unwrap();
+        }
+        let select_in_elapsed = start.elapsed();
+
+        println!(
+            "from root: {:?}, inner html: {:?}, selectIn: {:?}",
+            from_root, inner_html, select_in_elapsed
+        );
+    }
+}
@@ -1,7 +1,12 @@
 use rquickjs::{Ctx, Function, Value};
 use anyhow::Result;
 
-use crate::http::{HttpClient, HttpRequest};
+use crate::api::property_api;
+use crate::crypto;
+use crate::http::{CookieManager, HttpClient, HttpRequest};
+
+/// 模块默认请求头在模块属性中的键名（JSON）
+pub(crate) const DEFAULT_HEADERS_KEY: &str = "__default_headers__";
 
 /// 注册 http 对象到 JS 全局
 pub fn register(ctx: &Ctx<'_>) -> Result<()> {
@@ -25,15 +30,12 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
         
         tracing::debug!("[JS HTTP] Making {} request to: {}", request.method, request.url);
         
-        // 使用 tokio 的阻塞线程执行异步请求
+        // 在共享运行时上执行异步请求
         // 注意：这会阻塞当前线程，但 QuickJS 是单线程的所以没问题
-        let result = std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async {
-                let client = HttpClient::new()?;
-                client.request(request).await
-            })
-        }).join();
+        let result = super::block_on(async move {
+            let client = HttpClient::shared()?;
+            client.request(request).await
+        });
         
         match result {
             Ok(Ok(response)) => {
@@ -59,38 +61,270 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
         }
     })?)?;
     
+    // 注册同步的 HTTP 请求函数（返回原始字节）
+    // 响应体以 base64 编码返回，用于图片解扰或非 UTF-8 网页
+    globals.set("__native_http_request_bytes_sync__", Function::new(ctx.clone(), |config_json: String| -> String {
+        tracing::debug!("[JS HTTP] Received bytes request: {}", &config_json[..config_json.len().min(200)]);
+        
+        let request: HttpRequest = match serde_json::from_str(&config_json) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::error!("[JS HTTP] Failed to parse request: {}", e);
+                return serde_json::to_string(&serde_json::json!({
+                    "error": format!("Failed to parse request: {}", e)
+                })).unwrap_or_default();
+            }
+        };
+        
+        tracing::debug!("[JS HTTP] Making {} bytes request to: {}", request.method, request.url);
+        
+        let started = std::time::Instant::now();
+        let result = super::block_on(async move {
+            let client = HttpClient::shared()?;
+            client.request_bytes(request).await
+        });
+        
+        match result {
+            Ok(Ok((status, headers, body))) => {
+                let elapsed_ms = started.elapsed().as_millis() as u64;
+                tracing::debug!("[JS HTTP] Response status: {}, {} bytes", status, body.len());
+                let content_type = headers
+                    .get("content-type")
+                    .cloned()
+                    .unwrap_or_else(|| "application/octet-stream".to_string());
+                serde_json::json!({
+                    "status": status,
+                    "headers": headers,
+                    "body": crypto::base64_encode(&body),
+                    "content_type": content_type,
+                    "elapsed_ms": elapsed_ms,
+                    "body_size": body.len(),
+                }).to_string()
+            }
+            Ok(Err(e)) => {
+                tracing::error!("[JS HTTP] Bytes request failed: {:?}", e);
+                serde_json::to_string(&serde_json::json!({
+                    "error": format!("Request failed: {:?}", e)
+                })).unwrap_or_default()
+            }
+            Err(_) => {
+                tracing::error!("[JS HTTP] Thread panicked");
+                serde_json::to_string(&serde_json::json!({
+                    "error": "HTTP request thread panicked"
+                })).unwrap_or_default()
+            }
+        }
+    })?)?;
+    
+    // 获取资源大小（字节），长度未知时返回 -1
+    globals.set("__native_http_content_length_sync__", Function::new(ctx.clone(), |config_json: String| -> f64 {
+        let request: HttpRequest = match serde_json::from_str(&config_json) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::error!("[JS HTTP] Failed to parse request: {}", e);
+                return -1.0;
+            }
+        };
+
+        let result = super::block_on(async move {
+            let client = HttpClient::shared()?;
+            Ok::<_, anyhow::Error>(client.content_length_of(request).await)
+        });
+
+        match result {
+            Ok(Ok(Some(length))) => length as f64,
+            Ok(Ok(None)) => -1.0,
+            Ok(Err(e)) => {
+                tracing::error!("[JS HTTP] Content length probe failed: {:?}", e);
+                -1.0
+            }
+            Err(_) => -1.0,
+        }
+    })?)?;
+    
+    // 清除模块 Cookie
+    globals.set("__native_http_clear_cookies_sync__", Function::new(ctx.clone(), |module_id: String| -> bool {
+        tracing::debug!("[JS HTTP] Clear cookies: module={}", module_id);
+        
+        let result = super::block_on(async move {
+            CookieManager::instance().clear(&module_id).await
+        });
+        
+        match result {
+            Ok(Ok(())) => true,
+            Ok(Err(e)) => {
+                tracing::error!("[JS HTTP] Clear cookies failed: {:?}", e);
+                false
+            }
+            Err(_) => false,
+        }
+    })?)?;
+    
+    // 模块默认请求头，保存在模块的内部属性中（storage 不能写入内部属性）
+    globals.set("__native_http_default_headers_sync__", Function::new(ctx.clone(), |module_id: String| -> String {
+        let result = super::block_on(async move {
+            property_api::load_property(module_id, DEFAULT_HEADERS_KEY.to_string()).await
+        });
+        match result {
+            Ok(Ok(value)) => value.unwrap_or_default(),
+            _ => String::new(),
+        }
+    })?)?;
+    
+    globals.set("__native_http_set_default_headers_sync__", Function::new(ctx.clone(), |module_id: String, headers_json: String| -> bool {
+        tracing::debug!("[JS HTTP] Set default headers: module={}", module_id);
+        
+        let result = super::block_on(async move {
+            if headers_json.is_empty() {
+                property_api::delete_property(module_id, DEFAULT_HEADERS_KEY.to_string()).await
+            } else {
+                property_api::save_property(module_id, DEFAULT_HEADERS_KEY.to_string(), headers_json).await
+            }
+        });
+        
+        match result {
+            Ok(Ok(())) => true,
+            Ok(Err(e)) => {
+                tracing::error!("[JS HTTP] Set default headers failed: {:?}", e);
+                false
+            }
+            Err(_) => false,
+        }
+    })?)?;
+    
     // 注册辅助 JS 代码
     // 提供 http.get/post/request 接口
     let http_helper = r#"
-        const http = {
+        // 挂在 globalThis 上，未授予 http 权限时可以删除
+        globalThis.http = {
+            // 设为 true 后，请求会携带并保存本模块的持久化 Cookie
+            cookiesEnabled: false,
+            // 按模块缓存的默认请求头，避免每次请求读库
+            _defaultHeaders: {},
+            _prepare: function(config) {
+                // 未指定超时时使用 App 的全局默认超时
+                if (!config.timeout_secs) delete config.timeout_secs;
+                config.module_id = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : null;
+                config.headers = http._mergeHeaders(http.getDefaultHeaders(), config.headers || {});
+                if (config.enable_cookies === undefined) {
+                    config.enable_cookies = http.cookiesEnabled;
+                }
+                return JSON.stringify(config);
+            },
             get: function(url, headers) {
-                headers = headers || {};
-                var config = JSON.stringify({
+                var config = http._prepare({
                     url: url,
                     method: 'GET',
-                    headers: headers,
-                    timeout_secs: 30
+                    headers: headers || {}
                 });
                 var responseJson = __native_http_request_sync__(config);
                 return JSON.parse(responseJson);
             },
             post: function(url, headers, body) {
-                headers = headers || {};
-                var config = JSON.stringify({
+                var config = http._prepare({
                     url: url,
                     method: 'POST',
-                    headers: headers,
-                    body: body || null,
-                    timeout_secs: 30
+                    headers: headers || {},
+                    body: body || null
                 });
                 var responseJson = __native_http_request_sync__(config);
                 return JSON.parse(responseJson);
             },
             request: function(config) {
-                config.timeout_secs = config.timeout_secs || 30;
-                var configJson = JSON.stringify(config);
+                var configJson = http._prepare(config);
                 var responseJson = __native_http_request_sync__(configJson);
                 return JSON.parse(responseJson);
+            },
+            // auth: {basic: {user, pass}} 或 {bearer: token}，由 Rust 生成 Authorization 头
+            getWithAuth: function(url, auth, headers) {
+                return http.request({
+                    url: url,
+                    method: 'GET',
+                    headers: headers || {},
+                    auth: auth
+                });
+            },
+            postWithAuth: function(url, auth, headers, body) {
+                return http.request({
+                    url: url,
+                    method: 'POST',
+                    headers: headers || {},
+                    body: body || null,
+                    auth: auth
+                });
+            },
+            // 返回 {status, headers, body, content_type, elapsed_ms, body_size}，body 为 base64 编码的原始字节
+            requestBytes: function(config) {
+                config.method = config.method || 'GET';
+                var configJson = http._prepare(config);
+                var responseJson = __native_http_request_bytes_sync__(configJson);
+                return JSON.parse(responseJson);
+            },
+            // parts: [{name, value}] 文本字段，或 [{name, data, filename, content_type}] 文件（data 为 base64）
+            postMultipart: function(url, headers, parts) {
+                return http.request({
+                    url: url,
+                    method: 'POST',
+                    headers: headers || {},
+                    multipart: parts || []
+                });
+            },
+            // 异步请求，返回 Promise，可配合 await / Promise.all 使用
+            requestAsync: function(config) {
+                return __async__.spawn('http', http._prepare(config)).then(function(responseJson) {
+                    return JSON.parse(responseJson);
+                });
+            },
+            // 默认请求头合并到每次请求中，调用时传入的同名请求头（不区分大小写）优先
+            _mergeHeaders: function(defaults, headers) {
+                var merged = {};
+                var overridden = {};
+                for (var key in headers) overridden[key.toLowerCase()] = true;
+                for (var key in defaults) {
+                    if (!overridden[key.toLowerCase()]) merged[key] = defaults[key];
+                }
+                for (var key in headers) merged[key] = headers[key];
+                return merged;
+            },
+            // 设置本模块的默认请求头（如 Referer、Origin），保存在模块存储中，传入空值清除
+            setDefaultHeaders: function(headers) {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                var value = headers || {};
+                var json = Object.keys(value).length > 0 ? JSON.stringify(value) : '';
+                var ok = __native_http_set_default_headers_sync__(moduleId, json);
+                delete http._defaultHeaders[moduleId];
+                return ok;
+            },
+            getDefaultHeaders: function() {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                if (!(moduleId in http._defaultHeaders)) {
+                    var raw = __native_http_default_headers_sync__(moduleId);
+                    var headers = {};
+                    if (raw) {
+                        try {
+                            headers = JSON.parse(raw);
+                        } catch (e) {
+                            console.warn('http: invalid default headers for module ' + moduleId);
+                        }
+                    }
+                    http._defaultHeaders[moduleId] = headers;
+                }
+                return http._defaultHeaders[moduleId];
+            },
+            // 获取资源大小（字节），不下载内容；长度未知时返回 null
+            contentLength: function(url, headers) {
+                var config = http._prepare({
+                    url: url,
+                    method: 'HEAD',
+                    headers: headers || {}
+                });
+                var length = __native_http_content_length_sync__(config);
+                return length < 0 ? null : length;
+            },
+            // 清除本模块保存的所有 Cookie
+            clearCookies: function() {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                return __native_http_clear_cookies_sync__(moduleId);
             }
         };
     "#;
@@ -105,8 +339,42 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
 /// 执行 HTTP 请求（供 Rust 端调用）- 保留用于其他用途
 pub async fn execute_http_request(config_json: &str) -> Result<String> {
     let request: HttpRequest = serde_json::from_str(config_json)?;
-    let client = HttpClient::new()?;
+    let client = HttpClient::shared()?;
     let response = client.request(request).await?;
     let response_json = serde_json::to_string(&response)?;
     Ok(response_json)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::database;
+    use crate::js_engine::JsRuntime;
+    use crate::http::test_server::TestServer;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_default_headers_overridable() {
+        database::init_test_database().await;
+        let server = TestServer::echo();
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("http_default_headers_test", r#"
+            function main(args) {
+                http.setDefaultHeaders({ Referer: 'https://example.com/' });
+                var byDefault = http.get(args.url, {}).body;
+                var overridden = http.get(args.url, { referer: 'https://other.com/' }).body;
+                http.setDefaultHeaders(null);
+                var cleared = http.get(args.url).body;
+                return { byDefault: byDefault, overridden: overridden, cleared: cleared, keys: storage.keys() };
+            }
+        "#).unwrap();
+
+        let args = serde_json::json!({ "url": server.url("/") }).to_string();
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", &args).unwrap()).unwrap();
+        assert!(result["byDefault"].as_str().unwrap().contains("referer: https://example.com/"));
+        let overridden = result["overridden"].as_str().unwrap();
+        assert!(overridden.contains("referer: https://other.com/"));
+        assert!(!overridden.contains("example.com"));
+        assert!(!result["cleared"].as_str().unwrap().contains("referer"));
+        // 默认请求头保存为内部属性，不出现在 storage.keys() 中
+        assert_eq!(result["keys"], serde_json::json!([]));
+    }
+}
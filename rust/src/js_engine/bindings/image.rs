@@ -1,4 +1,5 @@
 use rquickjs::{Ctx, Function, Object};
+use rquickjs::function::Opt;
 use anyhow::Result;
 
 use crate::api::image_api;
@@ -33,6 +34,77 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
         }
     })?)?;
     
+    // image.rearrangeCols(imageDataBase64, cols) -> base64 encoded PNG
+    image_obj.set("rearrangeCols", Function::new(ctx.clone(), |image_data_base64: String, cols: u32| -> String {
+        match image_api::rearrange_image_cols(image_data_base64, cols) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Image] Failed to rearrange image cols: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // image.rearrangeGrid(imageDataBase64, cols, rows, permutation) -> base64 encoded PNG
+    // permutation 为数组，目标第 i 个格子（行优先）取源图第 permutation[i] 个格子
+    image_obj.set("rearrangeGrid", Function::new(ctx.clone(), |image_data_base64: String, cols: u32, rows: u32, permutation: Vec<u32>| -> String {
+        match image_api::rearrange_image_grid(image_data_base64, cols, rows, permutation) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Image] Failed to rearrange image grid: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // image.convert(imageDataBase64, format, quality?) -> base64 encoded image
+    // format: "jpeg" | "png" | "webp"，quality 仅对 JPEG 生效（1-100）
+    image_obj.set("convert", Function::new(ctx.clone(), |image_data_base64: String, format: String, quality: Opt<u8>| -> String {
+        match image_api::convert_image(image_data_base64, format, quality.0) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Image] Failed to convert image: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // image.concatVertical(imageDataBase64Array, maxHeight) -> base64 encoded PNG 数组
+    // 条漫拼接：按顺序堆叠后切分为高度不超过 maxHeight 的若干张
+    image_obj.set("concatVertical", Function::new(ctx.clone(), |images: Vec<String>, max_height: u32| -> Vec<String> {
+        match image_api::concat_images_vertical(images, max_height) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Image] Failed to concat images vertically: {}", e);
+                Vec::new()
+            }
+        }
+    })?)?;
+    
+    // image.autoOrient(imageDataBase64) -> base64，按 EXIF 方向标记校正
+    image_obj.set("autoOrient", Function::new(ctx.clone(), |image_data_base64: String| -> String {
+        match image_api::auto_orient_image(image_data_base64) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Image] Failed to auto orient image: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    // image.readExif(imageDataBase64) -> JSON string，标签名到值的映射
+    image_obj.set("readExif", Function::new(ctx.clone(), |image_data_base64: String| -> String {
+        match image_api::read_exif(image_data_base64) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("[JS Image] Failed to read exif: {}", e);
+                serde_json::json!({
+                    "error": format!("Failed to read exif: {}", e)
+                }).to_string()
+            }
+        }
+    })?)?;
+    
     // image.crop(imageDataBase64, x, y, width, height) -> base64 encoded PNG
     image_obj.set("crop", Function::new(ctx.clone(), |image_data_base64: String, x: u32, y: u32, width: u32, height: u32| -> String {
         match image_api::crop_image(image_data_base64, x, y, width, height) {
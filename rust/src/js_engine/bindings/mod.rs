@@ -4,9 +4,125 @@ pub mod storage;
 pub mod console;
 pub mod html;
 pub mod image;
+pub mod encoding;
+pub mod fetch;
+pub mod cache;
+pub mod text;
+pub mod url;
+pub mod require;
 
 use rquickjs::{Ctx, Value};
 use anyhow::Result;
+use once_cell::sync::Lazy;
+use std::future::Future;
+
+/// 同步绑定共享的 Tokio 运行时
+/// 避免每次 HTTP/存储调用都新建运行时
+static BLOCKING_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .thread_name("js-binding-rt")
+        .enable_all()
+        .build()
+        .expect("Failed to create JS binding runtime")
+});
+
+/// 在共享运行时上同步等待异步任务完成
+/// 
+/// QuickJS 的绑定函数是同步调用的，且通常运行在 Tokio 运行时内部，
+/// 此时不能直接 block_on，改为交给共享运行时的工作线程执行并通过通道等待结果。
+pub(crate) fn block_on<F>(future: F) -> Result<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    if tokio::runtime::Handle::try_current().is_err() {
+        return Ok(BLOCKING_RUNTIME.block_on(future));
+    }
+    let (tx, rx) = std::sync::mpsc::sync_channel(1);
+    BLOCKING_RUNTIME.spawn(async move {
+        let _ = tx.send(future.await);
+    });
+    // 任务 panic 时发送端被丢弃，recv 返回错误
+    rx.recv().map_err(|_| anyhow::anyhow!("Blocking task panicked"))
+}
+
+/// 在共享运行时上后台执行异步任务（供事件循环使用）
+pub(crate) fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    BLOCKING_RUNTIME.spawn(future);
+}
+
+/// 模块可声明的全部权限
+pub const ALL_PERMISSIONS: [&str; 3] = ["http", "storage", "crypto"];
+
+/// 权限对应的绑定
+struct PermissionBindings {
+    /// runtime 对象上的子对象
+    members: &'static [&'static str],
+    /// 全局辅助对象
+    globals: &'static [&'static str],
+    /// 背后原生函数的名称前缀
+    native_prefixes: &'static [&'static str],
+    /// 事件循环中的异步操作类型
+    async_ops: &'static [&'static str],
+}
+
+fn permission_bindings(permission: &str) -> PermissionBindings {
+    match permission {
+        "http" => PermissionBindings {
+            members: &["http", "fetch"],
+            globals: &["http", "fetch"],
+            native_prefixes: &["__native_http_"],
+            async_ops: &["http", "fetch"],
+        },
+        "storage" => PermissionBindings {
+            members: &["storage", "cache"],
+            globals: &["storage", "__cache__"],
+            native_prefixes: &["__native_storage_", "__native_cache_"],
+            async_ops: &[],
+        },
+        "crypto" => PermissionBindings {
+            members: &["crypto"],
+            globals: &["__crypto__"],
+            native_prefixes: &[],
+            async_ops: &[],
+        },
+        _ => PermissionBindings { members: &[], globals: &[], native_prefixes: &[], async_ops: &[] },
+    }
+}
+
+/// 删除未授予权限对应的绑定：runtime 子对象、全局辅助对象及其背后的原生函数
+/// 
+/// 需要在加载模块脚本之前调用。删除后脚本既不能通过 `runtime.http`，
+/// 也不能通过全局的 `http`、`__native_http_request_sync__` 等访问；
+/// 返回需要在事件循环中禁止的异步操作类型
+pub fn revoke_permissions(ctx: &Ctx<'_>, granted: &[String]) -> Result<Vec<&'static str>> {
+    // runtime 由 const 声明，不在 globalThis 上
+    let runtime: rquickjs::Object = ctx.eval("runtime")?;
+    let globals = ctx.globals();
+    let names: Vec<String> = globals.keys::<String>().collect::<rquickjs::Result<_>>()?;
+    
+    let mut denied_ops = Vec::new();
+    for permission in ALL_PERMISSIONS {
+        if granted.iter().any(|p| p == permission) {
+            continue;
+        }
+        let bindings = permission_bindings(permission);
+        for member in bindings.members {
+            runtime.remove(*member)?;
+        }
+        for name in bindings.globals {
+            globals.remove(*name)?;
+        }
+        for name in names.iter().filter(|name| bindings.native_prefixes.iter().any(|prefix| name.starts_with(prefix))) {
+            globals.remove(name.as_str())?;
+        }
+        denied_ops.extend_from_slice(bindings.async_ops);
+    }
+    Ok(denied_ops)
+}
 
 /// 注册所有 JS 绑定
 pub fn register_all(ctx: &Ctx<'_>) -> Result<()> {
@@ -16,6 +132,11 @@ pub fn register_all(ctx: &Ctx<'_>) -> Result<()> {
     storage::register(ctx)?;
     html::register(ctx)?;
     image::register(ctx)?;
+    encoding::register(ctx)?;
+    fetch::register(ctx)?;
+    cache::register(ctx)?;
+    text::register(ctx)?;
+    url::register(ctx)?;
     
     // 创建 runtime 对象，作为模块的标准接口
     // 模块脚本使用 runtime.http.get, runtime.storage.get 等
@@ -26,7 +147,13 @@ pub fn register_all(ctx: &Ctx<'_>) -> Result<()> {
             crypto: __crypto__,
             console: console,
             html: __html__,
-            image: __image__
+            image: __image__,
+            encoding: __encoding__,
+            fetch: fetch,
+            cache: __cache__,
+            text: __text__,
+            url: url,
+            imageUrl: url.image
         };
     "#;
     
@@ -36,3 +163,95 @@ pub fn register_all(ctx: &Ctx<'_>) -> Result<()> {
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_on_reuses_shared_workers() {
+        // 在运行时内部调用时由共享运行时的工作线程执行，而不是每次新建线程
+        for i in 0..50 {
+            let (value, thread) = block_on(async move {
+                (i, std::thread::current().name().map(str::to_string))
+            }).unwrap();
+            assert_eq!(value, i);
+            assert_eq!(thread.as_deref(), Some("js-binding-rt"));
+        }
+    }
+
+    /// 对比每次新建线程 + 运行时与共享运行时的同步调用开销
+    ///
+    /// 运行：cargo test --release bench_block_on_overhead -- --ignored --nocapture
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore]
+    async fn bench_block_on_overhead() {
+        use std::time::Instant;
+        const CALLS: usize = 200;
+
+        // 旧方式：每次调用新建线程 + 运行时
+        let start = Instant::now();
+        for i in 0..CALLS {
+            let value = std::thread::spawn(move || {
+                let rt = tokio::runtime::Runtime::new().unwrap();
+                rt.block_on(async move { i })
+            }).join().unwrap();
+            assert_eq!(value, i);
+        }
+        let fresh = start.elapsed();
+
+        // 新方式：交给共享运行时的工作线程
+        let start = Instant::now();
+        for i in 0..CALLS {
+            assert_eq!(block_on(async move { i }).unwrap(), i);
+        }
+        let shared = start.elapsed();
+
+        println!("{} calls: fresh runtime {:?}, shared runtime {:?}", CALLS, fresh, shared);
+        assert!(shared < fresh);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_on_reports_panic() {
+        let result = block_on(async { panic!("boom") });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_runtime_crypto_gunzip() {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all("你好 gzip".as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let runtime = crate::js_engine::JsRuntime::new().unwrap();
+        runtime.set_global("raw", crate::crypto::base64_encode(&compressed)).unwrap();
+        let decoded = runtime.eval_string("runtime.crypto.gunzip(raw)").unwrap();
+        assert_eq!(crate::crypto::base64_decode_string(&decoded).unwrap(), "你好 gzip");
+        assert_eq!(runtime.eval_string("runtime.crypto.gunzip('bm90IGd6aXA=')").unwrap(), "");
+    }
+
+    #[test]
+    fn test_runtime_image_binding_registered() {
+        let mut png = Vec::new();
+        ::image::RgbaImage::new(3, 2)
+            .write_to(&mut std::io::Cursor::new(&mut png), ::image::ImageFormat::Png)
+            .unwrap();
+
+        let runtime = crate::js_engine::JsRuntime::new().unwrap();
+        runtime.set_global("img", crate::crypto::base64_encode(&png)).unwrap();
+        assert_eq!(runtime.eval_string("typeof runtime.image.getInfo").unwrap(), "function");
+
+        let info: serde_json::Value = serde_json::from_str(&runtime.eval_string("runtime.image.getInfo(img)").unwrap()).unwrap();
+        assert_eq!(info["width"], 3);
+        assert_eq!(info["height"], 2);
+        assert_eq!(info["format"], "png");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_on_inside_runtime() {
+        // 在 Tokio 运行时内部调用不应 panic
+        let value = block_on(async { 42 }).unwrap();
+        assert_eq!(value, 42);
+    }
+}
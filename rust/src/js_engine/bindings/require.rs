@@ -0,0 +1,51 @@
+use rquickjs::{Ctx, Exception, Function, Value};
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::js_engine::ModuleLoader;
+use crate::js_engine::runtime::eval_with_filename;
+
+/// 注册 require 到 JS 全局，从 `modules/lib/` 目录加载共享库
+/// 
+/// 库脚本遵循 CommonJS 约定：通过 `module.exports`（或 `exports.xxx`）导出，
+/// 可以再 `require` 其他库。同一运行时内每个库只执行一次，之后返回缓存的 exports。
+/// 库名只能是 lib 目录下的文件名（可省略 `.js`），不允许子目录和 `..`
+pub fn register<'js>(ctx: &Ctx<'js>, lib_dir: PathBuf) -> Result<()> {
+    let globals = ctx.globals();
+
+    // 编译库脚本为 function(module, exports, require)，不经过 eval，沙箱模式下也可用
+    globals.set("__native_require_compile__", Function::new(ctx.clone(), move |ctx: Ctx<'js>, name: String| -> rquickjs::Result<Value<'js>> {
+        tracing::debug!("[JS Require] loading lib: {}", name);
+        let source = ModuleLoader::read_lib(&lib_dir, &name)
+            .map_err(|e| Exception::throw_message(&ctx, &e.to_string()))?;
+        // 包装代码与源码第一行同行，保持堆栈中的行号不变
+        eval_with_filename(&ctx, &format!("(function(module, exports, require) {{{}\n}})", source), &format!("lib/{}.js", name))
+    })?)?;
+
+    let require_helper = r#"
+        var require = (function() {
+            var cache = {};
+            return function require(name) {
+                name = String(name).replace(/^\.\//, '').replace(/\.js$/, '');
+                if (Object.prototype.hasOwnProperty.call(cache, name)) {
+                    return cache[name].exports;
+                }
+                var factory = __native_require_compile__(name);
+                var module = { exports: {} };
+                // 先放入缓存，循环引用时返回已导出的部分
+                cache[name] = module;
+                try {
+                    factory.call(module.exports, module, module.exports, require);
+                } catch (e) {
+                    delete cache[name];
+                    throw e;
+                }
+                return module.exports;
+            };
+        })();
+    "#;
+
+    let _: Value = ctx.eval(require_helper)?;
+
+    Ok(())
+}
@@ -2,8 +2,25 @@ use rquickjs::{Ctx, Function, Value};
 use anyhow::Result;
 use crate::database;
 use crate::database::entities::property;
-use sea_orm::{EntityTrait, Set, ActiveModelTrait};
+use sea_orm::{EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait};
 use chrono::Utc;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// App 为模块保存的内部属性（代理设置、更新用的 ETag 等），不对 keys/clear 暴露
+fn is_internal_key(key: &str) -> bool {
+    key.starts_with("__") || key == "source_etag" || key == "source_last_modified"
+}
+
+/// App 保留的存储空间（如 `__app__` 应用设置），脚本不能读写
+fn is_reserved_module(module_id: &str) -> bool {
+    module_id.starts_with("__")
+}
+
+/// 脚本能否修改该属性：内部属性只能由 App 写入，否则模块可以篡改自己的 Cookie、运行时限制等
+fn is_writable(module_id: &str, key: &str) -> bool {
+    !is_reserved_module(module_id) && !is_internal_key(key)
+}
 
 /// 注册 storage 对象到 JS 全局
 /// 
@@ -14,25 +31,25 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
     // 同步版本的 storage get
     globals.set("__native_storage_get_sync__", Function::new(ctx.clone(), |module_id: String, key: String| -> String {
         tracing::debug!("[JS Storage] get: module={}, key={}", module_id, key);
+        if is_reserved_module(&module_id) {
+            return String::new();
+        }
         
-        let result = std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async {
-                let db = match database::get_database() {
-                    Some(d) => d,
-                    None => return None::<String>,
-                };
-                let conn = db.read().await;
-                let id = property::Model::create_id(&module_id, &key);
-                
-                property::Entity::find_by_id(&id)
-                    .one(&*conn)
-                    .await
-                    .ok()
-                    .flatten()
-                    .map(|m| m.value)
-            })
-        }).join();
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return None::<String>,
+            };
+            let conn = db.read().await;
+            let id = property::Model::create_id(&module_id, &key);
+            
+            property::Entity::find_by_id(&id)
+                .one(&*conn)
+                .await
+                .ok()
+                .flatten()
+                .map(|m| m.value)
+        });
         
         match result {
             Ok(Some(value)) => value,
@@ -43,50 +60,51 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
     // 同步版本的 storage set
     globals.set("__native_storage_set_sync__", Function::new(ctx.clone(), |module_id: String, key: String, value: String| -> bool {
         tracing::debug!("[JS Storage] set: module={}, key={}, value_len={}", module_id, key, value.len());
+        if !is_writable(&module_id, &key) {
+            tracing::warn!("[JS Storage] Rejected write to internal key: module={}, key={}", module_id, key);
+            return false;
+        }
         
-        let result = std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async {
-                let db = match database::get_database() {
-                    Some(d) => d,
-                    None => return false,
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return false,
+            };
+            let conn = db.read().await;
+            let id = property::Model::create_id(&module_id, &key);
+            let now = Utc::now().naive_utc();
+            
+            // 先尝试找到现有记录
+            let existing = property::Entity::find_by_id(&id)
+                .one(&*conn)
+                .await
+                .ok()
+                .flatten();
+            
+            if existing.is_some() {
+                // 更新
+                let active = property::ActiveModel {
+                    id: Set(id),
+                    module_id: Set(module_id),
+                    key: Set(key),
+                    value: Set(value),
+                    created_at: sea_orm::ActiveValue::NotSet,
+                    updated_at: Set(now),
                 };
-                let conn = db.read().await;
-                let id = property::Model::create_id(&module_id, &key);
-                let now = Utc::now().naive_utc();
-                
-                // 先尝试找到现有记录
-                let existing = property::Entity::find_by_id(&id)
-                    .one(&*conn)
-                    .await
-                    .ok()
-                    .flatten();
-                
-                if existing.is_some() {
-                    // 更新
-                    let active = property::ActiveModel {
-                        id: Set(id),
-                        module_id: Set(module_id),
-                        key: Set(key),
-                        value: Set(value),
-                        created_at: sea_orm::ActiveValue::NotSet,
-                        updated_at: Set(now),
-                    };
-                    active.update(&*conn).await.is_ok()
-                } else {
-                    // 插入
-                    let active = property::ActiveModel {
-                        id: Set(id),
-                        module_id: Set(module_id),
-                        key: Set(key),
-                        value: Set(value),
-                        created_at: Set(now),
-                        updated_at: Set(now),
-                    };
-                    active.insert(&*conn).await.is_ok()
-                }
-            })
-        }).join();
+                active.update(&*conn).await.is_ok()
+            } else {
+                // 插入
+                let active = property::ActiveModel {
+                    id: Set(id),
+                    module_id: Set(module_id),
+                    key: Set(key),
+                    value: Set(value),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                };
+                active.insert(&*conn).await.is_ok()
+            }
+        });
         
         result.unwrap_or(false)
     })?)?;
@@ -94,31 +112,165 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
     // 同步版本的 storage remove
     globals.set("__native_storage_remove_sync__", Function::new(ctx.clone(), |module_id: String, key: String| -> bool {
         tracing::debug!("[JS Storage] remove: module={}, key={}", module_id, key);
+        if !is_writable(&module_id, &key) {
+            tracing::warn!("[JS Storage] Rejected removal of internal key: module={}, key={}", module_id, key);
+            return false;
+        }
         
-        let result = std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async {
-                let db = match database::get_database() {
-                    Some(d) => d,
-                    None => return false,
-                };
-                let conn = db.read().await;
-                let id = property::Model::create_id(&module_id, &key);
-                
-                property::Entity::delete_by_id(&id)
-                    .exec(&*conn)
-                    .await
-                    .is_ok()
-            })
-        }).join();
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return false,
+            };
+            let conn = db.read().await;
+            let id = property::Model::create_id(&module_id, &key);
+            
+            property::Entity::delete_by_id(&id)
+                .exec(&*conn)
+                .await
+                .is_ok()
+        });
         
         result.unwrap_or(false)
     })?)?;
     
+    // 同步版本的 storage has
+    globals.set("__native_storage_has_sync__", Function::new(ctx.clone(), |module_id: String, key: String| -> bool {
+        if is_reserved_module(&module_id) {
+            return false;
+        }
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return false,
+            };
+            let conn = db.read().await;
+            let id = property::Model::create_id(&module_id, &key);
+            
+            property::Entity::find_by_id(&id)
+                .one(&*conn)
+                .await
+                .ok()
+                .flatten()
+                .is_some()
+        });
+        
+        result.unwrap_or(false)
+    })?)?;
+    
+    // 同步版本的 storage keys，返回 JSON 数组（不含 module_id 前缀）
+    globals.set("__native_storage_keys_sync__", Function::new(ctx.clone(), |module_id: String| -> String {
+        tracing::debug!("[JS Storage] keys: module={}", module_id);
+        if is_reserved_module(&module_id) {
+            return "[]".to_string();
+        }
+        
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return Vec::new(),
+            };
+            let conn = db.read().await;
+            
+            property::Entity::find()
+                .filter(property::Column::ModuleId.eq(&module_id))
+                .all(&*conn)
+                .await
+                .map(|items| items.into_iter()
+                    .map(|p| p.key)
+                    .filter(|key| !is_internal_key(key))
+                    .collect())
+                .unwrap_or_default()
+        });
+        
+        serde_json::to_string(&result.unwrap_or_default()).unwrap_or_else(|_| "[]".to_string())
+    })?)?;
+    
+    // 同步版本的 storage clear，返回删除的条数（保留内部属性）
+    globals.set("__native_storage_clear_sync__", Function::new(ctx.clone(), |module_id: String| -> u64 {
+        tracing::debug!("[JS Storage] clear: module={}", module_id);
+        if is_reserved_module(&module_id) {
+            return 0;
+        }
+        
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return 0,
+            };
+            let conn = db.read().await;
+            
+            let ids: Vec<String> = match property::Entity::find()
+                .filter(property::Column::ModuleId.eq(&module_id))
+                .all(&*conn)
+                .await {
+                Ok(items) => items.into_iter()
+                    .filter(|p| !is_internal_key(&p.key))
+                    .map(|p| p.id)
+                    .collect(),
+                Err(_) => return 0,
+            };
+            if ids.is_empty() {
+                return 0;
+            }
+            
+            property::Entity::delete_many()
+                .filter(property::Column::Id.is_in(ids))
+                .exec(&*conn)
+                .await
+                .map(|r| r.rows_affected)
+                .unwrap_or(0)
+        });
+        
+        result.unwrap_or(0)
+    })?)?;
+    
+    // 同步版本的 storage removePrefix，返回删除的条数（保留内部属性）
+    globals.set("__native_storage_remove_prefix_sync__", Function::new(ctx.clone(), |module_id: String, prefix: String| -> u64 {
+        tracing::debug!("[JS Storage] removePrefix: module={}, prefix={}", module_id, prefix);
+        if is_reserved_module(&module_id) {
+            return 0;
+        }
+        
+        let result = super::block_on(async move {
+            let db = match database::get_database() {
+                Some(d) => d,
+                None => return 0,
+            };
+            let conn = db.read().await;
+            
+            let ids: Vec<String> = match property::Entity::find()
+                .filter(property::Column::ModuleId.eq(&module_id))
+                .filter(property::Column::Key.starts_with(&prefix))
+                .all(&*conn)
+                .await {
+                // LIKE 不区分大小写且 `_`、`%` 是通配符，与内存存储一样按原样的前缀过滤
+                Ok(items) => items.into_iter()
+                    .filter(|p| p.key.starts_with(&prefix) && !is_internal_key(&p.key))
+                    .map(|p| p.id)
+                    .collect(),
+                Err(_) => return 0,
+            };
+            if ids.is_empty() {
+                return 0;
+            }
+            
+            property::Entity::delete_many()
+                .filter(property::Column::Id.is_in(ids))
+                .exec(&*conn)
+                .await
+                .map(|r| r.rows_affected)
+                .unwrap_or(0)
+        });
+        
+        result.unwrap_or(0)
+    })?)?;
+    
     // 注册辅助函数 - 同步版本
     // 注意：__MODULE_ID__ 在加载模块时设置
     let storage_helper = r#"
-        const storage = {
+        // 挂在 globalThis 上，未授予 storage 权限时可以删除
+        globalThis.storage = {
             get: function(key) {
                 var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
                 var result = __native_storage_get_sync__(moduleId, key);
@@ -131,6 +283,38 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
             remove: function(key) {
                 var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
                 return __native_storage_remove_sync__(moduleId, key);
+            },
+            has: function(key) {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                return __native_storage_has_sync__(moduleId, key);
+            },
+            keys: function() {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                return JSON.parse(__native_storage_keys_sync__(moduleId));
+            },
+            clear: function() {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                return __native_storage_clear_sync__(moduleId);
+            },
+            // 删除以 prefix 开头的所有键，返回删除的条数
+            removePrefix: function(prefix) {
+                var moduleId = typeof __MODULE_ID__ !== 'undefined' ? __MODULE_ID__ : 'default';
+                return __native_storage_remove_prefix_sync__(moduleId, String(prefix));
+            },
+            // 读取 JSON 值，不存在或解析失败时返回 defaultValue
+            getJSON: function(key, defaultValue) {
+                if (defaultValue === undefined) defaultValue = null;
+                var raw = storage.get(key);
+                if (raw === null) return defaultValue;
+                try {
+                    return JSON.parse(raw);
+                } catch (e) {
+                    console.warn('storage.getJSON: invalid JSON for key ' + key);
+                    return defaultValue;
+                }
+            },
+            setJSON: function(key, value) {
+                return storage.set(key, JSON.stringify(value));
             }
         };
     "#;
@@ -141,3 +325,218 @@ pub fn register(ctx: &Ctx<'_>) -> Result<()> {
     
     Ok(())
 }
+
+/// 用内存中的键值表替换 storage 的原生实现，数据随运行时释放
+/// 
+/// 用于试运行脚本，storage 的读写不会落到数据库；需要在 [`register`] 之后调用
+pub fn register_memory(ctx: &Ctx<'_>) -> Result<()> {
+    let globals = ctx.globals();
+    let store: Arc<Mutex<BTreeMap<String, String>>> = Arc::default();
+    
+    let data = store.clone();
+    globals.set("__native_storage_get_sync__", Function::new(ctx.clone(), move |_module_id: String, key: String| -> String {
+        data.lock().unwrap().get(&key).cloned().unwrap_or_default()
+    })?)?;
+    let data = store.clone();
+    globals.set("__native_storage_set_sync__", Function::new(ctx.clone(), move |_module_id: String, key: String, value: String| -> bool {
+        if is_internal_key(&key) {
+            return false;
+        }
+        data.lock().unwrap().insert(key, value);
+        true
+    })?)?;
+    let data = store.clone();
+    globals.set("__native_storage_remove_sync__", Function::new(ctx.clone(), move |_module_id: String, key: String| -> bool {
+        if is_internal_key(&key) {
+            return false;
+        }
+        data.lock().unwrap().remove(&key);
+        true
+    })?)?;
+    let data = store.clone();
+    globals.set("__native_storage_has_sync__", Function::new(ctx.clone(), move |_module_id: String, key: String| -> bool {
+        data.lock().unwrap().contains_key(&key)
+    })?)?;
+    let data = store.clone();
+    globals.set("__native_storage_keys_sync__", Function::new(ctx.clone(), move |_module_id: String| -> String {
+        let keys: Vec<String> = data.lock().unwrap().keys().filter(|key| !is_internal_key(key)).cloned().collect();
+        serde_json::to_string(&keys).unwrap_or_else(|_| "[]".to_string())
+    })?)?;
+    let data = store.clone();
+    globals.set("__native_storage_clear_sync__", Function::new(ctx.clone(), move |_module_id: String| -> u64 {
+        let mut data = data.lock().unwrap();
+        let before = data.len();
+        data.retain(|key, _| is_internal_key(key));
+        (before - data.len()) as u64
+    })?)?;
+    let data = store;
+    globals.set("__native_storage_remove_prefix_sync__", Function::new(ctx.clone(), move |_module_id: String, prefix: String| -> u64 {
+        let mut data = data.lock().unwrap();
+        let before = data.len();
+        data.retain(|key, _| is_internal_key(key) || !key.starts_with(&prefix));
+        (before - data.len()) as u64
+    })?)?;
+    
+    tracing::debug!("[JS Storage] In-memory storage registered");
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::property_api;
+    use crate::database;
+    use crate::js_engine::JsRuntime;
+
+    #[tokio::test]
+    async fn test_storage_keys_has_clear() {
+        database::init_test_database().await;
+        property_api::save_property("storage_keys_test".to_string(), "__proxy__".to_string(), "direct".to_string()).await.unwrap();
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("storage_keys_test", r#"
+            function main() {
+                storage.set("a", "1");
+                storage.set("b", "2");
+                var before = { keys: storage.keys().sort(), hasA: storage.has("a"), hasC: storage.has("c") };
+                var cleared = storage.clear();
+                return { before: before, cleared: cleared, after: storage.keys(), hasA: storage.has("a") };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["before"]["keys"], serde_json::json!(["a", "b"]));
+        assert_eq!(result["before"]["hasA"], true);
+        assert_eq!(result["before"]["hasC"], false);
+        assert_eq!(result["cleared"], 2);
+        assert_eq!(result["after"], serde_json::json!([]));
+        assert_eq!(result["hasA"], false);
+        // 内部属性不受 clear 影响
+        assert_eq!(runtime.eval_string("storage.get('__proxy__')").unwrap(), "direct");
+    }
+
+    #[tokio::test]
+    async fn test_storage_rejects_internal_writes() {
+        database::init_test_database().await;
+        property_api::save_property("storage_internal_test".to_string(), "__cookies__".to_string(), "sid=1".to_string()).await.unwrap();
+        property_api::save_app_setting("storage_internal_test".to_string(), "app".to_string()).await.unwrap();
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("storage_internal_test", r#"
+            function main() {
+                return {
+                    set: storage.set("__cookies__", "sid=evil"),
+                    remove: storage.remove("source_etag"),
+                    cookies: storage.get("__cookies__"),
+                    appSet: __native_storage_set_sync__("__app__", "storage_internal_test", "evil"),
+                    appGet: __native_storage_get_sync__("__app__", "storage_internal_test"),
+                    appClear: __native_storage_clear_sync__("__app__")
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["set"], false);
+        assert_eq!(result["remove"], false);
+        assert_eq!(result["cookies"], "sid=1");
+        assert_eq!(result["appSet"], false);
+        assert_eq!(result["appGet"], "");
+        assert_eq!(result["appClear"], 0);
+        assert_eq!(property_api::load_app_setting("storage_internal_test".to_string()).await.unwrap().as_deref(), Some("app"));
+    }
+
+    #[tokio::test]
+    async fn test_storage_json_helpers() {
+        database::init_test_database().await;
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("storage_json_test", r#"
+            function main() {
+                storage.setJSON("settings", { page: 2, tags: ["a"] });
+                storage.set("broken", "{not json");
+                return {
+                    settings: storage.getJSON("settings", {}),
+                    broken: storage.getJSON("broken", { fallback: true }),
+                    missing: storage.getJSON("missing"),
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["settings"], serde_json::json!({ "page": 2, "tags": ["a"] }));
+        assert_eq!(result["broken"], serde_json::json!({ "fallback": true }));
+        assert_eq!(result["missing"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_storage_remove_prefix() {
+        database::init_test_database().await;
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("storage_prefix_test", r#"
+            function main() {
+                storage.set("cache:list", "1");
+                storage.set("cache:detail", "2");
+                storage.set("config:quality", "high");
+                var removed = storage.removePrefix("cache:");
+                return { removed: removed, keys: storage.keys() };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["removed"], 2);
+        assert_eq!(result["keys"], serde_json::json!(["config:quality"]));
+    }
+
+    #[tokio::test]
+    async fn test_storage_remove_prefix_is_literal() {
+        database::init_test_database().await;
+        let script = r#"
+            function main() {
+                ["Cache_a", "Cache_b", "CacheXa", "cache_a", "CACHE-a", "100%_done", "100x_done"].forEach(function (key) {
+                    storage.set(key, "1");
+                });
+                var removed = storage.removePrefix("Cache_") + storage.removePrefix("100%");
+                return { removed: removed, keys: storage.keys().sort() };
+            }
+        "#;
+        let expected = serde_json::json!({
+            "removed": 3,
+            "keys": ["100x_done", "CACHE-a", "CacheXa", "cache_a"],
+        });
+
+        // 数据库存储与内存存储的结果一致
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("storage_prefix_literal_test", script).unwrap();
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result, expected);
+
+        let runtime = JsRuntime::new().unwrap();
+        runtime.use_memory_storage().unwrap();
+        runtime.load_module("storage_prefix_literal_test", script).unwrap();
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_memory_storage_does_not_touch_database() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.use_memory_storage().unwrap();
+        runtime.load_module("storage_memory_test", r#"
+            function main() {
+                storage.set("a", "1");
+                storage.setJSON("b", { x: 1 });
+                storage.set("cache:x", "2");
+                var removed = storage.removePrefix("cache:");
+                return { keys: storage.keys().sort(), a: storage.get("a"), b: storage.getJSON("b"), removed: removed };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["keys"], serde_json::json!(["a", "b"]));
+        assert_eq!(result["a"], "1");
+        assert_eq!(result["b"], serde_json::json!({ "x": 1 }));
+        assert_eq!(result["removed"], 1);
+
+        // 新的运行时看不到之前写入的数据
+        let runtime = JsRuntime::new().unwrap();
+        runtime.use_memory_storage().unwrap();
+        assert_eq!(runtime.eval_string("String(storage.get('a'))").unwrap(), "null");
+    }
+}
@@ -0,0 +1,173 @@
+use rquickjs::{Ctx, Exception, Function, Object, Value};
+use anyhow::Result;
+use regex::{Captures, Regex, RegexBuilder};
+use serde_json::{json, Value as JsonValue};
+
+/// 注册 text 文本处理对象到 JS 全局
+/// 
+/// 提供基于 Rust regex 的提取函数，适合从大段 HTML / 内联脚本中提取多个捕获组
+pub fn register(ctx: &Ctx<'_>) -> Result<()> {
+    let globals = ctx.globals();
+    
+    let text_obj = Object::new(ctx.clone())?;
+    
+    // text.__regexFind(input, pattern, flags) -> JSON
+    text_obj.set("__regexFind", Function::new(ctx.clone(), |input: String, pattern: String, flags: String| -> String {
+        to_json(regex_find(&input, &pattern, &flags))
+    })?)?;
+    
+    // text.__regexFindAll(input, pattern, flags) -> JSON
+    text_obj.set("__regexFindAll", Function::new(ctx.clone(), |input: String, pattern: String, flags: String| -> String {
+        to_json(regex_find_all(&input, &pattern, &flags))
+    })?)?;
+    
+    // text.parseJson5(input) -> string
+    // 宽松解析 JS 对象字面量（无引号的键、单引号、尾随逗号、注释），返回标准 JSON 字符串
+    text_obj.set("parseJson5", Function::new(ctx.clone(), |ctx: Ctx<'_>, input: String| -> rquickjs::Result<String> {
+        parse_json5(&input).map_err(|e| Exception::throw_syntax(&ctx, &e.to_string()))
+    })?)?;
+    
+    globals.set("__text__", text_obj)?;
+    
+    // 包装为直接返回 JS 值的函数
+    // 无效的正则返回 {error: string}，不抛出异常
+    let text_helper = r#"
+        // text.regexFind(input, pattern, flags) -> Array<string|null> | null | {error}
+        // 返回第一个匹配的捕获组，下标 0 为完整匹配
+        __text__.regexFind = function(input, pattern, flags) {
+            return JSON.parse(__text__.__regexFind(String(input), String(pattern), flags || ""));
+        };
+        
+        // text.regexFindAll(input, pattern, flags) -> Array<Array<string|null>> | {error}
+        // 返回所有匹配的捕获组
+        __text__.regexFindAll = function(input, pattern, flags) {
+            return JSON.parse(__text__.__regexFindAll(String(input), String(pattern), flags || ""));
+        };
+    "#;
+    
+    let _: Value = ctx.eval(text_helper)?;
+    
+    tracing::debug!("[JS Text] Text bindings registered");
+    
+    Ok(())
+}
+
+/// 将结果序列化为 JSON，错误转为 {error}
+fn to_json(result: Result<JsonValue>) -> String {
+    let value = result.unwrap_or_else(|e| {
+        tracing::warn!("[JS Text] Regex error: {}", e);
+        json!({ "error": e.to_string() })
+    });
+    serde_json::to_string(&value).unwrap_or_else(|_| "null".to_string())
+}
+
+/// 按 flags 构建正则，支持 i（忽略大小写）、m（多行）、s（. 匹配换行）、x（忽略空白）、U（非贪婪）
+fn build_regex(pattern: &str, flags: &str) -> Result<Regex> {
+    let mut builder = RegexBuilder::new(pattern);
+    for flag in flags.chars() {
+        match flag {
+            'i' => { builder.case_insensitive(true); }
+            'm' => { builder.multi_line(true); }
+            's' => { builder.dot_matches_new_line(true); }
+            'x' => { builder.ignore_whitespace(true); }
+            'U' => { builder.swap_greed(true); }
+            // JS 的 g/u 标志在这里没有意义，忽略
+            'g' | 'u' => {}
+            other => return Err(anyhow::anyhow!("Unsupported regex flag: {}", other)),
+        }
+    }
+    Ok(builder.build()?)
+}
+
+/// 捕获组转为数组，未参与匹配的组为 null
+fn captures_to_json(captures: &Captures) -> JsonValue {
+    JsonValue::Array(
+        captures
+            .iter()
+            .map(|group| group.map(|m| json!(m.as_str())).unwrap_or(JsonValue::Null))
+            .collect(),
+    )
+}
+
+/// 返回第一个匹配的捕获组，无匹配时为 null
+fn regex_find(input: &str, pattern: &str, flags: &str) -> Result<JsonValue> {
+    let regex = build_regex(pattern, flags)?;
+    Ok(regex
+        .captures(input)
+        .map(|captures| captures_to_json(&captures))
+        .unwrap_or(JsonValue::Null))
+}
+
+/// 返回所有匹配的捕获组
+fn regex_find_all(input: &str, pattern: &str, flags: &str) -> Result<JsonValue> {
+    let regex = build_regex(pattern, flags)?;
+    Ok(JsonValue::Array(
+        regex
+            .captures_iter(input)
+            .map(|captures| captures_to_json(&captures))
+            .collect(),
+    ))
+}
+
+/// 按 JSON5 规则解析输入并转为标准 JSON
+fn parse_json5(input: &str) -> Result<String> {
+    let value: JsonValue = json5::from_str(input.trim())
+        .map_err(|e| anyhow::anyhow!("Invalid JSON5: {}", e))?;
+    Ok(serde_json::to_string(&value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_engine::JsRuntime;
+
+    #[test]
+    fn test_regex_find() {
+        let script = r#"<script>var data = {"images":["https://a.com/1.jpg","https://a.com/2.jpg"]};</script>"#;
+        assert_eq!(
+            regex_find(script, r#""images":\[(.*?)\]"#, "").unwrap(),
+            json!([r#""images":["https://a.com/1.jpg","https://a.com/2.jpg"]"#, r#""https://a.com/1.jpg","https://a.com/2.jpg""#])
+        );
+        assert_eq!(regex_find("abc", "(x)", "").unwrap(), JsonValue::Null);
+        assert_eq!(regex_find("ABC", "a(x)?b", "i").unwrap(), json!(["AB", null]));
+        assert!(regex_find("abc", "(", "").is_err());
+        assert!(regex_find("abc", "a", "z").is_err());
+    }
+
+    #[test]
+    fn test_parse_json5() {
+        let input = r#"{ id: 12, title: 'It\'s', tags: ["a", "b",], /* 注释 */ next: null, }"#;
+        let value: JsonValue = serde_json::from_str(&parse_json5(input).unwrap()).unwrap();
+        assert_eq!(value, json!({"id": 12, "title": "It's", "tags": ["a", "b"], "next": null}));
+
+        let err = parse_json5("{ id: }").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid JSON5"));
+    }
+
+    #[test]
+    fn test_parse_json5_binding() {
+        let runtime = JsRuntime::new().unwrap();
+        let result = runtime.eval_string(r#"
+            (function() {
+                var data = JSON.parse(runtime.text.parseJson5("{pages: [1, 2,], name: 'x'}"));
+                var error;
+                try { runtime.text.parseJson5("{ broken"); } catch (e) { error = e.name; }
+                return JSON.stringify({ pages: data.pages.length, name: data.name, error: error });
+            })()
+        "#).unwrap();
+        assert_eq!(serde_json::from_str::<JsonValue>(&result).unwrap(), json!({"pages": 2, "name": "x", "error": "SyntaxError"}));
+    }
+
+    #[test]
+    fn test_regex_find_all_binding() {
+        let runtime = JsRuntime::new().unwrap();
+        let result: JsonValue = serde_json::from_str(&runtime.eval_string(r#"
+            JSON.stringify({
+                all: runtime.text.regexFindAll("p1=a.jpg p2=b.png", "p(\\d)=(\\w+\\.\\w+)", "g"),
+                invalid: __text__.regexFind("abc", "(", ""),
+            })
+        "#).unwrap()).unwrap();
+        assert_eq!(result["all"], json!([["p1=a.jpg", "1", "a.jpg"], ["p2=b.png", "2", "b.png"]]));
+        assert!(result["invalid"]["error"].is_string());
+    }
+}
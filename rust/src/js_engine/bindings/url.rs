@@ -0,0 +1,195 @@
+use rquickjs::{Ctx, Function, Object, Value};
+use anyhow::Result;
+use serde_json::json;
+use ::url::Url;
+
+use crate::modules::RemoteImageInfo;
+
+/// 注册 url 对象到 JS 全局
+/// 
+/// 用于将相对图片地址解析为绝对地址，正确处理 `../`、查询参数和 `//host/...` 形式的地址
+pub fn register(ctx: &Ctx<'_>) -> Result<()> {
+    let globals = ctx.globals();
+    
+    let url_obj = Object::new(ctx.clone())?;
+    
+    // url.resolve(base, relative) -> string | null
+    // 按浏览器规则将相对地址解析为绝对地址
+    url_obj.set("resolve", Function::new(ctx.clone(), |base: String, relative: String| -> Option<String> {
+        match resolve(&base, &relative) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::warn!("[JS URL] Resolve error: {}", e);
+                None
+            }
+        }
+    })?)?;
+    
+    // url.join(base, path) -> string | null
+    // 将 base 视为目录拼接路径，不会替换 base 的最后一段
+    url_obj.set("join", Function::new(ctx.clone(), |base: String, path: String| -> Option<String> {
+        match join(&base, &path) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::warn!("[JS URL] Join error: {}", e);
+                None
+            }
+        }
+    })?)?;
+    
+    // url.__parse(u) -> JSON
+    url_obj.set("__parse", Function::new(ctx.clone(), |input: String| -> String {
+        match parse(&input) {
+            Ok(value) => value.to_string(),
+            Err(e) => {
+                tracing::warn!("[JS URL] Parse error: {}", e);
+                "null".to_string()
+            }
+        }
+    })?)?;
+    
+    // url.__image(infoJson) -> string
+    url_obj.set("__image", Function::new(ctx.clone(), |info_json: String| -> String {
+        match image_url(&info_json) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!("[JS URL] Image url error: {}", e);
+                String::new()
+            }
+        }
+    })?)?;
+    
+    globals.set("url", url_obj)?;
+    
+    let url_helper = r#"
+        // url.parse(u) -> {scheme, host, port, path, query, fragment, params} | null
+        url.parse = function(input) {
+            return JSON.parse(url.__parse(String(input)));
+        };
+        
+        // url.image(remoteImageInfo | string) -> string
+        // 与 RemoteImageInfo::to_url 相同的规则拼接 file_server 与 path
+        url.image = function(info) {
+            return url.__image(JSON.stringify(info === undefined ? null : info));
+        };
+    "#;
+    
+    let _: Value = ctx.eval(url_helper)?;
+    
+    tracing::debug!("[JS URL] URL bindings registered");
+    
+    Ok(())
+}
+
+/// 将相对地址解析为绝对地址
+fn resolve(base: &str, relative: &str) -> Result<String> {
+    Ok(Url::parse(base)?.join(relative)?.to_string())
+}
+
+/// 将 base 视为目录拼接路径
+fn join(base: &str, path: &str) -> Result<String> {
+    let mut base = Url::parse(base)?;
+    if !base.path().ends_with('/') {
+        let dir = format!("{}/", base.path());
+        base.set_path(&dir);
+    }
+    Ok(base.join(path.trim_start_matches('/'))?.to_string())
+}
+
+/// 按 RemoteImageInfo::to_url 的规则得到图片地址
+/// 
+/// 接受字符串地址或 {file_server, path} 对象，缺省字段视为空
+fn image_url(info_json: &str) -> Result<String> {
+    let info = match serde_json::from_str(info_json)? {
+        serde_json::Value::String(url) => RemoteImageInfo::from_url(url),
+        serde_json::Value::Object(mut map) => {
+            for key in ["original_name", "path", "file_server"] {
+                map.entry(key).or_insert_with(|| json!(""));
+            }
+            serde_json::from_value(serde_json::Value::Object(map))?
+        }
+        other => return Err(anyhow::anyhow!("Invalid image info: {}", other)),
+    };
+    Ok(info.to_url())
+}
+
+/// 解析地址的各个部分
+fn parse(input: &str) -> Result<serde_json::Value> {
+    let url = Url::parse(input)?;
+    let params: serde_json::Map<String, serde_json::Value> = url
+        .query_pairs()
+        .map(|(k, v)| (k.to_string(), json!(v)))
+        .collect();
+    Ok(json!({
+        "scheme": url.scheme(),
+        "host": url.host_str(),
+        "port": url.port_or_known_default(),
+        "path": url.path(),
+        "query": url.query(),
+        "fragment": url.fragment(),
+        "params": params
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::js_engine::JsRuntime;
+
+    #[test]
+    fn test_resolve_and_join() {
+        let base = "https://example.com/comic/12/ep/3?page=2";
+        assert_eq!(resolve(base, "../../cover.jpg").unwrap(), "https://example.com/comic/cover.jpg");
+        assert_eq!(resolve(base, "/static/a.jpg?x=1").unwrap(), "https://example.com/static/a.jpg?x=1");
+        assert_eq!(resolve(base, "//cdn.example.com/a.jpg").unwrap(), "https://cdn.example.com/a.jpg");
+        assert_eq!(resolve(base, "https://other.com/b.png").unwrap(), "https://other.com/b.png");
+        assert!(resolve("not a url", "a.jpg").is_err());
+
+        assert_eq!(join("https://example.com/api", "/v1/comics").unwrap(), "https://example.com/api/v1/comics");
+        assert_eq!(join("https://example.com/api/", "v1/../v2").unwrap(), "https://example.com/api/v2");
+    }
+
+    #[test]
+    fn test_url_binding() {
+        let runtime = JsRuntime::new().unwrap();
+        let result: serde_json::Value = serde_json::from_str(&runtime.eval_string(r#"
+            JSON.stringify({
+                parsed: url.parse("https://example.com:8443/a/b?id=1&q=%E4%B8%AD#top"),
+                invalid: url.parse("::"),
+                resolved: runtime.url.resolve("http://example.com/a/b", "c"),
+                failed: url.resolve("::", "c"),
+            })
+        "#).unwrap()).unwrap();
+        assert_eq!(result["parsed"]["scheme"], "https");
+        assert_eq!(result["parsed"]["host"], "example.com");
+        assert_eq!(result["parsed"]["port"], 8443);
+        assert_eq!(result["parsed"]["path"], "/a/b");
+        assert_eq!(result["parsed"]["query"], "id=1&q=%E4%B8%AD");
+        assert_eq!(result["parsed"]["fragment"], "top");
+        assert_eq!(result["parsed"]["params"]["q"], "中");
+        assert_eq!(result["invalid"], serde_json::Value::Null);
+        assert_eq!(result["resolved"], "http://example.com/a/c");
+        assert_eq!(result["failed"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_image_url_binding() {
+        let runtime = JsRuntime::new().unwrap();
+        let result: serde_json::Value = serde_json::from_str(&runtime.eval_string(r#"
+            JSON.stringify([
+                runtime.imageUrl({ file_server: "https://img.example.com", path: "covers/1.jpg" }),
+                runtime.imageUrl({ file_server: "https://img.example.com", path: "https://cdn.example.com/1.jpg" }),
+                runtime.imageUrl("https://cdn.example.com/2.jpg"),
+                runtime.imageUrl({ path: "https://cdn.example.com/3.jpg", headers: { Referer: "https://example.com" } }),
+                runtime.imageUrl(42),
+            ])
+        "#).unwrap()).unwrap();
+        assert_eq!(result, serde_json::json!([
+            "https://img.example.com/static/covers/1.jpg",
+            "https://cdn.example.com/1.jpg",
+            "https://cdn.example.com/2.jpg",
+            "https://cdn.example.com/3.jpg",
+            "",
+        ]));
+    }
+}
@@ -0,0 +1,205 @@
+use rquickjs::{Ctx, Function, Promise, Value};
+use rquickjs::promise::PromiseState;
+use anyhow::Result;
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::bindings;
+
+/// 等待异步结果时检查中断（取消）的间隔
+const INTERRUPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 异步操作完成后的结果，由后台任务发回 JS 线程
+struct Completion {
+    id: u32,
+    result: std::result::Result<String, String>,
+}
+
+/// JS 运行时的事件循环
+///
+/// 异步绑定（如 `__async__.spawn('http', ...)`）在共享 Tokio 运行时上执行，
+/// 完成后通过通道把结果发回；`await_promise` 在 JS 线程上交替执行 QuickJS 任务队列
+/// 和投递这些结果，直到 Promise 完成或超时。
+pub struct EventLoop {
+    sender: Sender<Completion>,
+    receiver: Mutex<Receiver<Completion>>,
+    pending: Arc<AtomicUsize>,
+    next_id: Arc<AtomicU32>,
+    /// 未授权的操作类型，启动时直接失败
+    denied: Arc<Mutex<HashSet<String>>>,
+}
+
+impl EventLoop {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+            pending: Arc::new(AtomicUsize::new(0)),
+            next_id: Arc::new(AtomicU32::new(1)),
+            denied: Arc::default(),
+        }
+    }
+
+    /// 禁止指定类型的异步操作（如未授予 http 权限时的 `http`、`fetch`）
+    pub fn deny(&self, kinds: &[&str]) {
+        if let Ok(mut denied) = self.denied.lock() {
+            denied.extend(kinds.iter().map(|kind| kind.to_string()));
+        }
+    }
+
+    /// 尚未完成的异步操作数量
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    /// 注册 `__async__` 对象到 JS 全局
+    pub fn register(&self, ctx: &Ctx<'_>) -> Result<()> {
+        let globals = ctx.globals();
+
+        let sender = self.sender.clone();
+        let pending = self.pending.clone();
+        let next_id = self.next_id.clone();
+        let denied = self.denied.clone();
+        globals.set("__native_async_spawn__", Function::new(ctx.clone(), move |kind: String, payload: String| -> u32 {
+            let id = next_id.fetch_add(1, Ordering::SeqCst);
+            tracing::debug!("[JS Async] spawn #{}: {}", id, kind);
+
+            let allowed = denied.lock().map(|denied| !denied.contains(&kind)).unwrap_or(false);
+            pending.fetch_add(1, Ordering::SeqCst);
+            let sender = sender.clone();
+            bindings::spawn(async move {
+                let result = match Self::operation(&kind, payload) {
+                    _ if !allowed => Err(format!("Async operation not permitted: {}", kind)),
+                    Some(op) => op.await.map_err(|e| format!("{:?}", e)),
+                    None => Err(format!("Unknown async operation: {}", kind)),
+                };
+                // 接收端已销毁说明运行时已释放，直接丢弃结果
+                let _ = sender.send(Completion { id, result });
+            });
+            id
+        })?)?;
+
+        // 使用 var 声明，使 __async__ 成为全局对象的属性，Rust 侧才能通过 globals 取到
+        let async_helper = r#"
+            var __async__ = {
+                _pending: {},
+                // 启动异步操作，返回在事件循环中完成的 Promise
+                spawn: function(kind, payload) {
+                    return new Promise(function(resolve, reject) {
+                        var id = __native_async_spawn__(kind, payload);
+                        __async__._pending[id] = { resolve: resolve, reject: reject };
+                    });
+                },
+                _complete: function(id, ok, value) {
+                    var entry = __async__._pending[id];
+                    delete __async__._pending[id];
+                    if (!entry) return;
+                    if (ok) {
+                        entry.resolve(value);
+                    } else {
+                        entry.reject(new Error(value));
+                    }
+                },
+                sleep: function(ms) {
+                    return __async__.spawn('timer', String(ms || 0));
+                }
+            };
+        "#;
+
+        let _: Value = ctx.eval(async_helper)?;
+
+        tracing::debug!("[JS Async] Event loop bindings registered");
+
+        Ok(())
+    }
+
+    /// 根据类型创建异步操作
+    fn operation(kind: &str, payload: String) -> Option<Pin<Box<dyn Future<Output = Result<String>> + Send>>> {
+        match kind {
+            "http" => Some(Box::pin(async move {
+                bindings::http::execute_http_request(&payload).await
+            })),
+            "fetch" => Some(Box::pin(async move {
+                bindings::fetch::execute_fetch(&payload).await
+            })),
+            "timer" => Some(Box::pin(async move {
+                let ms: u64 = payload.parse().unwrap_or(0);
+                tokio::time::sleep(Duration::from_millis(ms)).await;
+                Ok(String::new())
+            })),
+            _ => None,
+        }
+    }
+
+    /// 把完成的结果交给 JS 侧的 resolve/reject
+    fn deliver(&self, ctx: &Ctx<'_>, completion: Completion) -> Result<()> {
+        self.pending.fetch_sub(1, Ordering::SeqCst);
+        let async_obj: rquickjs::Object = ctx.globals().get("__async__")?;
+        let complete: Function = async_obj.get("_complete")?;
+        let (ok, value) = match completion.result {
+            Ok(value) => (true, value),
+            Err(e) => (false, e),
+        };
+        complete.call::<_, ()>((completion.id, ok, value))?;
+        Ok(())
+    }
+
+    /// 驱动事件循环直到 Promise 完成
+    ///
+    /// Promise 被 reject 时返回 `rquickjs::Error::Exception`，异常可通过 `ctx.catch()` 取得；
+    /// 等待期间定期检查 `interrupted`，返回 true 时停止等待
+    pub fn await_promise<'js>(
+        &self,
+        ctx: &Ctx<'js>,
+        promise: Promise<'js>,
+        timeout: Duration,
+        interrupted: impl Fn() -> bool,
+    ) -> Result<std::result::Result<Value<'js>, rquickjs::Error>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            // 执行所有就绪的 Promise 回调
+            while ctx.execute_pending_job() {}
+
+            if promise.state() != PromiseState::Pending {
+                return Ok(promise
+                    .result::<Value>()
+                    .unwrap_or(Err(rquickjs::Error::WouldBlock)));
+            }
+
+            if self.pending() == 0 {
+                return Err(anyhow::anyhow!("Promise never settled: no pending async operations"));
+            }
+
+            if interrupted() {
+                return Err(anyhow::anyhow!("Promise wait interrupted"));
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let received = self.receiver.lock()
+                .map_err(|e| anyhow::anyhow!("Event loop lock poisoned: {}", e))?
+                .recv_timeout(remaining.min(INTERRUPT_POLL_INTERVAL));
+            match received {
+                Ok(completion) => self.deliver(ctx, completion)?,
+                Err(RecvTimeoutError::Timeout) if Instant::now() < deadline => continue,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(anyhow::anyhow!("Promise timed out after {:?}", timeout));
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(anyhow::anyhow!("Event loop channel closed"));
+                }
+            }
+        }
+    }
+}
+
+impl Default for EventLoop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
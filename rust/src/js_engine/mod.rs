@@ -1,6 +1,7 @@
 pub mod runtime;
 pub mod bindings;
 pub mod module_loader;
+pub mod event_loop;
 
-pub use runtime::JsRuntime;
+pub use runtime::{JsRuntime, RuntimeLimits};
 pub use module_loader::ModuleLoader;
@@ -1,14 +1,107 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+use super::bindings::ALL_PERMISSIONS;
+use super::runtime::JsRuntime;
+use crate::modules::ModuleError;
+
+/// 模块必须导出的入口函数
+pub const REQUIRED_FUNCTIONS: [&str; 6] = [
+    "getCategories",
+    "getComics",
+    "getComicDetail",
+    "getEps",
+    "getPictures",
+    "search",
+];
+
 /// 模块元信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleMetadata {
     pub id: String,
     pub name: String,
     pub version: String,
+    #[serde(default)]
+    pub author: String,
     pub description: String,
+    /// 图标（URL 或 data URI）
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// 脚本的发布地址，用于检查更新
+    #[serde(default)]
+    pub source_url: Option<String>,
+    /// 是否以沙箱模式运行（禁用 eval 和 Function 构造器）
+    #[serde(default)]
+    pub sandbox: bool,
+    /// 模块需要的权限（如 `["http", "storage"]`），未声明时为全部权限
+    #[serde(default = "all_permissions")]
+    pub permissions: Vec<String>,
+}
+
+/// 全部权限，用于未声明 permissions 的模块
+pub fn all_permissions() -> Vec<String> {
+    ALL_PERMISSIONS.iter().map(|p| p.to_string()).collect()
+}
+
+/// 解析模块声明的权限，忽略未知权限；未声明时返回全部权限
+fn parse_permissions(value: Option<&serde_json::Value>) -> Vec<String> {
+    let Some(list) = value.and_then(|v| v.as_array()) else {
+        return all_permissions();
+    };
+    let mut permissions = Vec::new();
+    for name in list.iter().filter_map(|v| v.as_str()) {
+        if !ALL_PERMISSIONS.contains(&name) {
+            tracing::warn!("Ignoring unknown module permission: {}", name);
+        } else if !permissions.iter().any(|p| p == name) {
+            permissions.push(name.to_string());
+        }
+    }
+    permissions
+}
+
+/// 比较两个版本号（如 `1.2.10` 与 `1.2.9`）
+///
+/// 按 `.` 分段逐段比较，数字段按数值比较，缺失的段视为 0；
+/// 核心版本相同时带预发布后缀（`1.0.0-beta`）的版本较旧
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    fn split(version: &str) -> (&str, Option<&str>) {
+        let version = version.trim().trim_start_matches(['v', 'V']);
+        let version = version.split('+').next().unwrap_or(version);
+        match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        }
+    }
+
+    fn compare_segment(a: &str, b: &str) -> Ordering {
+        match (a.parse::<u64>(), b.parse::<u64>()) {
+            (Ok(a), Ok(b)) => a.cmp(&b),
+            _ => a.cmp(b),
+        }
+    }
+
+    let (core_a, pre_a) = split(a);
+    let (core_b, pre_b) = split(b);
+    let segments_a: Vec<&str> = core_a.split('.').collect();
+    let segments_b: Vec<&str> = core_b.split('.').collect();
+    for i in 0..segments_a.len().max(segments_b.len()) {
+        let seg_a = segments_a.get(i).copied().unwrap_or("0");
+        let seg_b = segments_b.get(i).copied().unwrap_or("0");
+        match compare_segment(seg_a, seg_b) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+
+    match (pre_a, pre_b) {
+        (None, None) => Ordering::Equal,
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(a), Some(b)) => compare_segment(a, b),
+    }
 }
 
 /// 模块加载器
@@ -35,31 +128,122 @@ impl ModuleLoader {
         Ok(script)
     }
 
+    /// 共享库目录（`modules/lib/`），供模块 `require` 使用
+    pub fn lib_dir(&self) -> PathBuf {
+        self.modules_dir.join("lib")
+    }
+
+    /// 读取共享库脚本，只允许解析到 lib 目录下的文件
+    pub fn read_lib(lib_dir: &Path, name: &str) -> Result<String> {
+        let valid = !name.is_empty()
+            && !name.starts_with('.')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if !valid {
+            return Err(anyhow::anyhow!("Invalid library name: {}", name));
+        }
+        
+        let path = lib_dir.join(format!("{}.js", name));
+        // 防止通过符号链接读取 lib 目录之外的文件
+        let resolved = path.canonicalize()
+            .map_err(|_| anyhow::anyhow!("Library not found: {}", name))?;
+        if !resolved.starts_with(lib_dir.canonicalize()?) {
+            return Err(anyhow::anyhow!("Library outside lib directory: {}", name));
+        }
+        
+        Ok(std::fs::read_to_string(resolved)?)
+    }
+
     /// 从脚本中提取模块元信息
+    /// 
+    /// 优先在临时运行时中执行脚本，读取 `getModuleInfo()` 的返回值或全局 `moduleInfo` 对象，
+    /// 支持计算值和模板字符串；执行失败或未定义时回退到正则匹配
     pub fn extract_metadata(&self, script: &str) -> Result<ModuleMetadata> {
-        // 查找模块导出的 metadata 对象
+        let evaluated = JsRuntime::new().and_then(|runtime| {
+            runtime.enable_require(self.lib_dir())?;
+            runtime.load_module("__metadata__", script)?;
+            Self::evaluate_metadata(&runtime)
+        });
+        match evaluated {
+            Ok(metadata) => Ok(metadata),
+            Err(e) => {
+                tracing::debug!("Evaluating module metadata failed, falling back to regex: {}", e);
+                self.extract_metadata_by_regex(script)
+            }
+        }
+    }
+
+    /// 从已执行脚本的运行时中读取元信息
+    fn evaluate_metadata(runtime: &JsRuntime) -> Result<ModuleMetadata> {
+        let json = runtime.eval_string(r#"
+            (function() {
+                var info = null;
+                if (typeof getModuleInfo === 'function') {
+                    info = getModuleInfo();
+                } else if (typeof moduleInfo === 'object' && moduleInfo !== null) {
+                    info = moduleInfo;
+                } else if (typeof module === 'object' && module !== null && typeof module.info === 'object') {
+                    info = module.info;
+                }
+                return JSON.stringify(info);
+            })()
+        "#)?;
+        
+        let info: serde_json::Value = serde_json::from_str(&json)?;
+        let field = |name: &str| -> Option<String> {
+            match info.get(name)? {
+                serde_json::Value::String(s) if !s.is_empty() => Some(s.clone()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            }
+        };
+        let required = |name: &str| -> Result<String> {
+            field(name).ok_or_else(|| anyhow::anyhow!("Field '{}' not found in module info", name))
+        };
+        
+        Ok(ModuleMetadata {
+            id: required("id")?,
+            name: required("name")?,
+            version: required("version")?,
+            author: field("author").unwrap_or_default(),
+            description: field("description").unwrap_or_default(),
+            icon: field("icon"),
+            source_url: field("sourceUrl"),
+            sandbox: info.get("sandbox").and_then(|v| v.as_bool()).unwrap_or(false),
+            permissions: parse_permissions(info.get("permissions")),
+        })
+    }
+
+    /// 使用正则从脚本文本中提取元信息
+    fn extract_metadata_by_regex(&self, script: &str) -> Result<ModuleMetadata> {
         // 期望格式:
         // const moduleInfo = {
         //   id: "module_id",
         //   name: "Module Name",
         //   version: "1.0.0",
-        //   description: "Description"
+        //   author: "Author",
+        //   description: "Description",
+        //   icon: "https://example.com/icon.png",      // 可选
+        //   sourceUrl: "https://example.com/module.js" // 可选
         // };
-        
-        // 使用正则或简单解析提取元信息
-        // 这里使用简化的方式，实际可以用 JS 运行时执行获取
-        
-        // 查找 moduleInfo 或 module.exports
         let id = self.extract_field(script, "id")?;
         let name = self.extract_field(script, "name")?;
         let version = self.extract_field(script, "version")?;
+        let author = self.extract_field(script, "author").unwrap_or_default();
         let description = self.extract_field(script, "description").unwrap_or_default();
+        let icon = self.extract_field(script, "icon").ok();
+        let source_url = self.extract_field(script, "sourceUrl").ok();
         
         Ok(ModuleMetadata {
             id,
             name,
             version,
+            author,
             description,
+            icon,
+            source_url,
+            // 无法执行的脚本也无法运行，沙箱标记只在执行脚本时读取
+            sandbox: false,
+            permissions: all_permissions(),
         })
     }
 
@@ -142,23 +326,31 @@ impl ModuleLoader {
         Err(anyhow::anyhow!("Field '{}' not found in module script", field))
     }
 
-    /// 验证模块脚本
-    pub fn validate_script(&self, script: &str) -> Result<()> {
-        // 检查必要的导出函数
-        let required_functions = ["getCategories", "getComicList", "getComicDetail", "getChapterImages"];
+    /// 验证模块脚本并返回其元信息
+    /// 
+    /// 在临时运行时中执行脚本，检查 [`REQUIRED_FUNCTIONS`] 是否都已定义，
+    /// 缺少时返回 `ModuleError::MissingFunctions`
+    pub fn validate_script(&self, script: &str) -> Result<ModuleMetadata> {
+        let runtime = JsRuntime::new()?;
+        runtime.load_module("__validate__", script)?;
         
-        for func in required_functions {
-            if !script.contains(&format!("function {}", func)) && 
-               !script.contains(&format!("{} =", func)) &&
-               !script.contains(&format!("{}:", func)) {
-                tracing::warn!("Module may be missing function: {}", func);
-            }
-        }
+        let metadata = match Self::evaluate_metadata(&runtime) {
+            Ok(metadata) => metadata,
+            Err(_) => self.extract_metadata_by_regex(script)?,
+        };
         
-        // 检查元信息
-        self.extract_metadata(script)?;
+        let missing: Vec<String> = REQUIRED_FUNCTIONS.iter()
+            .filter(|func| !runtime.has_function(func))
+            .map(|func| func.to_string())
+            .collect();
+        if !missing.is_empty() {
+            return Err(ModuleError::MissingFunctions {
+                module_id: metadata.id,
+                functions: missing,
+            }.into());
+        }
         
-        Ok(())
+        Ok(metadata)
     }
 
     /// 列出所有可用模块
@@ -191,15 +383,124 @@ mod tests {
                 id: "test_module",
                 name: "Test Module",
                 version: "1.0.0",
-                description: "A test module"
+                author: "Someone",
+                description: "A test module",
+                icon: "data:image/png;base64,iVBORw0KGgo=",
+                sourceUrl: "https://example.com/test_module.js",
+                sandbox: true
             };
         "#;
         
         let loader = ModuleLoader::new(Path::new("/tmp"));
         let metadata = loader.extract_metadata(script).unwrap();
+        assert!(metadata.sandbox);
         
         assert_eq!(metadata.id, "test_module");
         assert_eq!(metadata.name, "Test Module");
         assert_eq!(metadata.version, "1.0.0");
+        assert_eq!(metadata.author, "Someone");
+        assert_eq!(metadata.icon.as_deref(), Some("data:image/png;base64,iVBORw0KGgo="));
+        assert_eq!(metadata.source_url.as_deref(), Some("https://example.com/test_module.js"));
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        use std::cmp::Ordering;
+        assert_eq!(compare_versions("1.2.10", "1.2.9"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0", "1.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("v2.0.0", "1.9.9"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0.0-beta", "1.0.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0", "1.0.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_extract_metadata_evaluates_script() {
+        let script = r#"
+            const major = 2;
+            const minor = 3;
+            const moduleInfo = {
+                name: "Computed Module",
+                id: ["computed", "module"].join("_"),
+                version: `${major}.${minor}.0`,
+            };
+        "#;
+        
+        let loader = ModuleLoader::new(Path::new("/tmp"));
+        let metadata = loader.extract_metadata(script).unwrap();
+        
+        assert_eq!(metadata.id, "computed_module");
+        assert_eq!(metadata.version, "2.3.0");
+        assert_eq!(metadata.author, "");
+        assert_eq!(metadata.permissions, all_permissions());
+    }
+
+    #[test]
+    fn test_extract_metadata_permissions() {
+        let script = r#"
+            const moduleInfo = {
+                id: "limited_module",
+                name: "Limited",
+                version: "1.0.0",
+                permissions: ["http", "unknown", "http"]
+            };
+        "#;
+        
+        let loader = ModuleLoader::new(Path::new("/tmp"));
+        let metadata = loader.extract_metadata(script).unwrap();
+        assert_eq!(metadata.permissions, vec!["http"]);
+    }
+
+    #[test]
+    fn test_extract_metadata_from_function() {
+        let script = r#"
+            function getModuleInfo() {
+                return { id: "fn_module", name: "Function Module", version: "1.0." + 5, author: "Someone" };
+            }
+        "#;
+        
+        let loader = ModuleLoader::new(Path::new("/tmp"));
+        let metadata = loader.extract_metadata(script).unwrap();
+        
+        assert_eq!(metadata.id, "fn_module");
+        assert_eq!(metadata.version, "1.0.5");
+        assert_eq!(metadata.author, "Someone");
+    }
+
+    #[test]
+    fn test_extract_metadata_regex_fallback() {
+        // 脚本执行出错时仍可通过正则读取
+        let script = r#"
+            const moduleInfo = { id: "broken_module", name: "Broken", version: "1.0.0" };
+            undefinedFunction();
+        "#;
+        
+        let loader = ModuleLoader::new(Path::new("/tmp"));
+        let metadata = loader.extract_metadata(script).unwrap();
+        
+        assert_eq!(metadata.id, "broken_module");
+    }
+
+    #[test]
+    fn test_validate_script_reports_missing_functions() {
+        let script = r#"
+            const moduleInfo = { id: "partial_module", name: "Partial", version: "1.0.0" };
+            async function getCategories() { return []; }
+            async function getComics() { return {}; }
+            async function getComicDetail() { return {}; }
+            async function getEps() { return {}; }
+        "#;
+        
+        let loader = ModuleLoader::new(Path::new("/tmp"));
+        let error = loader.validate_script(script).unwrap_err();
+        match ModuleError::from(error) {
+            ModuleError::MissingFunctions { module_id, functions } => {
+                assert_eq!(module_id, "partial_module");
+                assert_eq!(functions, vec!["getPictures".to_string(), "search".to_string()]);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        
+        let complete = format!("{}\nasync function getPictures() {{}}\nasync function search() {{}}", script);
+        assert_eq!(loader.validate_script(&complete).unwrap().id, "partial_module");
     }
 }
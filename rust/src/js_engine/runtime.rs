@@ -1,9 +1,19 @@
-use rquickjs::{Context, Runtime, Function, Object, Value, FromJs, IntoJs, Promise};
+use rquickjs::{Context, Ctx, Runtime, Function, Object, Value, FromJs, IntoJs, Promise};
+use rquickjs::qjs;
+use std::ffi::CString;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use anyhow::Result;
 
 use super::bindings;
+use super::event_loop::EventLoop;
+use crate::cancel::CancelToken;
+use crate::modules::{ConsoleLog, ModuleError};
+
+/// 模块函数调用的默认超时时间
+pub const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(15);
 
 /// 截断 JSON 字符串中的 imageData 字段，保留其他字段完整
 fn truncate_image_data_in_json(json_str: &str) -> String {
@@ -55,32 +65,226 @@ fn truncate_image_data_in_json(json_str: &str) -> String {
     json_str.to_string()
 }
 
+/// 取出当前挂起的 JS 异常并转换为 ModuleError
+fn take_exception(ctx: &Ctx<'_>) -> ModuleError {
+    let exc: Value = ctx.catch();
+    if let Some(err_obj) = exc.as_object() {
+        ModuleError::JsException {
+            message: err_obj.get("message").unwrap_or_default(),
+            stack: err_obj.get("stack").unwrap_or_default(),
+        }
+    } else if let Some(err_str) = exc.as_string() {
+        ModuleError::JsException {
+            message: err_str.to_string().unwrap_or_default(),
+            stack: String::new(),
+        }
+    } else {
+        ModuleError::JsException {
+            message: format!("{:?}", exc),
+            stack: String::new(),
+        }
+    }
+}
+
+/// 以指定的文件名执行全局脚本（严格模式），异常堆栈中会显示该文件名
+/// 
+/// rquickjs 的 `eval_with_options` 固定使用 `eval_script` 作为文件名，这里直接调用 `JS_Eval`
+pub(crate) fn eval_with_filename<'js, V: FromJs<'js>>(ctx: &Ctx<'js>, source: &str, filename: &str) -> rquickjs::Result<V> {
+    let source = CString::new(source)?;
+    let filename = CString::new(filename)?;
+    let value = unsafe {
+        let raw = qjs::JS_Eval(
+            ctx.as_raw().as_ptr(),
+            source.as_ptr(),
+            source.as_bytes().len() as _,
+            filename.as_ptr(),
+            (qjs::JS_EVAL_TYPE_GLOBAL | qjs::JS_EVAL_FLAG_STRICT) as _,
+        );
+        if qjs::JS_IsException(raw) {
+            return Err(rquickjs::Error::Exception);
+        }
+        Value::from_raw(ctx.clone(), raw)
+    };
+    V::from_js(ctx, value)
+}
+
+/// 运行时的内存和栈限制
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RuntimeLimits {
+    /// 内存上限（MB）
+    #[serde(default = "default_memory_limit_mb")]
+    pub memory_limit_mb: u32,
+    /// 最大栈大小（KB）
+    #[serde(default = "default_stack_size_kb")]
+    pub stack_size_kb: u32,
+}
+
+/// 默认内存上限（MB）
+pub const DEFAULT_MEMORY_LIMIT_MB: u32 = 64;
+/// 默认最大栈大小（KB）
+pub const DEFAULT_STACK_SIZE_KB: u32 = 1024;
+/// 允许配置的最小内存上限（MB），再小无法完成全局绑定的注册
+pub const MIN_MEMORY_LIMIT_MB: u32 = 8;
+/// 允许配置的最小栈大小（KB）
+pub const MIN_STACK_SIZE_KB: u32 = 256;
+/// 允许配置的最大内存上限（MB）
+pub const MAX_MEMORY_LIMIT_MB: u32 = 1024;
+/// 允许配置的最大栈大小（KB），脚本运行在默认 2MB 栈的 Tokio 工作线程上，需要留出余量
+pub const MAX_STACK_SIZE_KB: u32 = 1536;
+
+fn default_memory_limit_mb() -> u32 {
+    DEFAULT_MEMORY_LIMIT_MB
+}
+
+fn default_stack_size_kb() -> u32 {
+    DEFAULT_STACK_SIZE_KB
+}
+
+impl Default for RuntimeLimits {
+    fn default() -> Self {
+        Self {
+            memory_limit_mb: DEFAULT_MEMORY_LIMIT_MB,
+            stack_size_kb: DEFAULT_STACK_SIZE_KB,
+        }
+    }
+}
+
+impl RuntimeLimits {
+    /// 检查限制在允许的范围内
+    pub fn validate(&self) -> Result<()> {
+        if !(MIN_MEMORY_LIMIT_MB..=MAX_MEMORY_LIMIT_MB).contains(&self.memory_limit_mb) {
+            return Err(anyhow::anyhow!("Memory limit must be between {} and {} MB", MIN_MEMORY_LIMIT_MB, MAX_MEMORY_LIMIT_MB));
+        }
+        if !(MIN_STACK_SIZE_KB..=MAX_STACK_SIZE_KB).contains(&self.stack_size_kb) {
+            return Err(anyhow::anyhow!("Stack size must be between {} and {} KB", MIN_STACK_SIZE_KB, MAX_STACK_SIZE_KB));
+        }
+        Ok(())
+    }
+}
+
+/// 沙箱模式下替换 eval 和各类 Function 构造器
+const SANDBOX_HELPER: &str = r#"
+    (function() {
+        function blocked() {
+            throw new EvalError('Code generation from strings is disabled in sandbox mode');
+        }
+        blocked.prototype = Function.prototype;
+        var constructors = [
+            Function,
+            Object.getPrototypeOf(async function() {}).constructor,
+            Object.getPrototypeOf(function*() {}).constructor,
+            Object.getPrototypeOf(async function*() {}).constructor
+        ];
+        constructors.forEach(function(ctor) {
+            Object.defineProperty(ctor.prototype, 'constructor', {
+                value: blocked, writable: false, configurable: false
+            });
+        });
+        Object.defineProperty(globalThis, 'Function', { value: blocked, writable: false, configurable: false });
+        Object.defineProperty(globalThis, 'eval', { value: blocked, writable: false, configurable: false });
+    })();
+"#;
+
 /// JavaScript 运行时封装
 pub struct JsRuntime {
     runtime: Runtime,
     context: Context,
+    event_loop: EventLoop,
+    /// 当前调用的截止时间，由中断回调检查
+    deadline: Arc<std::sync::Mutex<Option<Instant>>>,
+    /// 当前调用的取消令牌，取消后由中断回调中止脚本
+    cancel: Arc<std::sync::Mutex<Option<CancelToken>>>,
 }
 
 impl JsRuntime {
-    /// 创建新的 JS 运行时
+    /// 创建新的 JS 运行时（默认 64MB 内存、1MB 栈）
     pub fn new() -> Result<Self> {
+        Self::with_limits(RuntimeLimits::default())
+    }
+
+    /// 使用指定的内存和栈限制创建 JS 运行时
+    /// 
+    /// 超出内存限制时脚本抛出异常，调用返回错误，不会导致进程崩溃
+    pub fn with_limits(limits: RuntimeLimits) -> Result<Self> {
         let runtime = Runtime::new()?;
         
-        // 设置内存限制 (64MB)
-        runtime.set_memory_limit(64 * 1024 * 1024);
+        runtime.set_memory_limit(limits.memory_limit_mb as usize * 1024 * 1024);
+        runtime.set_max_stack_size(limits.stack_size_kb as usize * 1024);
         
-        // 设置最大栈大小
-        runtime.set_max_stack_size(1024 * 1024);
+        // 超过截止时间时中断脚本执行（防止死循环卡住模块）
+        let deadline: Arc<std::sync::Mutex<Option<Instant>>> = Arc::new(std::sync::Mutex::new(None));
+        let handler_deadline = deadline.clone();
+        let cancel: Arc<std::sync::Mutex<Option<CancelToken>>> = Arc::new(std::sync::Mutex::new(None));
+        let handler_cancel = cancel.clone();
+        runtime.set_interrupt_handler(Some(Box::new(move || {
+            let timed_out = handler_deadline
+                .lock()
+                .ok()
+                .and_then(|d| *d)
+                .is_some_and(|d| Instant::now() >= d);
+            timed_out || Self::token_cancelled(&handler_cancel)
+        })));
         
         let context = Context::full(&runtime)?;
+        let event_loop = EventLoop::new();
         
         // 注册全局绑定
         context.with(|ctx| -> Result<()> {
+            event_loop.register(&ctx)?;
             bindings::register_all(&ctx)?;
             Ok(())
         })?;
         
-        Ok(Self { runtime, context })
+        Ok(Self { runtime, context, event_loop, deadline, cancel })
+    }
+
+    /// 设置后续调用使用的取消令牌，None 表示不可取消
+    pub fn set_cancel_token(&self, token: Option<CancelToken>) {
+        if let Ok(mut current) = self.cancel.lock() {
+            *current = token;
+        }
+    }
+
+    fn token_cancelled(cancel: &std::sync::Mutex<Option<CancelToken>>) -> bool {
+        cancel.lock()
+            .ok()
+            .and_then(|t| t.as_ref().map(|t| t.is_cancelled()))
+            .unwrap_or(false)
+    }
+
+    /// 启用沙箱：禁用 `eval` 和 Function 构造器（含 async / generator 变体），
+    /// 并移除 `granted` 以外权限对应的全部绑定（同 [`Self::apply_permissions`]）
+    /// 
+    /// 需要在加载模块脚本之前调用。`instanceof Function` 仍然可用；
+    /// 宿主通过 `ctx.eval` 执行的代码（如绑定的辅助脚本）不受影响
+    pub fn apply_sandbox(&self, granted: &[String]) -> Result<()> {
+        self.context.with(|ctx| -> Result<()> {
+            let _: Value = ctx.eval(SANDBOX_HELPER)?;
+            Ok(())
+        })?;
+        self.apply_permissions(granted)
+    }
+
+    /// 只保留已授予权限对应的绑定（见 [`bindings::ALL_PERMISSIONS`]），
+    /// 未授权的全局对象、原生函数和异步操作都会被移除
+    /// 
+    /// 需要在加载模块脚本之前调用
+    pub fn apply_permissions(&self, granted: &[String]) -> Result<()> {
+        let denied = self.context.with(|ctx| bindings::revoke_permissions(&ctx, granted))?;
+        self.event_loop.deny(&denied);
+        Ok(())
+    }
+
+    /// 启用 `require(name)`，从指定的共享库目录加载脚本（见 [`bindings::require`]）
+    /// 
+    /// 需要在加载模块脚本之前调用
+    pub fn enable_require(&self, lib_dir: PathBuf) -> Result<()> {
+        self.context.with(|ctx| bindings::require::register(&ctx, lib_dir))
+    }
+
+    /// storage 改为使用内存中的键值表（见 [`bindings::storage::register_memory`]），不读写数据库
+    pub fn use_memory_storage(&self) -> Result<()> {
+        self.context.with(|ctx| bindings::storage::register_memory(&ctx))
     }
 
     /// 执行 JavaScript 代码
@@ -125,8 +329,8 @@ impl JsRuntime {
             let globals = ctx.globals();
             globals.set("__MODULE_ID__", module_id)?;
             
-            // 执行脚本，捕获详细错误信息
-            match ctx.eval::<Value, _>(script) {
+            // 以 `{module_id}.js` 作为文件名执行脚本，异常堆栈中会显示模块名，并捕获详细错误信息
+            match eval_with_filename::<Value>(&ctx, script, &format!("{}.js", module_id)) {
                 Ok(_) => Ok(()),
                 Err(rquickjs::Error::Exception) => {
                     // 获取异常详情
@@ -151,6 +355,17 @@ impl JsRuntime {
         })
     }
 
+    /// 重置调用级全局状态（模块 ID、上一次调用遗留的未完成异步回调）
+    pub fn reset_call_state(&self, module_id: &str) -> Result<()> {
+        self.context.with(|ctx| {
+            let globals = ctx.globals();
+            globals.set("__MODULE_ID__", module_id)?;
+            let async_obj: Object = globals.get("__async__")?;
+            async_obj.set("_pending", Object::new(ctx.clone())?)?;
+            Ok(())
+        })
+    }
+
     /// 调用模块中的函数
     pub fn call_function<T>(&self, func_name: &str, args: impl IntoIterator<Item = String>) -> Result<T>
     where
@@ -184,118 +399,166 @@ impl JsRuntime {
         })
     }
 
-    /// 调用模块中的函数，返回 JSON 字符串
+    /// 调用模块中的函数，返回 JSON 字符串（使用默认超时）
     /// 支持同步函数和 async 函数（返回 Promise）
     pub fn call_function_json(&self, func_name: &str, args_json: &str) -> Result<String> {
-        tracing::debug!("call_function_json START: func={}", func_name);
+        self.call_function_json_with_timeout(func_name, args_json, DEFAULT_CALL_TIMEOUT)
+    }
+
+    /// 调用模块中的函数，超过 timeout 时中断脚本执行并返回超时错误
+    /// 
+    /// 超时同时覆盖同步执行（通过 QuickJS 中断回调）和等待 Promise 的时间
+    pub fn call_function_json_with_timeout(&self, func_name: &str, args_json: &str, timeout: Duration) -> Result<String> {
+        tracing::debug!("call_function_json START: func={}, timeout={:?}", func_name, timeout);
         
-        self.context.with(|ctx| {
-            tracing::debug!("Inside context.with");
-            let globals = ctx.globals();
-            tracing::debug!("Got globals");
+        let deadline = Instant::now() + timeout;
+        self.set_deadline(Some(deadline));
+        let result = self.context.with(|ctx| {
+            let result = self.call_json_in_context(&ctx, func_name, args_json, deadline);
+            if result.is_err() && Self::token_cancelled(&self.cancel) {
+                let _ = ctx.catch();
+                tracing::info!("[JS Runtime] Function {} cancelled", func_name);
+                return Err(ModuleError::Cancelled.into());
+            }
+            if result.is_err() && Instant::now() >= deadline {
+                // 清除中断产生的异常，保证运行时后续可用
+                let _ = ctx.catch();
+                tracing::error!("[JS Runtime] Function {} timed out after {:?}", func_name, timeout);
+                return Err(ModuleError::Timeout {
+                    func_name: func_name.to_string(),
+                    timeout_ms: timeout.as_millis() as u64,
+                }.into());
+            }
+            result
+        });
+        self.set_deadline(None);
+        result
+    }
+
+    /// 调用模块中的函数，同时捕获调用期间的 console 输出
+    /// 
+    /// 无论调用成功与否都会返回已捕获的日志
+    pub fn call_function_json_with_logs(&self, func_name: &str, args_json: &str, timeout: Duration) -> (Result<String>, Vec<ConsoleLog>) {
+        bindings::console::begin_capture();
+        let result = self.call_function_json_with_timeout(func_name, args_json, timeout);
+        (result, bindings::console::end_capture())
+    }
+
+    /// 设置中断截止时间，None 表示不限制
+    fn set_deadline(&self, deadline: Option<Instant>) {
+        if let Ok(mut current) = self.deadline.lock() {
+            *current = deadline;
+        }
+    }
+
+    fn call_json_in_context<'js>(&self, ctx: &Ctx<'js>, func_name: &str, args_json: &str, deadline: Instant) -> Result<String> {
+        let globals = ctx.globals();
+        tracing::debug!("Got globals");
+        
+        let func: Function = match globals.get(func_name) {
+            Ok(f) => f,
+            Err(e) => {
+                tracing::error!("Failed to get function {}: {:?}", func_name, e);
+                return Err(anyhow::anyhow!("Function not found: {}", func_name));
+            }
+        };
+        
+        tracing::debug!("Got function: {}", func_name);
+        
+        // 解析 JSON 参数
+        let json: Object = globals.get("JSON")?;
+        let parse: Function = json.get("parse")?;
+        let args: Value = parse.call((args_json,))?;
+        
+        // 如果参数包含 imageData，只缩减 imageData 字段以避免日志过大
+        let log_args = if args_json.contains("\"imageData\"") {
+            truncate_image_data_in_json(args_json)
+        } else {
+            args_json.to_string()
+        };
+        tracing::info!("[JS Runtime] Calling function {} with args: {}", func_name, log_args);
+        tracing::debug!("Parsed args, calling function...");
+        
+        // 调用函数
+        let result: Value = match func.call((args,)) {
+            Ok(value) => value,
+            Err(rquickjs::Error::Exception) => {
+                let error = take_exception(ctx);
+                tracing::error!("[JS Runtime] Function {} threw: {}", func_name, error);
+                return Err(error.into());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        tracing::debug!("Function called, result type: {:?}", result.type_of());
+        
+        // 检查是否是 Promise
+        let final_value: Value = if result.is_promise() {
+            tracing::debug!("Result is a Promise, waiting for resolution...");
             
-            let func: Function = match globals.get(func_name) {
-                Ok(f) => f,
+            // 使用 Promise::from_value 转换
+            let promise = Promise::from_value(result)?;
+            
+            // 驱动事件循环：执行任务队列并投递异步绑定的结果，直到 Promise 完成
+            let settled = match self.event_loop.await_promise(
+                ctx,
+                promise,
+                deadline.saturating_duration_since(Instant::now()),
+                || Self::token_cancelled(&self.cancel),
+            ) {
+                Ok(settled) => settled,
                 Err(e) => {
-                    tracing::error!("Failed to get function {}: {:?}", func_name, e);
-                    return Err(anyhow::anyhow!("Function not found: {}", func_name));
+                    tracing::error!("Promise did not settle: {:?}", e);
+                    return Err(anyhow::anyhow!("JS Promise Error: {}", e));
                 }
             };
-            
-            tracing::debug!("Got function: {}", func_name);
-            
-            // 解析 JSON 参数
-            let json: Object = globals.get("JSON")?;
-            let parse: Function = json.get("parse")?;
-            let args: Value = parse.call((args_json,))?;
-            
-            // 如果参数包含 imageData，只缩减 imageData 字段以避免日志过大
-            let log_args = if args_json.contains("\"imageData\"") {
-                truncate_image_data_in_json(args_json)
-            } else {
-                args_json.to_string()
-            };
-            tracing::info!("[JS Runtime] Calling function {} with args: {}", func_name, log_args);
-            tracing::debug!("Parsed args, calling function...");
-            
-            // 调用函数
-            let result: Value = func.call((args,))?;
-            tracing::debug!("Function called, result type: {:?}", result.type_of());
-            
-            // 检查是否是 Promise
-            let final_value: Value = if result.is_promise() {
-                tracing::debug!("Result is a Promise, waiting for resolution...");
-                
-                // 使用 Promise::from_value 转换
-                let promise = Promise::from_value(result)?;
-                
-                // 使用 finish() 方法等待 Promise 完成
-                // finish() 会运行 QuickJS job queue 直到 Promise resolve 或 reject
-                match promise.finish::<Value>() {
-                    Ok(resolved_value) => {
-                        tracing::debug!("Promise resolved, value type: {:?}", resolved_value.type_of());
-                        // 尝试先序列化为 JSON 字符串，检查是否有类型错误
-                        let json: Object = globals.get("JSON")?;
-                        let stringify: Function = json.get("stringify")?;
-                        match stringify.call::<(Value,), String>((resolved_value.clone(),)) {
-                            Ok(json_str) => {
-                                tracing::debug!("Promise result serialized successfully, {} bytes", json_str.len());
-                                // 如果序列化成功，说明类型没问题，直接返回序列化后的字符串
-                                return Ok(json_str);
-                            }
-                            Err(e) => {
-                                tracing::error!("Failed to serialize promise result: {:?}", e);
-                                // 序列化失败，继续使用原始值（可能会在后续步骤失败）
-                                resolved_value
-                            }
+            match settled {
+                Ok(resolved_value) => {
+                    tracing::debug!("Promise resolved, value type: {:?}", resolved_value.type_of());
+                    // 尝试先序列化为 JSON 字符串，检查是否有类型错误
+                    let json: Object = globals.get("JSON")?;
+                    let stringify: Function = json.get("stringify")?;
+                    match stringify.call::<(Value,), String>((resolved_value.clone(),)) {
+                        Ok(json_str) => {
+                            tracing::debug!("Promise result serialized successfully, {} bytes", json_str.len());
+                            // 如果序列化成功，说明类型没问题，直接返回序列化后的字符串
+                            return Ok(json_str);
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to serialize promise result: {:?}", e);
+                            // 序列化失败，继续使用原始值（可能会在后续步骤失败）
+                            resolved_value
                         }
-                    }
-                    Err(rquickjs::Error::WouldBlock) => {
-                        // Promise 需要等待外部操作，无法立即完成
-                        tracing::warn!("Promise would block - async operation pending");
-                        // 返回 null 表示无法完成
-                        ctx.eval("null")?
-                    }
-                    Err(rquickjs::Error::Exception) => {
-                        tracing::error!("Promise rejected with exception");
-                        // 尝试获取异常信息
-                        let exc = ctx.catch();
-                        let error_msg = if let Some(err_obj) = exc.as_object() {
-                            let message: String = err_obj.get("message").unwrap_or_default();
-                            let stack: String = err_obj.get("stack").unwrap_or_default();
-                            format!("JS Error: {}\nStack: {}", message, stack)
-                        } else if let Some(err_str) = exc.as_string() {
-                            format!("JS Error: {}", err_str.to_string().unwrap_or_default())
-                        } else {
-                            format!("JS Error: {:?}", exc)
-                        };
-                        tracing::error!("Promise exception details: {}", error_msg);
-                        return Err(anyhow::anyhow!("JS Promise Error: {}", error_msg));
-                    }
-                    Err(e) => {
-                        tracing::error!("Promise rejected: {:?}", e);
-                        return Err(anyhow::anyhow!("JS Promise Error: {:?}", e));
                     }
                 }
-            } else {
-                tracing::debug!("Result is not a Promise, using directly");
-                result
-            };
-            
-            // 序列化结果
-            let stringify: Function = json.get("stringify")?;
-            let json_str: String = stringify.call((final_value,))?;
-            
-            // 如果结果包含 imageData，只缩减 imageData 字段以避免日志过大
-            if json_str.contains("\"imageData\"") {
-                let log_result = truncate_image_data_in_json(&json_str);
-                tracing::debug!("Serialized result: {} bytes, preview: {}", json_str.len(), log_result);
-            } else {
-                tracing::debug!("Serialized result: {} bytes", json_str.len());
+                Err(rquickjs::Error::Exception) => {
+                    tracing::error!("Promise rejected with exception");
+                    let error = take_exception(ctx);
+                    tracing::error!("Promise exception details: {}", error);
+                    return Err(error.into());
+                }
+                Err(e) => {
+                    tracing::error!("Promise rejected: {:?}", e);
+                    return Err(anyhow::anyhow!("JS Promise Error: {:?}", e));
+                }
             }
-            
-            Ok(json_str)
-        })
+        } else {
+            tracing::debug!("Result is not a Promise, using directly");
+            result
+        };
+        
+        // 序列化结果
+        let stringify: Function = json.get("stringify")?;
+        let json_str: String = stringify.call((final_value,))?;
+        
+        // 如果结果包含 imageData，只缩减 imageData 字段以避免日志过大
+        if json_str.contains("\"imageData\"") {
+            let log_result = truncate_image_data_in_json(&json_str);
+            tracing::debug!("Serialized result: {} bytes, preview: {}", json_str.len(), log_result);
+        } else {
+            tracing::debug!("Serialized result: {} bytes", json_str.len());
+        }
+        
+        Ok(json_str)
     }
 
     /// 检查函数是否存在
@@ -351,6 +614,222 @@ mod tests {
         assert_eq!(result, 3);
     }
 
+    #[test]
+    fn test_memory_limit_fails_cleanly() {
+        let runtime = JsRuntime::with_limits(RuntimeLimits { memory_limit_mb: 16, stack_size_kb: DEFAULT_STACK_SIZE_KB }).unwrap();
+        runtime.load_module("test", r#"
+            function grow() {
+                var chunks = [];
+                for (var i = 0; i < 64; i++) chunks.push(new Array(1024 * 1024).fill(i));
+                return { count: chunks.length };
+            }
+            function small() { return { ok: true }; }
+        "#).unwrap();
+
+        assert!(runtime.call_function_json("grow", "{}").is_err());
+        // 超限后运行时仍可继续使用
+        assert_eq!(runtime.call_function_json("small", "{}").unwrap(), r#"{"ok":true}"#);
+
+        assert!(RuntimeLimits { memory_limit_mb: 4, stack_size_kb: 1024 }.validate().is_err());
+        assert!(RuntimeLimits { memory_limit_mb: 4096, stack_size_kb: 1024 }.validate().is_err());
+        assert!(RuntimeLimits { memory_limit_mb: 64, stack_size_kb: 64 * 1024 }.validate().is_err());
+        assert!(RuntimeLimits::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_sandbox_blocks_code_generation() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.apply_sandbox(&crate::js_engine::module_loader::all_permissions()).unwrap();
+        runtime.load_module("sandboxed", r#"
+            function attempt(f) {
+                try { f(); return "allowed"; } catch (e) { return e.name; }
+            }
+            function main() {
+                return {
+                    eval: attempt(function() { eval("1 + 1"); }),
+                    ctor: attempt(function() { new Function("return 1"); }),
+                    viaPrototype: attempt(function() { (function() {}).constructor("return 1")(); }),
+                    asyncCtor: attempt(function() { (async function() {}).constructor("return 1"); }),
+                    instanceOf: (function() {}) instanceof Function,
+                    json: JSON.parse('{"a":1}').a
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["eval"], "EvalError");
+        assert_eq!(result["ctor"], "EvalError");
+        assert_eq!(result["viaPrototype"], "EvalError");
+        assert_eq!(result["asyncCtor"], "EvalError");
+        assert_eq!(result["instanceOf"], true);
+        assert_eq!(result["json"], 1);
+
+        // 未启用沙箱的运行时不受影响
+        let runtime = JsRuntime::new().unwrap();
+        assert_eq!(runtime.eval::<i32>("eval('1 + 1')").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_sandbox_removes_non_granted_bindings() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.apply_sandbox(&["crypto".to_string()]).unwrap();
+        runtime.load_module("sandboxed", r#"
+            function main() {
+                return {
+                    http: typeof http,
+                    fetch: typeof fetch,
+                    native: typeof __native_http_request_sync__,
+                    storage: typeof storage,
+                    cache: typeof __cache__,
+                    nativeStorage: typeof __native_storage_get_sync__,
+                    runtimeHttp: typeof runtime.http,
+                    crypto: typeof runtime.crypto
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        for key in ["http", "fetch", "native", "storage", "cache", "nativeStorage", "runtimeHttp"] {
+            assert_eq!(result[key], "undefined", "{}", key);
+        }
+        assert_eq!(result["crypto"], "object");
+    }
+
+    #[test]
+    fn test_permissions_hide_runtime_members() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.apply_permissions(&["storage".to_string()]).unwrap();
+        assert_eq!(runtime.eval_string("typeof runtime.http").unwrap(), "undefined");
+        assert_eq!(runtime.eval_string("typeof runtime.fetch").unwrap(), "undefined");
+        assert_eq!(runtime.eval_string("typeof runtime.crypto").unwrap(), "undefined");
+        assert_eq!(runtime.eval_string("typeof runtime.storage").unwrap(), "object");
+        assert_eq!(runtime.eval_string("typeof runtime.html").unwrap(), "object");
+    }
+
+    #[test]
+    fn test_permissions_remove_global_bindings() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.apply_permissions(&["storage".to_string()]).unwrap();
+        runtime.load_module("no_http", r#"
+            function attempt(f) {
+                try { f(); return "allowed"; } catch (e) { return e.name; }
+            }
+            async function viaAsync() {
+                try { await __async__.spawn('http', '{}'); return "allowed"; } catch (e) { return e.message; }
+            }
+            function main() {
+                return {
+                    http: attempt(function() { http.get("http://127.0.0.1/"); }),
+                    fetch: attempt(function() { fetch("http://127.0.0.1/"); }),
+                    native: attempt(function() { __native_http_request_sync__("{}"); }),
+                    crypto: attempt(function() { __crypto__.md5("a"); }),
+                    storage: typeof storage
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["http"], "ReferenceError");
+        assert_eq!(result["fetch"], "ReferenceError");
+        assert_eq!(result["native"], "ReferenceError");
+        assert_eq!(result["crypto"], "ReferenceError");
+        assert_eq!(result["storage"], "object");
+
+        // 绕过 fetch 直接启动异步请求同样被拒绝
+        let message = runtime.call_function_json("viaAsync", "{}").unwrap();
+        assert!(message.contains("not permitted"), "{}", message);
+    }
+
+    #[test]
+    fn test_error_stack_names_module() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("stack_module", r#"
+            function helper() {
+                throw new Error('broken');
+            }
+            function main() {
+                return helper();
+            }
+        "#).unwrap();
+
+        let err = runtime.call_function_json("main", "{}").unwrap_err().to_string();
+        assert!(err.contains("broken"), "{}", err);
+        assert!(err.contains("stack_module.js"), "{}", err);
+
+        // 加载阶段的异常同样带有模块名
+        let err = runtime.load_module("load_error_module", "null.x;").unwrap_err().to_string();
+        assert!(err.contains("load_error_module.js"), "{}", err);
+    }
+
+    #[test]
+    fn test_require_shared_lib() {
+        let dir = std::env::temp_dir().join(format!("comics-lib-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("utils.js"), r#"
+            globalThis.utilsLoads = (globalThis.utilsLoads || 0) + 1;
+            module.exports = {
+                trim: function(s) { return String(s).trim(); }
+            };
+        "#).unwrap();
+        std::fs::write(dir.join("format.js"), r#"
+            var utils = require('utils');
+            exports.title = function(s) { return '[' + utils.trim(s) + ']'; };
+        "#).unwrap();
+
+        let runtime = JsRuntime::new().unwrap();
+        runtime.enable_require(dir.clone()).unwrap();
+        runtime.load_module("lib_user", r#"
+            const utils = require('utils');
+            const format = require('./format.js');
+            function main() {
+                var escaped;
+                try { require('../secret'); escaped = true; } catch (e) { escaped = false; }
+                return {
+                    title: format.title('  hello '),
+                    same: require('utils') === utils,
+                    loads: globalThis.utilsLoads,
+                    escaped: escaped
+                };
+            }
+        "#).unwrap();
+
+        let result: serde_json::Value = serde_json::from_str(&runtime.call_function_json("main", "{}").unwrap()).unwrap();
+        assert_eq!(result["title"], "[hello]");
+        assert_eq!(result["same"], true);
+        assert_eq!(result["loads"], 1);
+        assert_eq!(result["escaped"], false);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_cancel_interrupts_call() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("cancel_module", r#"
+            function spin() { while (true) {} }
+            async function waits() { await __async__.sleep(10000); return 1; }
+            function quick() { return 1; }
+        "#).unwrap();
+
+        for func in ["spin", "waits"] {
+            let token = CancelToken::new();
+            runtime.set_cancel_token(Some(token.clone()));
+            let canceller = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                token.cancel();
+            });
+            let started = Instant::now();
+            let err = runtime.call_function_json_with_timeout(func, "{}", Duration::from_secs(10)).unwrap_err();
+            canceller.join().unwrap();
+            assert_eq!(err.downcast_ref::<ModuleError>(), Some(&ModuleError::Cancelled), "{}", func);
+            assert!(started.elapsed() < Duration::from_secs(5));
+        }
+
+        // 清除令牌后运行时仍可正常使用
+        runtime.set_cancel_token(None);
+        assert_eq!(runtime.call_function_json("quick", "{}").unwrap(), "1");
+    }
+
     #[test]
     fn test_eval_string() {
         let runtime = JsRuntime::new().unwrap();
@@ -364,4 +843,52 @@ mod tests {
         let result = runtime.eval_string("JSON.stringify({a: 1, b: 2})").unwrap();
         assert_eq!(result, r#"{"a":1,"b":2}"#);
     }
+
+    #[test]
+    fn test_call_async_function() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("test", r#"
+            async function main(args) {
+                await __async__.sleep(10);
+                var values = await Promise.all([__async__.sleep(5), __async__.sleep(1)]);
+                return { v: args.x + values.length };
+            }
+            async function fails() {
+                await __async__.sleep(1);
+                throw new Error('boom');
+            }
+            function never() {
+                return new Promise(function() {});
+            }
+        "#).unwrap();
+
+        assert_eq!(runtime.call_function_json("main", r#"{"x":1}"#).unwrap(), r#"{"v":3}"#);
+
+        let err = runtime.call_function_json("fails", "{}").unwrap_err();
+        assert!(err.to_string().contains("boom"));
+
+        // 没有待完成的异步操作时不应一直等待
+        assert!(runtime.call_function_json("never", "{}").is_err());
+    }
+
+    #[test]
+    fn test_call_timeout_interrupts_infinite_loop() {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module("test", r#"
+            function spin() { while (true) {} }
+            async function spinAsync() { await __async__.sleep(10000); }
+            function ok() { return 1; }
+        "#).unwrap();
+
+        let start = std::time::Instant::now();
+        let err = runtime.call_function_json_with_timeout("spin", "{}", Duration::from_millis(200)).unwrap_err();
+        assert!(err.to_string().contains("timed out"));
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let err = runtime.call_function_json_with_timeout("spinAsync", "{}", Duration::from_millis(200)).unwrap_err();
+        assert!(err.to_string().contains("timed out"));
+
+        // 中断后运行时仍可继续使用
+        assert_eq!(runtime.call_function_json("ok", "{}").unwrap(), "1");
+    }
 }
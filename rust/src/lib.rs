@@ -5,6 +5,7 @@ pub mod js_engine;
 pub mod modules;
 pub mod http;
 pub mod crypto;
+pub mod cancel;
 
 use once_cell::sync::OnceCell;
 use std::path::PathBuf;
@@ -43,15 +44,21 @@ pub fn get_cache_dir() -> Option<&'static PathBuf> {
 
 /// 初始化应用
 pub async fn init_application(root: String) -> anyhow::Result<()> {
+    init_application_with_key(root, None).await
+}
+
+/// 初始化应用，db_key 不为空时使用 SQLCipher 加密数据库（需要 sqlcipher feature）
+/// 
+/// 密钥错误时返回错误且不会记录任何路径，可使用正确的密钥重新调用
+pub async fn init_application_with_key(root: String, db_key: Option<String>) -> anyhow::Result<()> {
     // 初始化日志（只初始化一次）
-    let _ = tracing_subscriber::fmt()
-        .with_env_filter("info")
-        .try_init();
+    api::log_api::init_logging();
     
     let root_path = PathBuf::from(&root);
     
-    // 设置路径
-    ROOT_PATH.set(root_path.clone()).map_err(|_| anyhow::anyhow!("Root path already set"))?;
+    if ROOT_PATH.get().is_some() {
+        return Err(anyhow::anyhow!("Root path already set"));
+    }
     
     let db_dir = root_path.join("database");
     let modules_dir = root_path.join("modules");
@@ -62,19 +69,28 @@ pub async fn init_application(root: String) -> anyhow::Result<()> {
     tokio::fs::create_dir_all(&modules_dir).await?;
     tokio::fs::create_dir_all(&cache_dir).await?;
     
+    // 先打开数据库，加密密钥错误时可以重试
+    let encrypted = db_key.is_some();
+    database::init_database_with_key(&db_dir, db_key).await?;
+    
+    // 设置路径
+    ROOT_PATH.set(root_path.clone()).map_err(|_| anyhow::anyhow!("Root path already set"))?;
     DATABASE_DIR.set(db_dir.clone()).map_err(|_| anyhow::anyhow!("Database dir already set"))?;
     MODULES_DIR.set(modules_dir.clone()).map_err(|_| anyhow::anyhow!("Modules dir already set"))?;
     CACHE_DIR.set(cache_dir).map_err(|_| anyhow::anyhow!("Cache dir already set"))?;
     
-    // 初始化数据库
-    database::init_database(&db_dir).await?;
+    api::db_api::save_encryption_state(encrypted).await?;
     
     // 初始化模块管理器
     api::module_api::init_module_manager(&modules_dir)?;
+    api::module_api::init_module_pool().await?;
     
     // 初始化代理设置（从数据库加载）
     api::proxy_api::init_proxy().await?;
     
+    // 初始化 HTTP 设置（证书校验等）
+    api::http_api::init_http_settings().await?;
+    
     tracing::info!("Application initialized at: {}", root);
     
     Ok(())
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use super::types::ModuleResult;
+
+/// 模块调用错误
+///
+/// 通过 FRB 传到 Flutter 端后可按类型区分处理（如提示启用模块、重试网络请求等）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, thiserror::Error)]
+#[serde(tag = "type")]
+pub enum ModuleError {
+    /// 模块不存在或未加载
+    #[error("Module not found: {module_id}")]
+    NotFound { module_id: String },
+    /// 模块已禁用
+    #[error("Module is disabled: {module_id}")]
+    Disabled { module_id: String },
+    /// JS 抛出异常（包括 Promise 被 reject）
+    #[error("JS Error: {message}\nStack: {stack}")]
+    JsException { message: String, stack: String },
+    /// 模块缺少必需的入口函数
+    #[error("Module {module_id} is missing required functions: {}", functions.join(", "))]
+    MissingFunctions { module_id: String, functions: Vec<String> },
+    /// 模块未实现该可选功能
+    #[error("Module {module_id} does not support {func_name}")]
+    Unsupported { module_id: String, func_name: String },
+    /// 执行超时
+    #[error("module timed out after {timeout_ms}ms while calling {func_name}")]
+    Timeout { func_name: String, timeout_ms: u64 },
+    /// 网络请求失败
+    #[error("HTTP error: {message}")]
+    HttpError { message: String },
+    /// 模块返回的数据无法解析
+    #[error("Failed to parse {target}: {message}")]
+    ParseError { target: String, message: String },
+    /// 调用被取消（见 `crate::cancel`）
+    #[error("Operation cancelled")]
+    Cancelled,
+    /// 其他内部错误（数据库、IO 等）
+    #[error("{message}")]
+    Internal { message: String },
+}
+
+impl ModuleError {
+    /// 错误代码，与 `ModuleResult::Error::code` 对应
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound { .. } => "NOT_FOUND",
+            Self::Disabled { .. } => "DISABLED",
+            Self::JsException { .. } => "JS_EXCEPTION",
+            Self::MissingFunctions { .. } => "MISSING_FUNCTIONS",
+            Self::Unsupported { .. } => "UNSUPPORTED",
+            Self::Timeout { .. } => "TIMEOUT",
+            Self::HttpError { .. } => "HTTP_ERROR",
+            Self::ParseError { .. } => "PARSE_ERROR",
+            Self::Cancelled => "CANCELLED",
+            Self::Internal { .. } => "INTERNAL",
+        }
+    }
+
+    pub fn not_found(module_id: impl Into<String>) -> Self {
+        Self::NotFound { module_id: module_id.into() }
+    }
+
+    pub fn disabled(module_id: impl Into<String>) -> Self {
+        Self::Disabled { module_id: module_id.into() }
+    }
+
+    pub fn unsupported(module_id: impl Into<String>, func_name: impl Into<String>) -> Self {
+        Self::Unsupported {
+            module_id: module_id.into(),
+            func_name: func_name.into(),
+        }
+    }
+
+    pub fn parse(target: impl Into<String>, error: impl std::fmt::Display) -> Self {
+        Self::ParseError {
+            target: target.into(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// 保留 anyhow 链中已有的 ModuleError，其余归为 Internal
+impl From<anyhow::Error> for ModuleError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<ModuleError>() {
+            Ok(module_error) => module_error,
+            Err(error) if crate::cancel::is_cancelled_error(&error) => Self::Cancelled,
+            Err(error) => match error.downcast_ref::<reqwest::Error>() {
+                Some(http_error) => Self::HttpError { message: http_error.to_string() },
+                None => Self::Internal { message: format!("{:#}", error) },
+            },
+        }
+    }
+}
+
+impl<T> From<ModuleError> for ModuleResult<T> {
+    fn from(error: ModuleError) -> Self {
+        ModuleResult::error_with_code(error.to_string(), error.code())
+    }
+}
+
+impl<T> From<Result<T, ModuleError>> for ModuleResult<T> {
+    fn from(result: Result<T, ModuleError>) -> Self {
+        match result {
+            Ok(data) => ModuleResult::success(data),
+            Err(e) => e.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_error_survives_anyhow() {
+        let error: anyhow::Error = ModuleError::disabled("demo").into();
+        let error = error.context("while loading");
+        assert_eq!(ModuleError::from(error), ModuleError::disabled("demo"));
+
+        let error = ModuleError::from(anyhow::anyhow!("database locked"));
+        assert_eq!(error.code(), "INTERNAL");
+    }
+
+    #[test]
+    fn test_module_error_into_result() {
+        let result: ModuleResult<String> = Err(ModuleError::Timeout {
+            func_name: "search".to_string(),
+            timeout_ms: 100,
+        }).into();
+        match result {
+            ModuleResult::Error { code, .. } => assert_eq!(code.as_deref(), Some("TIMEOUT")),
+            _ => panic!("expected error"),
+        }
+    }
+}
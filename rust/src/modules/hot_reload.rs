@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::path::Path;
+use std::time::Duration;
+use anyhow::Result;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// 模块脚本热重载监听（开发用）
+/// 
+/// 监听模块目录中 `.js` 文件的变化，防抖后以模块 ID 回调。
+/// 被 drop 时停止监听。
+pub struct HotReloadWatcher {
+    _watcher: RecommendedWatcher,
+    task: JoinHandle<()>,
+}
+
+impl HotReloadWatcher {
+    /// 开始监听 `dir`，同一模块在 `debounce` 时间内的连续变更只回调一次
+    /// 
+    /// 需要在 tokio 运行时中调用
+    pub fn start<F, Fut>(dir: &Path, debounce: Duration, on_change: F) -> Result<Self>
+    where
+        F: Fn(String) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+        
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            match result {
+                Ok(event) => {
+                    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+                        return;
+                    }
+                    for module_id in event.paths.iter().filter_map(|p| module_id_from_path(p)) {
+                        let _ = tx.send(module_id);
+                    }
+                }
+                Err(e) => tracing::warn!("[HotReload] watch error: {}", e),
+            }
+        })?;
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        
+        let task = tokio::spawn(async move {
+            while let Some(first) = rx.recv().await {
+                let mut pending = HashSet::from([first]);
+                // 收集防抖时间内的后续变更（编辑器保存时常触发多个事件）
+                let mut closed = false;
+                loop {
+                    match tokio::time::timeout(debounce, rx.recv()).await {
+                        Ok(Some(module_id)) => {
+                            pending.insert(module_id);
+                        }
+                        Ok(None) => {
+                            closed = true;
+                            break;
+                        }
+                        Err(_) => break,
+                    }
+                }
+                for module_id in pending {
+                    tracing::info!("[HotReload] module script changed: {}", module_id);
+                    on_change(module_id).await;
+                }
+                if closed {
+                    break;
+                }
+            }
+        });
+        
+        tracing::info!("[HotReload] watching {:?}", dir);
+        Ok(Self { _watcher: watcher, task })
+    }
+}
+
+impl Drop for HotReloadWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// 只处理 `.js` 文件，文件名即模块 ID
+fn module_id_from_path(path: &Path) -> Option<String> {
+    if path.extension()?.to_str()? != "js" {
+        return None;
+    }
+    Some(path.file_stem()?.to_str()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_module_id_from_path() {
+        assert_eq!(module_id_from_path(Path::new("/m/demo.js")).as_deref(), Some("demo"));
+        assert_eq!(module_id_from_path(Path::new("/m/demo.js.swp")), None);
+        assert_eq!(module_id_from_path(Path::new("/m/readme.txt")), None);
+    }
+
+    #[tokio::test]
+    async fn test_debounced_changes() {
+        let dir = std::env::temp_dir().join(format!("comics-hot-reload-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let changed = Arc::new(Mutex::new(Vec::new()));
+        let sink = changed.clone();
+        let watcher = HotReloadWatcher::start(&dir, Duration::from_millis(200), move |module_id| {
+            let sink = sink.clone();
+            async move { sink.lock().unwrap().push(module_id) }
+        }).unwrap();
+
+        for i in 0..3 {
+            std::fs::write(dir.join("demo.js"), format!("// {}", i)).unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        tokio::time::sleep(Duration::from_millis(800)).await;
+
+        assert_eq!(*changed.lock().unwrap(), vec!["demo".to_string()]);
+        drop(watcher);
+    }
+}
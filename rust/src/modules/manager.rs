@@ -1,20 +1,86 @@
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use anyhow::Result;
 use sea_orm::{EntityTrait, ActiveModelTrait, Set};
 use chrono::Utc;
 
+use crate::cancel::{cancelable, CancelToken};
+use crate::api::property_api::APP_MODULE_ID;
 use crate::database::{self, entities::{module_info, property}};
-use crate::js_engine::{JsRuntime, ModuleLoader};
+use crate::js_engine::{JsRuntime, ModuleLoader, RuntimeLimits};
+use crate::js_engine::module_loader::{all_permissions, compare_versions};
+use crate::js_engine::runtime::DEFAULT_CALL_TIMEOUT;
 use super::types::*;
+use super::error::ModuleError;
+
+/// 登录返回的会话 token 在模块 storage 中的键名
+pub const SESSION_TOKEN_KEY: &str = "session_token";
+
+/// 模块运行时内存/栈限制保存在应用设置中（不在模块自己的存储里，脚本无法修改），
+/// 键名为该前缀加模块 ID，值为 JSON
+const RUNTIME_LIMITS_SETTING_PREFIX: &str = "module_runtime_limits:";
+
+/// 默认最多同时保留的模块运行时数量
+pub const DEFAULT_MAX_LOADED_MODULES: usize = 5;
 
 /// 模块运行时实例
+/// 
+/// 并发模型：每个模块只有一个长期存在的 JsRuntime，放在模块自己的异步 Mutex 中，
+/// 只有持有锁才能访问运行时，因此同一模块的调用串行执行，不同模块的调用可以并发。
+/// 每次调用前都会重置 `__MODULE_ID__` 等调用级全局状态；
+/// 模块脚本自己声明的全局变量会在调用之间保留（例如缓存的 token）。
+/// 已加载的运行时超过上限时，最久未使用的空闲实例会被卸载，下次调用时重新加载。
 struct ModuleInstance {
     #[allow(dead_code)]
     info: ModuleInfo,
-    runtime: JsRuntime,
+    runtime: tokio::sync::Mutex<JsRuntime>,
+    last_used: std::sync::Mutex<Instant>,
+}
+
+impl ModuleInstance {
+    fn new(info: ModuleInfo, runtime: JsRuntime) -> Self {
+        Self {
+            info,
+            runtime: tokio::sync::Mutex::new(runtime),
+            last_used: std::sync::Mutex::new(Instant::now()),
+        }
+    }
+
+    /// 记录一次使用
+    fn touch(&self) {
+        if let Ok(mut last_used) = self.last_used.lock() {
+            *last_used = Instant::now();
+        }
+    }
+
+    fn last_used(&self) -> Instant {
+        self.last_used.lock()
+            .map(|t| *t)
+            .unwrap_or_else(|_| Instant::now())
+    }
+}
+
+impl From<module_info::Model> for ModuleInfo {
+    fn from(m: module_info::Model) -> Self {
+        Self {
+            id: m.id,
+            name: m.name,
+            version: m.version,
+            author: m.author.unwrap_or_default(),
+            description: m.description,
+            icon: m.icon,
+            enabled: m.enabled,
+            source_url: m.source_url,
+            sandbox: m.sandbox,
+            permissions: m.permissions
+                .and_then(|p| serde_json::from_str(&p).ok())
+                .unwrap_or_else(all_permissions),
+        }
+    }
 }
 
 /// 模块管理器
@@ -23,6 +89,10 @@ pub struct ModuleManager {
     modules_dir: std::path::PathBuf,
     loader: ModuleLoader,
     instances: RwLock<HashMap<String, Arc<ModuleInstance>>>,
+    /// 按 (模块, 函数) 统计的调用指标
+    metrics: std::sync::Mutex<HashMap<(String, String), FunctionMetrics>>,
+    /// 最多同时保留的模块运行时数量
+    max_loaded: AtomicUsize,
 }
 
 impl ModuleManager {
@@ -31,6 +101,40 @@ impl ModuleManager {
             modules_dir: modules_dir.to_path_buf(),
             loader: ModuleLoader::new(modules_dir),
             instances: RwLock::new(HashMap::new()),
+            metrics: std::sync::Mutex::new(HashMap::new()),
+            max_loaded: AtomicUsize::new(DEFAULT_MAX_LOADED_MODULES),
+        }
+    }
+
+    /// 最多同时保留的模块运行时数量
+    pub fn max_loaded(&self) -> usize {
+        self.max_loaded.load(Ordering::Relaxed)
+    }
+
+    /// 设置最多同时保留的模块运行时数量（至少为 1），超出的立即卸载
+    pub async fn set_max_loaded(&self, max: usize) {
+        self.max_loaded.store(max.max(1), Ordering::Relaxed);
+        self.evict_idle_instances(None).await;
+    }
+
+    /// 已加载的运行时超过上限时，按最近使用时间卸载空闲的模块
+    /// 
+    /// 正在执行调用的模块不会被卸载，`keep` 指定的模块（刚加载的）也会保留
+    async fn evict_idle_instances(&self, keep: Option<&str>) {
+        let max = self.max_loaded();
+        let mut instances = self.instances.write().await;
+        while instances.len() > max {
+            let lru = instances.iter()
+                .filter(|(id, instance)| Some(id.as_str()) != keep && instance.runtime.try_lock().is_ok())
+                .min_by_key(|(_, instance)| instance.last_used())
+                .map(|(id, _)| id.clone());
+            match lru {
+                Some(id) => {
+                    instances.remove(&id);
+                    tracing::debug!("Module evicted: {}", id);
+                }
+                None => break,
+            }
         }
     }
 
@@ -44,57 +148,56 @@ impl ModuleManager {
             .all(&*conn)
             .await?;
         
-        Ok(modules.into_iter().map(|m| ModuleInfo {
-            id: m.id,
-            name: m.name,
-            version: m.version,
-            author: String::new(),
-            description: m.description,
-            icon: None,
-            enabled: m.enabled,
-            source_url: m.source_url,
-        }).collect())
+        Ok(modules.into_iter().map(ModuleInfo::from).collect())
     }
 
-    /// 通过URL导入插件
+    /// 通过URL导入插件（已存在的同名模块会被覆盖）
     pub async fn import_from_url(&self, url: &str) -> Result<ModuleInfo> {
+        self.install_from_url(url, true).await
+    }
+
+    /// 从URL安装插件
+    /// 
+    /// 脚本先经过校验并提取元信息，校验失败时不会写入任何文件或记录；
+    /// 声明的 id 与已安装模块冲突时，除非 `overwrite` 为 true，否则拒绝安装。
+    pub async fn install_from_url(&self, url: &str, overwrite: bool) -> Result<ModuleInfo> {
         use crate::http::client::HttpClient;
-        let client = HttpClient::new()?;
+        let client = HttpClient::shared()?;
         let response = client.get(url, HashMap::new()).await?;
 
         if response.status != 200 {
-            return Err(anyhow::anyhow!("Failed to download plugin: HTTP {}", response.status));
+            return Err(ModuleError::HttpError {
+                message: format!("Failed to download plugin: HTTP {}", response.status),
+            }.into());
         }
 
         let script = response.body;
+        let metadata = self.loader.validate_script(&script)?;
+
+        if self.module_exists(&metadata.id).await? {
+            if !overwrite {
+                return Err(anyhow::anyhow!("Module already installed: {}", metadata.id));
+            }
+            self.unload_module(&metadata.id).await?;
+        }
 
         let module_info = self.save_script_and_register(&script, Some(url.to_string())).await?;
 
         // 保存 ETag / Last-Modified
         self.save_source_headers(&module_info.id, &response.headers).await.ok();
 
+        tracing::info!("Module installed from {}: {} v{}", url, module_info.id, module_info.version);
+
         Ok(module_info)
     }
 
-    /// 更新插件（如果有URL来源），支持 ETag/Last-Modified 以跳过未变更
-    pub async fn update_module(&self, module_id: &str) -> Result<ModuleInfo> {
+    /// 读取已保存的 ETag / Last-Modified，用于条件请求
+    async fn conditional_headers(&self, module_id: &str) -> Result<HashMap<String, String>> {
         let db = database::get_database()
             .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
-        
         let conn = db.read().await;
-        let module = module_info::Entity::find_by_id(module_id)
-            .one(&*conn)
-            .await?
-            .ok_or_else(|| anyhow::anyhow!("Module not found: {}", module_id))?;
-        
-        let source_url = module.source_url.clone()
-            .ok_or_else(|| anyhow::anyhow!("Module has no source URL"))?;
-
-        // 条件请求
-        use crate::http::client::HttpClient;
         let mut headers = HashMap::new();
 
-        // 读取已保存的 ETag / Last-Modified
         if let Some(etag_item) = property::Entity::find_by_id(&property::Model::create_id(module_id, "source_etag"))
             .one(&*conn)
             .await? {
@@ -106,27 +209,72 @@ impl ModuleManager {
             headers.insert("If-Modified-Since".to_string(), lm_item.value);
         }
 
-        drop(conn); // 释放数据库连接
+        Ok(headers)
+    }
 
-        let client = HttpClient::new()?;
-        let response = client.get(&source_url, headers).await?;
+    /// 获取模块的数据库记录及来源URL
+    async fn find_module_with_source(&self, module_id: &str) -> Result<(module_info::Model, String)> {
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        
+        let conn = db.read().await;
+        let module = module_info::Entity::find_by_id(module_id)
+            .one(&*conn)
+            .await?
+            .ok_or_else(|| ModuleError::not_found(module_id))?;
+        
+        let source_url = module.source_url.clone()
+            .ok_or_else(|| anyhow::anyhow!("Module has no source URL"))?;
+        
+        Ok((module, source_url))
+    }
+
+    /// 更新插件（如果有URL来源）
+    /// 
+    /// 只有远程脚本声明的版本比已安装的新（或 `force` 为 true）时才替换脚本和数据库记录；
+    /// 未强制更新时使用 ETag/Last-Modified 跳过未变更的脚本。
+    /// 下载失败或远程脚本校验不通过时返回 `Failed`，原版本保持不变。
+    pub async fn update_module(&self, module_id: &str, force: bool) -> Result<ModuleUpdateResult> {
+        let (module, source_url) = self.find_module_with_source(module_id).await?;
+
+        let headers = if force {
+            HashMap::new()
+        } else {
+            self.conditional_headers(module_id).await?
+        };
+
+        use crate::http::client::HttpClient;
+        let client = HttpClient::shared()?;
+        let response = match client.get(&source_url, headers).await {
+            Ok(response) => response,
+            Err(e) => return Ok(ModuleUpdateResult::Failed { message: e.to_string() }),
+        };
 
         if response.status == 304 {
-            // 未变更，直接返回当前信息
-            return Ok(ModuleInfo {
-                id: module.id,
-                name: module.name,
-                version: module.version,
-                author: String::new(),
-                description: module.description,
-                icon: None,
-                enabled: module.enabled,
-                source_url: Some(source_url.clone()),
-            });
+            return Ok(ModuleUpdateResult::UpToDate);
         }
 
         if response.status != 200 {
-            return Err(anyhow::anyhow!("Failed to download plugin: HTTP {}", response.status));
+            return Ok(ModuleUpdateResult::Failed {
+                message: format!("Failed to download plugin: HTTP {}", response.status),
+            });
+        }
+
+        // 先校验远程脚本，失败时不动已安装的版本
+        let metadata = match self.loader.validate_script(&response.body) {
+            Ok(metadata) => metadata,
+            Err(e) => return Ok(ModuleUpdateResult::Failed { message: format!("Invalid module script: {}", e) }),
+        };
+
+        if metadata.id != module_id {
+            return Ok(ModuleUpdateResult::Failed {
+                message: format!("Module id mismatch: expected {}, got {}", module_id, metadata.id),
+            });
+        }
+
+        if !force && compare_versions(&metadata.version, &module.version) != std::cmp::Ordering::Greater {
+            self.save_source_headers(module_id, &response.headers).await.ok();
+            return Ok(ModuleUpdateResult::UpToDate);
         }
 
         // 先卸载模块
@@ -137,7 +285,52 @@ impl ModuleManager {
         // 保存返回的 ETag/Last-Modified
         self.save_source_headers(module_id, &response.headers).await.ok();
 
-        Ok(module_info)
+        tracing::info!("Module updated: {} {} -> {}", module_id, module.version, module_info.version);
+
+        Ok(ModuleUpdateResult::Updated {
+            from: module.version,
+            to: module_info.version,
+        })
+    }
+
+    /// 检查所有带来源URL的模块是否有新版本（不应用更新）
+    pub async fn check_updates(&self) -> Result<Vec<ModuleUpdateInfo>> {
+        use crate::http::client::HttpClient;
+        let client = HttpClient::shared()?;
+        let mut updates = Vec::new();
+
+        for module in self.list_modules().await? {
+            let Some(source_url) = module.source_url else {
+                continue;
+            };
+
+            let response = match client.get(&source_url, HashMap::new()).await {
+                Ok(response) if response.status == 200 => response,
+                Ok(response) => {
+                    tracing::warn!("Update check failed for {}: HTTP {}", module.id, response.status);
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("Update check failed for {}: {}", module.id, e);
+                    continue;
+                }
+            };
+
+            match self.loader.extract_metadata(&response.body) {
+                Ok(metadata) if metadata.id == module.id
+                    && compare_versions(&metadata.version, &module.version) == std::cmp::Ordering::Greater => {
+                    updates.push(ModuleUpdateInfo {
+                        module_id: module.id,
+                        current_version: module.version,
+                        latest_version: metadata.version,
+                    });
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Update check failed for {}: {}", module.id, e),
+            }
+        }
+
+        Ok(updates)
     }
 
     async fn save_source_headers(&self, module_id: &str, headers: &HashMap<String, String>) -> Result<()> {
@@ -197,16 +390,41 @@ impl ModuleManager {
 
     async fn save_script_and_register(&self, script: &str, source_url: Option<String>) -> Result<ModuleInfo> {
         // 验证和提取信息
-        self.loader.validate_script(script)?;
-        let metadata = self.loader.extract_metadata(script)?;
+        let metadata = self.loader.validate_script(script)?;
         let module_id = metadata.id.clone();
 
-        // 保存脚本文件
+        // 先写入临时文件再替换，避免留下写了一半的脚本
         let script_path = self.modules_dir.join(format!("{}.js", module_id));
-        tokio::fs::write(&script_path, script).await?;
+        let tmp_path = self.modules_dir.join(format!("{}.js.tmp", module_id));
+        let previous = tokio::fs::read(&script_path).await.ok();
+        tokio::fs::write(&tmp_path, script).await?;
+        if let Err(e) = tokio::fs::rename(&tmp_path, &script_path).await {
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return Err(e.into());
+        }
 
-        // 注册到数据库
-        self.register_module_with_source(&module_id, source_url).await
+        // 注册到数据库，失败时恢复原脚本
+        match self.register_module_with_source(&module_id, source_url).await {
+            Ok(info) => Ok(info),
+            Err(e) => {
+                let _ = match previous {
+                    Some(previous) => tokio::fs::write(&script_path, previous).await,
+                    None => tokio::fs::remove_file(&script_path).await,
+                };
+                Err(e)
+            }
+        }
+    }
+
+    /// 模块是否已安装（数据库中有记录或脚本文件已存在）
+    async fn module_exists(&self, module_id: &str) -> Result<bool> {
+        if self.modules_dir.join(format!("{}.js", module_id)).exists() {
+            return Ok(true);
+        }
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        let conn = db.read().await;
+        Ok(module_info::Entity::find_by_id(module_id).one(&*conn).await?.is_some())
     }
 
     /// 注册模块（带来源URL）
@@ -214,11 +432,8 @@ impl ModuleManager {
         // 加载脚本
         let script = self.loader.load_script(module_id).await?;
         
-        // 验证脚本
-        self.loader.validate_script(&script)?;
-        
-        // 提取元信息
-        let metadata = self.loader.extract_metadata(&script)?;
+        // 验证脚本并提取元信息
+        let metadata = self.loader.validate_script(&script)?;
         
         // 保存到数据库
         let db = database::get_database()
@@ -232,16 +447,25 @@ impl ModuleManager {
             .one(&*conn)
             .await?;
         
-        if let Some(_) = existing {
+        // 来源优先使用导入地址，其次是脚本声明的 sourceUrl，最后保留数据库中已有的来源
+        let source_url = source_url
+            .or_else(|| metadata.source_url.clone())
+            .or_else(|| existing.as_ref().and_then(|m| m.source_url.clone()));
+        
+        if existing.is_some() {
             // 更新，保留或覆盖来源
             let active_model = module_info::ActiveModel {
                 id: Set(metadata.id.clone()),
                 name: Set(metadata.name.clone()),
                 version: Set(metadata.version.clone()),
+                author: Set(Some(metadata.author.clone())),
                 description: Set(metadata.description.clone()),
                 script_path: Set(format!("{}.js", module_id)),
                 source_url: Set(source_url.clone()),
+                icon: Set(metadata.icon.clone()),
                 enabled: Set(true),
+                sandbox: Set(metadata.sandbox),
+                permissions: Set(Some(serde_json::to_string(&metadata.permissions)?)),
                 created_at: sea_orm::ActiveValue::NotSet,
                 updated_at: Set(now),
             };
@@ -252,10 +476,14 @@ impl ModuleManager {
                 id: Set(metadata.id.clone()),
                 name: Set(metadata.name.clone()),
                 version: Set(metadata.version.clone()),
+                author: Set(Some(metadata.author.clone())),
                 description: Set(metadata.description.clone()),
                 script_path: Set(format!("{}.js", module_id)),
                 source_url: Set(source_url.clone()),
+                icon: Set(metadata.icon.clone()),
                 enabled: Set(true),
+                sandbox: Set(metadata.sandbox),
+                permissions: Set(Some(serde_json::to_string(&metadata.permissions)?)),
                 created_at: Set(now),
                 updated_at: Set(now),
             };
@@ -268,15 +496,17 @@ impl ModuleManager {
             id: metadata.id,
             name: metadata.name,
             version: metadata.version,
-            author: String::new(),
+            author: metadata.author,
             description: metadata.description,
-            icon: None,
+            icon: metadata.icon,
             enabled: true,
-            source_url: source_url.clone(),
+            source_url,
+            sandbox: metadata.sandbox,
+            permissions: metadata.permissions,
         })
     }
 
-    /// 注册/更新模块（不记录来源）
+    /// 注册/更新模块（来源取脚本声明的 sourceUrl 或保留已有来源）
     pub async fn register_module(&self, module_id: &str) -> Result<ModuleInfo> {
         self.register_module_with_source(module_id, None).await
     }
@@ -299,45 +529,78 @@ impl ModuleManager {
         let module = module_info::Entity::find_by_id(module_id)
             .one(&*conn)
             .await?
-            .ok_or_else(|| anyhow::anyhow!("Module not found: {}", module_id))?;
+            .ok_or_else(|| ModuleError::not_found(module_id))?;
         
         if !module.enabled {
-            return Err(anyhow::anyhow!("Module is disabled: {}", module_id));
+            return Err(ModuleError::disabled(module_id).into());
         }
         
         // 加载脚本
         let script = self.loader.load_script(module_id).await?;
         
         // 创建 JS 运行时
-        let runtime = JsRuntime::new()?;
+        let runtime = JsRuntime::with_limits(self.runtime_limits(module_id).await?)?;
+        let info = ModuleInfo::from(module);
+        if info.sandbox {
+            runtime.apply_sandbox(&info.permissions)?;
+        } else {
+            runtime.apply_permissions(&info.permissions)?;
+        }
+        runtime.enable_require(self.loader.lib_dir())?;
         runtime.load_module(module_id, &script)?;
         
         // 保存实例
-        let instance = Arc::new(ModuleInstance {
-            info: ModuleInfo {
-                id: module.id.clone(),
-                name: module.name,
-                version: module.version,
-                author: String::new(),
-                description: module.description,
-                icon: None,
-                enabled: module.enabled,
-                source_url: module.source_url,
-            },
-            runtime,
-        });
+        let instance = Arc::new(ModuleInstance::new(info, runtime));
         
         {
             let mut instances = self.instances.write().await;
             instances.insert(module_id.to_string(), instance);
         }
+        self.evict_idle_instances(Some(module_id)).await;
         
         tracing::debug!("Module loaded: {}", module_id);
         
         Ok(())
     }
 
-    /// 卸载模块
+    /// 模块运行时是否已加载
+    pub async fn is_loaded(&self, module_id: &str) -> bool {
+        self.instances.read().await.contains_key(module_id)
+    }
+
+    /// 模块运行时的内存和栈限制，未单独配置时使用默认值
+    pub async fn runtime_limits(&self, module_id: &str) -> Result<RuntimeLimits> {
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        let conn = db.read().await;
+        let value = property::Entity::find_by_id(property::Model::create_id(APP_MODULE_ID, &Self::runtime_limits_key(module_id)))
+            .one(&*conn)
+            .await?
+            .map(|p| p.value);
+        Ok(value
+            .and_then(|v| serde_json::from_str::<RuntimeLimits>(&v).ok())
+            .filter(|limits| limits.validate().is_ok())
+            .unwrap_or_default())
+    }
+
+    /// 设置模块运行时的内存和栈限制，None 恢复默认值
+    /// 
+    /// 已加载的运行时会被卸载，下次调用时按新限制重新创建
+    pub async fn set_runtime_limits(&self, module_id: &str, limits: Option<RuntimeLimits>) -> Result<()> {
+        match limits {
+            Some(limits) => {
+                limits.validate()?;
+                self.save_module_property(APP_MODULE_ID, &Self::runtime_limits_key(module_id), &serde_json::to_string(&limits)?).await?;
+            }
+            None => self.delete_module_property(APP_MODULE_ID, &Self::runtime_limits_key(module_id)).await?,
+        }
+        self.unload_module(module_id).await
+    }
+
+    fn runtime_limits_key(module_id: &str) -> String {
+        format!("{}{}", RUNTIME_LIMITS_SETTING_PREFIX, module_id)
+    }
+
     pub async fn unload_module(&self, module_id: &str) -> Result<()> {
         let mut instances = self.instances.write().await;
         instances.remove(module_id);
@@ -345,6 +608,41 @@ impl ModuleManager {
         Ok(())
     }
 
+    /// 卸载并删除模块：移除运行时实例、脚本文件和数据库记录
+    /// 
+    /// 模块正在执行调用时返回错误，不做任何修改
+    pub async fn uninstall_module(&self, module_id: &str) -> Result<()> {
+        {
+            let mut instances = self.instances.write().await;
+            if let Some(instance) = instances.get(module_id) {
+                if instance.runtime.try_lock().is_err() {
+                    return Err(anyhow::anyhow!("Module is busy: {}", module_id));
+                }
+            }
+            instances.remove(module_id);
+        }
+
+        let script_path = self.modules_dir.join(format!("{}.js", module_id));
+        if let Err(e) = tokio::fs::remove_file(&script_path).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e.into());
+            }
+        }
+
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        let conn = db.read().await;
+        module_info::Entity::delete_by_id(module_id)
+            .exec(&*conn)
+            .await?;
+        property::Entity::delete_by_id(property::Model::create_id(APP_MODULE_ID, &Self::runtime_limits_key(module_id)))
+            .exec(&*conn)
+            .await?;
+
+        tracing::info!("Module uninstalled: {}", module_id);
+        Ok(())
+    }
+
     /// 启用/禁用模块
     pub async fn set_module_enabled(&self, module_id: &str, enabled: bool) -> Result<()> {
         let db = database::get_database()
@@ -356,7 +654,7 @@ impl ModuleManager {
         let module = module_info::Entity::find_by_id(module_id)
             .one(&*conn)
             .await?
-            .ok_or_else(|| anyhow::anyhow!("Module not found: {}", module_id))?;
+            .ok_or_else(|| ModuleError::not_found(module_id))?;
         
         let mut active_model: module_info::ActiveModel = module.into();
         active_model.enabled = Set(enabled);
@@ -370,8 +668,50 @@ impl ModuleManager {
         Ok(())
     }
 
+    /// 确保模块已加载并取出实例
+    /// 
+    /// 取出实例后立即释放 instances 读锁，避免长调用阻塞加载/卸载
+    async fn loaded_instance(&self, module_id: &str) -> Result<Arc<ModuleInstance>> {
+        self.load_module(module_id).await?;
+        let instances = self.instances.read().await;
+        let instance = instances.get(module_id)
+            .cloned()
+            .ok_or_else(|| ModuleError::not_found(module_id))?;
+        instance.touch();
+        Ok(instance)
+    }
+
+    /// 模块是否定义了指定函数
+    pub async fn has_function(&self, module_id: &str, func_name: &str) -> Result<bool> {
+        let instance = self.loaded_instance(module_id).await?;
+        let runtime = instance.runtime.lock().await;
+        Ok(runtime.has_function(func_name))
+    }
+
+    /// 调用可选的模块函数，模块未定义时返回 `ModuleError::Unsupported`
+    async fn call_optional_function(&self, module_id: &str, func_name: &str, args_json: &str) -> Result<String> {
+        if !self.has_function(module_id, func_name).await? {
+            return Err(ModuleError::unsupported(module_id, func_name).into());
+        }
+        self.call_function(module_id, func_name, args_json, None).await
+    }
+
     /// 调用模块函数
-    pub async fn call_function(&self, module_id: &str, func_name: &str, args_json: &str) -> Result<String> {
+    /// 
+    /// `timeout` 为 None 时使用默认超时（`DEFAULT_CALL_TIMEOUT`）
+    pub async fn call_function(&self, module_id: &str, func_name: &str, args_json: &str, timeout: Option<Duration>) -> Result<String> {
+        self.call_function_cancelable(module_id, func_name, args_json, timeout, None).await
+    }
+
+    /// 调用模块函数，令牌取消时中止等待或中断正在执行的脚本，返回 `ModuleError::Cancelled`
+    pub async fn call_function_cancelable(
+        &self,
+        module_id: &str,
+        func_name: &str,
+        args_json: &str,
+        timeout: Option<Duration>,
+        cancel: Option<&CancelToken>,
+    ) -> Result<String> {
         // 如果参数包含 imageData，只显示部分内容以避免日志过大
         let log_args = if args_json.contains("imageData") && args_json.len() > 200 {
             format!("{}... ({} bytes, contains imageData)", &args_json[..200.min(args_json.len())], args_json.len())
@@ -380,34 +720,124 @@ impl ModuleManager {
         };
         tracing::debug!("call_function: module={}, func={}, args={}", module_id, func_name, log_args);
         
-        // 确保模块已加载
-        self.load_module(module_id).await?;
-        
-        let instances = self.instances.read().await;
-        let instance = instances.get(module_id)
-            .ok_or_else(|| anyhow::anyhow!("Module not loaded: {}", module_id))?;
+        let instance = cancelable(cancel, self.loaded_instance(module_id)).await??;
         
-        tracing::debug!("Calling JS function: {}", func_name);
-        let result = instance.runtime.call_function_json(func_name, args_json)?;
+        // 同一模块的调用串行执行，取得锁之后才设置令牌，避免中断其他调用
+        let runtime = cancelable(cancel, instance.runtime.lock()).await?;
+        runtime.set_cancel_token(cancel.cloned());
+        let result = self.call_locked(&runtime, module_id, func_name, args_json, timeout);
+        runtime.set_cancel_token(None);
+        let result = result?;
         tracing::debug!("JS function returned: {} bytes", result.len());
         
         Ok(result)
     }
 
+    /// 在已持有锁的运行时上调用函数：调用前重置调用级全局状态，并记录调用指标
+    fn call_locked(&self, runtime: &JsRuntime, module_id: &str, func_name: &str, args_json: &str, timeout: Option<Duration>) -> Result<String> {
+        let started = std::time::Instant::now();
+        let result = runtime.reset_call_state(module_id).and_then(|_| {
+            tracing::debug!("Calling JS function: {}", func_name);
+            let timeout = timeout.unwrap_or(DEFAULT_CALL_TIMEOUT);
+            runtime.call_function_json_with_timeout(func_name, args_json, timeout)
+        });
+        self.record_metrics(module_id, func_name, started.elapsed(), result.as_ref().err());
+        result
+    }
+
+    /// 在同一模块上依次执行多个调用，只加载模块和获取锁一次
+    /// 
+    /// 单个调用失败不影响后续调用，结果与 calls 一一对应；仅模块无法加载时返回 Err
+    pub async fn batch_call(&self, module_id: &str, calls: &[BatchCall]) -> Result<Vec<BatchCallResult>> {
+        let instance = self.loaded_instance(module_id).await?;
+        let runtime = instance.runtime.lock().await;
+        Ok(calls.iter()
+            .map(|call| {
+                match self.call_locked(&runtime, module_id, &call.func_name, &call.args_json, None) {
+                    Ok(result) => BatchCallResult { result: Some(result), error: None },
+                    Err(e) => BatchCallResult { result: None, error: Some(e.to_string()) },
+                }
+            })
+            .collect())
+    }
+
+    /// 记录一次函数调用的耗时和结果
+    fn record_metrics(&self, module_id: &str, func_name: &str, elapsed: Duration, error: Option<&anyhow::Error>) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.entry((module_id.to_string(), func_name.to_string()))
+                .or_insert_with(|| FunctionMetrics {
+                    module_id: module_id.to_string(),
+                    function: func_name.to_string(),
+                    ..Default::default()
+                })
+                .record(elapsed, error.map(|e| e.to_string()));
+        }
+    }
+
+    /// 各模块函数的调用统计，按模块和函数名排序
+    pub fn metrics(&self) -> Vec<FunctionMetrics> {
+        let mut metrics: Vec<FunctionMetrics> = self.metrics.lock()
+            .map(|m| m.values().cloned().collect())
+            .unwrap_or_default();
+        metrics.sort_by(|a, b| (&a.module_id, &a.function).cmp(&(&b.module_id, &b.function)));
+        metrics
+    }
+
+    /// 调用模块函数并返回调用期间的 console 输出，供模块调试使用
+    /// 
+    /// 函数本身的错误记录在返回值的 error 中，仅模块未加载等情况返回 Err
+    pub async fn call_function_with_logs(&self, module_id: &str, func_name: &str, args_json: &str, timeout: Option<Duration>) -> Result<ModuleCallOutput> {
+        let instance = self.loaded_instance(module_id).await?;
+        
+        let runtime = instance.runtime.lock().await;
+        runtime.reset_call_state(module_id)?;
+        
+        let timeout = timeout.unwrap_or(DEFAULT_CALL_TIMEOUT);
+        let (result, logs) = runtime.call_function_json_with_logs(func_name, args_json, timeout);
+        Ok(ModuleCallOutput::from_result(result, logs))
+    }
+
+    /// 获取模块支持的可选功能
+    pub async fn get_capabilities(&self, module_id: &str) -> Result<ModuleCapabilities> {
+        let instance = self.loaded_instance(module_id).await?;
+        let runtime = instance.runtime.lock().await;
+
+        let declared = runtime.eval_string(
+            "JSON.stringify(typeof capabilities === 'object' && capabilities !== null ? capabilities : {})"
+        )?;
+        let declared: serde_json::Value = serde_json::from_str(&declared)
+            .map_err(|e| ModuleError::parse("capabilities", e))?;
+        let supports = |key: &str, func_name: &str| {
+            declared.get(key)
+                .and_then(|v| v.as_bool())
+                .unwrap_or_else(|| runtime.has_function(func_name))
+        };
+
+        Ok(ModuleCapabilities {
+            search: supports("search", "search"),
+            comments: supports("comments", "getComments"),
+            login: supports("login", "login"),
+            download: supports("download", "getPictures"),
+            process_image: supports("processImage", "processImage"),
+        })
+    }
+
     /// 获取分类列表
     pub async fn get_categories(&self, module_id: &str) -> Result<Vec<Category>> {
         tracing::debug!("Getting categories for module: {}", module_id);
-        let result = self.call_function(module_id, "getCategories", "{}").await?;
+        let result = self.call_function(module_id, "getCategories", "{}", None).await?;
         tracing::debug!("getCategories result: {}", &result[..std::cmp::min(500, result.len())]);
-        let categories: Vec<Category> = serde_json::from_str(&result)?;
+        let categories: Vec<Category> = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("Category", e))?;
         tracing::debug!("Parsed {} categories", categories.len());
         Ok(categories)
     }
 
     /// 获取排序选项
     pub async fn get_sort_options(&self, module_id: &str) -> Result<Vec<SortOption>> {
-        let result = self.call_function(module_id, "getSortOptions", "{}").await?;
-        let options: Vec<SortOption> = serde_json::from_str(&result)?;
+        let result = self.call_function(module_id, "getSortOptions", "{}", None).await?;
+        let options: Vec<SortOption> = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("SortOption", e))?;
         Ok(options)
     }
 
@@ -418,7 +848,7 @@ impl ModuleManager {
             "sortBy": sort_by,
             "page": page
         });
-        let result = self.call_function(module_id, "getComics", &args.to_string()).await?;
+        let result = self.call_function(module_id, "getComics", &args.to_string(), None).await?;
         tracing::debug!("getComics raw result (first 1000 chars): {}", &result[..std::cmp::min(1000, result.len())]);
         
         // 尝试解析，如果失败则输出更详细的错误信息
@@ -444,19 +874,65 @@ impl ModuleManager {
                     }
                 }
                 
-                return Err(anyhow::anyhow!("Failed to parse ComicsPage: {}", e));
+                return Err(ModuleError::parse("ComicsPage", e).into());
             }
         };
         Ok(response)
     }
 
+    /// 获取标签列表，模块未定义 getTags 时返回空列表
+    pub async fn get_tags(&self, module_id: &str) -> Result<Vec<Tag>> {
+        if !self.has_function(module_id, "getTags").await? {
+            return Ok(Vec::new());
+        }
+        let result = self.call_function(module_id, "getTags", "{}", None).await?;
+        let tags: Vec<Tag> = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("Tag", e))?;
+        Ok(tags)
+    }
+
+    /// 按标签获取漫画列表，需要模块定义 getComicsByTag
+    pub async fn get_comics_by_tag(&self, module_id: &str, tag: &str, sort_by: &str, page: i32) -> Result<ComicsPage> {
+        let args = serde_json::json!({
+            "tag": tag,
+            "sortBy": sort_by,
+            "page": page
+        });
+        let result = self.call_optional_function(module_id, "getComicsByTag", &args.to_string()).await?;
+        let comics: ComicsPage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("ComicsPage", e))?;
+        Ok(comics)
+    }
+
+    /// 获取作者的作品列表
+    /// 
+    /// 作者名原样传给模块的 getComicsByAuthor（由模块负责 URL 编码）；
+    /// 模块未定义该函数时退化为以作者名搜索
+    pub async fn get_comics_by_author(&self, module_id: &str, author: &str, page: i32) -> Result<ComicsPage> {
+        if !self.has_function(module_id, "getComicsByAuthor").await? {
+            if !self.has_function(module_id, "search").await? {
+                return Err(ModuleError::unsupported(module_id, "getComicsByAuthor").into());
+            }
+            return self.search(module_id, author, "", page).await;
+        }
+        let args = serde_json::json!({
+            "author": author,
+            "page": page
+        });
+        let result = self.call_function(module_id, "getComicsByAuthor", &args.to_string(), None).await?;
+        let comics: ComicsPage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("ComicsPage", e))?;
+        Ok(comics)
+    }
+
     /// 获取漫画详情
     pub async fn get_comic_detail(&self, module_id: &str, comic_id: &str) -> Result<ComicDetail> {
         let args = serde_json::json!({
             "comicId": comic_id
         });
-        let result = self.call_function(module_id, "getComicDetail", &args.to_string()).await?;
-        let detail: ComicDetail = serde_json::from_str(&result)?;
+        let result = self.call_function(module_id, "getComicDetail", &args.to_string(), None).await?;
+        let detail: ComicDetail = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("ComicDetail", e))?;
         Ok(detail)
     }
 
@@ -466,32 +942,243 @@ impl ModuleManager {
             "comicId": comic_id,
             "page": page
         });
-        let result = self.call_function(module_id, "getEps", &args.to_string()).await?;
-        let eps: EpPage = serde_json::from_str(&result)?;
+        let result = self.call_function(module_id, "getEps", &args.to_string(), None).await?;
+        let eps: EpPage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("EpPage", e))?;
         Ok(eps)
     }
 
+    /// 获取漫画评论，模块未定义 getComments 时返回空页
+    pub async fn get_comments(&self, module_id: &str, comic_id: &str, page: i32) -> Result<CommentPage> {
+        if !self.has_function(module_id, "getComments").await? {
+            return Ok(CommentPage {
+                page_info: PageInfo::empty(),
+                docs: Vec::new(),
+            });
+        }
+        let args = serde_json::json!({
+            "comicId": comic_id,
+            "page": page
+        });
+        let result = self.call_function(module_id, "getComments", &args.to_string(), None).await?;
+        let comments: CommentPage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("CommentPage", e))?;
+        Ok(comments)
+    }
+
+    /// 获取相关推荐漫画，模块未定义 getRecommend 时返回空页
+    pub async fn get_recommend(&self, module_id: &str, comic_id: &str, page: i32) -> Result<ComicsPage> {
+        if !self.has_function(module_id, "getRecommend").await? {
+            return Ok(ComicsPage {
+                page_info: PageInfo::empty(),
+                docs: Vec::new(),
+            });
+        }
+        let args = serde_json::json!({
+            "comicId": comic_id,
+            "page": page
+        });
+        let result = self.call_function(module_id, "getRecommend", &args.to_string(), None).await?;
+        let recommend: ComicsPage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("ComicsPage", e))?;
+        Ok(recommend)
+    }
+
+    /// 随机获取一部漫画（"随便看看"），目录为空时返回 None
+    /// 
+    /// 模块定义了 getRandomComic 时直接调用；否则随机选一个分类，
+    /// 再从该分类的漫画列表中随机选一页、一部漫画
+    pub async fn get_random_comic(&self, module_id: &str) -> Result<Option<ComicSimple>> {
+        if self.has_function(module_id, "getRandomComic").await? {
+            let result = self.call_function(module_id, "getRandomComic", "{}", None).await?;
+            let comic: Option<ComicSimple> = serde_json::from_str(&result)
+                .map_err(|e| ModuleError::parse("ComicSimple", e))?;
+            return Ok(comic);
+        }
+        
+        let categories: Vec<Category> = self.get_categories(module_id).await?
+            .into_iter()
+            .filter(|c| c.active && !c.is_web)
+            .collect();
+        let category_slug = Self::pick_random(&categories).map(|c| c.id.clone()).unwrap_or_default();
+        let sort_by = self.get_sort_options(module_id).await
+            .ok()
+            .and_then(|options| options.into_iter().next())
+            .map(|o| o.value)
+            .unwrap_or_default();
+        
+        let mut page = self.get_comics(module_id, &category_slug, &sort_by, 1).await?;
+        if page.page_info.pages > 1 {
+            let random_page = rand::Rng::gen_range(&mut rand::thread_rng(), 1..=page.page_info.pages);
+            if random_page != 1 {
+                page = self.get_comics(module_id, &category_slug, &sort_by, random_page).await?;
+            }
+        }
+        Ok(Self::pick_random(&page.docs).cloned())
+    }
+
+    fn pick_random<T>(items: &[T]) -> Option<&T> {
+        rand::seq::SliceRandom::choose(items, &mut rand::thread_rng())
+    }
+
+    /// 发表评论，需要模块定义 postComment
+    pub async fn post_comment(&self, module_id: &str, comic_id: &str, content: &str) -> Result<()> {
+        let args = serde_json::json!({
+            "comicId": comic_id,
+            "content": content
+        });
+        self.call_optional_function(module_id, "postComment", &args.to_string()).await?;
+        Ok(())
+    }
+
+    /// 登录
+    /// 
+    /// 调用模块的 `login(credentials)`，credentials 为 Flutter 传入的 JSON 对象。
+    /// 模块可以返回布尔值，或 `{ success, token?, message? }`；
+    /// 返回 token 时会以 `SESSION_TOKEN_KEY` 存入模块的 storage，模块通过 `storage.get('session_token')` 读取。
+    pub async fn login(&self, module_id: &str, credentials_json: &str) -> Result<LoginResult> {
+        let result = self.call_optional_function(module_id, "login", credentials_json).await?;
+        let value: serde_json::Value = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("LoginResult", e))?;
+
+        let (success, token, message) = match &value {
+            serde_json::Value::Bool(success) => (*success, None, None),
+            serde_json::Value::Object(obj) => (
+                obj.get("success").and_then(|v| v.as_bool()).unwrap_or(false),
+                obj.get("token").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                obj.get("message").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            ),
+            _ => return Err(ModuleError::parse("LoginResult", format!("unexpected value: {}", value)).into()),
+        };
+
+        if success {
+            if let Some(token) = token {
+                self.save_module_property(module_id, SESSION_TOKEN_KEY, &token).await?;
+            }
+        }
+
+        Ok(LoginResult { success, message })
+    }
+
+    /// 登出：调用模块的 `logout()`（可选）并清除保存的会话 token
+    pub async fn logout(&self, module_id: &str) -> Result<()> {
+        if self.has_function(module_id, "logout").await? {
+            self.call_function(module_id, "logout", "{}", None).await?;
+        }
+        self.delete_module_property(module_id, SESSION_TOKEN_KEY).await
+    }
+
+    /// 获取登录状态：优先调用模块的 `isLoggedIn()`，未定义时根据是否保存了会话 token 判断
+    pub async fn get_login_status(&self, module_id: &str) -> Result<bool> {
+        if self.has_function(module_id, "isLoggedIn").await? {
+            let result = self.call_function(module_id, "isLoggedIn", "{}", None).await?;
+            return serde_json::from_str::<bool>(&result)
+                .map_err(|e| ModuleError::parse("isLoggedIn", e).into());
+        }
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        let conn = db.read().await;
+        let token = property::Entity::find_by_id(property::Model::create_id(module_id, SESSION_TOKEN_KEY))
+            .one(&*conn)
+            .await?;
+        Ok(token.is_some_and(|t| !t.value.is_empty()))
+    }
+
+    /// 保存模块级属性（与 JS storage 共用存储）
+    async fn save_module_property(&self, module_id: &str, key: &str, value: &str) -> Result<()> {
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        let conn = db.read().await;
+        let now = Utc::now().naive_utc();
+        let id = property::Model::create_id(module_id, key);
+
+        let existing = property::Entity::find_by_id(&id)
+            .one(&*conn)
+            .await?;
+        let active_model = property::ActiveModel {
+            id: Set(id),
+            module_id: Set(module_id.to_string()),
+            key: Set(key.to_string()),
+            value: Set(value.to_string()),
+            created_at: if existing.is_some() { sea_orm::ActiveValue::NotSet } else { Set(now) },
+            updated_at: Set(now),
+        };
+        if existing.is_some() {
+            active_model.update(&*conn).await?;
+        } else {
+            active_model.insert(&*conn).await?;
+        }
+        Ok(())
+    }
+
+    async fn delete_module_property(&self, module_id: &str, key: &str) -> Result<()> {
+        let db = database::get_database()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?;
+        let conn = db.read().await;
+        property::Entity::delete_by_id(property::Model::create_id(module_id, key))
+            .exec(&*conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 切换收藏状态，返回切换后的状态；模块未定义 toggleFavourite 时返回 Unsupported 错误
+    pub async fn toggle_favourite(&self, module_id: &str, comic_id: &str) -> Result<bool> {
+        self.call_toggle(module_id, "toggleFavourite", comic_id).await
+    }
+
+    /// 切换点赞状态，返回切换后的状态；模块未定义 toggleLike 时返回 Unsupported 错误
+    pub async fn toggle_like(&self, module_id: &str, comic_id: &str) -> Result<bool> {
+        self.call_toggle(module_id, "toggleLike", comic_id).await
+    }
+
+    async fn call_toggle(&self, module_id: &str, func_name: &str, comic_id: &str) -> Result<bool> {
+        let args = serde_json::json!({ "comicId": comic_id });
+        let result = self.call_optional_function(module_id, func_name, &args.to_string()).await?;
+        let state: bool = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse(func_name, e))?;
+        Ok(state)
+    }
+
     /// 获取章节图片 (参考 pikapika pictures)
     pub async fn get_pictures(&self, module_id: &str, comic_id: &str, ep_id: &str, page: i32) -> Result<PicturePage> {
+        self.get_pictures_cancelable(module_id, comic_id, ep_id, page, None).await
+    }
+
+    /// 获取章节图片，可通过令牌取消
+    pub async fn get_pictures_cancelable(
+        &self,
+        module_id: &str,
+        comic_id: &str,
+        ep_id: &str,
+        page: i32,
+        cancel: Option<&CancelToken>,
+    ) -> Result<PicturePage> {
         let args = serde_json::json!({
             "comicId": comic_id,
             "epId": ep_id,
             "page": page
         });
-        let result = self.call_function(module_id, "getPictures", &args.to_string()).await?;
-        let pictures: PicturePage = serde_json::from_str(&result)?;
+        let result = self.call_function_cancelable(module_id, "getPictures", &args.to_string(), None, cancel).await?;
+        let pictures: PicturePage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("PicturePage", e))?;
         Ok(pictures)
     }
 
     /// 搜索漫画 (参考 pikapika search)
     pub async fn search(&self, module_id: &str, keyword: &str, sort_by: &str, page: i32) -> Result<ComicsPage> {
+        self.search_with_timeout(module_id, keyword, sort_by, page, None).await
+    }
+
+    /// 搜索漫画，可指定超时时间
+    pub async fn search_with_timeout(&self, module_id: &str, keyword: &str, sort_by: &str, page: i32, timeout: Option<Duration>) -> Result<ComicsPage> {
         let args = serde_json::json!({
             "keyword": keyword,
             "sortBy": sort_by,
             "page": page
         });
-        let result = self.call_function(module_id, "search", &args.to_string()).await?;
-        let response: ComicsPage = serde_json::from_str(&result)?;
+        let result = self.call_function(module_id, "search", &args.to_string(), timeout).await?;
+        let response: ComicsPage = serde_json::from_str(&result)
+            .map_err(|e| ModuleError::parse("ComicsPage", e))?;
         Ok(response)
     }
 
@@ -510,3 +1197,628 @@ impl ModuleManager {
         Ok(registered)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{TestResponse, TestServer};
+
+    fn test_instance(module_id: &str, script: &str) -> Arc<ModuleInstance> {
+        let runtime = JsRuntime::new().unwrap();
+        runtime.load_module(module_id, script).unwrap();
+        Arc::new(ModuleInstance::new(
+            ModuleInfo {
+                id: module_id.to_string(),
+                name: module_id.to_string(),
+                version: "1.0.0".to_string(),
+                author: String::new(),
+                description: String::new(),
+                icon: None,
+                enabled: true,
+                source_url: None,
+                sandbox: false,
+                permissions: all_permissions(),
+            },
+            runtime,
+        ))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_interleaved_calls_are_isolated() {
+        let manager = Arc::new(ModuleManager::new(&std::env::temp_dir()));
+        let instance = test_instance("isolation", r#"
+            var current = null;
+            async function work(args) {
+                current = args.id;
+                await __async__.sleep(args.delay);
+                return { id: args.id, seen: current, module: __MODULE_ID__ };
+            }
+        "#);
+        manager.instances.write().await.insert("isolation".to_string(), instance);
+
+        let calls = (0..4).map(|i| {
+            let manager = manager.clone();
+            tokio::spawn(async move {
+                let args = serde_json::json!({ "id": i, "delay": 20 - i * 5 }).to_string();
+                manager.call_function("isolation", "work", &args, None).await.unwrap()
+            })
+        }).collect::<Vec<_>>();
+
+        for (i, call) in calls.into_iter().enumerate() {
+            let result: serde_json::Value = serde_json::from_str(&call.await.unwrap()).unwrap();
+            assert_eq!(result["id"], i);
+            assert_eq!(result["seen"], i);
+            assert_eq!(result["module"], "isolation");
+        }
+    }
+
+    /// 为测试脚本补上必需的入口函数
+    fn with_required_functions(script: &str) -> String {
+        let stubs: String = crate::js_engine::module_loader::REQUIRED_FUNCTIONS.iter()
+            .map(|func| format!("async function {}() {{ return {{}}; }}\n", func))
+            .collect();
+        format!("{}\n{}", script, stubs)
+    }
+
+    #[tokio::test]
+    async fn test_module_metadata_is_listed() {
+        database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("author_module.js"), with_required_functions(r#"
+            const moduleInfo = {
+                id: "author_module",
+                name: "Author Module",
+                version: "1.0.0",
+                author: "niuhuan",
+                description: "Has an author",
+                icon: "https://example.com/icon.png",
+                sourceUrl: "https://example.com/author_module.js"
+            };
+        "#)).unwrap();
+
+        let manager = ModuleManager::new(&dir);
+        let registered = manager.register_module("author_module").await.unwrap();
+        assert_eq!(registered.author, "niuhuan");
+
+        let modules = manager.list_modules().await.unwrap();
+        let module = modules.iter().find(|m| m.id == "author_module").unwrap();
+        assert_eq!(module.author, "niuhuan");
+        assert_eq!(module.icon.as_deref(), Some("https://example.com/icon.png"));
+        assert_eq!(module.source_url.as_deref(), Some("https://example.com/author_module.js"));
+    }
+
+    /// 启动依次返回给定脚本的本地 HTTP 服务
+    fn serve_scripts(scripts: Vec<String>) -> TestServer {
+        let scripts = std::sync::Mutex::new(std::collections::VecDeque::from(scripts));
+        TestServer::start(move |_| {
+            let body = scripts.lock().unwrap().pop_front().unwrap_or_default();
+            TestResponse::ok(body).header("Content-Type", "application/javascript")
+        })
+    }
+
+    #[tokio::test]
+    async fn test_update_module_compares_versions() {
+        database::init_test_database().await;
+        let v2 = with_required_functions(r#"const moduleInfo = { id: "update_module", name: "Update", version: "2.0.0" };"#);
+        let broken = r#"const nothing = {};"#.to_string();
+        let server = serve_scripts(vec![v2.clone(), v2, broken]);
+
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let v1 = with_required_functions(&format!(
+            r#"const moduleInfo = {{ id: "update_module", name: "Update", version: "1.0.0", sourceUrl: "{}" }};"#,
+            server.url("/update_module.js")
+        ));
+        std::fs::write(dir.join("update_module.js"), &v1).unwrap();
+
+        let manager = ModuleManager::new(&dir);
+        manager.register_module("update_module").await.unwrap();
+
+        let result = manager.update_module("update_module", false).await.unwrap();
+        assert_eq!(result, ModuleUpdateResult::Updated { from: "1.0.0".to_string(), to: "2.0.0".to_string() });
+
+        let result = manager.update_module("update_module", false).await.unwrap();
+        assert_eq!(result, ModuleUpdateResult::UpToDate);
+
+        // 远程脚本无效时保留已安装的版本
+        let result = manager.update_module("update_module", true).await.unwrap();
+        assert!(matches!(result, ModuleUpdateResult::Failed { .. }));
+        let script = std::fs::read_to_string(dir.join("update_module.js")).unwrap();
+        assert!(script.contains("2.0.0"));
+    }
+
+    #[tokio::test]
+    async fn test_install_module_from_url() {
+        database::init_test_database().await;
+        let script = with_required_functions(r#"const moduleInfo = { id: "install_module", name: "Install", version: "1.0.0" };"#);
+        // 缺少入口函数，校验不通过
+        let incomplete = r#"const moduleInfo = { id: "install_module", name: "Install", version: "2.0.0" };"#.to_string();
+        let server = serve_scripts(vec![script.clone(), script, incomplete]);
+        let url = server.url("/install_module.js");
+
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let manager = ModuleManager::new(&dir);
+
+        let info = manager.install_from_url(&url, false).await.unwrap();
+        assert_eq!(info.id, "install_module");
+        assert_eq!(info.source_url.as_deref(), Some(url.as_str()));
+        assert!(dir.join("install_module.js").exists());
+
+        // 同名模块未允许覆盖时拒绝安装
+        assert!(manager.install_from_url(&url, false).await.is_err());
+
+        // 校验失败不留下任何文件，原版本保持不变
+        assert!(manager.install_from_url(&url, true).await.is_err());
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        let installed = std::fs::read_to_string(dir.join("install_module.js")).unwrap();
+        assert!(installed.contains("1.0.0"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_uninstall_module() {
+        database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = with_required_functions(r#"
+            const moduleInfo = { id: "uninstall_module", name: "Uninstall", version: "1.0.0" };
+            async function slow() { await __async__.sleep(200); return 1; }
+        "#);
+        std::fs::write(dir.join("uninstall_module.js"), script).unwrap();
+
+        let manager = Arc::new(ModuleManager::new(&dir));
+        manager.register_module("uninstall_module").await.unwrap();
+        manager.load_module("uninstall_module").await.unwrap();
+
+        // 调用进行中时拒绝卸载
+        let call = {
+            let manager = manager.clone();
+            tokio::spawn(async move { manager.call_function("uninstall_module", "slow", "{}", None).await })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(manager.uninstall_module("uninstall_module").await.is_err());
+        call.await.unwrap().unwrap();
+
+        manager.uninstall_module("uninstall_module").await.unwrap();
+        assert!(!dir.join("uninstall_module.js").exists());
+        assert!(manager.list_modules().await.unwrap().iter().all(|m| m.id != "uninstall_module"));
+    }
+
+    #[tokio::test]
+    async fn test_module_capabilities() {
+        database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("capability_module.js"), with_required_functions(r#"
+            const moduleInfo = { id: "capability_module", name: "Capability", version: "1.0.0" };
+            const capabilities = { search: false };
+            async function processImage(args) { return args; }
+        "#)).unwrap();
+
+        let manager = ModuleManager::new(&dir);
+        manager.register_module("capability_module").await.unwrap();
+        let capabilities = manager.get_capabilities("capability_module").await.unwrap();
+        assert_eq!(capabilities, ModuleCapabilities {
+            search: false,
+            comments: false,
+            login: false,
+            download: true,
+            process_image: true,
+        });
+    }
+
+    #[tokio::test]
+    async fn test_comments_degrade_gracefully() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("no_comments".to_string(), test_instance("no_comments", ""));
+        manager.instances.write().await.insert("comments".to_string(), test_instance("comments", r#"
+            async function getComments(args) {
+                return {
+                    total: 1, limit: 20, page: args.page, pages: 1,
+                    docs: [{ id: "c1", author: "reader", content: "nice " + args.comicId, likes: 3 }]
+                };
+            }
+        "#));
+
+        let empty = manager.get_comments("no_comments", "comic", 1).await.unwrap();
+        assert!(empty.docs.is_empty());
+        let error = manager.post_comment("no_comments", "comic", "hi").await.unwrap_err();
+        assert_eq!(ModuleError::from(error).code(), "UNSUPPORTED");
+
+        let page = manager.get_comments("comments", "comic", 1).await.unwrap();
+        assert_eq!(page.docs.len(), 1);
+        assert_eq!(page.docs[0].content, "nice comic");
+        assert_eq!(page.docs[0].likes, 3);
+    }
+
+    #[tokio::test]
+    async fn test_recommend_degrades_gracefully() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("no_recommend".to_string(), test_instance("no_recommend", ""));
+        manager.instances.write().await.insert("recommend".to_string(), test_instance("recommend", r#"
+            async function getRecommend(args) {
+                return {
+                    total: 1, limit: 20, page: args.page, pages: 1,
+                    docs: [{ id: "r-" + args.comicId, title: "Related", thumb: { original_name: "", path: "", file_server: "" } }]
+                };
+            }
+        "#));
+
+        let empty = manager.get_recommend("no_recommend", "comic", 1).await.unwrap();
+        assert!(empty.docs.is_empty());
+        assert_eq!(empty.page_info.pages, 0);
+
+        let page = manager.get_recommend("recommend", "comic", 2).await.unwrap();
+        assert_eq!(page.page_info.page, 2);
+        assert_eq!(page.docs[0].id, "r-comic");
+    }
+
+    #[tokio::test]
+    async fn test_random_comic() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("random_native".to_string(), test_instance("random_native", r#"
+            async function getRandomComic() {
+                return { id: "lucky", title: "Lucky", thumb: { original_name: "", path: "", file_server: "" } };
+            }
+        "#));
+        manager.instances.write().await.insert("random_fallback".to_string(), test_instance("random_fallback", r#"
+            var requested = [];
+            async function getCategories() {
+                return [
+                    { id: "web", title: "Web", is_web: true },
+                    { id: "hidden", title: "Hidden", active: false },
+                    { id: "action", title: "Action" }
+                ];
+            }
+            async function getSortOptions() { return [{ value: "new", name: "New" }]; }
+            async function getComics(args) {
+                requested.push(args.categorySlug + ":" + args.sortBy + ":" + args.page);
+                return {
+                    total: 6, limit: 2, page: args.page, pages: 3,
+                    docs: [0, 1].map(function(i) {
+                        return { id: "p" + args.page + "-" + i, title: "T", thumb: { original_name: "", path: "", file_server: "" } };
+                    })
+                };
+            }
+        "#));
+        manager.instances.write().await.insert("random_empty".to_string(), test_instance("random_empty", r#"
+            async function getCategories() { return []; }
+            async function getSortOptions() { return []; }
+            async function getComics(args) { return { total: 0, limit: 20, page: 1, pages: 0, docs: [] }; }
+        "#));
+
+        let comic = manager.get_random_comic("random_native").await.unwrap().unwrap();
+        assert_eq!(comic.id, "lucky");
+
+        let comic = manager.get_random_comic("random_fallback").await.unwrap().unwrap();
+        assert!(comic.id.starts_with('p'));
+        let instance = manager.loaded_instance("random_fallback").await.unwrap();
+        let requested = instance.runtime.lock().await.eval_string("JSON.stringify(requested)").unwrap();
+        let requested: Vec<String> = serde_json::from_str(&requested).unwrap();
+        assert_eq!(requested[0], "action:new:1");
+        assert!(requested.iter().all(|r| r.starts_with("action:new:")));
+
+        assert!(manager.get_random_comic("random_empty").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tags() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("no_tags".to_string(), test_instance("no_tags", ""));
+        manager.instances.write().await.insert("tags".to_string(), test_instance("tags", r#"
+            async function getTags() {
+                return [{ id: "romance", title: "Romance", count: 12 }, { id: "sci-fi", title: "Sci-Fi" }];
+            }
+            async function getComicsByTag(args) {
+                return {
+                    total: 1, limit: 20, page: args.page, pages: 1,
+                    docs: [{ id: args.tag + "-" + args.sortBy, title: "T", thumb: { original_name: "", path: "", file_server: "" } }]
+                };
+            }
+        "#));
+
+        assert!(manager.get_tags("no_tags").await.unwrap().is_empty());
+        let error = manager.get_comics_by_tag("no_tags", "romance", "", 1).await.unwrap_err();
+        assert_eq!(ModuleError::from(error).code(), "UNSUPPORTED");
+
+        let tags = manager.get_tags("tags").await.unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].count, Some(12));
+        assert_eq!(tags[1].count, None);
+
+        let page = manager.get_comics_by_tag("tags", "romance", "hot", 1).await.unwrap();
+        assert_eq!(page.docs[0].id, "romance-hot");
+    }
+
+    #[tokio::test]
+    async fn test_comics_by_author_falls_back_to_search() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        let page = |id: &str| format!(
+            "return {{ total: 1, limit: 20, page: args.page, pages: 1, docs: [{{ id: {}, title: 'T', thumb: {{ original_name: '', path: '', file_server: '' }} }}] }};",
+            id
+        );
+        manager.instances.write().await.insert("by_author".to_string(), test_instance("by_author", &format!(
+            "async function getComicsByAuthor(args) {{ {} }}\nasync function search(args) {{ {} }}",
+            page("'author:' + args.author"), page("'search'")
+        )));
+        manager.instances.write().await.insert("author_search".to_string(), test_instance("author_search", &format!(
+            "async function search(args) {{ {} }}", page("'search:' + args.keyword")
+        )));
+        manager.instances.write().await.insert("no_author".to_string(), test_instance("no_author", ""));
+
+        let comics = manager.get_comics_by_author("by_author", "佚名 & Co", 1).await.unwrap();
+        assert_eq!(comics.docs[0].id, "author:佚名 & Co");
+
+        let comics = manager.get_comics_by_author("author_search", "佚名", 2).await.unwrap();
+        assert_eq!(comics.docs[0].id, "search:佚名");
+        assert_eq!(comics.page_info.page, 2);
+
+        let error = manager.get_comics_by_author("no_author", "佚名", 1).await.unwrap_err();
+        assert_eq!(ModuleError::from(error).code(), "UNSUPPORTED");
+    }
+
+    #[tokio::test]
+    async fn test_batch_call_continues_after_failure() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("batch".to_string(), test_instance("batch", r#"
+            async function getComicDetail(args) { return { id: args.comicId }; }
+            function broken() { throw new Error("broken call"); }
+            async function getEps(args) { return { comicId: args.comicId, module: __MODULE_ID__ }; }
+        "#));
+
+        let calls = vec![
+            BatchCall { func_name: "getComicDetail".to_string(), args_json: r#"{"comicId":"c1"}"#.to_string() },
+            BatchCall { func_name: "broken".to_string(), args_json: "{}".to_string() },
+            BatchCall { func_name: "getEps".to_string(), args_json: r#"{"comicId":"c1"}"#.to_string() },
+        ];
+        let results = manager.batch_call("batch", &calls).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].result.as_deref(), Some(r#"{"id":"c1"}"#));
+        assert!(results[1].result.is_none());
+        assert!(results[1].error.as_deref().unwrap().contains("broken call"));
+        assert_eq!(results[2].result.as_deref(), Some(r#"{"comicId":"c1","module":"batch"}"#));
+
+        assert!(manager.batch_call("batch_missing", &calls).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_module_runtime_limits() {
+        database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("limited_module.js"), with_required_functions(r#"
+            const moduleInfo = { id: "limited_module", name: "Limited", version: "1.0.0" };
+            function grow() {
+                var chunks = [];
+                for (var i = 0; i < 64; i++) chunks.push(new Array(1024 * 1024).fill(i));
+                return { count: chunks.length };
+            }
+        "#)).unwrap();
+        let manager = ModuleManager::new(&dir);
+        manager.register_module("limited_module").await.unwrap();
+        assert_eq!(manager.runtime_limits("limited_module").await.unwrap(), RuntimeLimits::default());
+
+        let limits = RuntimeLimits { memory_limit_mb: 16, stack_size_kb: 512 };
+        manager.set_runtime_limits("limited_module", Some(limits)).await.unwrap();
+        assert_eq!(manager.runtime_limits("limited_module").await.unwrap(), limits);
+
+        // 脚本不能通过 storage 修改自己的限制
+        let raised = serde_json::to_string(&RuntimeLimits { memory_limit_mb: 4096, stack_size_kb: 512 }).unwrap();
+        let script = format!(
+            "storage.set('__runtime_limits__', '{0}') || __native_storage_set_sync__('__app__', 'module_runtime_limits:limited_module', '{0}')",
+            raised
+        );
+        manager.load_module("limited_module").await.unwrap();
+        assert!(!manager.instances.read().await["limited_module"].runtime.lock().await.eval::<bool>(&script).unwrap());
+        assert_eq!(manager.runtime_limits("limited_module").await.unwrap(), limits);
+        assert!(manager.call_function("limited_module", "grow", "{}", None).await.is_err());
+
+        let too_small = RuntimeLimits { memory_limit_mb: 1, stack_size_kb: 512 };
+        assert!(manager.set_runtime_limits("limited_module", Some(too_small)).await.is_err());
+
+        manager.set_runtime_limits("limited_module", None).await.unwrap();
+        assert_eq!(manager.runtime_limits("limited_module").await.unwrap(), RuntimeLimits::default());
+        assert!(!manager.is_loaded("limited_module").await);
+    }
+
+    #[tokio::test]
+    async fn test_login_persists_session_token() {
+        database::init_test_database().await;
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("login_module".to_string(), test_instance("login_module", r#"
+            async function login(credentials) {
+                if (credentials.password !== "secret") {
+                    return { success: false, message: "wrong password" };
+                }
+                return { success: true, token: "token-" + credentials.username };
+            }
+        "#));
+
+        let result = manager.login("login_module", r#"{"username":"u","password":"bad"}"#).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(result.message.as_deref(), Some("wrong password"));
+        assert!(!manager.get_login_status("login_module").await.unwrap());
+
+        let result = manager.login("login_module", r#"{"username":"u","password":"secret"}"#).await.unwrap();
+        assert!(result.success);
+        assert!(manager.get_login_status("login_module").await.unwrap());
+
+        manager.logout("login_module").await.unwrap();
+        assert!(!manager.get_login_status("login_module").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_toggle_favourite() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("toggle_module".to_string(), test_instance("toggle_module", r#"
+            var favourites = {};
+            async function toggleFavourite(args) {
+                favourites[args.comicId] = !favourites[args.comicId];
+                return favourites[args.comicId];
+            }
+        "#));
+
+        assert!(manager.toggle_favourite("toggle_module", "c1").await.unwrap());
+        assert!(!manager.toggle_favourite("toggle_module", "c1").await.unwrap());
+
+        let error = manager.toggle_like("toggle_module", "c1").await.unwrap_err();
+        assert!(matches!(ModuleError::from(error), ModuleError::Unsupported { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_call_function_with_logs() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("logging".to_string(), test_instance("logging", r#"
+            async function work(args) {
+                console.log("start", args.id);
+                await __async__.sleep(1);
+                console.warn("slow");
+                if (args.fail) {
+                    console.error("failing");
+                    throw new Error("boom");
+                }
+                return { id: args.id };
+            }
+        "#));
+
+        let output = manager.call_function_with_logs("logging", "work", r#"{"id":1}"#, None).await.unwrap();
+        assert_eq!(output.result.as_deref(), Some(r#"{"id":1}"#));
+        let lines: Vec<(&str, &str)> = output.logs.iter().map(|l| (l.level.as_str(), l.message.as_str())).collect();
+        assert_eq!(lines, vec![("log", "start 1"), ("warn", "slow")]);
+
+        let output = manager.call_function_with_logs("logging", "work", r#"{"id":2,"fail":true}"#, None).await.unwrap();
+        assert!(output.result.is_none());
+        assert!(output.error.unwrap().contains("boom"));
+        assert_eq!(output.logs.len(), 3);
+        assert_eq!(output.logs[2].level, "error");
+
+        // 普通调用不捕获日志，也不会残留到下一次捕获
+        manager.call_function("logging", "work", r#"{"id":3}"#, None).await.unwrap();
+        let output = manager.call_function_with_logs("logging", "work", r#"{"id":4}"#, None).await.unwrap();
+        assert_eq!(output.logs[0].message, "start 4");
+    }
+
+    #[tokio::test]
+    async fn test_call_metrics() {
+        let manager = ModuleManager::new(&std::env::temp_dir());
+        manager.instances.write().await.insert("metrics".to_string(), test_instance("metrics", r#"
+            function ok() { return {}; }
+            function fail(args) { throw new Error("bad " + args.id); }
+        "#));
+
+        manager.call_function("metrics", "ok", "{}", None).await.unwrap();
+        manager.call_function("metrics", "ok", "{}", None).await.unwrap();
+        manager.call_function("metrics", "fail", r#"{"id":1}"#, None).await.unwrap_err();
+        manager.call_function("metrics", "fail", r#"{"id":2}"#, None).await.unwrap_err();
+
+        let metrics = manager.metrics();
+        assert_eq!(metrics.len(), 2);
+        let fail = &metrics[0];
+        assert_eq!((fail.function.as_str(), fail.calls, fail.errors), ("fail", 2, 2));
+        assert!(fail.last_error.as_deref().unwrap().contains("bad 2"));
+        let ok = &metrics[1];
+        assert_eq!((ok.module_id.as_str(), ok.function.as_str(), ok.calls, ok.errors), ("metrics", "ok", 2, 0));
+        assert!(ok.last_error.is_none());
+        assert_eq!(ok.avg_ms, ok.total_ms / 2);
+    }
+
+    /// 请求会等待下一个请求到达（最多 wait），两者同时在执行时返回 "together"，否则返回 "alone"
+    fn rendezvous_server(wait: Duration) -> TestServer {
+        let arrivals = Arc::new((std::sync::Mutex::new(0usize), std::sync::Condvar::new()));
+        TestServer::start(move |_| {
+            let (count, arrived) = &*arrivals;
+            let mut count = count.lock().unwrap();
+            *count += 1;
+            arrived.notify_all();
+            let (count, _) = arrived.wait_timeout_while(count, wait, |count| *count < 2).unwrap();
+            TestResponse::ok(if *count >= 2 { "together" } else { "alone" })
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_different_modules_run_concurrently() {
+        let manager = Arc::new(ModuleManager::new(&std::env::temp_dir()));
+        let script = r#"
+            function work(args) {
+                return { module: __MODULE_ID__, body: http.get(args.url).body };
+            }
+        "#;
+        manager.instances.write().await.insert("concurrent_a".to_string(), test_instance("concurrent_a", script));
+        manager.instances.write().await.insert("concurrent_b".to_string(), test_instance("concurrent_b", script));
+
+        let run = |module_ids: [&'static str; 2], server: TestServer| {
+            let manager = manager.clone();
+            async move {
+                let args = serde_json::json!({ "url": server.url("/") }).to_string();
+                let calls = module_ids.map(|module_id| {
+                    let manager = manager.clone();
+                    let args = args.clone();
+                    tokio::spawn(async move {
+                        manager.call_function(module_id, "work", &args, None).await.unwrap()
+                    })
+                });
+                let mut bodies = Vec::new();
+                for (call, module_id) in calls.into_iter().zip(module_ids) {
+                    let result: serde_json::Value = serde_json::from_str(&call.await.unwrap()).unwrap();
+                    assert_eq!(result["module"], module_id);
+                    bodies.push(result["body"].as_str().unwrap().to_string());
+                }
+                bodies
+            }
+        };
+
+        // 不同模块并发执行：两个请求同时在等待
+        let bodies = run(["concurrent_a", "concurrent_b"], rendezvous_server(Duration::from_secs(10))).await;
+        assert_eq!(bodies, vec!["together", "together"]);
+
+        // 同一模块串行执行：第一个请求等不到第二个
+        let bodies = run(["concurrent_a", "concurrent_a"], rendezvous_server(Duration::from_millis(200))).await;
+        assert_eq!(bodies[0], "alone");
+    }
+
+    #[tokio::test]
+    async fn test_lru_evicts_least_recently_used_module() {
+        database::init_test_database().await;
+        let dir = std::env::temp_dir().join(format!("comics-modules-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let manager = ModuleManager::new(&dir);
+        assert_eq!(manager.max_loaded(), DEFAULT_MAX_LOADED_MODULES);
+
+        let ids: Vec<String> = (0..7).map(|i| format!("lru_module_{}", i)).collect();
+        for id in &ids {
+            std::fs::write(dir.join(format!("{}.js", id)), with_required_functions(&format!(
+                "const moduleInfo = {{ id: \"{}\", name: \"{}\", version: \"1.0.0\" }};", id, id
+            ))).unwrap();
+            manager.register_module(id).await.unwrap();
+        }
+        let loaded = |manager: &ModuleManager| {
+            let instances = manager.instances.try_read().unwrap();
+            let mut ids: Vec<String> = instances.keys().cloned().collect();
+            ids.sort();
+            ids
+        };
+
+        for id in &ids[..5] {
+            manager.load_module(id).await.unwrap();
+        }
+        assert_eq!(loaded(&manager).len(), 5);
+
+        // 第 6 个模块加载后卸载最早使用的模块
+        manager.load_module(&ids[5]).await.unwrap();
+        assert_eq!(loaded(&manager), ids[1..6].to_vec());
+
+        // 使用过的模块不会被优先卸载
+        manager.has_function(&ids[1], "getCategories").await.unwrap();
+        manager.load_module(&ids[6]).await.unwrap();
+        let expected = vec![ids[1].clone(), ids[3].clone(), ids[4].clone(), ids[5].clone(), ids[6].clone()];
+        assert_eq!(loaded(&manager), expected);
+
+        // 调小上限立即生效，被卸载的模块再次调用时重新加载
+        manager.set_max_loaded(2).await;
+        assert_eq!(loaded(&manager), vec![ids[1].clone(), ids[6].clone()]);
+        assert!(manager.has_function(&ids[0], "getCategories").await.unwrap());
+        assert_eq!(loaded(&manager), vec![ids[0].clone(), ids[6].clone()]);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod types;
 pub mod manager;
+pub mod error;
+pub mod hot_reload;
 
 pub use types::*;
 pub use manager::ModuleManager;
+pub use error::ModuleError;
@@ -12,6 +12,142 @@ pub struct ModuleInfo {
     pub icon: Option<String>,
     pub enabled: bool,
     pub source_url: Option<String>,
+    /// 是否以沙箱模式运行
+    #[serde(default)]
+    pub sandbox: bool,
+    /// 授予的权限（http / storage / crypto），安装时可提示用户
+    #[serde(default = "crate::js_engine::module_loader::all_permissions")]
+    pub permissions: Vec<String>,
+}
+
+/// 模块更新结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ModuleUpdateResult {
+    /// 已是最新版本
+    UpToDate,
+    /// 已更新
+    Updated { from: String, to: String },
+    /// 更新失败（下载失败或远程脚本校验不通过），已保留原版本
+    Failed { message: String },
+}
+
+/// 可用的模块更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleUpdateInfo {
+    pub module_id: String,
+    pub current_version: String,
+    pub latest_version: String,
+}
+
+/// 模块支持的可选功能
+/// 
+/// 默认根据模块是否定义对应函数判断，模块导出的 `capabilities` 对象优先
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModuleCapabilities {
+    /// search
+    pub search: bool,
+    /// getComments
+    pub comments: bool,
+    /// login
+    pub login: bool,
+    /// getPictures，可通过 `capabilities.download = false` 禁止下载
+    pub download: bool,
+    /// processImage
+    pub process_image: bool,
+}
+
+/// 模块登录结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginResult {
+    pub success: bool,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// 模块脚本输出的一行控制台日志
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsoleLog {
+    /// log / warn / error / debug
+    pub level: String,
+    pub message: String,
+    /// 毫秒时间戳
+    pub timestamp: i64,
+}
+
+/// 带控制台日志的函数调用结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleCallOutput {
+    /// 函数返回值（JSON），调用失败时为 None
+    pub result: Option<String>,
+    /// 调用失败时的错误信息
+    pub error: Option<String>,
+    /// JS 异常的调用栈
+    pub stack: Option<String>,
+    pub logs: Vec<ConsoleLog>,
+}
+
+impl ModuleCallOutput {
+    /// 由调用结果和捕获的日志构建，JS 异常会拆出调用栈
+    pub fn from_result(result: anyhow::Result<String>, logs: Vec<ConsoleLog>) -> Self {
+        match result {
+            Ok(result) => Self { result: Some(result), error: None, stack: None, logs },
+            Err(e) => {
+                let stack = match e.downcast_ref::<super::ModuleError>() {
+                    Some(super::ModuleError::JsException { stack, .. }) if !stack.is_empty() => Some(stack.clone()),
+                    _ => None,
+                };
+                Self { result: None, error: Some(e.to_string()), stack, logs }
+            }
+        }
+    }
+}
+
+/// 批量调用中的一次函数调用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCall {
+    pub func_name: String,
+    pub args_json: String,
+}
+
+/// 批量调用中单次调用的结果，result 和 error 只有一个有值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCallResult {
+    pub result: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 模块函数的调用统计（仅保存在内存中，重启后清零）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FunctionMetrics {
+    pub module_id: String,
+    pub function: String,
+    /// 调用次数
+    pub calls: u64,
+    /// 失败次数
+    pub errors: u64,
+    /// 累计耗时（毫秒）
+    pub total_ms: u64,
+    /// 平均耗时（毫秒）
+    pub avg_ms: u64,
+    /// 最长耗时（毫秒）
+    pub max_ms: u64,
+    /// 最近一次失败的错误信息
+    pub last_error: Option<String>,
+}
+
+impl FunctionMetrics {
+    /// 记录一次调用
+    pub fn record(&mut self, elapsed: std::time::Duration, error: Option<String>) {
+        let elapsed_ms = elapsed.as_millis() as u64;
+        self.calls += 1;
+        self.total_ms += elapsed_ms;
+        self.max_ms = self.max_ms.max(elapsed_ms);
+        self.avg_ms = self.total_ms / self.calls;
+        if error.is_some() {
+            self.errors += 1;
+            self.last_error = error;
+        }
+    }
 }
 
 /// 远程图片信息 (参考 pikapika RemoteImageInfo)
@@ -98,6 +234,17 @@ pub struct Category {
 
 fn default_true() -> bool { true }
 
+/// 标签（题材等），与分类是不同的浏览维度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    /// 传给 getComicsByTag 的标识
+    pub id: String,
+    pub title: String,
+    /// 该标签下的漫画数量（可选，用于标签云）
+    #[serde(default)]
+    pub count: Option<i32>,
+}
+
 /// 分页信息 (参考 pikapika Page)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageInfo {
@@ -140,6 +287,9 @@ pub struct ComicSimple {
     pub thumb: RemoteImageInfo,
     #[serde(default)]
     pub likes_count: i32,
+    /// 来源模块，聚合搜索时填充
+    #[serde(default)]
+    pub module_id: Option<String>,
 }
 
 /// 漫画详情 (参考 pikapika ComicInfo)
@@ -203,6 +353,31 @@ pub struct EpPage {
     pub docs: Vec<Ep>,
 }
 
+/// 评论 (参考 pikapika Comment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub avatar: Option<RemoteImageInfo>,
+    pub content: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub likes: i32,
+    #[serde(default)]
+    pub reply_count: i32,
+}
+
+/// 评论分页
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentPage {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    pub docs: Vec<Comment>,
+}
+
 /// 漫画图片 (参考 pikapika Picture)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Picture {
@@ -240,6 +415,45 @@ pub struct SearchResult {
     pub search_query: String,
 }
 
+/// 单个模块的聚合搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleSearchPage {
+    pub module_id: String,
+    pub page: ComicsPage,
+}
+
+/// 单个模块的聚合搜索错误
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleSearchError {
+    pub module_id: String,
+    pub message: String,
+}
+
+/// 多模块聚合搜索结果，单个模块失败不影响其他模块
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedSearchResult {
+    pub results: Vec<ModuleSearchPage>,
+    pub errors: Vec<ModuleSearchError>,
+}
+
+/// 单个模块的预加载错误
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleLoadError {
+    pub module_id: String,
+    pub message: String,
+}
+
+/// 模块预加载结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModulePreloadResult {
+    /// 本次加载成功的模块
+    pub loaded: Vec<String>,
+    /// 已经加载过、跳过的模块
+    pub skipped: Vec<String>,
+    /// 加载失败的模块（含已禁用的模块）
+    pub failed: Vec<ModuleLoadError>,
+}
+
 /// 排序方式
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SortOption {
@@ -395,11 +395,11 @@ async function search(keyword, sortBy, page) {
  *    - console.error(...args) - 打印错误
  */
 
-// 示例: 使用存储保存登录状态
-async function login(username, password) {
+// 示例: 登录，返回的 token 会由 App 保存到 storage 的 session_token 中
+async function login(credentials) {
     const response = await http.post("https://api.example.com/auth/login", JSON.stringify({
-        username,
-        password,
+        username: credentials.username,
+        password: credentials.password,
     }), {
         "Content-Type": "application/json",
     });
@@ -407,17 +407,15 @@ async function login(username, password) {
     const data = JSON.parse(response);
     
     if (data.token) {
-        // 保存 token 到存储
-        storage.set("auth_token", data.token);
-        return true;
+        return { success: true, token: data.token };
     }
     
-    return false;
+    return { success: false, message: data.message || "登录失败" };
 }
 
 // 示例: 带认证的请求
 async function authenticatedRequest(url) {
-    const token = storage.get("auth_token");
+    const token = storage.get("session_token");
     
     const headers = {
         "Content-Type": "application/json",